@@ -0,0 +1,97 @@
+"""Concurrent resolve() throughput of the Rust container.
+
+Measures resolves per second with 1, 2, 4 and 8 threads hammering one
+container, for cached singletons (the hot path of a running service) and
+for a transient with two cached dependencies. Each thread resolves its own
+key set, so the numbers reflect registry and cache contention rather than
+construction cost.
+
+Usage:
+    uv run python benchmarks/concurrent_resolve.py
+    uv run python benchmarks/concurrent_resolve.py --seconds 5 --keys 64
+"""
+
+from __future__ import annotations
+
+import argparse
+import platform
+import sys
+import threading
+import time
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+def make_keys(count: int) -> list[type]:
+    return [type(f'Singleton{index}', (), {}) for index in range(count)]
+
+
+class Config:
+    pass
+
+
+class Repository:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Handler:
+    def __init__(self, repository: Repository, config: Config) -> None:
+        self.repository = repository
+
+
+def build_container(keys: list[type]) -> RustContainer:
+    container = RustContainer()
+    for key in keys:
+        container.register_singleton_factory(key, key)
+    container.register_class(Config, Config)
+    container.register_class(Repository, Repository)
+    container.register_transient_factory(Handler, Handler)
+    for key in [*keys, Handler]:
+        container.resolve(key)
+    return container
+
+
+def throughput(container: RustContainer, keys: list[type], threads: int, seconds: float) -> float:
+    """Resolves per second across all threads."""
+    stop = threading.Event()
+    counts = [0] * threads
+
+    def worker(slot: int) -> None:
+        own = keys[slot::threads] or keys
+        done = 0
+        while not stop.is_set():
+            for key in own:
+                container.resolve(key)
+            done += len(own)
+        counts[slot] = done
+
+    workers = [threading.Thread(target=worker, args=(slot,)) for slot in range(threads)]
+    started = time.perf_counter()
+    for thread in workers:
+        thread.start()
+    time.sleep(seconds)
+    stop.set()
+    for thread in workers:
+        thread.join()
+    return sum(counts) / (time.perf_counter() - started)
+
+
+def main() -> None:
+    parser = argparse.ArgumentParser(description=__doc__.splitlines()[0])
+    parser.add_argument('--seconds', type=float, default=2.0, help='measurement time per row')
+    parser.add_argument('--keys', type=int, default=32, help='distinct singleton keys')
+    args = parser.parse_args()
+
+    keys = make_keys(args.keys)
+    container = build_container(keys)
+    print(f'{platform.platform()}, Python {sys.version.split()[0]}')
+    print(f'{"threads":>7}  {"singletons/s":>14}  {"transients/s":>14}')
+    for threads in (1, 2, 4, 8):
+        singletons = throughput(container, keys, threads, args.seconds)
+        transients = throughput(container, [Handler], threads, args.seconds)
+        print(f'{threads:>7}  {singletons:>14,.0f}  {transients:>14,.0f}')
+
+
+if __name__ == '__main__':
+    main()
@@ -1,21 +1,496 @@
 """Type stubs for Rust core module."""
 
-from collections.abc import Callable
-from typing import TypeVar
+from collections.abc import Awaitable, Callable, Generator, Iterable, Mapping, Sequence
+from enum import Enum
+from os import PathLike
+from types import ModuleType
+from typing import Any, ClassVar, Generic, Literal, Protocol, TypeVar, overload
 
 T = TypeVar('T')
+ResolveHookT = TypeVar('ResolveHookT', bound=Callable[[type | Enum, str, bool, float], object])
+RegisterHookT = TypeVar('RegisterHookT', bound=Callable[[type | Enum, str, str], object])
+
+class DioxideError(KeyError):
+    """Base class for errors raised by the Rust container core."""
+
+    requested_type: type | Enum | None
+    resolution_path: list[type | Enum]
+
+class DependencyNotRegisteredError(DioxideError): ...
+class DuplicateRegistrationError(DioxideError): ...
+class ProviderRegistrationError(DioxideError): ...
+class CircularDependencyError(DioxideError): ...
+class UnresolvableParameterError(DioxideError): ...
+class NotCachedError(DioxideError): ...
+class SingletonsFrozenError(DioxideError): ...
+class TenantRequiredError(DioxideError): ...
+class FallbackCycleError(DioxideError): ...
+class AsyncProviderError(DioxideError): ...
+
+class ValidationError(DioxideError):
+    """Aggregated result of ``Container.validate()``."""
+
+    problems: list[str]
+
+class FrozenContainerError(DioxideError): ...
+class PoolExhaustedError(DioxideError): ...
+class ContainerClosedError(DioxideError): ...
+class EnvironmentVariableError(DioxideError): ...
+class WiringError(DioxideError): ...
+
+class Lifetime:
+    """How long an instance built by a class provider lives."""
+
+    SINGLETON: ClassVar[Lifetime]
+    TRANSIENT: ClassVar[Lifetime]
+    THREAD: ClassVar[Lifetime]
+
+    @property
+    def value(self) -> str: ...
+
+class DuplicatePolicy:
+    """What registering an already-registered key does."""
+
+    ERROR: ClassVar[DuplicatePolicy]
+    REPLACE: ClassVar[DuplicatePolicy]
+    IGNORE: ClassVar[DuplicatePolicy]
+
+    @property
+    def value(self) -> str: ...
 
 class Container:
-    """Rust-backed container implementation."""
-
-    def __init__(self) -> None: ...
-    def register_instance(self, py_type: type[T], instance: T) -> None: ...
-    def register_class(self, py_type: type[T], implementation: type[T]) -> None: ...
-    def register_singleton_factory(self, py_type: type[T], factory: Callable[[], T]) -> None: ...
-    def register_transient_factory(self, py_type: type[T], factory: Callable[[], T]) -> None: ...
-    def resolve(self, py_type: type[T]) -> T: ...
+    """Rust-backed container implementation.
+
+    Keys are classes or enum members (e.g. ``Environment.PROD``).
+    """
+
+    def __init__(
+        self,
+        on_duplicate: DuplicatePolicy | str | None = None,
+        profile: str | None = None,
+        autowire: bool = False,
+    ) -> None: ...
+    @staticmethod
+    def from_config(
+        path: str | PathLike[str],
+        on_duplicate: DuplicatePolicy | str | None = None,
+        profile: str | None = None,
+        autowire: bool = False,
+    ) -> Container: ...
+    def load_config(self, path: str | PathLike[str]) -> list[type | Enum]: ...
+    def duplicate_policy(self) -> DuplicatePolicy: ...
+    def profile(self) -> str | None: ...
+    def autowire(self) -> bool: ...
+    def set_duplicate_policy(self, policy: DuplicatePolicy | str) -> None: ...
+    def register_instance(
+        self,
+        key: type[T] | Enum,
+        instance: T,
+        type_check: bool = False,
+        copy: bool = False,
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register_class(
+        self,
+        key: type[T] | Enum,
+        implementation: type[T],
+        type_check: bool = False,
+        lifetime: Lifetime | str | None = None,
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        cache: Literal['strong', 'weak'] | None = None,
+        strict: bool = False,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+        before: Sequence[type | Enum] | None = None,
+        after: Sequence[type | Enum] | None = None,
+    ) -> None: ...
+    def register_singleton_factory(
+        self,
+        key: type[T] | Enum,
+        factory: Callable[..., T],
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        cache: Literal['strong', 'weak'] | None = None,
+        args: Sequence[object] | None = None,
+        kwargs: Mapping[str, object] | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register_transient_factory(
+        self,
+        key: type[T] | Enum,
+        factory: Callable[..., T],
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        args: Sequence[object] | None = None,
+        kwargs: Mapping[str, object] | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register_env(
+        self,
+        key: type | Enum,
+        variable: str,
+        cast: Callable[[str], object] | None = None,
+        required: bool = True,
+        default: object = None,
+        cache: bool = False,
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register_tenant_factory(
+        self,
+        key: type[T] | Enum,
+        factory: Callable[..., T],
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        args: Sequence[object] | None = None,
+        kwargs: Mapping[str, object] | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register_thread_factory(
+        self,
+        key: type[T] | Enum,
+        factory: Callable[..., T],
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        args: Sequence[object] | None = None,
+        kwargs: Mapping[str, object] | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register_pooled_factory(
+        self,
+        key: type[T] | Enum,
+        factory: Callable[..., T],
+        size: int,
+        timeout_ms: int | None = None,
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register_async_factory(
+        self,
+        key: type[T] | Enum,
+        factory: Callable[[], Awaitable[T]],
+        lifetime: Lifetime | str | None = None,
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register_generator_factory(
+        self,
+        key: type[T] | Enum,
+        factory: Callable[[], Generator[T, None, None]],
+        lifetime: Lifetime | str | None = None,
+        on_duplicate: DuplicatePolicy | str | None = None,
+        name: str | None = None,
+        profile: str | None = None,
+        tags: Sequence[str] | None = None,
+        description: str | None = None,
+    ) -> None: ...
+    def register(self, key: type | Enum) -> RegistrationBuilder: ...
+    def register_module(self, module: ModuleType) -> list[type | Enum]: ...
+    def install(self, bundle: Bundle) -> list[type | Enum]: ...
+    def scan(self, package: str, profile: str | None = None) -> list[type]: ...
+    def rebind_factory(self, key: type[T] | Enum, factory: Callable[[], T]) -> None: ...
+    def register_multi_instance(self, key: type[T] | Enum, instance: T, priority: int = 0) -> None: ...
+    def register_multi_class(
+        self,
+        key: type[T] | Enum,
+        implementation: type[T],
+        lifetime: Lifetime | str | None = None,
+        priority: int = 0,
+    ) -> None: ...
+    def register_multi_factory(
+        self,
+        key: type[T] | Enum,
+        factory: Callable[[], T],
+        lifetime: Lifetime | str | None = None,
+        priority: int = 0,
+        cache: Literal['strong', 'weak'] | None = None,
+    ) -> None: ...
+    def register_for_consumer(self, key: type[T] | Enum, consumer: type, provider: type[T] | T) -> None: ...
+    def resolve_all(self, key: type[T] | Enum) -> list[T]: ...
+    @overload
+    def resolve_async(self, key: type[T], tenant: str | None = None, name: str | None = None) -> AsyncResolve[T]: ...
+    @overload
+    def resolve_async(self, key: Enum, tenant: str | None = None, name: str | None = None) -> AsyncResolve[Any]: ...
+    @overload
+    def resolve(
+        self,
+        key: type[T],
+        tenant: str | None = None,
+        name: str | None = None,
+        overrides: Mapping[str, object] | None = None,
+    ) -> T: ...
+    @overload
+    def resolve(
+        self,
+        key: Enum,
+        tenant: str | None = None,
+        name: str | None = None,
+        overrides: Mapping[str, object] | None = None,
+    ) -> Any: ...
+    @overload
+    def try_resolve(self, key: type[T], tenant: str | None = None, name: str | None = None) -> T | None: ...
+    @overload
+    def try_resolve(self, key: Enum, tenant: str | None = None, name: str | None = None) -> Any: ...
+    def resolve_many(self, keys: Iterable[type | Enum], tenant: str | None = None) -> tuple[Any, ...]: ...
+    def validate(self) -> None: ...
+    def to_dot(self) -> str: ...
+    def list_registrations(self) -> list[dict[str, Any]]: ...
+    def registration_info(self, key: type | Enum, name: str | None = None) -> dict[str, Any] | None: ...
+    def to_json(self, indent: int | None = None) -> str: ...
+    def instantiate_all(self) -> list[Any]: ...
+    def build_order(self) -> list[Any]: ...
+    def healthcheck(self) -> dict[str, Any]: ...
+    def all_bindings(self, py_type: type) -> list[dict[str, Any]]: ...
+    def resolve_cached_only(self, keys: list[type | Enum], tenant: str | None = None) -> list[Any]: ...
+    def resolve_verbose(self, key: type | Enum, tenant: str | None = None) -> tuple[Any, dict[str, Any]]: ...
+    def resolve_as(self, key: type | Enum, expected_type: type[T]) -> T: ...
+    def on_resolve(self, hook: ResolveHookT) -> ResolveHookT: ...
+    def remove_resolve_hook(self, hook: Callable[[type | Enum, str, bool, float], object]) -> bool: ...
+    def on_register(self, hook: RegisterHookT) -> RegisterHookT: ...
+    def remove_register_hook(self, hook: Callable[[type | Enum, str, str], object]) -> bool: ...
+    def add_decorator(self, key: type[T] | Enum, decorator: Callable[[T], T]) -> None: ...
+    def add_interceptor(
+        self, interceptor: Callable[[Any], Any], key: type | Enum | None = None, tag: str | None = None
+    ) -> None: ...
+    def tag(self, key: type | Enum, *tags: str) -> None: ...
+    def tags(self, key: type | Enum) -> list[str]: ...
+    def is_equivalent_to(self, other: Container) -> bool: ...
+    def merge(
+        self, other: Container, on_conflict: Literal['error', 'prefer_self', 'prefer_other'] = 'error'
+    ) -> None: ...
+    def create_child(self) -> Container: ...
+    def add_fallback_container(self, other: Container) -> None: ...
     def is_empty(self) -> bool: ...
     def __len__(self) -> int: ...
     def reset(self) -> None: ...
-    def get_registered_types(self) -> list[type]: ...
-    def contains(self, py_type: type) -> bool: ...
+    def clear_singletons(self) -> None: ...
+    def release_thread_instances(self) -> int: ...
+    def unregister(self, key: type | Enum, name: str | None = None) -> None: ...
+    def clear(self) -> None: ...
+    def shutdown(self, timeout_ms: int) -> list[str]: ...
+    def close(self) -> list[str]: ...
+    def close_generators(self) -> list[str]: ...
+    def is_closed(self) -> bool: ...
+    def start(self) -> None: ...
+    def stop(self) -> list[str]: ...
+    def __enter__(self) -> Container: ...
+    def __exit__(self, exc_type: object, exc_value: object, traceback: object) -> bool: ...
+    def __aenter__(self) -> AsyncResolve[Container]: ...
+    def __aexit__(self, exc_type: object, exc_value: object, traceback: object) -> AsyncResolve[bool]: ...
+    def aclose(self) -> AsyncResolve[list[str]]: ...
+    def async_instantiate_all(self) -> AsyncResolve[list[Any]]: ...
+    def async_dispose(self) -> AsyncResolve[list[str]]: ...
+    def mark_unmanaged(self, key: type | Enum) -> None: ...
+    def register_config(self, values: Mapping[str, Any]) -> None: ...
+    def set_flag(self, name: str, value: object, invalidate: bool = False) -> None: ...
+    def dispose_tenant(self, tenant: str, timeout_ms: int) -> list[str]: ...
+    def freeze(self) -> None: ...
+    def is_frozen(self) -> bool: ...
+    def freeze_singletons(self) -> SingletonFreeze: ...
+    def cache_namespace(self, name: str) -> CacheNamespace: ...
+    def override(self, key: type[T] | Enum, instance: T, name: str | None = None) -> ProviderOverride[T]: ...
+    def snapshot_for_fork(self) -> ForkSnapshot: ...
+    def restore_fork_snapshot(self, snapshot: ForkSnapshot) -> None: ...
+    def snapshot(self) -> ContainerSnapshot: ...
+    def restore(self, snapshot: ContainerSnapshot) -> None: ...
+    def construction_log(self) -> list[str]: ...
+    def clear_construction_log(self) -> None: ...
+    def enable_transient_telemetry(self, window_ms: int = 1000) -> None: ...
+    def disable_transient_telemetry(self) -> None: ...
+    def transient_allocation_rates(self) -> dict[type | Enum, float]: ...
+    def stats(self) -> dict[str, Any]: ...
+    def reset_stats(self) -> None: ...
+    def get_registered_types(self) -> list[type | Enum]: ...
+    def check_shadowing(self) -> list[str]: ...
+    def duplicate_instances(self) -> list[dict[str, Any]]: ...
+    def contains(self, key: type | Enum, name: str | None = None) -> bool: ...
+    def is_registered(self, key: type | Enum, name: str | None = None) -> bool: ...
+    def __contains__(self, key: object) -> bool: ...
+
+class AsyncResolve(Generic[T]):
+    """Awaitable returned by ``Container.resolve_async()`` and the async lifecycle; await it once."""
+
+    def __await__(self) -> Generator[Any, Any, T]: ...
+
+class Lifespan:
+    """Async context manager from ``lifespan_context()``: warms singletons on entry, closes the container on exit."""
+
+    def __aenter__(self) -> AsyncResolve[Container]: ...
+    def __aexit__(self, exc_type: object, exc_value: object, traceback: object) -> AsyncResolve[bool]: ...
+
+class DoNotCache(Generic[T]):
+    """Factory return wrapper: return ``value`` without caching it as the singleton."""
+
+    value: T
+
+    def __init__(self, value: T) -> None: ...
+
+class BoundFactory(Generic[T]):
+    """Factory registered with bound ``args``/``kwargs``; call-time arguments merge on top."""
+
+    __wrapped__: Callable[..., T]
+    def __call__(self, *args: Any, **kwargs: Any) -> T: ...
+
+class SingletonFreeze:
+    """Marker returned by ``Container.freeze_singletons()``; ``release()`` lifts the freeze."""
+
+    def release(self) -> None: ...
+    def __enter__(self) -> SingletonFreeze: ...
+    def __exit__(self, exc_type: object, exc_value: object, traceback: object) -> bool: ...
+
+class CacheNamespace:
+    """Isolated singleton cache for resolves in this thread or asyncio task inside its ``with`` block."""
+
+    @property
+    def name(self) -> str: ...
+    def __enter__(self) -> CacheNamespace: ...
+    def __exit__(self, exc_type: object, exc_value: object, traceback: object) -> bool: ...
+
+class PooledInstance(Generic[T]):
+    """Instance borrowed from a pooled provider; ``release()`` or ``with`` returns it."""
+
+    @property
+    def value(self) -> T: ...
+    @property
+    def released(self) -> bool: ...
+    def release(self) -> None: ...
+    def __enter__(self) -> T: ...
+    def __exit__(self, exc_type: object, exc_value: object, traceback: object) -> bool: ...
+
+class Lazy(Generic[T]):
+    """Proxy injected for ``Lazy[X]`` parameters; resolves ``X`` on first attribute access."""
+
+    def __getattr__(self, name: str) -> Any: ...
+    def __call__(self, *args: Any, **kwargs: Any) -> Any: ...
+
+class Resolver(Generic[T]):
+    """Callable injected for ``Callable[[], X]`` parameters; resolves ``X`` on every call."""
+
+    def __call__(self) -> T: ...
+
+class ProviderOverride(Generic[T]):
+    """Binding swapped in by ``Container.override()`` until its ``with`` block exits."""
+
+    def __enter__(self) -> T: ...
+    def __exit__(self, exc_type: object, exc_value: object, traceback: object) -> bool: ...
+
+class ForkSnapshot:
+    """Warm singleton cache captured for forked workers.
+
+    Only fork-safe singletons should be shared this way; sockets, database
+    connections, threads and locks must be recreated after the fork.
+    """
+
+    def __len__(self) -> int: ...
+    def keys(self) -> list[type | Enum]: ...
+
+class ContainerSnapshot:
+    """Registry and singleton cache captured by ``Container.snapshot()``."""
+
+    def __len__(self) -> int: ...
+
+class RegistrationBuilder:
+    """Fluent registration returned by ``Container.register()``.
+
+    Choose exactly one of ``as_class()``, ``as_instance()`` or ``as_factory()``,
+    optionally ``scoped()``, ``named()`` and ``with_tags()``, then commit with
+    ``done()`` or a ``with`` block.
+    """
+
+    def as_class(self, cls: type) -> RegistrationBuilder: ...
+    def as_instance(self, instance: object) -> RegistrationBuilder: ...
+    def as_factory(self, factory: Callable[[], Any]) -> RegistrationBuilder: ...
+    def scoped(self, scope: str | Enum) -> RegistrationBuilder: ...
+    def named(self, name: str) -> RegistrationBuilder: ...
+    def with_tags(self, tags: Iterable[str]) -> RegistrationBuilder: ...
+    def done(self) -> None: ...
+    def __enter__(self) -> RegistrationBuilder: ...
+    def __exit__(self, exc_type: object, exc_value: object, traceback: object) -> bool: ...
+
+class Binder:
+    """Collects the registrations a bundle declares for ``Container.install()``."""
+
+    def bind_class(
+        self, key: type | Enum, cls: type, scope: str | Enum | None = None, name: str | None = None
+    ) -> None: ...
+    def bind_instance(self, key: type | Enum, instance: object, name: str | None = None) -> None: ...
+    def bind_factory(
+        self,
+        key: type | Enum,
+        factory: Callable[[], Any],
+        scope: str | Enum | None = None,
+        name: str | None = None,
+    ) -> None: ...
+    def install(self, bundle: Bundle) -> None: ...
+
+class Bundle(Protocol):
+    """Any object declaring registrations through ``configure(binder)``."""
+
+    def configure(self, binder: Binder, /) -> None: ...
+
+class Config:
+    """Marks an ``Annotated`` parameter as the configuration value ``key``."""
+
+    def __init__(self, key: str) -> None: ...
+    @property
+    def key(self) -> str: ...
+
+class Flag:
+    """Marks an ``Annotated`` parameter as the feature flag ``name``."""
+
+    def __init__(self, name: str) -> None: ...
+    @property
+    def name(self) -> str: ...
+
+class Default:
+    """Marks an ``Annotated`` parameter with the value injected when nothing else supplies it."""
+
+    def __init__(self, value: object) -> None: ...
+    @property
+    def value(self) -> Any: ...
+
+class ProviderSpec:
+    """Options recorded by ``@provider`` for ``Container.register_module()``."""
+
+    @property
+    def key(self) -> type | Enum | None: ...
+    @property
+    def scope(self) -> str | None: ...
+
+@overload
+def component(target: type[T], /) -> type[T]: ...
+@overload
+def component(
+    *, scope: str | Enum | None = None, name: str | None = None, tags: Sequence[str] | None = None
+) -> Callable[[type[T]], type[T]]: ...
+def post_inject(method: T, /) -> T: ...
+def lifespan_context(container: Container) -> Lifespan: ...
+@overload
+def provider(target: T, /) -> T: ...
+@overload
+def provider(
+    *, key: type | Enum | None = None, scope: str | Enum | None = None, name: str | None = None
+) -> Callable[[T], T]: ...
@@ -238,6 +238,7 @@ class AdapterDecorator:
         scope: Scope = Scope.SINGLETON,
         multi: bool = False,
         priority: int = 0,
+        enter_context: bool = False,
     ) -> Callable[[type[T]], type[T]]:
         """Register an adapter for a port with profile(s) and optional scope.
 
@@ -294,6 +295,11 @@ class AdapterDecorator:
             priority: Ordering priority for multi-bindings (only used when
                 ``multi=True``). Lower values are instantiated first. Default is 0.
                 Use negative values to run before default, positive to run after.
+            enter_context: Enter instances that are context managers when a
+                scope creates them, and exit them when that scope closes. Applies
+                to REQUEST and FACTORY instances resolved through a ScopedContainer.
+                Async context managers must be resolved with ``scope.aresolve()``.
+                Default is ``False``.
 
         Returns:
             Decorator function that marks the class as an adapter. The decorated
@@ -467,6 +473,7 @@ class AdapterDecorator:
             cls.__dioxide_scope__ = scope  # type: ignore[attr-defined]
             cls.__dioxide_multi__ = multi  # type: ignore[attr-defined]
             cls.__dioxide_priority__ = priority  # type: ignore[attr-defined]
+            cls.__dioxide_enter_context__ = enter_context  # type: ignore[attr-defined]
 
             # Register with global registry
             _adapter_registry.add(cls)
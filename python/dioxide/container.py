@@ -775,15 +775,16 @@ class Container:
     def register_class(self, component_type: type[T], implementation: type[T]) -> None:
         """Register a class to instantiate for a given type.
 
-        Registers a class that will be instantiated with no arguments when
-        the type is resolved. The class's __init__ method will be called
-        without parameters.
+        Registers a class that will be instantiated on first resolve and
+        cached, like a singleton factory. Constructor parameters annotated
+        with registered types are resolved from this container; other
+        parameters keep their defaults.
 
         Args:
             component_type: The type to register. This is used as the lookup
                 key when resolving dependencies.
-            implementation: The class to instantiate. Must have a no-argument
-                __init__ method (or no __init__ at all).
+            implementation: The class to instantiate. Every __init__
+                parameter must have a registered type annotation or a default.
 
         Raises:
             KeyError: If the type is already registered in this container.
@@ -801,8 +802,8 @@ class Container:
             >>> assert db.connected is True
 
         Note:
-            For classes requiring constructor arguments, use
-            register_singleton_factory() or register_transient_factory()
+            For classes requiring arguments that are not registered types,
+            use register_singleton_factory() or register_transient_factory()
             with a lambda that provides the arguments.
         """
         self._rust_core.register_class(component_type, implementation)
@@ -956,7 +957,7 @@ class Container:
         """
         self.register_transient_factory(component_type, factory)
 
-    def resolve(self, component_type: type[T]) -> T:
+    def resolve(self, component_type: type[T], scope: ScopedContainer | None = None) -> T:
         """Resolve a component instance.
 
         Retrieves or creates an instance of the requested type based on its
@@ -970,6 +971,10 @@ class Container:
             component_type: The type to resolve. Must have been previously
                 registered via scan() or manual registration methods.
                 Can be a ``list[Port]`` type to resolve multi-bindings.
+            scope: Resolve within this scope instead of the container itself.
+                REQUEST-scoped components are cached in that scope, whichever
+                scope the caller is currently running in. Must be an open scope
+                created by this container's ``create_scope()``.
 
         Returns:
             An instance of the requested type. For SINGLETON scope, the same
@@ -984,7 +989,8 @@ class Container:
                 be resolved (not registered or has unresolvable dependencies).
             ScopeError: If trying to resolve a REQUEST-scoped component outside
                 of a scope context. Use ``container.create_scope()`` to create
-                a scope.
+                a scope. Also raised if ``scope`` has been closed or belongs to
+                a different container.
 
         Example:
             >>> from dioxide import Container, component
@@ -1008,17 +1014,26 @@ class Container:
             Type annotations in constructors enable automatic dependency
             injection. The container recursively resolves all dependencies.
         """
+        # Resolve on behalf of an explicit scope, independent of the caller's scope
+        if scope is not None:
+            component_name = getattr(component_type, '__name__', repr(component_type))
+            if scope.parent is not self:
+                raise ScopeError(f'Cannot resolve {component_name}: scope {scope.scope_id} belongs to another container')
+            if scope.closed:
+                raise ScopeError(f'Cannot resolve {component_name}: scope {scope.scope_id} is closed')
+            return scope.resolve(component_type)
+
         # Check if this is a list[Port] type hint for multi-bindings
         multi_binding_result = self._resolve_multi_binding(component_type)
         if multi_binding_result is not None:
             return multi_binding_result  # type: ignore[return-value]
 
         # Check if this is a REQUEST-scoped component being resolved outside a scope
-        scope = self._get_component_scope(component_type)
-        if scope is not None:
+        component_scope = self._get_component_scope(component_type)
+        if component_scope is not None:
             from dioxide.scope import Scope
 
-            if scope == Scope.REQUEST:
+            if component_scope == Scope.REQUEST:
                 component_name = component_type.__name__
                 raise ScopeError(f'Cannot resolve {component_name}: REQUEST-scoped, requires active scope')
 
@@ -3454,12 +3469,20 @@ class ScopedContainer:
         self._scope_id = scope_id
         self._request_cache: dict[type[Any], Any] = {}
         self._lifecycle_instances: list[Any] = []  # Track for disposal
+        self._entered_contexts: list[Any] = []  # Exited in reverse on scope close
+        self._pending_async_contexts: list[Any] | None = None  # Set while aresolve() runs
+        self._closed = False
 
     @property
     def scope_id(self) -> str:
         """Get the unique identifier for this scope."""
         return self._scope_id
 
+    @property
+    def closed(self) -> bool:
+        """Whether this scope has exited and disposed its components."""
+        return self._closed
+
     @property
     def parent(self) -> Container:
         """Get the parent container."""
@@ -3529,6 +3552,7 @@ class ScopedContainer:
 
             # Create new instance using parent's factory logic
             instance = self._create_instance(component_type)
+            self._enter_context(instance)
 
             # Cache in scope
             self._request_cache[component_type] = instance
@@ -3541,7 +3565,76 @@ class ScopedContainer:
 
         else:  # FACTORY
             # Always create new instance, no caching
-            return self._create_instance(component_type)
+            instance = self._create_instance(component_type)
+            self._enter_context(instance)
+            return instance
+
+    async def aresolve(self, component_type: type[T]) -> T:
+        """Resolve a component within this scope, entering async context managers.
+
+        Behaves like :meth:`resolve`, but components registered with
+        ``enter_context=True`` that are async context managers (``__aenter__``
+        / ``__aexit__``) are entered with ``await`` once the requested
+        component and its dependencies have been created. They are exited
+        when the scope closes.
+
+        Args:
+            component_type: The type to resolve.
+
+        Returns:
+            An instance of the requested type.
+
+        Example:
+            >>> @service(scope=Scope.REQUEST, enter_context=True)
+            ... class Transaction:
+            ...     async def __aenter__(self) -> Transaction: ...
+            ...     async def __aexit__(self, *exc_info: object) -> None: ...
+            >>>
+            >>> async with container.create_scope() as scope:
+            ...     tx = await scope.aresolve(Transaction)  # entered
+            ... # exited here
+        """
+        pending: list[Any] = []
+        self._pending_async_contexts = pending
+        try:
+            instance = self.resolve(component_type)
+        finally:
+            self._pending_async_contexts = None
+
+        # Dependencies were queued first, so they are entered first
+        for manager in pending:
+            await manager.__aenter__()
+            self._entered_contexts.append(manager)
+
+        return instance
+
+    def _enter_context(self, instance: Any) -> None:
+        """Enter a newly created instance registered with ``enter_context=True``.
+
+        Sync context managers are entered immediately. Async context managers
+        are queued for :meth:`aresolve` to await, and cannot be resolved with
+        the synchronous :meth:`resolve`.
+
+        Args:
+            instance: The instance this scope just created.
+
+        Raises:
+            ScopeError: If the instance is an async context manager and no
+                ``aresolve()`` call is in progress.
+        """
+        if not getattr(type(instance), '__dioxide_enter_context__', False):
+            return
+
+        if hasattr(instance, '__enter__') and hasattr(instance, '__exit__'):
+            instance.__enter__()
+            self._entered_contexts.append(instance)
+        elif hasattr(instance, '__aenter__') and hasattr(instance, '__aexit__'):
+            if self._pending_async_contexts is None:
+                component_name = type(instance).__name__
+                raise ScopeError(
+                    f'Cannot resolve {component_name}: async context manager, use await scope.aresolve() instead'
+                )
+            self._pending_async_contexts.append(instance)
 
     def _get_component_scope(self, component_type: type[Any]) -> Scope:
         """Get the scope for a component type.
@@ -3696,6 +3789,28 @@ class ScopedContainer:
 
         self._lifecycle_instances.clear()
 
+    async def _exit_contexts(
+        self,
+        exc_type: type[BaseException] | None,
+        exc_val: BaseException | None,
+        exc_tb: Any,
+    ) -> None:
+        """Exit entered context managers in reverse order.
+
+        Each manager receives the exception that ended the scope, if any, so
+        resources like transactions can roll back.
+        """
+        for manager in reversed(self._entered_contexts):
+            try:
+                if hasattr(manager, '__aexit__') and not hasattr(manager, '__exit__'):
+                    await manager.__aexit__(exc_type, exc_val, exc_tb)
+                else:
+                    manager.__exit__(exc_type, exc_val, exc_tb)
+            except Exception as e:
+                logger.error(f'Error exiting scoped component {manager.__class__.__name__}: {e}')
+
+        self._entered_contexts.clear()
+
 
 class ScopedContainerContextManager:
     """Async context manager for ScopedContainer.
@@ -3739,7 +3854,8 @@ class ScopedContainerContextManager:
     ) -> None:
         """Exit the scope context.
 
-        Disposes all REQUEST-scoped lifecycle components.
+        Exits entered context managers, then disposes all REQUEST-scoped
+        lifecycle components.
 
         Args:
             exc_type: Exception type if an exception was raised.
@@ -3747,7 +3863,9 @@ class ScopedContainerContextManager:
             exc_tb: Exception traceback if an exception was raised.
         """
         if self._scope is not None:
+            await self._scope._exit_contexts(exc_type, exc_val, exc_tb)
             await self._scope._dispose_lifecycle_components()
+            self._scope._closed = True
             self._scope = None
 
 
@@ -180,6 +180,7 @@ def service(cls: type[T]) -> type[T]: ...
 def service(
     *,
     scope: Scope = Scope.SINGLETON,
+    enter_context: bool = False,
 ) -> Callable[[type[T]], type[T]]: ...
 
 
@@ -187,6 +188,7 @@ def service(
     cls: type[T] | None = None,
     *,
     scope: Scope = Scope.SINGLETON,
+    enter_context: bool = False,
 ) -> type[T] | Callable[[type[T]], type[T]]:
     """Mark a class as a core domain service.
 
@@ -254,6 +256,10 @@ def service(
             - SINGLETON: One shared instance for the lifetime of the container
             - REQUEST: One instance per scope (via container.create_scope())
             - FACTORY: New instance on every resolve()
+        enter_context: Enter instances that are context managers when a scope
+            creates them, and exit them when that scope closes. Applies to
+            REQUEST and FACTORY instances resolved through a ScopedContainer.
+            Async context managers must be resolved with ``scope.aresolve()``.
 
     Returns:
         The decorated class with dioxide metadata attached, or a decorator
@@ -287,6 +293,7 @@ def service(
 
         # Store DI metadata on the class
         cls_to_decorate.__dioxide_scope__ = scope  # type: ignore[attr-defined]
+        cls_to_decorate.__dioxide_enter_context__ = enter_context  # type: ignore[attr-defined]
         cls_to_decorate.__dioxide_profiles__ = frozenset(['*'])  # type: ignore[attr-defined]  # Available in all profiles
         # Add to global registry for auto-discovery
         _component_registry.add(cls_to_decorate)
@@ -1,15 +1,40 @@
+use pyo3::exceptions::{
+    PyException, PyKeyError, PyRuntimeError, PyRuntimeWarning, PyStopIteration, PyTimeoutError,
+    PyTypeError, PyValueError,
+};
 use pyo3::prelude::*;
-use pyo3::types::PyType;
-use std::collections::HashMap;
+use pyo3::type_object::PyTypeCheck;
+use pyo3::types::{
+    PyBool, PyCFunction, PyDict, PyFrozenSet, PyList, PyModule, PySet, PyString, PyTuple, PyType,
+    PyWeakrefReference,
+};
+use std::borrow::Cow;
+use std::cell::{Cell, RefCell};
+use std::collections::hash_map::Entry;
+use std::collections::{BTreeSet, BinaryHeap, HashMap, HashSet, VecDeque};
+use std::ffi::CString;
 use std::hash::{Hash, Hasher};
-use std::sync::{Arc, RwLock};
+use std::sync::atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, OnceLock, RwLock, Weak};
+use std::thread::{self, ThreadId};
+use std::time::{Duration, Instant};
 use thiserror::Error;
 
 /// Container error types
 #[derive(Error, Debug)]
 pub enum ContainerError {
-    #[error("Dependency not registered: {type_name}")]
-    DependencyNotRegistered { type_name: String },
+    #[error("Dependency not registered: {type_name}{suggestions}")]
+    DependencyNotRegistered {
+        type_name: String,
+        /// Near-miss registrations, as " (did you mean X?)", or empty
+        suggestions: String,
+    },
+
+    #[error("Enum member not registered: {member_name} (registered members: {registered})")]
+    EnumMemberNotRegistered {
+        member_name: String,
+        registered: String,
+    },
 
     #[error("Provider registration failed: {type_name} - {reason}")]
     ProviderRegistrationFailed { type_name: String, reason: String },
@@ -17,49 +42,568 @@ pub enum ContainerError {
     #[error("Duplicate provider registration: {type_name}")]
     DuplicateRegistration { type_name: String },
 
+    #[error("Dependencies not cached (warm-up missing): {type_names}")]
+    NotCached { type_names: String },
+
+    #[error("Cannot create singleton {type_name}: singleton creation is frozen")]
+    SingletonsFrozen { type_name: String },
+
+    #[error("Cannot resolve {type_name}: per-tenant provider requires a tenant id")]
+    TenantRequired { type_name: String },
+
+    #[error("Resolved {type_name} to an instance of {actual}, expected an instance of {expected}")]
+    UnexpectedType {
+        type_name: String,
+        expected: String,
+        actual: String,
+    },
+
+    #[error("Cannot auto-wire {type_name}: parameter '{parameter}' has no registered type annotation and no default")]
+    UnresolvableParameter {
+        type_name: String,
+        parameter: String,
+    },
+
+    #[error("Cannot auto-wire {type_name}: parameter '{parameter}' needs configuration value '{config_key}', which is not registered")]
+    MissingConfigValue {
+        type_name: String,
+        parameter: String,
+        config_key: String,
+    },
+
+    #[error("Cannot auto-wire {type_name}: parameter '{parameter}' needs feature flag '{flag}', which is not set")]
+    MissingFlag {
+        type_name: String,
+        parameter: String,
+        flag: String,
+    },
+
+    #[error("Cannot resolve {type_name}: environment variable {variable} is not set")]
+    MissingEnvironmentVariable { type_name: String, variable: String },
+
+    #[error("Cannot resolve {type_name}: environment variable {variable} is invalid: {reason}")]
+    InvalidEnvironmentVariable {
+        type_name: String,
+        variable: String,
+        reason: String,
+    },
+
+    #[error("Invalid wiring in {file}{}: {reason}", entry.as_ref().map(|entry| format!(" at {entry}")).unwrap_or_default())]
+    InvalidWiring {
+        file: String,
+        entry: Option<String>,
+        reason: String,
+    },
+
+    #[error("Circular dependency detected: {cycle}")]
+    CircularDependency { cycle: String },
+
+    #[error("Fallback container would create a cycle")]
+    FallbackCycle,
+
+    #[error(
+        "Cannot resolve {type_name} synchronously: it has an async provider; use resolve_async()"
+    )]
+    AsyncProviderRequired { type_name: String },
+
+    #[error("Container validation failed: {}", problems.join("; "))]
+    ValidationFailed { problems: Vec<String> },
+
+    #[error("Cannot {action}: container is frozen")]
+    ContainerFrozen { action: String },
+
+    #[error("Pool for {type_name} is exhausted: all {size} instances are borrowed")]
+    PoolExhausted { type_name: String, size: usize },
+
+    #[error(
+        "Cannot override arguments of {type_name}: {kind} providers are not called per resolve"
+    )]
+    OverridesUnsupported {
+        type_name: String,
+        kind: &'static str,
+    },
+
+    #[error("Cannot resolve {type_name}: container is closed")]
+    ContainerClosed { type_name: String },
+
+    /// Raised by Python code the container called (a factory, a
+    /// constructor, a hook), kept so it becomes the `__cause__`
     #[error("Python error: {0}")]
-    PythonError(String),
+    PythonError(PyErr),
+}
+
+// Python exception hierarchy: name, base and docstring. Each module
+// instance creates its own types (so every interpreter that imports the
+// module raises its own); the base subclasses KeyError so callers that catch
+// KeyError (including dioxide.Container) keep working
+const EXCEPTIONS: [(&str, &str, &str); 17] = [
+    (
+        "DioxideError",
+        "KeyError",
+        "Base class for errors raised by the Rust container core",
+    ),
+    (
+        "DependencyNotRegisteredError",
+        "DioxideError",
+        "No provider is registered for the requested key",
+    ),
+    (
+        "DuplicateRegistrationError",
+        "DioxideError",
+        "The key already has a provider",
+    ),
+    (
+        "ProviderRegistrationError",
+        "DioxideError",
+        "A provider could not be registered or rebound",
+    ),
+    (
+        "CircularDependencyError",
+        "DioxideError",
+        "A key depends on itself, directly or indirectly",
+    ),
+    (
+        "UnresolvableParameterError",
+        "DioxideError",
+        "A constructor parameter cannot be auto-wired",
+    ),
+    (
+        "NotCachedError",
+        "DioxideError",
+        "A cached-only resolve found keys that were never warmed up",
+    ),
+    (
+        "SingletonsFrozenError",
+        "DioxideError",
+        "A singleton would be created while singleton creation is frozen",
+    ),
+    (
+        "TenantRequiredError",
+        "DioxideError",
+        "A per-tenant provider was resolved without a tenant id",
+    ),
+    (
+        "FallbackCycleError",
+        "DioxideError",
+        "Adding a fallback container would create a cycle",
+    ),
+    (
+        "AsyncProviderError",
+        "DioxideError",
+        "An async provider was resolved synchronously",
+    ),
+    (
+        "ValidationError",
+        "DioxideError",
+        "Static validation found registrations that cannot be resolved",
+    ),
+    (
+        "FrozenContainerError",
+        "DioxideError",
+        "The registry was changed after the container was frozen",
+    ),
+    (
+        "PoolExhaustedError",
+        "DioxideError",
+        "Every instance of a pooled provider is borrowed",
+    ),
+    (
+        "ContainerClosedError",
+        "DioxideError",
+        "A key was resolved after the container was closed",
+    ),
+    (
+        "EnvironmentVariableError",
+        "DioxideError",
+        "An environment variable provider found its variable unset or invalid",
+    ),
+    (
+        "WiringError",
+        "DioxideError",
+        "A wiring file could not be read or has an invalid binding",
+    ),
+];
+
+/// Keys of a resolution path with their display names, outermost first
+type ResolutionPath = Vec<(Py<PyAny>, String)>;
+
+thread_local! {
+    /// Keys under construction when the innermost resolve failure on this
+    /// thread happened, ending with the key that failed
+    static FAILURE_PATH: RefCell<Option<ResolutionPath>> = const { RefCell::new(None) };
+}
+
+impl ContainerError {
+    /// Convert into the matching `DioxideError` subclass
+    ///
+    /// The exception carries `requested_type` (the key the caller asked for,
+    /// if any) and `resolution_path` (keys under construction when a resolve
+    /// failed, outermost first; empty otherwise). A `ValidationError` also
+    /// carries its `problems` list. An error raised by Python code becomes
+    /// the `__cause__`, so its traceback is shown too. A failure below the
+    /// requested key ends its message with the chain that led there, as
+    /// "(while resolving A, needed B, needed C)".
+    fn into_py_err(self, py: Python, requested: Option<&Bound<'_, PyAny>>) -> PyErr {
+        let path = FAILURE_PATH
+            .with(|path| path.borrow_mut().take())
+            .unwrap_or_default();
+        let mut message = self.to_string();
+        // A cycle's message already is the chain
+        if path.len() > 1 && !matches!(self, ContainerError::CircularDependency { .. }) {
+            let (outermost, needed) = path.split_first().unwrap();
+            message.push_str(&format!(" (while resolving {}", outermost.1));
+            for (_, name) in needed {
+                message.push_str(&format!(", needed {name}"));
+            }
+            message.push(')');
+        }
+        let mut problems = None;
+        let mut cause = None;
+        let exception = match self {
+            ContainerError::DependencyNotRegistered { .. }
+            | ContainerError::EnumMemberNotRegistered { .. } => "DependencyNotRegisteredError",
+            ContainerError::DuplicateRegistration { .. } => "DuplicateRegistrationError",
+            ContainerError::ProviderRegistrationFailed { .. } => "ProviderRegistrationError",
+            ContainerError::CircularDependency { .. } => "CircularDependencyError",
+            ContainerError::UnresolvableParameter { .. }
+            | ContainerError::MissingConfigValue { .. }
+            | ContainerError::MissingFlag { .. } => "UnresolvableParameterError",
+            ContainerError::NotCached { .. } => "NotCachedError",
+            ContainerError::SingletonsFrozen { .. } => "SingletonsFrozenError",
+            ContainerError::TenantRequired { .. } => "TenantRequiredError",
+            ContainerError::FallbackCycle => "FallbackCycleError",
+            ContainerError::AsyncProviderRequired { .. } => "AsyncProviderError",
+            ContainerError::ValidationFailed { problems: found } => {
+                problems = Some(found);
+                "ValidationError"
+            }
+            ContainerError::ContainerFrozen { .. } => "FrozenContainerError",
+            ContainerError::PoolExhausted { .. } => "PoolExhaustedError",
+            ContainerError::ContainerClosed { .. } => "ContainerClosedError",
+            ContainerError::MissingEnvironmentVariable { .. }
+            | ContainerError::InvalidEnvironmentVariable { .. } => "EnvironmentVariableError",
+            ContainerError::InvalidWiring { .. } => "WiringError",
+            ContainerError::PythonError(raised) => {
+                cause = Some(raised);
+                "DioxideError"
+            }
+            ContainerError::UnexpectedType { .. } | ContainerError::OverridesUnsupported { .. } => {
+                "DioxideError"
+            }
+        };
+        let err = module_error(py, exception, message);
+        if cause.is_some() {
+            err.set_cause(py, cause);
+        }
+
+        let value = err.value(py);
+        let path = path.into_iter().map(|(key, _)| key);
+        let attached = value
+            .setattr("requested_type", requested.map(|key| key.clone().unbind()))
+            .and_then(|_| value.setattr("resolution_path", PyList::new(py, path)?))
+            .and_then(|_| match problems {
+                Some(problems) => value.setattr("problems", PyList::new(py, problems)?),
+                None => Ok(()),
+            });
+        match attached {
+            Ok(()) => err,
+            Err(attach_err) => attach_err,
+        }
+    }
 }
 
 impl From<PyErr> for ContainerError {
     fn from(err: PyErr) -> Self {
-        ContainerError::PythonError(err.to_string())
+        ContainerError::PythonError(err)
     }
 }
 
-/// Type key for provider registry (Python type object)
+/// Registry key variants
 #[derive(Debug)]
-pub struct TypeKey {
+pub enum Key {
     /// Python type object (class)
-    py_type: Py<PyType>,
+    Type(Py<PyType>),
+
+    /// Enum member (e.g. `Environment.PROD`), hashed by its value
+    EnumMember {
+        enum_type: Py<PyType>,
+        member: Py<PyAny>,
+        value_hash: isize,
+    },
+
+    /// Parameterized generic (e.g. `Repository[User]`), compared by origin
+    /// and type arguments, since each subscription builds a new alias
+    Generic {
+        origin: Py<PyType>,
+        alias: Py<PyAny>,
+        args: Vec<GenericArg>,
+    },
+}
+
+/// One type argument of a generic key
+#[derive(Debug)]
+pub enum GenericArg {
+    /// A class, enum member or nested generic, compared as a key
+    Key(TypeKey),
+
+    /// Anything else (`Any`, a `TypeVar`, `None`), compared by identity
+    Object(Py<PyAny>),
+}
+
+impl GenericArg {
+    fn clone_ref(&self, py: Python) -> Self {
+        match self {
+            GenericArg::Key(key) => GenericArg::Key(key.clone()),
+            GenericArg::Object(obj) => GenericArg::Object(obj.clone_ref(py)),
+        }
+    }
+}
+
+impl Hash for GenericArg {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        match self {
+            GenericArg::Key(key) => key.hash(state),
+            GenericArg::Object(obj) => obj.as_ptr().hash(state),
+        }
+    }
+}
+
+impl PartialEq for GenericArg {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (GenericArg::Key(a), GenericArg::Key(b)) => a == b,
+            (GenericArg::Object(a), GenericArg::Object(b)) => a.as_ptr() == b.as_ptr(),
+            _ => false,
+        }
+    }
+}
+
+/// Type key for provider registry
+///
+/// An optional name qualifies the key, so `(Database, "replica")` and the
+/// unnamed `Database` are separate bindings.
+#[derive(Debug)]
+pub struct TypeKey {
+    key: Key,
+    name: Option<String>,
 }
 
 impl TypeKey {
     pub fn new(py_type: Py<PyType>) -> Self {
-        TypeKey { py_type }
+        TypeKey {
+            key: Key::Type(py_type),
+            name: None,
+        }
+    }
+
+    /// Qualify this key with a binding name (or clear it with `None`)
+    pub fn with_name(mut self, name: Option<String>) -> Self {
+        self.name = name;
+        self
+    }
+
+    /// The binding name, if this key is qualified
+    pub fn name(&self) -> Option<&str> {
+        self.name.as_deref()
+    }
+
+    /// Build a key from a Python object (a class, an enum member or a
+    /// parameterized generic class)
+    pub fn from_object(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(py_type) = obj.cast::<PyType>() {
+            return Ok(TypeKey::new(py_type.clone().unbind()));
+        }
+
+        let py = obj.py();
+        let enum_base = py.import("enum")?.getattr("Enum")?;
+        if obj.is_instance(&enum_base)? {
+            // Unhashable enum values fall back to a constant hash; equality
+            // still distinguishes members by identity
+            let value_hash = obj
+                .getattr("value")
+                .and_then(|value| value.hash())
+                .unwrap_or(0);
+            return Ok(TypeKey {
+                key: Key::EnumMember {
+                    enum_type: obj.get_type().unbind(),
+                    member: obj.clone().unbind(),
+                    value_hash,
+                },
+                name: None,
+            });
+        }
+
+        // Unions (`X | None`) have a type as their origin too, but are not
+        // a class that can be bound
+        let typing = py.import("typing")?;
+        let origin = typing.call_method1("get_origin", (obj,))?;
+        let union = py.import("types")?.getattr("UnionType")?;
+        if let Ok(origin) = origin.cast::<PyType>() {
+            let args = typing.call_method1("get_args", (obj,))?;
+            if !origin.is(&union) && !args.is_empty()? {
+                let args = args
+                    .try_iter()?
+                    .map(|arg| {
+                        let arg = arg?;
+                        Ok(match TypeKey::from_object(&arg) {
+                            Ok(key) => GenericArg::Key(key),
+                            Err(_) => GenericArg::Object(arg.unbind()),
+                        })
+                    })
+                    .collect::<PyResult<_>>()?;
+                return Ok(TypeKey {
+                    key: Key::Generic {
+                        origin: origin.clone().unbind(),
+                        alias: obj.clone().unbind(),
+                        args,
+                    },
+                    name: None,
+                });
+            }
+        }
+
+        Err(PyTypeError::new_err(format!(
+            "Container key must be a type or an enum member, got {}",
+            obj.repr()?
+        )))
     }
 
     pub fn type_name(&self, py: Python) -> String {
-        self.py_type
-            .bind(py)
-            .name()
-            .map(|n| n.to_string())
-            .unwrap_or_else(|_| "<unknown>".to_string())
+        let base = self.base_type_name(py);
+        match &self.name {
+            Some(name) => format!("{base}[name={name}]"),
+            None => base,
+        }
+    }
+
+    fn base_type_name(&self, py: Python) -> String {
+        match &self.key {
+            Key::Type(py_type) => py_type
+                .bind(py)
+                .name()
+                .map(|n| n.to_string())
+                .unwrap_or_else(|_| "<unknown>".to_string()),
+            Key::EnumMember {
+                enum_type, member, ..
+            } => {
+                let enum_name = enum_type
+                    .bind(py)
+                    .name()
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|_| "<unknown>".to_string());
+                let member_name = member
+                    .bind(py)
+                    .getattr("name")
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|_| "<unknown>".to_string());
+                format!("{}.{}", enum_name, member_name)
+            }
+            Key::Generic { origin, args, .. } => {
+                let args: Vec<String> = args
+                    .iter()
+                    .map(|arg| match arg {
+                        GenericArg::Key(key) => key.base_type_name(py),
+                        GenericArg::Object(obj) => obj
+                            .bind(py)
+                            .repr()
+                            .map(|r| r.to_string())
+                            .unwrap_or_else(|_| "<unknown>".to_string()),
+                    })
+                    .collect();
+                let origin = origin
+                    .bind(py)
+                    .name()
+                    .map(|n| n.to_string())
+                    .unwrap_or_else(|_| "<unknown>".to_string());
+                format!("{origin}[{}]", args.join(", "))
+            }
+        }
+    }
+
+    /// The Python object this key was built from
+    pub fn py_object(&self, py: Python) -> Py<PyAny> {
+        match &self.key {
+            Key::Type(py_type) => py_type.clone_ref(py).into_any(),
+            Key::EnumMember { member, .. } => member.clone_ref(py),
+            Key::Generic { alias, .. } => alias.clone_ref(py),
+        }
+    }
+
+    /// The Python type object, if this is a type key (for a generic, its
+    /// unparameterized class)
+    fn as_type(&self) -> Option<&Py<PyType>> {
+        match &self.key {
+            Key::Type(py_type)
+            | Key::Generic {
+                origin: py_type, ..
+            } => Some(py_type),
+            Key::EnumMember { .. } => None,
+        }
+    }
+
+    /// Check if this key is a member of the given enum class
+    fn is_member_of(&self, enum_class: &Py<PyType>) -> bool {
+        match &self.key {
+            Key::EnumMember { enum_type, .. } => enum_type.as_ptr() == enum_class.as_ptr(),
+            Key::Type(_) | Key::Generic { .. } => false,
+        }
     }
 }
 
 impl Hash for TypeKey {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // Hash the pointer to the Python type object
-        // This is safe because type objects are immortal
-        self.py_type.as_ptr().hash(state);
+        match &self.key {
+            Key::Type(py_type) => {
+                // Hash the pointer to the Python type object
+                // This is safe because type objects are immortal
+                0u8.hash(state);
+                py_type.as_ptr().hash(state);
+            }
+            Key::EnumMember {
+                enum_type,
+                value_hash,
+                ..
+            } => {
+                1u8.hash(state);
+                enum_type.as_ptr().hash(state);
+                value_hash.hash(state);
+            }
+            Key::Generic { origin, args, .. } => {
+                2u8.hash(state);
+                origin.as_ptr().hash(state);
+                args.hash(state);
+            }
+        }
+        self.name.hash(state);
     }
 }
 
 impl PartialEq for TypeKey {
     fn eq(&self, other: &Self) -> bool {
-        // Compare pointer equality (type objects are unique)
-        self.py_type.as_ptr() == other.py_type.as_ptr()
+        if self.name != other.name {
+            return false;
+        }
+        match (&self.key, &other.key) {
+            // Compare pointer equality (type objects are unique)
+            (Key::Type(a), Key::Type(b)) => a.as_ptr() == b.as_ptr(),
+            // Enum members are singletons, so identity is value equality
+            (Key::EnumMember { member: a, .. }, Key::EnumMember { member: b, .. }) => {
+                a.as_ptr() == b.as_ptr()
+            }
+            (
+                Key::Generic {
+                    origin: a_origin,
+                    args: a_args,
+                    ..
+                },
+                Key::Generic {
+                    origin: b_origin,
+                    args: b_args,
+                    ..
+                },
+            ) => a_origin.as_ptr() == b_origin.as_ptr() && a_args == b_args,
+            _ => false,
+        }
     }
 }
 
@@ -68,8 +612,240 @@ impl Eq for TypeKey {}
 impl Clone for TypeKey {
     fn clone(&self) -> Self {
         Python::attach(|py| TypeKey {
-            py_type: self.py_type.clone_ref(py),
+            key: match &self.key {
+                Key::Type(py_type) => Key::Type(py_type.clone_ref(py)),
+                Key::EnumMember {
+                    enum_type,
+                    member,
+                    value_hash,
+                } => Key::EnumMember {
+                    enum_type: enum_type.clone_ref(py),
+                    member: member.clone_ref(py),
+                    value_hash: *value_hash,
+                },
+                Key::Generic {
+                    origin,
+                    alias,
+                    args,
+                } => Key::Generic {
+                    origin: origin.clone_ref(py),
+                    alias: alias.clone_ref(py),
+                    args: args.iter().map(|arg| arg.clone_ref(py)).collect(),
+                },
+            },
+            name: self.name.clone(),
+        })
+    }
+}
+
+/// How long an instance built by a class provider lives
+#[pyclass(eq, eq_int, from_py_object)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Lifetime {
+    /// Constructed on first resolve and cached
+    #[pyo3(name = "SINGLETON")]
+    Singleton,
+
+    /// Constructed on every resolve
+    #[pyo3(name = "TRANSIENT")]
+    Transient,
+
+    /// Constructed once per OS thread and cached for that thread
+    #[pyo3(name = "THREAD")]
+    Thread,
+}
+
+#[pymethods]
+impl Lifetime {
+    /// Lowercase name, matching `dioxide.Scope` values
+    #[getter]
+    fn value(&self) -> &'static str {
+        match self {
+            Lifetime::Singleton => "singleton",
+            Lifetime::Transient => "transient",
+            Lifetime::Thread => "thread",
+        }
+    }
+}
+
+impl Lifetime {
+    /// Accept a `Lifetime` member or its string value
+    fn from_object(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(lifetime) = obj.extract::<Lifetime>() {
+            return Ok(lifetime);
+        }
+        match obj.extract::<String>()?.as_str() {
+            "singleton" => Ok(Lifetime::Singleton),
+            "transient" => Ok(Lifetime::Transient),
+            "thread" => Ok(Lifetime::Thread),
+            other => Err(PyTypeError::new_err(format!(
+                "Unknown lifetime {other:?}; expected singleton, transient or thread"
+            ))),
+        }
+    }
+}
+
+/// Parse a registration's `cache` mode; `true` means cache through a weakref
+fn parse_weak_cache(cache: Option<&str>) -> PyResult<bool> {
+    match cache {
+        None | Some("strong") => Ok(false),
+        Some("weak") => Ok(true),
+        Some(other) => Err(PyTypeError::new_err(format!(
+            "Unknown cache mode {other:?}; expected strong or weak"
+        ))),
+    }
+}
+
+/// One parameter of an `inspect.Signature`: (name, kind, has default)
+type SignatureParam = (String, String, bool);
+
+fn signature_params(signature: &Bound<'_, PyAny>) -> PyResult<Vec<SignatureParam>> {
+    let empty = signature
+        .py()
+        .import("inspect")?
+        .getattr("Parameter")?
+        .getattr("empty")?;
+    signature
+        .getattr("parameters")?
+        .call_method0("values")?
+        .try_iter()?
+        .map(|param| {
+            let param = param?;
+            Ok((
+                param.getattr("name")?.extract()?,
+                param.getattr("kind")?.getattr("name")?.extract()?,
+                !param.getattr("default")?.is(&empty),
+            ))
         })
+        .collect()
+}
+
+/// Whether a method with signature `actual` accepts every call that a
+/// method with signature `expected` does
+///
+/// Each expected parameter must be matched, by position when it is
+/// positional-only and by name otherwise, unless `*args` / `**kwargs`
+/// absorbs it; each required parameter of `actual` must be one the
+/// expected signature supplies.
+fn signature_accepts(actual: &Bound<'_, PyAny>, expected: &Bound<'_, PyAny>) -> PyResult<bool> {
+    let actual = signature_params(actual)?;
+    let expected = signature_params(expected)?;
+    let is_var = |kind: &str| kind == "VAR_POSITIONAL" || kind == "VAR_KEYWORD";
+    let has_kind = |params: &[SignatureParam], kind: &str| params.iter().any(|(_, k, _)| k == kind);
+    let positional = |params: &[SignatureParam]| {
+        params
+            .iter()
+            .filter(|(_, kind, _)| kind == "POSITIONAL_ONLY" || kind == "POSITIONAL_OR_KEYWORD")
+            .count()
+    };
+    let by_name = |params: &[SignatureParam], name: &str| {
+        params
+            .iter()
+            .any(|(n, kind, _)| n == name && kind != "POSITIONAL_ONLY" && !is_var(kind))
+    };
+
+    for (index, (name, kind, _)) in expected.iter().enumerate() {
+        let matched = match kind.as_str() {
+            "VAR_POSITIONAL" => has_kind(&actual, "VAR_POSITIONAL"),
+            "VAR_KEYWORD" => has_kind(&actual, "VAR_KEYWORD"),
+            "POSITIONAL_ONLY" => positional(&actual) > index || has_kind(&actual, "VAR_POSITIONAL"),
+            _ => by_name(&actual, name) || has_kind(&actual, "VAR_KEYWORD"),
+        };
+        if !matched {
+            return Ok(false);
+        }
+    }
+    for (index, (name, kind, has_default)) in actual.iter().enumerate() {
+        if *has_default || is_var(kind) {
+            continue;
+        }
+        let supplied = if kind == "POSITIONAL_ONLY" {
+            positional(&expected) > index
+        } else {
+            by_name(&expected, name)
+                || (kind == "POSITIONAL_OR_KEYWORD" && index < positional(&expected))
+        };
+        if !supplied {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// What registering an already-registered key does
+#[pyclass(eq, eq_int, from_py_object)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DuplicatePolicy {
+    /// Reject the registration (the default)
+    #[pyo3(name = "ERROR")]
+    Error,
+
+    /// Replace the existing provider and evict what it cached
+    #[pyo3(name = "REPLACE")]
+    Replace,
+
+    /// Keep the existing provider and drop the new one
+    #[pyo3(name = "IGNORE")]
+    Ignore,
+}
+
+#[pymethods]
+impl DuplicatePolicy {
+    /// Lowercase name, as accepted wherever a policy is expected
+    #[getter]
+    fn value(&self) -> &'static str {
+        match self {
+            DuplicatePolicy::Error => "error",
+            DuplicatePolicy::Replace => "replace",
+            DuplicatePolicy::Ignore => "ignore",
+        }
+    }
+}
+
+impl DuplicatePolicy {
+    /// Accept a `DuplicatePolicy` member or its string value
+    fn from_object(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        if let Ok(policy) = obj.extract::<DuplicatePolicy>() {
+            return Ok(policy);
+        }
+        match obj.extract::<String>()?.as_str() {
+            "error" => Ok(DuplicatePolicy::Error),
+            "replace" => Ok(DuplicatePolicy::Replace),
+            "ignore" => Ok(DuplicatePolicy::Ignore),
+            other => Err(PyTypeError::new_err(format!(
+                "Unknown duplicate policy {other:?}; expected error, replace or ignore"
+            ))),
+        }
+    }
+
+    /// Parse an optional policy argument
+    fn from_optional(obj: Option<&Bound<'_, PyAny>>) -> PyResult<Option<Self>> {
+        obj.map(DuplicatePolicy::from_object).transpose()
+    }
+}
+
+/// Which binding `RustContainer::merge()` keeps for a key both containers register
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum MergeConflict {
+    /// Reject the merge, changing nothing
+    Error,
+    /// Keep this container's binding
+    PreferSelf,
+    /// Take the other container's binding
+    PreferOther,
+}
+
+impl MergeConflict {
+    /// Parse an `on_conflict` argument
+    fn from_value(value: &str) -> PyResult<Self> {
+        match value {
+            "error" => Ok(MergeConflict::Error),
+            "prefer_self" => Ok(MergeConflict::PreferSelf),
+            "prefer_other" => Ok(MergeConflict::PreferOther),
+            other => Err(PyTypeError::new_err(format!(
+                "Unknown conflict policy {other:?}; expected error, prefer_self or prefer_other"
+            ))),
+        }
     }
 }
 
@@ -78,289 +854,9709 @@ pub enum Provider {
     /// Pre-created instance
     Instance(Py<PyAny>),
 
-    /// Class to instantiate (calls __init__)
-    Class(Py<PyType>),
+    /// Template instance deep-copied on every resolve (prototype - transient)
+    Prototype(Py<PyAny>),
+
+    /// Class to instantiate, auto-wiring __init__; cached (per thread for
+    /// the thread lifetime) unless transient
+    Class(Py<PyType>, Lifetime),
 
     /// Factory function to invoke (singleton - caches result)
     SingletonFactory(Py<PyAny>),
 
+    /// Class or factory whose singleton is cached through a weak reference,
+    /// so it is rebuilt once Python has collected it
+    WeakSingleton(Py<PyAny>),
+
     /// Factory function to invoke (transient - creates new each time)
     TransientFactory(Py<PyAny>),
+
+    /// Factory function to invoke (per tenant - caches one result per tenant id)
+    TenantFactory(Py<PyAny>),
+
+    /// Factory function to invoke (per thread - caches one result per OS thread)
+    ThreadFactory(Py<PyAny>),
+
+    /// Bounded pool of factory-built instances, borrowed by each resolve
+    Pooled(Arc<ObjectPool>),
+
+    /// Coroutine function awaited by `resolve_async`; cached unless transient
+    AsyncFactory(Py<PyAny>, Lifetime),
+
+    /// Generator function: its first yield is the instance, and the code
+    /// after it runs on `close()`; cached unless transient
+    GeneratorFactory(Py<PyAny>, Lifetime),
+
+    /// Environment variable read on every resolve, or once when cached
+    Env(Arc<EnvVariable>),
+}
+
+/// Environment variable read by `Provider::Env`
+pub struct EnvVariable {
+    /// Variable name, as a Python string so it identifies the registration
+    variable: Py<PyString>,
+    /// Applied to the raw string; `bool` parses true/false words
+    cast: Option<Py<PyAny>>,
+    /// Resolved when the variable is unset
+    default: Option<Py<PyAny>>,
+    /// Whether an unset variable without a default fails the resolve
+    /// rather than resolving to None
+    required: bool,
+    /// Read once and cache the value as a singleton
+    cached: bool,
+}
+
+impl EnvVariable {
+    /// Read and cast the variable from `os.environ`
+    fn read(&self, py: Python, type_key: &TypeKey) -> Result<Py<PyAny>, ContainerError> {
+        let variable = self.variable.bind(py);
+        let raw = py
+            .import("os")?
+            .getattr("environ")?
+            .call_method1("get", (variable,))?;
+        if raw.is_none() {
+            return match (&self.default, self.required) {
+                (Some(default), _) => Ok(default.clone_ref(py)),
+                (None, false) => Ok(py.None()),
+                (None, true) => Err(ContainerError::MissingEnvironmentVariable {
+                    type_name: type_key.type_name(py),
+                    variable: variable.to_string(),
+                }),
+            };
+        }
+        let Some(cast) = &self.cast else {
+            return Ok(raw.unbind());
+        };
+        let invalid = |reason: String| ContainerError::InvalidEnvironmentVariable {
+            type_name: type_key.type_name(py),
+            variable: variable.to_string(),
+            reason,
+        };
+        let cast = cast.bind(py);
+        if cast.is(py.get_type::<PyBool>()) {
+            let raw: String = raw.extract()?;
+            let value = match raw.trim().to_ascii_lowercase().as_str() {
+                "1" | "true" | "yes" | "on" => true,
+                "0" | "false" | "no" | "off" | "" => false,
+                _ => return Err(invalid(format!("{raw:?} is not a boolean"))),
+            };
+            return Ok(PyBool::new(py, value).to_owned().into_any().unbind());
+        }
+        cast.call1((&raw,))
+            .map(Bound::unbind)
+            .map_err(|err| invalid(err.to_string()))
+    }
+}
+
+impl Provider {
+    /// The wrapped instance, class or factory, identifying this registration
+    fn target_ptr(&self) -> *mut pyo3::ffi::PyObject {
+        match self {
+            Provider::Instance(obj)
+            | Provider::Prototype(obj)
+            | Provider::SingletonFactory(obj)
+            | Provider::WeakSingleton(obj)
+            | Provider::TransientFactory(obj)
+            | Provider::TenantFactory(obj)
+            | Provider::ThreadFactory(obj)
+            | Provider::AsyncFactory(obj, _)
+            | Provider::GeneratorFactory(obj, _) => obj.as_ptr(),
+            Provider::Class(cls, _) => cls.as_ptr(),
+            Provider::Pooled(pool) => pool.factory.as_ptr(),
+            Provider::Env(env) => env.variable.as_ptr(),
+        }
+    }
+
+    /// Short name of the provider kind for introspection
+    pub fn kind_name(&self) -> &'static str {
+        match self {
+            Provider::Instance(_) => "instance",
+            Provider::Prototype(_) => "prototype",
+            Provider::Class(_, Lifetime::Singleton) => "class",
+            Provider::Class(_, Lifetime::Transient) => "transient_class",
+            Provider::Class(_, Lifetime::Thread) => "thread_class",
+            Provider::SingletonFactory(_) => "singleton_factory",
+            Provider::WeakSingleton(_) => "weak_singleton",
+            Provider::TransientFactory(_) => "transient_factory",
+            Provider::TenantFactory(_) => "tenant_factory",
+            Provider::ThreadFactory(_) => "thread_factory",
+            Provider::Pooled(_) => "pooled_factory",
+            // Async and generator factories are never registered per thread
+            Provider::AsyncFactory(_, Lifetime::Singleton | Lifetime::Thread) => "async_factory",
+            Provider::AsyncFactory(_, Lifetime::Transient) => "transient_async_factory",
+            Provider::GeneratorFactory(_, Lifetime::Singleton | Lifetime::Thread) => {
+                "generator_factory"
+            }
+            Provider::GeneratorFactory(_, Lifetime::Transient) => "transient_generator_factory",
+            Provider::Env(_) => "env",
+        }
+    }
+
+    /// How long the provided instance lives: singleton, transient, tenant,
+    /// thread or pooled
+    pub fn lifetime_name(&self) -> &'static str {
+        match self {
+            Provider::Instance(_)
+            | Provider::SingletonFactory(_)
+            | Provider::WeakSingleton(_)
+            | Provider::Class(_, Lifetime::Singleton)
+            | Provider::AsyncFactory(_, Lifetime::Singleton | Lifetime::Thread)
+            | Provider::GeneratorFactory(_, Lifetime::Singleton | Lifetime::Thread) => "singleton",
+            Provider::Prototype(_)
+            | Provider::TransientFactory(_)
+            | Provider::Class(_, Lifetime::Transient)
+            | Provider::AsyncFactory(_, Lifetime::Transient)
+            | Provider::GeneratorFactory(_, Lifetime::Transient) => "transient",
+            Provider::TenantFactory(_) => "tenant",
+            Provider::Class(_, Lifetime::Thread) | Provider::ThreadFactory(_) => "thread",
+            Provider::Pooled(_) => "pooled",
+            Provider::Env(env) if env.cached => "singleton",
+            Provider::Env(_) => "transient",
+        }
+    }
 }
 
 impl Clone for Provider {
     fn clone(&self) -> Self {
-        Python::attach(|py| match self {
+        Python::attach(|py| self.clone_ref(py))
+    }
+}
+
+impl Provider {
+    /// Clone with the GIL already held
+    fn clone_ref(&self, py: Python) -> Self {
+        match self {
             Provider::Instance(obj) => Provider::Instance(obj.clone_ref(py)),
-            Provider::Class(cls) => Provider::Class(cls.clone_ref(py)),
+            Provider::Prototype(obj) => Provider::Prototype(obj.clone_ref(py)),
+            Provider::Class(cls, lifetime) => Provider::Class(cls.clone_ref(py), *lifetime),
             Provider::SingletonFactory(factory) => {
                 Provider::SingletonFactory(factory.clone_ref(py))
             }
+            Provider::WeakSingleton(factory) => Provider::WeakSingleton(factory.clone_ref(py)),
             Provider::TransientFactory(factory) => {
                 Provider::TransientFactory(factory.clone_ref(py))
             }
-        })
+            Provider::TenantFactory(factory) => Provider::TenantFactory(factory.clone_ref(py)),
+            Provider::ThreadFactory(factory) => Provider::ThreadFactory(factory.clone_ref(py)),
+            Provider::Pooled(pool) => Provider::Pooled(Arc::clone(pool)),
+            Provider::AsyncFactory(factory, lifetime) => {
+                Provider::AsyncFactory(factory.clone_ref(py), *lifetime)
+            }
+            Provider::GeneratorFactory(factory, lifetime) => {
+                Provider::GeneratorFactory(factory.clone_ref(py), *lifetime)
+            }
+            Provider::Env(env) => Provider::Env(Arc::clone(env)),
+        }
     }
 }
 
-/// Core Rust container implementation
-pub struct RustContainer {
-    /// Provider registry: maps Python type to Provider
-    providers: Arc<RwLock<HashMap<TypeKey, Provider>>>,
+/// Bounded object pool behind a pooled provider
+///
+/// At most `size` instances exist at once. A borrow takes an idle instance,
+/// or builds a new one while the pool has room; otherwise it waits up to
+/// `timeout` (forever when `None`) for a `PooledInstance` to be returned.
+pub struct ObjectPool {
+    factory: Py<PyAny>,
+    size: usize,
+    timeout: Option<Duration>,
+    state: Mutex<PoolState>,
+    returned: Condvar,
+}
 
-    /// Singleton instance cache: maps Python type to cached instance
-    singletons: Arc<RwLock<HashMap<TypeKey, Py<PyAny>>>>,
+struct PoolState {
+    /// Returned instances ready to be borrowed again, most recent last
+    idle: Vec<Py<PyAny>>,
+    /// Instances in existence: idle, borrowed or being built
+    live: usize,
 }
 
-impl RustContainer {
-    /// Create a new empty container
-    pub fn new() -> Self {
-        RustContainer {
-            providers: Arc::new(RwLock::new(HashMap::new())),
-            singletons: Arc::new(RwLock::new(HashMap::new())),
+/// What `ObjectPool::acquire()` grants a borrower
+enum PoolSlot {
+    /// A returned instance to reuse
+    Idle(Py<PyAny>),
+    /// Room to build a new instance
+    Vacant,
+}
+
+impl ObjectPool {
+    fn new(factory: Py<PyAny>, size: usize, timeout: Option<Duration>) -> Self {
+        ObjectPool {
+            factory,
+            size,
+            timeout,
+            state: Mutex::new(PoolState {
+                idle: Vec::new(),
+                live: 0,
+            }),
+            returned: Condvar::new(),
         }
     }
 
-    /// Register an instance provider
-    pub fn register_instance(
-        &self,
-        py: Python,
-        type_key: TypeKey,
-        instance: Py<PyAny>,
-    ) -> Result<(), ContainerError> {
-        let mut providers = self.providers.write().unwrap();
+    fn take_slot(&self, state: &mut PoolState) -> Option<PoolSlot> {
+        if let Some(instance) = state.idle.pop() {
+            return Some(PoolSlot::Idle(instance));
+        }
+        if state.live < self.size {
+            state.live += 1;
+            return Some(PoolSlot::Vacant);
+        }
+        None
+    }
 
-        // Check for duplicate registration
-        if providers.contains_key(&type_key) {
-            return Err(ContainerError::DuplicateRegistration {
-                type_name: type_key.type_name(py),
-            });
+    /// Grant a slot, waiting (with the GIL released) while the pool is
+    /// exhausted; `None` once the timeout passes
+    fn acquire(&self, py: Python) -> Option<PoolSlot> {
+        if let Some(slot) = self.take_slot(&mut self.state.lock().unwrap()) {
+            return Some(slot);
+        }
+        if self.timeout == Some(Duration::ZERO) {
+            return None;
         }
+        let deadline = self.timeout.map(|timeout| Instant::now() + timeout);
+        py.detach(|| {
+            let mut state = self.state.lock().unwrap();
+            loop {
+                if let Some(slot) = self.take_slot(&mut state) {
+                    return Some(slot);
+                }
+                state = match deadline {
+                    None => self.returned.wait(state).unwrap(),
+                    Some(deadline) => {
+                        let remaining = deadline.saturating_duration_since(Instant::now());
+                        if remaining.is_zero() {
+                            return None;
+                        }
+                        self.returned.wait_timeout(state, remaining).unwrap().0
+                    }
+                };
+            }
+        })
+    }
 
-        providers.insert(type_key, Provider::Instance(instance));
-        Ok(())
+    /// Put a borrowed instance back for the next borrower
+    fn give_back(&self, instance: Py<PyAny>) {
+        self.state.lock().unwrap().idle.push(instance);
+        self.returned.notify_one();
     }
 
-    /// Register a class provider
-    pub fn register_class(
-        &self,
-        py: Python,
-        type_key: TypeKey,
-        class: Py<PyType>,
-    ) -> Result<(), ContainerError> {
-        let mut providers = self.providers.write().unwrap();
+    /// Free the slot of an instance that failed to build
+    fn forget_one(&self) {
+        self.state.lock().unwrap().live -= 1;
+        self.returned.notify_one();
+    }
+}
 
-        // Check for duplicate registration
-        if providers.contains_key(&type_key) {
-            return Err(ContainerError::DuplicateRegistration {
-                type_name: type_key.type_name(py),
-            });
-        }
+/// Cached instances of one type keyed by tenant id
+type TenantPartitions = HashMap<String, Py<PyAny>>;
 
-        providers.insert(type_key, Provider::Class(class));
-        Ok(())
-    }
+/// Thread-lifetime instances of each OS thread, by key
+type ThreadPartitions = HashMap<ThreadId, HashMap<TypeKey, Py<PyAny>>>;
 
-    /// Register a singleton factory provider (caches result)
-    pub fn register_singleton_factory(
-        &self,
-        py: Python,
-        type_key: TypeKey,
-        factory: Py<PyAny>,
-    ) -> Result<(), ContainerError> {
-        let mut providers = self.providers.write().unwrap();
+/// Number of independently locked shards in the singleton cache
+const SINGLETON_SHARDS: usize = 16;
 
-        // Check for duplicate registration
-        if providers.contains_key(&type_key) {
-            return Err(ContainerError::DuplicateRegistration {
-                type_name: type_key.type_name(py),
-            });
+/// Multiplicative hash picking a cache shard
+///
+/// The shard map hashes the key again with its own (DoS-resistant) hasher,
+/// so picking the shard must be cheap; keys hash object pointers, which
+/// this spreads well enough.
+struct ShardHasher(u64);
+
+impl Hasher for ShardHasher {
+    fn finish(&self) -> u64 {
+        self.0
+    }
+
+    fn write(&mut self, bytes: &[u8]) {
+        for chunk in bytes.chunks(8) {
+            let mut word = [0u8; 8];
+            word[..chunk.len()].copy_from_slice(chunk);
+            self.write_u64(u64::from_le_bytes(word));
         }
+    }
 
-        providers.insert(type_key, Provider::SingletonFactory(factory));
-        Ok(())
+    fn write_u64(&mut self, word: u64) {
+        self.0 = (self.0.rotate_left(5) ^ word).wrapping_mul(0x517c_c1b7_2722_0a95);
     }
 
-    /// Register a transient factory provider (creates new instance each time)
-    pub fn register_transient_factory(
+    fn write_usize(&mut self, word: usize) {
+        self.write_u64(word as u64);
+    }
+}
+
+/// A singleton cache entry
+enum CachedInstance {
+    Strong(Py<PyAny>),
+    /// Weak reference to the instance, revalidated on every read
+    Weak(Py<PyWeakrefReference>),
+}
+
+impl CachedInstance {
+    fn clone_ref(&self, py: Python) -> Self {
+        match self {
+            CachedInstance::Strong(instance) => CachedInstance::Strong(instance.clone_ref(py)),
+            CachedInstance::Weak(reference) => CachedInstance::Weak(reference.clone_ref(py)),
+        }
+    }
+
+    /// The cached instance, unless a weakly cached one was collected
+    ///
+    /// Dereferencing a weak reference runs no Python code, so this is safe
+    /// under a cache lock.
+    fn upgrade(&self, py: Python) -> Option<Py<PyAny>> {
+        match self {
+            CachedInstance::Strong(instance) => Some(instance.clone_ref(py)),
+            CachedInstance::Weak(reference) => reference.bind(py).upgrade().map(Bound::unbind),
+        }
+    }
+
+    /// The object the cache held, for the caller to drop
+    fn into_object(self) -> Py<PyAny> {
+        match self {
+            CachedInstance::Strong(instance) => instance,
+            CachedInstance::Weak(reference) => reference.into_any(),
+        }
+    }
+}
+
+/// Singleton instance cache split into independently locked shards
+///
+/// Every resolve consults this cache, so a single lock would serialize
+/// threads resolving unrelated keys; each key locks only its own shard.
+/// Operations spanning shards (length, snapshots, clearing) visit the shards
+/// one at a time and are not atomic across them.
+struct SingletonCache {
+    shards: Vec<RwLock<HashMap<TypeKey, CachedInstance>>>,
+}
+
+impl SingletonCache {
+    fn new() -> Self {
+        SingletonCache {
+            shards: (0..SINGLETON_SHARDS)
+                .map(|_| RwLock::new(HashMap::new()))
+                .collect(),
+        }
+    }
+
+    fn shard(&self, type_key: &TypeKey) -> &RwLock<HashMap<TypeKey, CachedInstance>> {
+        let mut hasher = ShardHasher(0);
+        type_key.hash(&mut hasher);
+        &self.shards[(hasher.finish() >> 32) as usize % SINGLETON_SHARDS]
+    }
+
+    fn get(&self, py: Python, type_key: &TypeKey) -> Option<Py<PyAny>> {
+        let shard = self.shard(type_key).read().unwrap();
+        shard.get(type_key)?.upgrade(py)
+    }
+
+    fn contains_key(&self, py: Python, type_key: &TypeKey) -> bool {
+        self.get(py, type_key).is_some()
+    }
+
+    /// Cache `instance` unless the key already has one; returns the
+    /// instance that was already cached, if any
+    fn insert_if_absent(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        instance: &Py<PyAny>,
+    ) -> Option<Py<PyAny>> {
+        self.insert_entry_if_absent(py, type_key, CachedInstance::Strong(instance.clone_ref(py)))
+    }
+
+    /// Cache `entry` unless the key already has a live instance (a collected
+    /// weak entry is replaced); returns that instance, if any
+    fn insert_entry_if_absent(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        entry: CachedInstance,
+    ) -> Option<Py<PyAny>> {
+        let mut shard = self.shard(type_key).write().unwrap();
+        let collected = match shard.entry(type_key.clone()) {
+            Entry::Occupied(mut existing) => match existing.get().upgrade(py) {
+                Some(instance) => return Some(instance),
+                None => existing.insert(entry),
+            },
+            Entry::Vacant(vacant) => {
+                vacant.insert(entry);
+                return None;
+            }
+        };
+        drop(shard);
+        drop(collected);
+        None
+    }
+
+    fn remove(&self, type_key: &TypeKey) -> Option<CachedInstance> {
+        self.shard(type_key).write().unwrap().remove(type_key)
+    }
+
+    fn len(&self) -> usize {
+        self.shards
+            .iter()
+            .map(|shard| shard.read().unwrap().len())
+            .sum()
+    }
+
+    /// Every cached key and live instance, in no particular order
+    fn entries(&self, py: Python) -> Vec<(TypeKey, Py<PyAny>)> {
+        self.shards
+            .iter()
+            .flat_map(|shard| {
+                let shard = shard.read().unwrap();
+                shard
+                    .iter()
+                    .filter_map(|(key, entry)| Some((key.clone(), entry.upgrade(py)?)))
+                    .collect::<Vec<_>>()
+            })
+            .collect()
+    }
+
+    /// Empty the cache, returning the instances for the caller to drop
+    /// once it holds no lock
+    fn take_all(&self) -> Vec<Py<PyAny>> {
+        self.shards
+            .iter()
+            .flat_map(|shard| std::mem::take(&mut *shard.write().unwrap()).into_values())
+            .map(CachedInstance::into_object)
+            .collect()
+    }
+}
+
+thread_local! {
+    /// Instances created by providers on this thread, across all containers;
+    /// nested resolves run on the caller's thread, so deltas count a whole graph
+    static CONSTRUCTIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Per-call diagnostics returned by `RustContainer::resolve_verbose`
+pub struct ResolveInfo {
+    pub cache_hit: bool,
+    pub constructions: usize,
+    pub elapsed: Duration,
+    pub provider: &'static str,
+}
+
+/// One `__init__` parameter, as seen by constructor auto-wiring
+struct ConstructorParam {
+    name: String,
+    /// Registry key for the annotation, when it is a type or enum member
+    key: Option<TypeKey>,
+    /// Default value, when the signature declares one
+    default: Option<Py<PyAny>>,
+    /// Annotated `Optional[X]` / `X | None`: receives None when X is unbound
+    optional: bool,
+    positional_only: bool,
+    /// Index among the parameters that can be passed positionally
+    position: Option<usize>,
+    /// Annotated `Lazy[X]`: injected as a proxy resolved on first use
+    lazy: bool,
+    /// Annotated `Callable[[], X]`: injected as a callable resolving X on
+    /// every call
+    deferred: bool,
+    /// Element key of a `list[X]`, `tuple[X, ...]`, `set[X]`, `frozenset[X]`
+    /// or `dict[str, X]` annotation: unless the collection itself is bound,
+    /// it is assembled from X's bindings
+    collection: Option<(Collection, TypeKey)>,
+    /// Annotated `Annotated[X, Config("name")]`: injected from the
+    /// registered configuration value `name`
+    config: Option<String>,
+    /// Annotated `Annotated[X, Flag("name")]`: injected from the feature
+    /// flag `name`
+    flag: Option<String>,
+    /// Annotated `Annotated[X, Default(value)]`: injected when no provider,
+    /// configuration value or flag supplies the parameter
+    fallback: Option<Py<PyAny>>,
+}
+
+/// How a collection-typed constructor parameter is assembled
+#[derive(Clone, Copy, PartialEq)]
+enum Collection {
+    /// `list[X]` / `Sequence[X]`: every provider multi-bound to X
+    List,
+    /// `tuple[X, ...]` (or `tuple[X]`): the multi-bound providers as a tuple
+    Tuple,
+    /// `set[X]` / `MutableSet[X]`: the multi-bound providers as a set
+    Set,
+    /// `frozenset[X]` / `Set[X]`: the multi-bound providers as a frozenset
+    FrozenSet,
+    /// `dict[str, X]` / `Mapping[str, X]`: binding name to instance, for
+    /// every named binding of X
+    Mapping,
+}
+
+impl ConstructorParam {
+    /// Whether auto-wiring fails when nothing provides this parameter
+    fn required(&self) -> bool {
+        self.default.is_none()
+            && self.fallback.is_none()
+            && !self.optional
+            && self.collection.is_none()
+    }
+
+    /// Whether an empty collection must stand in for this parameter, which
+    /// has nothing else to fall back on
+    fn required_collection(&self) -> bool {
+        self.default.is_none() && self.fallback.is_none() && !self.optional
+    }
+
+    /// Whether the caller passes this parameter among explicit arguments
+    fn given(&self, positional: usize, kwargs: &Bound<'_, PyDict>) -> PyResult<bool> {
+        if self.position.is_some_and(|position| position < positional) {
+            return Ok(true);
+        }
+        Ok(!self.positional_only && kwargs.contains(&self.name)?)
+    }
+}
+
+/// Inspected constructor parameters, by class
+type ConstructorParams = HashMap<TypeKey, Arc<Vec<ConstructorParam>>>;
+
+/// Names of the methods called once a class is constructed, by class
+type PostInjectHooks = HashMap<TypeKey, Arc<Vec<String>>>;
+
+/// Source of registry generations, shared by every container so that a
+/// change anywhere yields a value no container has seen before
+static REGISTRY_GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Compiled constructor call for one class
+///
+/// Records which `__init__` parameters auto-wiring fills, so a resolve
+/// replays it instead of re-deciding every parameter. Valid while the
+/// registry generation (here and in every fallback) is unchanged.
+struct ResolutionPlan {
+    generation: u64,
+    /// Parameters resolved on every call, in signature order
+    slots: Vec<PlanSlot>,
+    /// Parameters injected from registered configuration values
+    configured: Vec<usize>,
+    /// Parameters injected from feature flags
+    flagged: Vec<usize>,
+    /// Parameters nothing provides, given the value of their `Default`
+    /// marker
+    defaulted: Vec<usize>,
+    /// Required parameters nothing provides (as indexes into `params`);
+    /// construction fails unless the caller passes them explicitly
+    unresolved: Vec<usize>,
+    /// Optional parameters nothing provides and without a default; they
+    /// receive None
+    unbound: Vec<usize>,
+    params: Arc<Vec<ConstructorParam>>,
+}
+
+/// One resolved argument of a `ResolutionPlan`
+struct PlanSlot {
+    /// Index of the parameter in the class's `ConstructorParams`
+    param: usize,
+    key: TypeKey,
+    /// Assembled from the bindings of `key` rather than resolving it
+    collection: Option<Collection>,
+    /// Served by the provider registered for this consumer and `key`
+    /// rather than `key`'s own
+    overridden: bool,
+}
+
+/// One step of `close()`
+enum Finalizer {
+    /// Dispose an instance, named for reports
+    Dispose(String, Py<PyAny>),
+    /// Finish a generator factory
+    Teardown(Teardown),
+}
+
+/// A started generator factory awaiting finalization by `close()`
+struct Teardown {
+    type_key: TypeKey,
+    generator: Py<PyAny>,
+    /// Whether the yielded instance went into the singleton cache
+    cached: bool,
+}
+
+/// Started lifecycle components as (type name, instance)
+type StartedComponents = Vec<(String, Py<PyAny>)>;
+
+/// Tracked transients outside a tenant, as (key, weak reference) in
+/// creation order
+type Resources = Vec<(TypeKey, Py<PyWeakrefReference>)>;
+
+/// Tracked transients by tenant, as (key, instance) in creation order
+type TenantTransients = HashMap<String, Vec<(TypeKey, Py<PyAny>)>>;
+
+/// Interceptors as (tag, interceptor), in the order they were added
+type TagInterceptors = Vec<(String, Py<PyAny>)>;
+
+/// Singleton caches isolated per cache namespace name
+type NamespacePartitions = HashMap<String, HashMap<TypeKey, Py<PyAny>>>;
+
+/// One provider contributed to a multi-binding, with its cached instance
+struct MultiBinding {
+    provider: Provider,
+    instance: Option<CachedInstance>,
+    /// Higher priorities resolve first; ties keep registration order
+    priority: i32,
+}
+
+/// Providers contributed to each multi-binding, in registration order
+type MultiBindings = HashMap<TypeKey, Vec<MultiBinding>>;
+
+impl MultiBinding {
+    fn clone_ref(&self, py: Python) -> Self {
+        MultiBinding {
+            provider: self.provider.clone_ref(py),
+            instance: self
+                .instance
+                .as_ref()
+                .map(|instance| instance.clone_ref(py)),
+            priority: self.priority,
+        }
+    }
+}
+
+/// Provider injected into one consumer class in place of a dependency's
+/// own, with the instance it supplied once used
+struct ConsumerOverride {
+    /// Class to auto-wire, or the instance itself
+    provider: Py<PyAny>,
+    instance: Option<Py<PyAny>>,
+}
+
+/// Consumer-specific providers, by consumer class and then dependency
+type ConsumerOverrides = HashMap<TypeKey, HashMap<TypeKey, ConsumerOverride>>;
+
+/// Registry and singleton cache captured by `RustContainer::snapshot()`
+pub struct RegistrySnapshot {
+    providers: HashMap<TypeKey, Provider>,
+    decorators: HashMap<TypeKey, Vec<Py<PyAny>>>,
+    multi_bindings: MultiBindings,
+    /// Cached singletons in creation order
+    singletons: Vec<(TypeKey, Py<PyAny>)>,
+}
+
+/// What `push_override()` replaced, put back by `pop_override()`
+struct OverrideEntry {
+    id: u64,
+    type_key: TypeKey,
+    /// Provider registered before the override, if any
+    previous: Option<Provider>,
+    /// Singleton cached for the key before the override, if any
+    cached: Option<CachedInstance>,
+}
+
+/// Active overrides, innermost last
+#[derive(Default)]
+struct OverrideLog {
+    next_id: u64,
+    entries: Vec<OverrideEntry>,
+}
+
+/// Sliding-window counter of transient constructions per key
+struct TransientTelemetry {
+    window: Duration,
+    constructions: HashMap<TypeKey, VecDeque<Instant>>,
+}
+
+impl TransientTelemetry {
+    fn new(window: Duration) -> Self {
+        TransientTelemetry {
+            window,
+            constructions: HashMap::new(),
+        }
+    }
+
+    fn record(&mut self, type_key: &TypeKey, now: Instant) {
+        let window = self.window;
+        let timestamps = self.constructions.entry(type_key.clone()).or_default();
+        timestamps.push_back(now);
+        Self::prune(timestamps, window, now);
+    }
+
+    /// Constructions per second over the window, per key still active in it
+    fn rates(&mut self, now: Instant) -> Vec<(TypeKey, f64)> {
+        let window = self.window;
+        self.constructions.retain(|_, timestamps| {
+            Self::prune(timestamps, window, now);
+            !timestamps.is_empty()
+        });
+        let seconds = window.as_secs_f64();
+        self.constructions
+            .iter()
+            .map(|(key, timestamps)| (key.clone(), timestamps.len() as f64 / seconds))
+            .collect()
+    }
+
+    fn prune(timestamps: &mut VecDeque<Instant>, window: Duration, now: Instant) {
+        while timestamps
+            .front()
+            .is_some_and(|&oldest| now.duration_since(oldest) > window)
+        {
+            timestamps.pop_front();
+        }
+    }
+}
+
+/// Shared singletons under construction, each by a single thread
+///
+/// Another thread resolving the same key waits for the builder and then
+/// finds the instance cached, so a singleton is constructed once even when
+/// resolves race (with or without the GIL).
+#[derive(Default)]
+struct ConstructionGates {
+    state: Mutex<GateState>,
+    finished: Condvar,
+    /// Keys claimed right now
+    in_flight: AtomicUsize,
+    /// Most keys ever claimed at once
+    max_in_flight: AtomicUsize,
+    /// Times a thread waited for another thread's construction
+    waits: AtomicU64,
+}
+
+#[derive(Default)]
+struct GateState {
+    /// Thread building each key
+    owners: HashMap<TypeKey, ThreadId>,
+    /// Key each blocked thread waits for
+    waiting: HashMap<ThreadId, TypeKey>,
+}
+
+impl GateState {
+    /// Whether `owner` waits, directly or through other waiting threads,
+    /// for a key `thread` is building
+    fn waits_on(&self, mut owner: ThreadId, thread: ThreadId) -> bool {
+        for _ in 0..=self.waiting.len() {
+            let Some(next) = self
+                .waiting
+                .get(&owner)
+                .and_then(|key| self.owners.get(key))
+            else {
+                return false;
+            };
+            if *next == thread {
+                return true;
+            }
+            owner = *next;
+        }
+        false
+    }
+}
+
+/// Ownership of a `ConstructionGates` key, released (waking the waiting
+/// threads) when dropped
+struct ConstructionClaim<'a> {
+    gates: &'a ConstructionGates,
+    key: TypeKey,
+}
+
+impl ConstructionGates {
+    /// Claim `key` for this thread, first waiting while another thread
+    /// builds it
+    ///
+    /// The wait is detached from the interpreter so the builder can run.
+    /// Returns None without waiting when the builder itself waits for a key
+    /// this thread builds: waiting would deadlock, so both threads build
+    /// and the first instance cached wins.
+    fn claim(&self, py: Python, key: &TypeKey) -> Option<ConstructionClaim<'_>> {
+        let thread = thread::current().id();
+        let mut state = self.state.lock().unwrap();
+        loop {
+            let Some(&owner) = state.owners.get(key) else {
+                state.owners.insert(key.clone(), thread);
+                let in_flight = self.in_flight.fetch_add(1, Ordering::Relaxed) + 1;
+                self.max_in_flight.fetch_max(in_flight, Ordering::Relaxed);
+                return Some(ConstructionClaim {
+                    gates: self,
+                    key: key.clone(),
+                });
+            };
+            if owner == thread || state.waits_on(owner, thread) {
+                return None;
+            }
+            state.waiting.insert(thread, key.clone());
+            drop(state);
+            self.waits.fetch_add(1, Ordering::Relaxed);
+            py.detach(|| {
+                let state = self.state.lock().unwrap();
+                let _state = self
+                    .finished
+                    .wait_while(state, |state| state.owners.get(key) == Some(&owner))
+                    .unwrap();
+            });
+            state = self.state.lock().unwrap();
+            state.waiting.remove(&thread);
+        }
+    }
+}
+
+impl Drop for ConstructionClaim<'_> {
+    fn drop(&mut self) {
+        self.gates.state.lock().unwrap().owners.remove(&self.key);
+        self.gates.in_flight.fetch_sub(1, Ordering::Relaxed);
+        self.gates.finished.notify_all();
+    }
+}
+
+/// Async factory singleton being awaited by one `resolve_async`
+///
+/// Resolves racing it on the same event loop await `future`, which the
+/// first one settles with the instance it caches.
+struct InFlight {
+    future: Py<PyAny>,
+    event_loop: Py<PyAny>,
+    /// Resolves awaiting `future`
+    waiters: usize,
+}
+
+/// Where and why a binding was registered, for introspection
+#[derive(Clone, Default)]
+struct RegistrationMetadata {
+    description: Option<String>,
+    /// File and line of the code that registered the binding
+    source: Option<(String, u32)>,
+}
+
+/// File and line of the innermost Python frame outside dioxide itself, so a
+/// registration made through a dioxide helper points at the helper's caller
+fn caller_location(py: Python) -> Option<(String, u32)> {
+    let mut frame = py
+        .import("sys")
+        .ok()?
+        .call_method1("_getframe", (0,))
+        .ok()?;
+    while !frame.is_none() {
+        let module = frame
+            .getattr("f_globals")
+            .and_then(|globals| globals.get_item("__name__"))
+            .and_then(|name| name.extract::<String>())
+            .unwrap_or_default();
+        if module != "dioxide" && !module.starts_with("dioxide.") {
+            let file = frame.getattr("f_code").ok()?.getattr("co_filename").ok()?;
+            let line = frame.getattr("f_lineno").ok()?;
+            return Some((file.extract().ok()?, line.extract().ok()?));
+        }
+        frame = frame.getattr("f_back").ok()?;
+    }
+    None
+}
+
+/// In-flight async singleton constructions, by cache namespace and key
+type InFlightResolves = HashMap<(Option<String>, TypeKey), InFlight>;
+
+/// Lifetime counters reported by `RustContainer::stats`
+#[derive(Default)]
+struct Counters {
+    resolutions: AtomicU64,
+    cache_hits: AtomicU64,
+    cache_misses: AtomicU64,
+    /// Instances created, by provider kind
+    created: Mutex<HashMap<&'static str, u64>>,
+}
+
+impl Counters {
+    fn count(counter: &AtomicU64) {
+        counter.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an instance built by a provider of `kind`; `cached` marks a
+    /// singleton or tenant instance built on a cache miss
+    fn created(&self, kind: &'static str, cached: bool) {
+        *self.created.lock().unwrap().entry(kind).or_default() += 1;
+        if cached {
+            Self::count(&self.cache_misses);
+        }
+    }
+}
+
+/// Snapshot of a container's counters and sizes
+pub struct ContainerStats {
+    /// Resolves served or attempted, dependencies included
+    pub resolutions: u64,
+    /// Resolves served from a singleton, namespace or tenant cache
+    pub cache_hits: u64,
+    /// Resolves that built an instance and cached it
+    pub cache_misses: u64,
+    /// Instances created, by provider kind
+    pub instances_created: Vec<(&'static str, u64)>,
+    /// Instances currently cached, across every partition
+    pub cache_size: usize,
+    /// Registered bindings
+    pub registrations: usize,
+    /// Most singleton constructions seen in flight at once
+    pub max_concurrent_constructions: usize,
+    /// Resolves that waited for another thread to finish a construction
+    pub single_flight_waits: u64,
+    /// Transient constructions per second by key, while telemetry is enabled
+    pub transient_allocation_rates: Option<Vec<(TypeKey, f64)>>,
+}
+
+/// Registry sealed by `freeze()`, read without locking
+struct FrozenRegistry {
+    providers: HashMap<TypeKey, Provider>,
+    decorators: HashMap<TypeKey, Vec<Py<PyAny>>>,
+}
+
+/// Core Rust container implementation
+///
+/// Cloning yields another handle to the same registry and caches.
+///
+/// No Python code runs while one of the locks below is held: providers,
+/// decorators and hooks are called on snapshots, and Python objects removed
+/// from a map are dropped only after the guard is released (a `__del__` may
+/// re-enter the container). Besides avoiding self-deadlock on re-entry, this
+/// keeps a thread from holding a lock across a GIL switch, so factories may
+/// freely resolve from the container they are building for, and register
+/// new providers into it (a plugin factory registering the services it
+/// contributes). Registrations made mid-resolve are visible to the next
+/// lookup, including lookups by the same factory.
+#[derive(Clone)]
+pub struct RustContainer {
+    /// Provider registry: maps Python type to Provider
+    providers: Arc<RwLock<HashMap<TypeKey, Provider>>>,
+
+    /// Singleton instance cache: maps Python type to cached instance
+    singletons: Arc<SingletonCache>,
+
+    /// Instance decorators: maps Python type to wrappers applied in order
+    decorators: Arc<RwLock<HashMap<TypeKey, Vec<Py<PyAny>>>>>,
+
+    /// Tags attached to registrations, matched by tag interceptors
+    tags: Arc<RwLock<HashMap<TypeKey, BTreeSet<String>>>>,
+
+    /// Description and source location of each registration
+    metadata: Arc<RwLock<HashMap<TypeKey, RegistrationMetadata>>>,
+
+    /// Interceptors wrapping instances of every key with a tag
+    interceptors: Arc<RwLock<TagInterceptors>>,
+
+    /// Configuration values injected into `Annotated[X, Config(name)]`
+    /// parameters, by dotted name
+    config: Arc<RwLock<HashMap<String, Py<PyAny>>>>,
+
+    /// Feature flags for `Annotated[X, Flag(name)]` parameters, by name
+    flags: Arc<RwLock<HashMap<String, Py<PyAny>>>>,
+
+    /// Singleton creation order (dependencies finish constructing first)
+    singleton_order: Arc<RwLock<Vec<TypeKey>>>,
+
+    /// Per-tenant instance cache: maps Python type to instances by tenant id
+    tenant_singletons: Arc<RwLock<HashMap<TypeKey, TenantPartitions>>>,
+
+    /// Per-tenant instance creation order
+    tenant_order: Arc<RwLock<Vec<(TypeKey, String)>>>,
+
+    /// Disposable transients created for each tenant, in creation order,
+    /// awaiting `dispose_tenant()`
+    tenant_transients: Arc<RwLock<TenantTransients>>,
+
+    /// Keys whose transients are never tracked for disposal
+    unmanaged: Arc<RwLock<HashSet<TypeKey>>>,
+
+    /// Disposable transients created outside a tenant, awaiting
+    /// `close()`; held weakly so dropping one does not leak it
+    resources: Arc<RwLock<Resources>>,
+
+    /// Set by `close()`; resolving afterwards fails
+    closed: Arc<AtomicBool>,
+
+    /// Thread-lifetime instance cache: instances by thread, then by type
+    thread_singletons: Arc<RwLock<ThreadPartitions>>,
+
+    /// `threading.local` holding a `ThreadRelease` on each thread with cached
+    /// thread-lifetime instances, created on first use
+    thread_exit: Arc<OnceLock<Py<PyAny>>>,
+
+    /// Names of singletons in the order they were constructed (for tests)
+    construction_log: Arc<RwLock<Vec<String>>>,
+
+    /// Containers consulted in order when a key is not registered here
+    fallbacks: Arc<RwLock<Vec<RustContainer>>>,
+
+    /// When set, singleton factories may no longer run (pre-fork warm cache)
+    singletons_frozen: Arc<AtomicBool>,
+
+    /// Transient allocation counters, present only while telemetry is enabled
+    transient_telemetry: Arc<RwLock<Option<TransientTelemetry>>>,
+
+    /// Singleton caches of cache namespaces, by namespace name
+    namespace_singletons: Arc<RwLock<NamespacePartitions>>,
+
+    /// `contextvars.ContextVar` holding the active cache namespace, created
+    /// when a namespace is first entered
+    namespace_var: Arc<OnceLock<Py<PyAny>>>,
+
+    /// Constructor parameters inspected for auto-wiring, cached per class
+    constructor_params: Arc<RwLock<ConstructorParams>>,
+
+    /// Post-construct hook methods discovered per class
+    post_inject_hooks: Arc<RwLock<PostInjectHooks>>,
+
+    /// Compiled constructor calls, by class
+    plans: Arc<RwLock<HashMap<TypeKey, Arc<ResolutionPlan>>>>,
+
+    /// Generation of the last change to this registry or its fallbacks;
+    /// plans compiled before it are stale
+    generation: Arc<AtomicU64>,
+
+    /// Keys being constructed on each thread, outermost first
+    resolving: Arc<RwLock<HashMap<ThreadId, Vec<TypeKey>>>>,
+
+    /// Policy for registrations that do not choose their own
+    duplicate_policy: Arc<RwLock<DuplicatePolicy>>,
+
+    /// Multi-bindings: several providers resolved together by `resolve_all`
+    multi_bindings: Arc<RwLock<MultiBindings>>,
+
+    /// Started generator factories, in creation order
+    teardowns: Arc<RwLock<Vec<Teardown>>>,
+
+    /// Set between `start_lifecycle()` and `stop_lifecycle()`; singletons
+    /// created meanwhile are started as they are cached
+    lifecycle_running: Arc<AtomicBool>,
+
+    /// Components started by the lifecycle, in start order
+    started: Arc<RwLock<StartedComponents>>,
+
+    /// Callbacks notified after every successful resolve, in order added
+    resolve_hooks: Arc<RwLock<Vec<Py<PyAny>>>>,
+
+    /// Callbacks notified before each registration is applied, in order added
+    register_hooks: Arc<RwLock<Vec<Py<PyAny>>>>,
+
+    /// Resolution and construction counters, kept until `reset_stats()`
+    counters: Arc<Counters>,
+
+    /// Shared singletons being built, so racing resolves build them once
+    construction_gates: Arc<ConstructionGates>,
+
+    /// Why the last attempt to build each shared singleton failed, until it
+    /// is built or the caches are emptied
+    construction_failures: Arc<RwLock<HashMap<TypeKey, String>>>,
+
+    /// Providers auto-wiring injects into specific consumer classes
+    consumer_overrides: Arc<RwLock<ConsumerOverrides>>,
+
+    /// Edges declared by `before=`/`after=` hints: each key to the keys
+    /// built before it (and so disposed after it)
+    ordering_hints: Arc<RwLock<HashMap<TypeKey, Vec<TypeKey>>>>,
+
+    /// Async factory singletons being awaited, so racing tasks share one
+    async_in_flight: Arc<Mutex<InFlightResolves>>,
+
+    /// Active profile; registrations tagged for other profiles are skipped
+    profile: Option<Arc<str>>,
+
+    /// Bind unregistered concrete classes implicitly on first resolve
+    autowire: bool,
+
+    /// Revert log of active `push_override()` calls
+    overrides: Arc<RwLock<OverrideLog>>,
+
+    /// Immutable copy of the providers and decorators, set once by `freeze()`;
+    /// resolves read it instead of taking the registry locks
+    sealed: Arc<OnceLock<FrozenRegistry>>,
+}
+
+/// Marks a key as under construction on this thread until dropped
+///
+/// Dropped before `complete()` (i.e. on an error path), it records the
+/// construction stack as the failure's resolution path.
+struct ResolvingGuard<'a> {
+    container: &'a RustContainer,
+    completed: bool,
+}
+
+impl ResolvingGuard<'_> {
+    fn complete(mut self) {
+        self.completed = true;
+    }
+}
+
+impl Drop for ResolvingGuard<'_> {
+    fn drop(&mut self) {
+        if !self.completed {
+            Python::attach(|py| self.container.record_failure_path(py, None));
+        }
+        let mut resolving = self.container.resolving.write().unwrap();
+        let thread_id = thread::current().id();
+        if let Some(stack) = resolving.get_mut(&thread_id) {
+            stack.pop();
+            if stack.is_empty() {
+                resolving.remove(&thread_id);
+            }
+        }
+    }
+}
+
+impl RustContainer {
+    /// Create a new empty container
+    pub fn new() -> Self {
+        RustContainer {
+            providers: Arc::new(RwLock::new(HashMap::new())),
+            singletons: Arc::new(SingletonCache::new()),
+            decorators: Arc::new(RwLock::new(HashMap::new())),
+            tags: Arc::new(RwLock::new(HashMap::new())),
+            metadata: Arc::new(RwLock::new(HashMap::new())),
+            interceptors: Arc::new(RwLock::new(Vec::new())),
+            config: Arc::new(RwLock::new(HashMap::new())),
+            flags: Arc::new(RwLock::new(HashMap::new())),
+            singleton_order: Arc::new(RwLock::new(Vec::new())),
+            tenant_singletons: Arc::new(RwLock::new(HashMap::new())),
+            tenant_order: Arc::new(RwLock::new(Vec::new())),
+            tenant_transients: Arc::new(RwLock::new(HashMap::new())),
+            unmanaged: Arc::new(RwLock::new(HashSet::new())),
+            resources: Arc::new(RwLock::new(Vec::new())),
+            closed: Arc::new(AtomicBool::new(false)),
+            thread_singletons: Arc::new(RwLock::new(HashMap::new())),
+            thread_exit: Arc::new(OnceLock::new()),
+            construction_log: Arc::new(RwLock::new(Vec::new())),
+            fallbacks: Arc::new(RwLock::new(Vec::new())),
+            singletons_frozen: Arc::new(AtomicBool::new(false)),
+            transient_telemetry: Arc::new(RwLock::new(None)),
+            namespace_singletons: Arc::new(RwLock::new(HashMap::new())),
+            namespace_var: Arc::new(OnceLock::new()),
+            constructor_params: Arc::new(RwLock::new(HashMap::new())),
+            post_inject_hooks: Arc::new(RwLock::new(HashMap::new())),
+            plans: Arc::new(RwLock::new(HashMap::new())),
+            generation: Arc::new(AtomicU64::new(0)),
+            resolving: Arc::new(RwLock::new(HashMap::new())),
+            duplicate_policy: Arc::new(RwLock::new(DuplicatePolicy::Error)),
+            multi_bindings: Arc::new(RwLock::new(HashMap::new())),
+            teardowns: Arc::new(RwLock::new(Vec::new())),
+            lifecycle_running: Arc::new(AtomicBool::new(false)),
+            started: Arc::new(RwLock::new(Vec::new())),
+            resolve_hooks: Arc::new(RwLock::new(Vec::new())),
+            register_hooks: Arc::new(RwLock::new(Vec::new())),
+            counters: Arc::new(Counters::default()),
+            construction_gates: Arc::new(ConstructionGates::default()),
+            construction_failures: Arc::new(RwLock::new(HashMap::new())),
+            consumer_overrides: Arc::new(RwLock::new(HashMap::new())),
+            ordering_hints: Arc::new(RwLock::new(HashMap::new())),
+            async_in_flight: Arc::new(Mutex::new(HashMap::new())),
+            sealed: Arc::new(OnceLock::new()),
+            profile: None,
+            autowire: false,
+            overrides: Arc::new(RwLock::new(OverrideLog::default())),
+        }
+    }
+
+    /// Set the active profile (normalised to lowercase)
+    ///
+    /// Without one, every registration applies whatever its profile tag.
+    pub fn with_profile(mut self, profile: Option<&str>) -> Self {
+        self.profile = profile.map(|profile| Arc::from(profile.to_lowercase()));
+        self
+    }
+
+    /// Active profile, if the container was created with one
+    pub fn profile(&self) -> Option<&str> {
+        self.profile.as_deref()
+    }
+
+    /// Enable (or disable) autowire mode, see `autowirable`
+    pub fn with_autowire(mut self, autowire: bool) -> Self {
+        self.autowire = autowire;
+        self
+    }
+
+    /// Whether unregistered concrete classes are bound on first resolve
+    pub fn autowire(&self) -> bool {
+        self.autowire
+    }
+
+    /// Check if a registration tagged `profile` applies to this container
+    ///
+    /// Untagged registrations, `"*"` and containers without an active
+    /// profile always match; tags are compared case-insensitively.
+    pub fn profile_matches(&self, profile: Option<&str>) -> bool {
+        match (self.profile.as_deref(), profile) {
+            (None, _) | (_, None) | (_, Some("*")) => true,
+            (Some(active), Some(tag)) => tag.eq_ignore_ascii_case(active),
+        }
+    }
+
+    /// Policy applied to registrations that pass no `on_duplicate`
+    pub fn duplicate_policy(&self) -> DuplicatePolicy {
+        *self.duplicate_policy.read().unwrap()
+    }
+
+    pub fn set_duplicate_policy(&self, policy: DuplicatePolicy) {
+        *self.duplicate_policy.write().unwrap() = policy;
+    }
+
+    /// Register a provider, handling an existing key per `on_duplicate`
+    ///
+    /// Without `on_duplicate` the container's policy applies. Replacing a
+    /// provider evicts the singleton and tenant instances the old one cached.
+    /// A provider tagged with a `profile` other than the active one is
+    /// skipped: it is never stored, so lookups cannot see it.
+    pub fn register_provider(
         &self,
         py: Python,
         type_key: TypeKey,
-        factory: Py<PyAny>,
+        provider: Provider,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
     ) -> Result<(), ContainerError> {
-        let mut providers = self.providers.write().unwrap();
+        self.ensure_unfrozen(py, "register", &type_key)?;
+        if !self.profile_matches(profile) {
+            return Ok(());
+        }
+        let policy = on_duplicate.unwrap_or_else(|| self.duplicate_policy());
+        let source = caller_location(py);
+        // Hooks run before the registry changes, so one can veto by raising
+        let applies = policy == DuplicatePolicy::Replace
+            || !self.providers.read().unwrap().contains_key(&type_key);
+        if applies {
+            self.notify_register(py, &type_key, &provider)?;
+        }
+
+        let replaced = {
+            let mut providers = self.providers.write().unwrap();
+            // Checked again under the write lock, which freeze() waits for
+            if self.is_frozen() {
+                drop(providers);
+                return self.ensure_unfrozen(py, "register", &type_key);
+            }
 
-        // Check for duplicate registration
-        if providers.contains_key(&type_key) {
+            let mut existing = match providers.entry(type_key.clone()) {
+                Entry::Vacant(entry) => {
+                    entry.insert(provider);
+                    self.record_source(&type_key, source);
+                    self.touch_registry();
+                    return Ok(());
+                }
+                Entry::Occupied(entry) => entry,
+            };
+            match policy {
+                DuplicatePolicy::Error => None,
+                DuplicatePolicy::Ignore => return Ok(()),
+                DuplicatePolicy::Replace => Some(existing.insert(provider)),
+            }
+        };
+        if replaced.is_none() {
             return Err(ContainerError::DuplicateRegistration {
                 type_name: type_key.type_name(py),
             });
         }
+        self.record_source(&type_key, source);
+        self.touch_registry();
+
+        self.evict_singleton(&type_key);
+        self.evict_tenant_instances(&type_key);
+        Ok(())
+    }
+
+    /// Start the metadata of a new registration of `type_key`, made at `source`
+    fn record_source(&self, type_key: &TypeKey, source: Option<(String, u32)>) {
+        let metadata = RegistrationMetadata {
+            description: None,
+            source,
+        };
+        self.metadata
+            .write()
+            .unwrap()
+            .insert(type_key.clone(), metadata);
+    }
+
+    /// Describe the registration of a type for introspection
+    pub fn describe(&self, type_key: &TypeKey, description: String) {
+        let mut metadata = self.metadata.write().unwrap();
+        metadata.entry(type_key.clone()).or_default().description = Some(description);
+    }
+
+    /// Register several providers atomically under the container's policy
+    ///
+    /// A key repeated within `entries` is always rejected. Under the error
+    /// policy nothing is registered if any key is already registered.
+    pub fn register_providers(
+        &self,
+        py: Python,
+        entries: Vec<(TypeKey, Provider)>,
+    ) -> Result<(), ContainerError> {
+        let policy = self.duplicate_policy();
+        let find_duplicate = |providers: &HashMap<TypeKey, Provider>| {
+            entries
+                .iter()
+                .enumerate()
+                .find_map(|(index, (type_key, _))| {
+                    let repeated = entries[..index].iter().any(|(key, _)| key == type_key);
+                    let existing = providers.contains_key(type_key);
+                    (repeated || (existing && policy == DuplicatePolicy::Error))
+                        .then(|| type_key.clone())
+                })
+        };
+        let duplicate_error = |type_key: TypeKey| ContainerError::DuplicateRegistration {
+            type_name: type_key.type_name(py),
+        };
+
+        for (type_key, _) in &entries {
+            self.ensure_unfrozen(py, "register", type_key)?;
+        }
+        let source = caller_location(py);
+
+        // Hooks see the whole batch before any of it is applied
+        let applying: Vec<&(TypeKey, Provider)> = {
+            let providers = self.providers.read().unwrap();
+            if let Some(duplicate) = find_duplicate(&providers) {
+                drop(providers);
+                return Err(duplicate_error(duplicate));
+            }
+            entries
+                .iter()
+                .filter(|(type_key, _)| {
+                    policy != DuplicatePolicy::Ignore || !providers.contains_key(type_key)
+                })
+                .collect()
+        };
+        for (type_key, provider) in applying {
+            self.notify_register(py, type_key, provider)?;
+        }
+
+        let mut replaced = Vec::new();
+        let mut previous = Vec::new();
+        {
+            let mut providers = self.providers.write().unwrap();
+            if let (true, Some((type_key, _))) = (self.is_frozen(), entries.first()) {
+                drop(providers);
+                return self.ensure_unfrozen(py, "register", type_key);
+            }
+            if let Some(duplicate) = find_duplicate(&providers) {
+                drop(providers);
+                return Err(duplicate_error(duplicate));
+            }
+
+            for (type_key, provider) in entries {
+                if providers.contains_key(&type_key) {
+                    if policy == DuplicatePolicy::Ignore {
+                        continue;
+                    }
+                    replaced.push(type_key.clone());
+                }
+                self.record_source(&type_key, source.clone());
+                previous.extend(providers.insert(type_key, provider));
+            }
+            self.touch_registry();
+        }
+        drop(previous);
+
+        for type_key in &replaced {
+            self.evict_singleton(type_key);
+            self.evict_tenant_instances(type_key);
+        }
+        Ok(())
+    }
+
+    /// Register an instance provider
+    pub fn register_instance(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        instance: Py<PyAny>,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        self.register_provider(
+            py,
+            type_key,
+            Provider::Instance(instance),
+            on_duplicate,
+            profile,
+        )
+    }
+
+    /// Register a prototype provider (deep copy of the template per resolve)
+    pub fn register_prototype(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        template: Py<PyAny>,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        self.register_provider(
+            py,
+            type_key,
+            Provider::Prototype(template),
+            on_duplicate,
+            profile,
+        )
+    }
+
+    /// Register a coroutine function awaited by `resolve_async`
+    pub fn register_async_factory(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        factory: Py<PyAny>,
+        lifetime: Lifetime,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        if lifetime == Lifetime::Thread {
+            return Err(ContainerError::ProviderRegistrationFailed {
+                type_name: type_key.type_name(py),
+                reason: "async factories cannot use the thread lifetime".to_string(),
+            });
+        }
+        let provider = Provider::AsyncFactory(factory, lifetime);
+        self.register_provider(py, type_key, provider, on_duplicate, profile)
+    }
+
+    /// Register a generator function whose teardown runs on `close()`
+    pub fn register_generator_factory(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        factory: Py<PyAny>,
+        lifetime: Lifetime,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        if lifetime == Lifetime::Thread {
+            return Err(ContainerError::ProviderRegistrationFailed {
+                type_name: type_key.type_name(py),
+                reason: "generator factories cannot use the thread lifetime".to_string(),
+            });
+        }
+        let provider = Provider::GeneratorFactory(factory, lifetime);
+        self.register_provider(py, type_key, provider, on_duplicate, profile)
+    }
+
+    /// Register a per-tenant factory provider (caches one instance per tenant)
+    pub fn register_tenant_factory(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        factory: Py<PyAny>,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        self.register_provider(
+            py,
+            type_key,
+            Provider::TenantFactory(factory),
+            on_duplicate,
+            profile,
+        )
+    }
+
+    /// Register a per-thread factory provider (caches one instance per OS thread)
+    pub fn register_thread_factory(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        factory: Py<PyAny>,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        let provider = Provider::ThreadFactory(factory);
+        self.register_provider(py, type_key, provider, on_duplicate, profile)
+    }
+
+    /// Register a factory whose instances are pooled, at most `size` at once
+    ///
+    /// Each resolve borrows an instance wrapped in a `PooledInstance`; when
+    /// all are borrowed it waits up to `timeout` (forever when `None`, not at
+    /// all when zero) for one to be returned.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_pooled_factory(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        factory: Py<PyAny>,
+        size: usize,
+        timeout: Option<Duration>,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        if size == 0 {
+            return Err(ContainerError::ProviderRegistrationFailed {
+                type_name: type_key.type_name(py),
+                reason: "pool size must be at least 1".to_string(),
+            });
+        }
+        let provider = Provider::Pooled(Arc::new(ObjectPool::new(factory, size, timeout)));
+        self.register_provider(py, type_key, provider, on_duplicate, profile)
+    }
+
+    /// Register a class provider with the given lifetime
+    pub fn register_class(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        class: Py<PyType>,
+        lifetime: Lifetime,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        self.register_provider(
+            py,
+            type_key,
+            Provider::Class(class, lifetime),
+            on_duplicate,
+            profile,
+        )
+    }
+
+    /// Register a singleton factory provider (caches result)
+    pub fn register_singleton_factory(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        factory: Py<PyAny>,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        self.register_provider(
+            py,
+            type_key,
+            Provider::SingletonFactory(factory),
+            on_duplicate,
+            profile,
+        )
+    }
+
+    /// Register a class or factory whose singleton is cached weakly
+    ///
+    /// The cache holds a weak reference: once nothing else references the
+    /// instance Python may collect it, and the next resolve builds a new one.
+    pub fn register_weak_singleton(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        factory: Py<PyAny>,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        let provider = Provider::WeakSingleton(factory);
+        self.register_provider(py, type_key, provider, on_duplicate, profile)
+    }
+
+    /// Register a transient factory provider (creates new instance each time)
+    pub fn register_transient_factory(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        factory: Py<PyAny>,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        self.register_provider(
+            py,
+            type_key,
+            Provider::TransientFactory(factory),
+            on_duplicate,
+            profile,
+        )
+    }
+
+    /// Register an environment variable read on resolve
+    ///
+    /// `cast` converts the raw string (`bool` parses true/false words); an
+    /// unset variable resolves to `default`, to None unless `required`, or
+    /// fails. With `cached` the value is read once and kept as a singleton.
+    #[allow(clippy::too_many_arguments)]
+    pub fn register_env(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        variable: &str,
+        cast: Option<Py<PyAny>>,
+        required: bool,
+        default: Option<Py<PyAny>>,
+        cached: bool,
+        on_duplicate: Option<DuplicatePolicy>,
+        profile: Option<&str>,
+    ) -> Result<(), ContainerError> {
+        let env = EnvVariable {
+            variable: PyString::new(py, variable).unbind(),
+            cast,
+            default,
+            required,
+            cached,
+        };
+        self.register_provider(
+            py,
+            type_key,
+            Provider::Env(Arc::new(env)),
+            on_duplicate,
+            profile,
+        )
+    }
+
+    /// Verify a class or instance provider satisfies its key type
+    ///
+    /// Classes must subclass the key and instances must be instances of it
+    /// (ABC virtual subclasses count). Protocol keys are checked structurally,
+    /// so interface-to-implementation bindings are accepted. Enum member keys
+    /// have no type to check against and always pass.
+    pub fn check_provider_type(
+        py: Python,
+        type_key: &TypeKey,
+        candidate: &Bound<'_, PyAny>,
+        is_class: bool,
+    ) -> Result<(), ContainerError> {
+        let Some(key_type) = type_key.as_type() else {
+            return Ok(());
+        };
+        let key_type = key_type.bind(py);
+
+        let impl_type = if is_class {
+            candidate.cast::<PyType>().map_err(PyErr::from)?.clone()
+        } else {
+            candidate.get_type()
+        };
+
+        let is_protocol = key_type
+            .getattr("_is_protocol")
+            .and_then(|flag| flag.is_truthy())
+            .unwrap_or(false);
+
+        let satisfied = if is_protocol {
+            // Structural check: every public callable of the protocol must exist
+            let mut satisfied = true;
+            for name in key_type.dir()? {
+                let name: String = name.extract()?;
+                if name.starts_with('_') {
+                    continue;
+                }
+                let is_method = key_type
+                    .getattr(name.as_str())
+                    .map(|attr| attr.is_callable())
+                    .unwrap_or(false);
+                if is_method && !impl_type.hasattr(name.as_str())? {
+                    satisfied = false;
+                    break;
+                }
+            }
+            satisfied
+        } else {
+            impl_type.is_subclass(key_type)?
+        };
+
+        if satisfied {
+            return Ok(());
+        }
+
+        let impl_name = impl_type
+            .name()
+            .map(|n| n.to_string())
+            .unwrap_or_else(|_| "<unknown>".to_string());
+        let reason = if is_class {
+            format!(
+                "{} is not a subclass of {}",
+                impl_name,
+                type_key.type_name(py)
+            )
+        } else {
+            format!(
+                "instance of {} is not an instance of {}",
+                impl_name,
+                type_key.type_name(py)
+            )
+        };
+
+        Err(ContainerError::ProviderRegistrationFailed {
+            type_name: type_key.type_name(py),
+            reason,
+        })
+    }
+
+    /// Verify a class fully implements the interface of its key type
+    ///
+    /// The stricter counterpart of `check_provider_type` for classes: the
+    /// class must be concrete, and must define every method the interface
+    /// declares (the abstract methods of an ABC, the public methods of a
+    /// Protocol) with a signature callers of the interface can use. Every
+    /// mismatch is reported in one error.
+    pub fn check_interface(
+        py: Python,
+        type_key: &TypeKey,
+        class: &Bound<'_, PyType>,
+    ) -> Result<(), ContainerError> {
+        RustContainer::check_provider_type(py, type_key, class.as_any(), true)?;
+        let Some(key_type) = type_key.as_type() else {
+            return Ok(());
+        };
+        let key_type = key_type.bind(py);
+        let inspect = py.import("inspect")?;
+        let impl_name = class
+            .name()
+            .map(|n| n.to_string())
+            .unwrap_or_else(|_| "<unknown>".to_string());
+
+        let mut problems = Vec::new();
+        if inspect.call_method1("isabstract", (class,))?.is_truthy()? {
+            let mut unimplemented: Vec<String> = class
+                .getattr("__abstractmethods__")?
+                .try_iter()?
+                .map(|name| name?.extract::<String>())
+                .collect::<PyResult<_>>()?;
+            unimplemented.sort();
+            problems.push(format!(
+                "{impl_name} is abstract (unimplemented: {})",
+                unimplemented.join(", ")
+            ));
+        }
+
+        let is_protocol = key_type
+            .getattr("_is_protocol")
+            .and_then(|flag| flag.is_truthy())
+            .unwrap_or(false);
+        let mut methods: Vec<String> = if is_protocol {
+            let mut methods = Vec::new();
+            for name in key_type.dir()? {
+                let name: String = name.extract()?;
+                if !name.starts_with('_') && key_type.getattr(name.as_str())?.is_callable() {
+                    methods.push(name);
+                }
+            }
+            methods
+        } else {
+            match key_type.getattr("__abstractmethods__") {
+                Ok(names) => names
+                    .try_iter()?
+                    .map(|name| name?.extract::<String>())
+                    .collect::<PyResult<_>>()?,
+                Err(_) => Vec::new(),
+            }
+        };
+        methods.sort();
+
+        for name in methods {
+            let Ok(actual) = class.getattr(name.as_str()) else {
+                problems.push(format!("{impl_name} does not define {name}()"));
+                continue;
+            };
+            if !actual.is_callable() {
+                problems.push(format!("{impl_name}.{name} is not callable"));
+                continue;
+            }
+            let expected = key_type.getattr(name.as_str())?;
+            // Builtins and C callables may have no introspectable signature
+            let (Ok(expected), Ok(actual)) = (
+                inspect.call_method1("signature", (&expected,)),
+                inspect.call_method1("signature", (&actual,)),
+            ) else {
+                continue;
+            };
+            if !signature_accepts(&actual, &expected)? {
+                problems.push(format!(
+                    "{impl_name}.{name}{} does not match {}.{name}{}",
+                    actual.str()?,
+                    type_key.type_name(py),
+                    expected.str()?
+                ));
+            }
+        }
+
+        if problems.is_empty() {
+            return Ok(());
+        }
+        Err(ContainerError::ProviderRegistrationFailed {
+            type_name: type_key.type_name(py),
+            reason: problems.join("; "),
+        })
+    }
+
+    /// Swap the factory of an existing factory registration
+    ///
+    /// The registration keeps its kind (singleton or transient) and any cached
+    /// singleton is evicted so the next resolve runs the new factory. Intended
+    /// for `importlib.reload` cycles during development.
+    pub fn rebind_factory(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        factory: Py<PyAny>,
+    ) -> Result<(), ContainerError> {
+        let previous = {
+            let mut providers = self.providers.write().unwrap();
+            if self.is_frozen() {
+                drop(providers);
+                return self.ensure_unfrozen(py, "rebind", type_key);
+            }
+            match providers.get_mut(type_key) {
+                Some(
+                    Provider::SingletonFactory(existing)
+                    | Provider::WeakSingleton(existing)
+                    | Provider::TransientFactory(existing)
+                    | Provider::TenantFactory(existing)
+                    | Provider::ThreadFactory(existing)
+                    | Provider::AsyncFactory(existing, _)
+                    | Provider::GeneratorFactory(existing, _),
+                ) => Ok(std::mem::replace(existing, factory)),
+                Some(
+                    Provider::Instance(_)
+                    | Provider::Prototype(_)
+                    | Provider::Class(..)
+                    | Provider::Pooled(_)
+                    | Provider::Env(_),
+                ) => Err(None),
+                None => Err(Some(Self::registered_siblings(type_key, &providers))),
+            }
+        };
+        match previous {
+            Ok(previous) => drop(previous),
+            Err(Some(siblings)) => return Err(Self::not_registered(py, type_key, &siblings)),
+            Err(None) => {
+                return Err(ContainerError::ProviderRegistrationFailed {
+                    type_name: type_key.type_name(py),
+                    reason: "registered provider is not a factory".to_string(),
+                });
+            }
+        }
+
+        // Evict the singleton (or tenant instances) built by the previous factory
+        self.evict_singleton(type_key);
+        self.evict_tenant_instances(type_key);
+        Ok(())
+    }
+
+    /// Temporarily bind `type_key` to `instance`, returning a revert token
+    ///
+    /// The replaced provider and its cached singleton are recorded in the
+    /// revert log and the key's cached instances are evicted. Register hooks
+    /// are not notified: an override is not a registration.
+    pub fn push_override(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        instance: Py<PyAny>,
+    ) -> Result<u64, ContainerError> {
+        let mut providers = self.providers.write().unwrap();
+        if self.is_frozen() {
+            drop(providers);
+            return Err(ContainerError::ContainerFrozen {
+                action: format!("override {}", type_key.type_name(py)),
+            });
+        }
+        let previous = providers.insert(type_key.clone(), Provider::Instance(instance));
+        let cached = self.singletons.remove(type_key);
+        if cached.is_some() {
+            self.singleton_order
+                .write()
+                .unwrap()
+                .retain(|key| key != type_key);
+        }
+        let mut evicted = self.evict_singleton(type_key);
+        evicted.extend(self.evict_tenant_instances(type_key));
+        let id = {
+            let mut overrides = self.overrides.write().unwrap();
+            let id = overrides.next_id;
+            overrides.next_id += 1;
+            overrides.entries.push(OverrideEntry {
+                id,
+                type_key: type_key.clone(),
+                previous,
+                cached,
+            });
+            id
+        };
+        self.touch_registry();
+        drop(providers);
+        drop(evicted);
+        Ok(id)
+    }
+
+    /// Undo the override `id` and every override pushed after it
+    ///
+    /// Entries are reverted newest first, so nested overrides of one key
+    /// unwind to the original provider and cached singleton. Reverting an
+    /// override that was already reverted (or cleared) does nothing.
+    pub fn pop_override(&self, py: Python, id: u64) -> Result<(), ContainerError> {
+        let mut providers = self.providers.write().unwrap();
+        let mut overrides = self.overrides.write().unwrap();
+        let Some(position) = overrides.entries.iter().position(|entry| entry.id == id) else {
+            return Ok(());
+        };
+        if self.is_frozen() {
+            let type_key = overrides.entries[position].type_key.clone();
+            drop((overrides, providers));
+            return self.ensure_unfrozen(py, "revert override of", &type_key);
+        }
+        let reverted: Vec<OverrideEntry> = overrides.entries.drain(position..).rev().collect();
+        drop(overrides);
+
+        let mut replaced = Vec::with_capacity(reverted.len());
+        let mut evicted = Vec::new();
+        for entry in &reverted {
+            replaced.push(match &entry.previous {
+                Some(previous) => providers.insert(entry.type_key.clone(), previous.clone_ref(py)),
+                None => providers.remove(&entry.type_key),
+            });
+            evicted.extend(self.evict_singleton(&entry.type_key));
+            evicted.extend(self.evict_tenant_instances(&entry.type_key));
+            if let Some(cached) = &entry.cached {
+                if self
+                    .singletons
+                    .insert_entry_if_absent(py, &entry.type_key, cached.clone_ref(py))
+                    .is_none()
+                {
+                    self.singleton_order
+                        .write()
+                        .unwrap()
+                        .push(entry.type_key.clone());
+                }
+            }
+        }
+        self.touch_registry();
+        drop(providers);
+        drop((reverted, replaced, evicted));
+        Ok(())
+    }
+
+    /// Resolve a dependency by type
+    pub fn resolve(&self, py: Python, type_key: &TypeKey) -> Result<Py<PyAny>, ContainerError> {
+        self.resolve_for_tenant(py, type_key, None)
+    }
+
+    /// Resolve a dependency by type on behalf of a tenant
+    ///
+    /// The tenant id selects the cache partition for tenant factories and is
+    /// required for them; other providers ignore it. Resolve hooks are
+    /// notified once the key (or any dependency it pulls in) resolves.
+    pub fn resolve_for_tenant(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        let hooks = self.resolve_hook_snapshot(py);
+        self.resolve_observed(py, type_key, tenant, &hooks)
+    }
+
+    /// Resolve a key for a tenant if anything provides it
+    ///
+    /// A key with no registration here or in a fallback, that auto-wiring
+    /// would not build either, yields None without building an error.
+    /// A provided key that fails to resolve still returns the failure.
+    pub fn try_resolve(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+    ) -> Result<Option<Py<PyAny>>, ContainerError> {
+        if !self.is_registered(type_key) && self.autowire_class(py, type_key).is_none() {
+            return Ok(None);
+        }
+        self.resolve_for_tenant(py, type_key, tenant).map(Some)
+    }
+
+    /// Resolve several keys for a tenant, in order, with one snapshot of
+    /// the resolve hooks
+    ///
+    /// Each key resolves exactly as `resolve_for_tenant` would. On failure,
+    /// the error comes back with the index of the key that failed.
+    pub fn resolve_many(
+        &self,
+        py: Python,
+        type_keys: &[TypeKey],
+        tenant: Option<&str>,
+    ) -> Result<Vec<Py<PyAny>>, (usize, ContainerError)> {
+        let hooks = self.resolve_hook_snapshot(py);
+        type_keys
+            .iter()
+            .enumerate()
+            .map(|(index, type_key)| {
+                self.resolve_observed(py, type_key, tenant, &hooks)
+                    .map_err(|err| (index, err))
+            })
+            .collect()
+    }
+
+    /// Resolve hooks registered now, in the order added
+    fn resolve_hook_snapshot(&self, py: Python) -> Vec<Py<PyAny>> {
+        let hooks = self.resolve_hooks.read().unwrap();
+        hooks.iter().map(|hook| hook.clone_ref(py)).collect()
+    }
+
+    /// Resolve a key for a tenant, notifying `hooks` once it resolves
+    fn resolve_observed(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+        hooks: &[Py<PyAny>],
+    ) -> Result<Py<PyAny>, ContainerError> {
+        if hooks.is_empty() {
+            return self.resolve_uninstrumented(py, type_key, tenant);
+        }
+
+        let cache_hit = self.is_cached(py, type_key, tenant);
+        let started = Instant::now();
+        let instance = self.resolve_uninstrumented(py, type_key, tenant)?;
+        let duration_ms = started.elapsed().as_secs_f64() * 1000.0;
+
+        let requested = type_key.py_object(py);
+        let provider = self.provider_kind(type_key).unwrap_or("unknown");
+        for hook in hooks {
+            hook.call1(py, (&requested, provider, cache_hit, duration_ms))?;
+        }
+        Ok(instance)
+    }
+
+    /// Decorators registered for `type_key`, in the order they apply
+    ///
+    /// The type's own decorators come first, then the interceptors of its
+    /// tags in the order they were added.
+    fn decorator_chain(&self, py: Python, type_key: &TypeKey) -> Vec<Py<PyAny>> {
+        let clone_chain = |chain: &Vec<Py<PyAny>>| chain.iter().map(|d| d.clone_ref(py)).collect();
+        let mut chain: Vec<Py<PyAny>> = match self.sealed.get() {
+            Some(sealed) => sealed.decorators.get(type_key).map(clone_chain),
+            None => self
+                .decorators
+                .read()
+                .unwrap()
+                .get(type_key)
+                .map(clone_chain),
+        }
+        .unwrap_or_default();
+
+        let interceptors = self.interceptors.read().unwrap();
+        if interceptors.is_empty() {
+            return chain;
+        }
+        if let Some(tags) = self.tags.read().unwrap().get(type_key) {
+            chain.extend(
+                interceptors
+                    .iter()
+                    .filter(|(tag, _)| tags.contains(tag))
+                    .map(|(_, interceptor)| interceptor.clone_ref(py)),
+            );
+        }
+        chain
+    }
+
+    /// Build a fresh instance of `type_key` with some arguments given
+    ///
+    /// `overrides` are passed as keyword arguments, on top of any bound to
+    /// the factory; a class's other parameters are auto-wired as usual. The
+    /// instance bypasses every cache, so a singleton binding keeps serving
+    /// its shared instance, but decorators still wrap it. Only class and
+    /// factory providers can be overridden; resolve hooks are not notified.
+    pub fn resolve_with_overrides(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+        overrides: &Bound<'_, PyDict>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        self.ensure_open(py, type_key)?;
+        Counters::count(&self.counters.resolutions);
+        let provider = match self.sealed.get() {
+            Some(sealed) => sealed.providers.get(type_key).map(|p| p.clone_ref(py)),
+            None => {
+                let providers = self.providers.read().unwrap();
+                providers.get(type_key).map(|p| p.clone_ref(py))
+            }
+        };
+        let kind = provider.as_ref().map_or("class", Provider::kind_name);
+        let target = match provider {
+            Some(Provider::Class(cls, _)) => cls.into_any(),
+            Some(
+                Provider::SingletonFactory(factory)
+                | Provider::WeakSingleton(factory)
+                | Provider::TransientFactory(factory)
+                | Provider::TenantFactory(factory)
+                | Provider::ThreadFactory(factory),
+            ) => factory,
+            Some(_) => {
+                return Err(ContainerError::OverridesUnsupported {
+                    type_name: type_key.type_name(py),
+                    kind,
+                });
+            }
+            None => {
+                if let Some(fallback) = self.fallback_for(type_key) {
+                    return fallback.resolve_with_overrides(py, type_key, tenant, overrides);
+                }
+                match self.autowire_class(py, type_key) {
+                    Some(cls) => cls.into_any(),
+                    None => {
+                        self.record_failure_path(py, Some(type_key));
+                        let siblings = {
+                            let providers = self.providers.read().unwrap();
+                            Self::registered_siblings(type_key, &providers)
+                        };
+                        return Err(Self::not_registered(py, type_key, &siblings));
+                    }
+                }
+            }
+        };
+
+        let guard = self.enter_resolving(py, type_key)?;
+        let instance = self.construct_with(py, target.bind(py), tenant, Some(overrides))?;
+        let mut instance = match instance.bind(py).cast::<DoNotCache>() {
+            Ok(wrapper) => wrapper.get().value.clone_ref(py),
+            Err(_) => instance,
+        };
+        CONSTRUCTIONS.with(|count| count.set(count.get() + 1));
+        self.counters.created(kind, false);
+        for decorator in self.decorator_chain(py, type_key) {
+            instance = decorator.call1(py, (instance,))?;
+        }
+        guard.complete();
+        Ok(instance)
+    }
+
+    /// Instance of `type_key` already cached for this resolve: in the
+    /// namespace's cache (if one is active) or the singleton cache, the
+    /// tenant's partition or this thread's instances
+    fn cached_instance(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+        namespace: Option<&String>,
+    ) -> Option<Py<PyAny>> {
+        let cached = if let Some(namespace) = namespace {
+            let namespace_singletons = self.namespace_singletons.read().unwrap();
+            namespace_singletons
+                .get(namespace)
+                .and_then(|singletons| singletons.get(type_key))
+                .map(|instance| instance.clone_ref(py))
+        } else {
+            self.singletons.get(py, type_key)
+        };
+        let cached = cached.or_else(|| {
+            let tenant = tenant?;
+            let tenant_singletons = self.tenant_singletons.read().unwrap();
+            tenant_singletons
+                .get(type_key)
+                .and_then(|partitions| partitions.get(tenant))
+                .map(|instance| instance.clone_ref(py))
+        });
+        cached.or_else(|| self.thread_instance(py, type_key))
+    }
+
+    /// Resolve without notifying resolve hooks
+    fn resolve_uninstrumented(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        self.ensure_open(py, type_key)?;
+        Counters::count(&self.counters.resolutions);
+        let namespace = self.active_namespace(py);
+
+        // Check singleton cache first (the namespace's own cache, if one is active)
+        if let Some(instance) = self.cached_instance(py, type_key, tenant, namespace.as_ref()) {
+            Counters::count(&self.counters.cache_hits);
+            return Ok(instance);
+        }
+
+        // Get provider; once frozen it is borrowed from the sealed registry
+        // without locking or cloning
+        let sealed = self.sealed.get();
+        let provider = match sealed {
+            Some(sealed) => match sealed.providers.get(type_key) {
+                Some(provider) => Ok(Cow::Borrowed(provider)),
+                None => Err(Self::registered_siblings(type_key, &sealed.providers)),
+            },
+            None => {
+                let providers = self.providers.read().unwrap();
+                match providers.get(type_key).cloned() {
+                    Some(provider) => Ok(Cow::Owned(provider)),
+                    None => Err(Self::registered_siblings(type_key, &providers)),
+                }
+            }
+        };
+        let provider = match provider {
+            Ok(provider) => provider,
+            Err(siblings) => {
+                // The fallback that owns the registration also owns
+                // (and caches) any singleton it creates
+                if let Some(fallback) = self.fallback_for(type_key) {
+                    return fallback.resolve_for_tenant(py, type_key, tenant);
+                }
+                let Some(cls) = self.autowire_class(py, type_key) else {
+                    self.record_failure_path(py, Some(type_key));
+                    return Err(Self::not_registered(py, type_key, &siblings));
+                };
+                Cow::Owned(self.bind_implicitly(py, type_key, cls)?)
+            }
+        };
+
+        // A key already under construction on this thread is a cycle,
+        // whether reached through auto-wiring or a factory calling resolve()
+        let guard = self.enter_resolving(py, type_key)?;
+
+        let per_tenant = matches!(*provider, Provider::TenantFactory(_));
+        let per_thread = matches!(
+            *provider,
+            Provider::Class(_, Lifetime::Thread) | Provider::ThreadFactory(_)
+        );
+        if per_tenant && tenant.is_none() {
+            return Err(ContainerError::TenantRequired {
+                type_name: type_key.type_name(py),
+            });
+        }
+
+        // Frozen containers only serve singletons that already exist
+        if matches!(
+            *provider,
+            Provider::Class(_, Lifetime::Singleton)
+                | Provider::SingletonFactory(_)
+                | Provider::WeakSingleton(_)
+                | Provider::TenantFactory(_)
+                | Provider::GeneratorFactory(_, Lifetime::Singleton)
+        ) && self.singletons_frozen.load(Ordering::Acquire)
+        {
+            return Err(ContainerError::SingletonsFrozen {
+                type_name: type_key.type_name(py),
+            });
+        }
+
+        // One thread builds a shared singleton; racing resolves wait for it
+        // and are served from the cache, released when this resolve returns
+        let shared = matches!(
+            *provider,
+            Provider::Class(_, Lifetime::Singleton)
+                | Provider::SingletonFactory(_)
+                | Provider::WeakSingleton(_)
+                | Provider::GeneratorFactory(_, Lifetime::Singleton)
+        ) || matches!(&*provider, Provider::Env(env) if env.cached);
+        let _claim = match shared {
+            true => {
+                let claim = self.construction_gates.claim(py, type_key);
+                let cached = self.cached_instance(py, type_key, tenant, namespace.as_ref());
+                if let Some(instance) = cached {
+                    Counters::count(&self.counters.cache_hits);
+                    guard.complete();
+                    return Ok(instance);
+                }
+                claim
+            }
+            false => None,
+        };
+
+        // Decorators registered for this type wrap every newly created instance
+        let decorators = self.decorator_chain(py, type_key);
+
+        let transient = matches!(
+            *provider,
+            Provider::Prototype(_)
+                | Provider::Class(_, Lifetime::Transient)
+                | Provider::TransientFactory(_)
+                | Provider::GeneratorFactory(_, Lifetime::Transient)
+        );
+        let mut constructed = !matches!(*provider, Provider::Instance(_) | Provider::Env(_));
+        let kind = provider.kind_name();
+        let target = provider.target_ptr();
+
+        // Create instance based on provider type; a shared singleton that
+        // fails to build is remembered for healthcheck()
+        let decorated = !decorators.is_empty();
+        let created = self.create_instance(py, type_key, &provider, tenant, decorated);
+        let (instance, cache) = match created {
+            Ok((instance, cache, created)) => {
+                constructed &= created;
+                (instance, cache)
+            }
+            Err(err) => {
+                if shared {
+                    let message = format!("{}: {}", type_key.type_name(py), err);
+                    self.construction_failures
+                        .write()
+                        .unwrap()
+                        .insert(type_key.clone(), message);
+                }
+                return Err(err);
+            }
+        };
+        if shared {
+            self.construction_failures.write().unwrap().remove(type_key);
+        }
+
+        if constructed {
+            CONSTRUCTIONS.with(|count| count.set(count.get() + 1));
+            self.counters.created(kind, cache);
+        }
+        if transient {
+            self.record_transient(type_key);
+        }
+
+        // Apply decorators in registration order, before caching
+        let mut instance = instance;
+        for decorator in &decorators {
+            instance = decorator.call1(py, (instance,))?;
+        }
+
+        // A generator factory's own teardown finalizes its transients
+        if transient && !matches!(*provider, Provider::GeneratorFactory(..)) {
+            self.track_transient(py, type_key, tenant, &instance);
+        }
+
+        // A provider may re-register its own key while it runs (a plugin
+        // replacing itself); the instance it built then belongs to a binding
+        // that no longer exists, so it is returned but not cached. A sealed
+        // registry cannot change, so there is nothing to re-check
+        let cache = cache
+            && (sealed.is_some()
+                || self
+                    .providers
+                    .read()
+                    .unwrap()
+                    .get(type_key)
+                    .is_some_and(|current| {
+                        current.target_ptr() == target && current.kind_name() == kind
+                    }));
+
+        // If another thread (or a re-entrant resolve) cached the key while
+        // this instance was being built, the cached one wins so every caller
+        // shares it; the loser is dropped once the cache lock is released
+        if let (true, true, Some(tenant)) = (cache, per_tenant, tenant) {
+            let cached = {
+                let mut tenant_singletons = self.tenant_singletons.write().unwrap();
+                let partitions = tenant_singletons.entry(type_key.clone()).or_default();
+                match partitions.entry(tenant.to_string()) {
+                    Entry::Occupied(entry) => Some(entry.get().clone_ref(py)),
+                    Entry::Vacant(entry) => {
+                        entry.insert(instance.clone_ref(py));
+                        None
+                    }
+                }
+            };
+            if let Some(cached) = cached {
+                guard.complete();
+                return Ok(cached);
+            }
+            self.tenant_order
+                .write()
+                .unwrap()
+                .push((type_key.clone(), tenant.to_string()));
+            let entry = format!("{}[tenant={}]", type_key.type_name(py), tenant);
+            self.construction_log.write().unwrap().push(entry);
+        } else if cache && per_thread {
+            let cached = {
+                let mut thread_singletons = self.thread_singletons.write().unwrap();
+                let instances = thread_singletons.entry(thread::current().id()).or_default();
+                match instances.entry(type_key.clone()) {
+                    Entry::Occupied(entry) => Some(entry.get().clone_ref(py)),
+                    Entry::Vacant(entry) => {
+                        entry.insert(instance.clone_ref(py));
+                        None
+                    }
+                }
+            };
+            if let Some(cached) = cached {
+                guard.complete();
+                return Ok(cached);
+            }
+            self.release_on_thread_exit(py)?;
+            let entry = format!("{}[thread]", type_key.type_name(py));
+            self.construction_log.write().unwrap().push(entry);
+        } else if let (true, Some(namespace)) = (cache, &namespace) {
+            let cached = {
+                let mut namespace_singletons = self.namespace_singletons.write().unwrap();
+                let singletons = namespace_singletons.entry(namespace.clone()).or_default();
+                match singletons.entry(type_key.clone()) {
+                    Entry::Occupied(entry) => Some(entry.get().clone_ref(py)),
+                    Entry::Vacant(entry) => {
+                        entry.insert(instance.clone_ref(py));
+                        None
+                    }
+                }
+            };
+            if let Some(cached) = cached {
+                guard.complete();
+                return Ok(cached);
+            }
+            let entry = format!("{}[namespace={}]", type_key.type_name(py), namespace);
+            self.construction_log.write().unwrap().push(entry);
+        } else if cache {
+            let weak = matches!(*provider, Provider::WeakSingleton(_));
+            let cached = if weak {
+                let reference = PyWeakrefReference::new(instance.bind(py))?.unbind();
+                self.singletons.insert_entry_if_absent(
+                    py,
+                    type_key,
+                    CachedInstance::Weak(reference),
+                )
+            } else {
+                self.singletons.insert_if_absent(py, type_key, &instance)
+            };
+            if let Some(cached) = cached {
+                guard.complete();
+                return Ok(cached);
+            }
+            let mut order = self.singleton_order.write().unwrap();
+            if weak {
+                // A rebuilt weak singleton takes the place of the collected one
+                order.retain(|key| key != type_key);
+            }
+            order.push(type_key.clone());
+            drop(order);
+            let entry = type_key.type_name(py);
+            self.construction_log.write().unwrap().push(entry);
+            self.start_if_running(py, type_key, &instance)?;
+        }
+
+        guard.complete();
+        Ok(instance)
+    }
+
+    /// Build a new instance of `type_key` from its provider
+    ///
+    /// Returns the instance, whether it should be cached and whether it was
+    /// newly built (a pooled borrow may hand out an idle one).
+    fn create_instance(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        provider: &Provider,
+        tenant: Option<&str>,
+        decorated: bool,
+    ) -> Result<(Py<PyAny>, bool, bool), ContainerError> {
+        let mut constructed = true;
+        let (instance, cache) = match provider {
+            Provider::Instance(obj) => {
+                // Instance providers are always singletons (pre-created); once
+                // decorated, cache the wrapper so the chain runs only once
+                (obj.clone_ref(py), decorated)
+            }
+            Provider::Prototype(template) => {
+                // Prototype providers hand out an independent deep copy each time
+                let copy = py.import("copy")?.call_method1("deepcopy", (template,))?;
+                (copy.unbind(), false)
+            }
+            Provider::Class(cls, lifetime) => {
+                // Singleton classes construct once and cache (thread ones once
+                // per thread); transient ones construct on every resolve
+                let instance = self.construct(py, cls.bind(py).as_any(), tenant)?;
+                (instance, *lifetime != Lifetime::Transient)
+            }
+            Provider::SingletonFactory(factory) | Provider::WeakSingleton(factory) => {
+                // Singleton factory - call once and cache result, unless the
+                // factory opted out for this call by returning DoNotCache
+                let instance = self.construct(py, factory.bind(py), tenant)?;
+                match instance.bind(py).cast::<DoNotCache>() {
+                    Ok(wrapper) => (wrapper.get().value.clone_ref(py), false),
+                    Err(_) => (instance, true),
+                }
+            }
+            Provider::TransientFactory(factory) => {
+                // Transient factory - create new instance each time (no caching);
+                // DoNotCache is redundant here but still unwrapped
+                let instance = self.construct(py, factory.bind(py), tenant)?;
+                match instance.bind(py).cast::<DoNotCache>() {
+                    Ok(wrapper) => (wrapper.get().value.clone_ref(py), false),
+                    Err(_) => (instance, false),
+                }
+            }
+            Provider::TenantFactory(factory) | Provider::ThreadFactory(factory) => {
+                // Tenant and thread factories - call once per tenant (or
+                // thread) and cache in its partition
+                let instance = self.construct(py, factory.bind(py), tenant)?;
+                match instance.bind(py).cast::<DoNotCache>() {
+                    Ok(wrapper) => (wrapper.get().value.clone_ref(py), false),
+                    Err(_) => (instance, true),
+                }
+            }
+            Provider::Pooled(pool) => {
+                // Pooled factory - borrow an idle instance, or build one while
+                // the pool has room; the handle returns it
+                let (handle, created) = self.borrow_pooled(py, type_key, pool, tenant)?;
+                constructed = created;
+                (handle, false)
+            }
+            Provider::AsyncFactory(..) => {
+                // Only an async singleton that resolve_async() already cached
+                // is served synchronously (by the resolve's cache check)
+                return Err(ContainerError::AsyncProviderRequired {
+                    type_name: type_key.type_name(py),
+                });
+            }
+            Provider::GeneratorFactory(factory, lifetime) => {
+                // Generator factory - the first yield is the instance; the
+                // generator is finished later by close()
+                let cache = *lifetime == Lifetime::Singleton;
+                let instance = self.start_generator(py, type_key, factory, tenant, cache)?;
+                (instance, cache)
+            }
+            Provider::Env(env) => (env.read(py, type_key)?, env.cached),
+        };
+        Ok((instance, cache, constructed))
+    }
+
+    /// Borrow an instance from a pooled provider, wrapped in a
+    /// `PooledInstance`; also reports whether it was newly built
+    fn borrow_pooled(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        pool: &Arc<ObjectPool>,
+        tenant: Option<&str>,
+    ) -> Result<(Py<PyAny>, bool), ContainerError> {
+        let (instance, created) = match pool.acquire(py) {
+            Some(PoolSlot::Idle(instance)) => (instance, false),
+            Some(PoolSlot::Vacant) => match self.construct(py, pool.factory.bind(py), tenant) {
+                Ok(instance) => (instance, true),
+                Err(err) => {
+                    pool.forget_one();
+                    return Err(err);
+                }
+            },
+            None => {
+                return Err(ContainerError::PoolExhausted {
+                    type_name: type_key.type_name(py),
+                    size: pool.size,
+                })
+            }
+        };
+        let handle = PooledInstance {
+            pool: Arc::clone(pool),
+            instance: Some(instance),
+        };
+        Ok((Py::new(py, handle)?.into_any(), created))
+    }
+
+    /// Advance a generator factory to its first yield and record its teardown
+    fn start_generator(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        factory: &Py<PyAny>,
+        tenant: Option<&str>,
+        cached: bool,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        let generator = self.construct(py, factory.bind(py), tenant)?;
+        let instance = match generator.bind(py).call_method0("__next__") {
+            Ok(instance) => instance.unbind(),
+            Err(err) if err.is_instance_of::<PyStopIteration>(py) => {
+                return Err(PyRuntimeError::new_err(format!(
+                    "generator factory for {} did not yield",
+                    type_key.type_name(py)
+                ))
+                .into());
+            }
+            Err(err) => return Err(err.into()),
+        };
+        self.teardowns.write().unwrap().push(Teardown {
+            type_key: type_key.clone(),
+            generator,
+            cached,
+        });
+        Ok(instance)
+    }
+
+    /// Finish every started generator factory, dependents first
+    ///
+    /// Each generator is resumed once so the code after its `yield` runs,
+    /// like a pytest fixture; singletons it produced are evicted from the
+    /// cache. Teardowns run in reverse dependency order (see
+    /// `disposal_order`). Returns one message per generator that raised or
+    /// yielded again; a failure never stops the remaining teardowns.
+    pub fn close_generators(&self, py: Python) -> Vec<String> {
+        let teardowns = std::mem::take(&mut *self.teardowns.write().unwrap());
+        let teardowns = self.disposal_order(
+            py,
+            teardowns
+                .into_iter()
+                .map(|teardown| (teardown.cached.then(|| teardown.type_key.clone()), teardown))
+                .collect(),
+        );
+        teardowns
+            .into_iter()
+            .filter_map(|teardown| self.finish_teardown(py, teardown))
+            .collect()
+    }
+
+    /// Resume a started generator factory so its teardown runs, evicting
+    /// the singleton it produced; returns a report if it misbehaved
+    fn finish_teardown(&self, py: Python, teardown: Teardown) -> Option<String> {
+        let type_name = teardown.type_key.type_name(py);
+        let generator = teardown.generator.bind(py);
+        let report = match generator.call_method0("__next__") {
+            Err(err) if err.is_instance_of::<PyStopIteration>(py) => None,
+            Err(err) => Some(format!("{}: teardown failed: {}", type_name, err)),
+            Ok(_) => {
+                if let Err(err) = generator.call_method0("close") {
+                    err.write_unraisable(py, Some(generator));
+                }
+                Some(format!(
+                    "{}: generator factory yielded more than once",
+                    type_name
+                ))
+            }
+        };
+        if teardown.cached {
+            self.evict_singleton(&teardown.type_key);
+        }
+        report
+    }
+
+    /// Start resolving a key for `resolve_async`
+    ///
+    /// Keys with an async factory get a pending awaitable around the
+    /// factory's coroutine, unless an awaited singleton is already cached.
+    /// While a singleton's coroutine is awaited, other resolves on the same
+    /// event loop await its result instead of calling the factory again.
+    /// Every other key is resolved right away and wrapped as a completed
+    /// awaitable, so async callers can use one method for everything. Like
+    /// `resolve`, an active cache namespace gets its own singletons.
+    pub fn start_async(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+    ) -> Result<AsyncResolve, ContainerError> {
+        self.ensure_open(py, type_key)?;
+        let provider = self.providers.read().unwrap().get(type_key).cloned();
+        let (factory, lifetime) = match provider {
+            Some(Provider::AsyncFactory(factory, lifetime)) => (factory, lifetime),
+            Some(_) => {
+                let instance = self.resolve_for_tenant(py, type_key, tenant)?;
+                return Ok(AsyncResolve::ready(instance));
+            }
+            None => {
+                return match self.fallback_for(type_key) {
+                    Some(fallback) => fallback.start_async(py, type_key, tenant),
+                    None => Ok(AsyncResolve::ready(
+                        self.resolve_for_tenant(py, type_key, tenant)?,
+                    )),
+                };
+            }
+        };
+
+        Counters::count(&self.counters.resolutions);
+        let namespace = self.active_namespace(py);
+        if let Some(instance) = self.cached_instance(py, type_key, tenant, namespace.as_ref()) {
+            Counters::count(&self.counters.cache_hits);
+            return Ok(AsyncResolve::ready(instance));
+        }
+        let cache = lifetime == Lifetime::Singleton;
+        if cache && self.singletons_frozen.load(Ordering::Acquire) {
+            return Err(ContainerError::SingletonsFrozen {
+                type_name: type_key.type_name(py),
+            });
+        }
+
+        // The first resolve of a singleton on a loop builds it; racing
+        // ones await a shielded future it settles, so cancelling one of
+        // them leaves the others waiting
+        let in_flight = match cache {
+            true => running_loop_future(py)?,
+            false => None,
+        };
+        if let Some((future, event_loop)) = &in_flight {
+            let mut flights = self.async_in_flight.lock().unwrap();
+            match flights.entry((namespace.clone(), type_key.clone())) {
+                Entry::Occupied(mut entry) if entry.get().event_loop.is(event_loop) => {
+                    entry.get_mut().waiters += 1;
+                    let shared = entry.get().future.clone_ref(py);
+                    drop(flights);
+                    let shielded = py.import("asyncio")?.call_method1("shield", (shared,))?;
+                    return Ok(AsyncResolve {
+                        state: AsyncState::Pending {
+                            iterator: shielded.call_method0("__await__")?.unbind(),
+                            finish: AsyncFinish::Shared,
+                        },
+                    });
+                }
+                Entry::Occupied(_) => {}
+                Entry::Vacant(entry) => {
+                    entry.insert(InFlight {
+                        future: future.clone_ref(py),
+                        event_loop: event_loop.clone_ref(py),
+                        waiters: 0,
+                    });
+                }
+            }
+        }
+        let in_flight = in_flight.map(|(future, _)| future);
+
+        let iterator = factory
+            .bind(py)
+            .call0()
+            .and_then(|coroutine| coroutine.call_method0("__await__"));
+        let iterator = match iterator {
+            Ok(iterator) => iterator,
+            Err(err) => {
+                let flight = (namespace, type_key.clone());
+                self.settle_async(py, &flight, in_flight, Err(Some(&err)))?;
+                return Err(err.into());
+            }
+        };
+        Ok(AsyncResolve {
+            state: AsyncState::Pending {
+                iterator: iterator.unbind(),
+                finish: AsyncFinish::Resolve {
+                    container: Box::new(self.clone()),
+                    type_key: type_key.clone(),
+                    namespace,
+                    cache,
+                    in_flight,
+                },
+            },
+        })
+    }
+
+    /// Release the in-flight entry of a namespace and key (if `future` is
+    /// still it) and settle `future` for the resolves awaiting it
+    ///
+    /// `Err(None)` means the owning awaitable was dropped unawaited. A
+    /// failure no resolve awaits cancels the future instead, so asyncio
+    /// does not report it as never retrieved.
+    fn settle_async(
+        &self,
+        py: Python,
+        flight: &(Option<String>, TypeKey),
+        future: Option<Py<PyAny>>,
+        outcome: Result<&Py<PyAny>, Option<&PyErr>>,
+    ) -> PyResult<()> {
+        let Some(future) = future else {
+            return Ok(());
+        };
+        let waiters = {
+            let mut flights = self.async_in_flight.lock().unwrap();
+            match flights.get(flight) {
+                Some(entry) if entry.future.is(&future) => {
+                    flights.remove(flight).map_or(0, |entry| entry.waiters)
+                }
+                _ => 0,
+            }
+        };
+        let future = future.bind(py);
+        if future.call_method0("done")?.is_truthy()? {
+            return Ok(());
+        }
+        let cancelled = py.import("asyncio")?.getattr("CancelledError")?;
+        match outcome {
+            Ok(instance) => future.call_method1("set_result", (instance,))?,
+            Err(Some(err)) if waiters > 0 && !err.is_instance(py, &cancelled) => {
+                future.call_method1("set_exception", (err.value(py),))?
+            }
+            Err(_) => future.call_method0("cancel")?,
+        };
+        Ok(())
+    }
+
+    /// Decorate and (for singletons) cache the awaited result of an async
+    /// factory, in `namespace`'s cache if the resolve started inside one
+    ///
+    /// If a concurrent `resolve_async` cached the key first, its instance
+    /// wins and this result is discarded.
+    fn finish_async(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        namespace: Option<&String>,
+        instance: Py<PyAny>,
+        cache: bool,
+    ) -> PyResult<Py<PyAny>> {
+        CONSTRUCTIONS.with(|count| count.set(count.get() + 1));
+        let kind = if cache {
+            "async_factory"
+        } else {
+            "transient_async_factory"
+        };
+        self.counters.created(kind, cache);
+        if !cache {
+            self.record_transient(type_key);
+        }
+
+        let decorators = self.decorator_chain(py, type_key);
+        let mut instance = instance;
+        for decorator in &decorators {
+            instance = decorator.call1(py, (instance,))?;
+        }
+        if !cache {
+            return Ok(instance);
+        }
+
+        if let Some(namespace) = namespace {
+            let cached = {
+                let mut namespace_singletons = self.namespace_singletons.write().unwrap();
+                let singletons = namespace_singletons.entry(namespace.clone()).or_default();
+                match singletons.entry(type_key.clone()) {
+                    Entry::Occupied(entry) => Some(entry.get().clone_ref(py)),
+                    Entry::Vacant(entry) => {
+                        entry.insert(instance.clone_ref(py));
+                        None
+                    }
+                }
+            };
+            if let Some(cached) = cached {
+                return Ok(cached);
+            }
+            let entry = format!("{}[namespace={}]", type_key.type_name(py), namespace);
+            self.construction_log.write().unwrap().push(entry);
+            return Ok(instance);
+        }
+        if let Some(existing) = self.singletons.insert_if_absent(py, type_key, &instance) {
+            return Ok(existing);
+        }
+        self.singleton_order.write().unwrap().push(type_key.clone());
+        let entry = type_key.type_name(py);
+        self.construction_log.write().unwrap().push(entry);
+        self.start_if_running(py, type_key, &instance)
+            .map_err(|err| err.into_py_err(py, None))?;
+        Ok(instance)
+    }
+
+    /// Start every cached singleton and keep starting new ones until stopped
+    ///
+    /// A component is started through `start()`, or `__enter__()` if it has
+    /// no `start()`; instances with neither are left alone. Singletons are
+    /// started in creation order, so dependencies start first. If a
+    /// component fails to start, the ones already started are stopped and
+    /// the error is raised.
+    pub fn start_lifecycle(&self, py: Python) -> PyResult<()> {
+        if self.lifecycle_running.swap(true, Ordering::AcqRel) {
+            return Err(PyRuntimeError::new_err(
+                "container lifecycle already started",
+            ));
+        }
+
+        let instances = self.snapshot_singletons(py);
+        for (type_key, instance) in &instances {
+            if let Err(err) = self.start_component(py, type_key, instance) {
+                warn_reports(py, self.stop_lifecycle(py))?;
+                return Err(err);
+            }
+        }
+        Ok(())
+    }
+
+    /// Stop started components in reverse start order
+    ///
+    /// Each is stopped through `stop()`, or `__exit__(None, None, None)` if
+    /// it was entered. Returns one message per component that failed to
+    /// stop; a failure never prevents the others from stopping.
+    pub fn stop_lifecycle(&self, py: Python) -> Vec<String> {
+        self.lifecycle_running.store(false, Ordering::Release);
+        let started = std::mem::take(&mut *self.started.write().unwrap());
+
+        let mut reports = Vec::new();
+        let none = py.None();
+        for (type_name, instance) in started.into_iter().rev() {
+            let instance = instance.bind(py);
+            let stopped = if instance.hasattr("stop").unwrap_or(false) {
+                instance.call_method0("stop").map(drop)
+            } else if instance.hasattr("__exit__").unwrap_or(false) {
+                instance
+                    .call_method1("__exit__", (&none, &none, &none))
+                    .map(drop)
+            } else {
+                Ok(())
+            };
+            if let Err(err) = stopped {
+                reports.push(format!("{}: stop failed: {}", type_name, err));
+            }
+        }
+        reports
+    }
+
+    /// Start a newly cached singleton if the lifecycle is running
+    ///
+    /// A singleton that fails to start is evicted, so the next resolve
+    /// constructs (and starts) it again.
+    fn start_if_running(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        instance: &Py<PyAny>,
+    ) -> Result<(), ContainerError> {
+        if !self.lifecycle_running.load(Ordering::Acquire) {
+            return Ok(());
+        }
+        self.start_component(py, type_key, instance).map_err(|err| {
+            self.evict_singleton(type_key);
+            ContainerError::from(err)
+        })
+    }
+
+    /// Start the lifecycle, returning the start coroutines of async components
+    ///
+    /// Components with `astart()` (or `__aenter__()`) have it called and
+    /// are returned with their coroutines, for the caller to await; the
+    /// others are started synchronously as in `start_lifecycle()`.
+    /// Singletons created later are started synchronously, so async-only
+    /// components should be resolved before entering.
+    pub fn start_lifecycle_async(&self, py: Python) -> PyResult<Vec<(Py<PyAny>, Py<PyAny>)>> {
+        if self.lifecycle_running.swap(true, Ordering::AcqRel) {
+            return Err(PyRuntimeError::new_err(
+                "container lifecycle already started",
+            ));
+        }
+
+        let instances = self.snapshot_singletons(py);
+        let mut coroutines = Vec::new();
+        for (type_key, instance) in &instances {
+            let bound = instance.bind(py);
+            let method = ["astart", "__aenter__"]
+                .into_iter()
+                .find(|name| bound.hasattr(*name).unwrap_or(false));
+            let started = match method {
+                Some(method) => bound.call_method0(method).map(|coroutine| {
+                    coroutines.push((instance.clone_ref(py), coroutine.unbind()));
+                    self.started
+                        .write()
+                        .unwrap()
+                        .push((type_key.type_name(py), instance.clone_ref(py)));
+                }),
+                None => self.start_component(py, type_key, instance),
+            };
+            if let Err(err) = started {
+                warn_reports(py, self.stop_lifecycle(py))?;
+                return Err(err);
+            }
+        }
+        Ok(coroutines)
+    }
+
+    /// Check the results of gathered async starts
+    ///
+    /// Components whose start failed are dropped from the started list, and
+    /// the first failure is raised; the rest stay started for `aclose()`.
+    fn finish_async_start(
+        &self,
+        py: Python,
+        components: Vec<Py<PyAny>>,
+        results: &Bound<'_, PyAny>,
+    ) -> PyResult<()> {
+        let base_exception = py.get_type::<pyo3::exceptions::PyBaseException>();
+        let mut first_error = None;
+        for (component, result) in components.iter().zip(results.try_iter()?) {
+            let result = result?;
+            if !result.is_instance(&base_exception)? {
+                continue;
+            }
+            self.started
+                .write()
+                .unwrap()
+                .retain(|(_, instance)| !instance.is(component));
+            first_error.get_or_insert_with(|| PyErr::from_value(result));
+        }
+        match first_error {
+            Some(err) => Err(err),
+            None => Ok(()),
+        }
+    }
+
+    /// Stop started components, returning the stop coroutines of async ones
+    ///
+    /// Components with `astop()` (or `__aexit__()`) have it called and their
+    /// coroutines returned with the component names; the others are stopped
+    /// synchronously, most recent first, and their failures reported.
+    pub fn stop_lifecycle_async(&self, py: Python) -> (StartedComponents, Vec<String>) {
+        self.lifecycle_running.store(false, Ordering::Release);
+        let started = std::mem::take(&mut *self.started.write().unwrap());
+
+        let mut coroutines = Vec::new();
+        let mut reports = Vec::new();
+        let none = py.None();
+        for (type_name, instance) in started.into_iter().rev() {
+            let instance = instance.bind(py);
+            let stopped = if instance.hasattr("astop").unwrap_or(false) {
+                instance.call_method0("astop")
+            } else if instance.hasattr("__aexit__").unwrap_or(false) {
+                instance.call_method1("__aexit__", (&none, &none, &none))
+            } else if instance.hasattr("stop").unwrap_or(false) {
+                instance.call_method0("stop").map(|_| none.bind(py).clone())
+            } else if instance.hasattr("__exit__").unwrap_or(false) {
+                instance
+                    .call_method1("__exit__", (&none, &none, &none))
+                    .map(|_| none.bind(py).clone())
+            } else {
+                continue;
+            };
+            match stopped {
+                Ok(coroutine) if !coroutine.is_none() => {
+                    coroutines.push((type_name, coroutine.unbind()));
+                }
+                Ok(_) => {}
+                Err(err) => reports.push(format!("{}: stop failed: {}", type_name, err)),
+            }
+        }
+        (coroutines, reports)
+    }
+
+    /// Add a report for each gathered async stop that failed
+    fn report_async_stops(
+        py: Python,
+        names: Vec<String>,
+        results: &Bound<'_, PyAny>,
+        reports: &mut Vec<String>,
+    ) -> PyResult<()> {
+        let base_exception = py.get_type::<pyo3::exceptions::PyBaseException>();
+        for (type_name, result) in names.into_iter().zip(results.try_iter()?) {
+            let result = result?;
+            if result.is_instance(&base_exception)? {
+                let err = PyErr::from_value(result);
+                reports.push(format!("{}: stop failed: {}", type_name, err));
+            }
+        }
+        Ok(())
+    }
+
+    /// Start one component and record it for `stop_lifecycle()`
+    fn start_component(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        instance: &Py<PyAny>,
+    ) -> PyResult<()> {
+        let bound = instance.bind(py);
+        if bound.hasattr("start")? {
+            bound.call_method0("start")?;
+        } else if bound.hasattr("__enter__")? {
+            bound.call_method0("__enter__")?;
+        } else {
+            return Ok(());
+        }
+        self.started
+            .write()
+            .unwrap()
+            .push((type_key.type_name(py), instance.clone_ref(py)));
+        Ok(())
+    }
+
+    /// Report container readiness without constructing anything
+    ///
+    /// Configuration problems are wiring mistakes that will fail (or behave
+    /// unexpectedly) on resolve: abstract classes or classes whose
+    /// constructor needs arguments registered as class providers, decorators
+    /// for unregistered types, shadowed registrations, and the remaining
+    /// `validate()` failures (cycles, conflicting ordering hints). A class
+    /// already reported is not reported again for its parameters. Runtime
+    /// problems are what would fail or misbehave once the container is in
+    /// use: singletons depending on tenant, thread or pooled instances they
+    /// would outlive, and shared singletons whose last construction failed.
+    /// The result is a plain dict suitable for a `/health` endpoint.
+    pub fn healthcheck<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let entries: Vec<(TypeKey, Provider)> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .map(|(key, provider)| (key.clone(), provider.clone()))
+                .collect()
+        };
+        let singleton_count = self.singletons.len();
+
+        let inspect = py.import("inspect")?;
+        let mut config_problems = Vec::new();
+        // Display names of classes reported below, whose parameter problems
+        // from `validate()` would repeat the same mistake
+        let mut reported: HashSet<String> = HashSet::new();
+        for (key, provider) in &entries {
+            let Provider::Class(cls, _) = provider else {
+                continue;
+            };
+            let cls = cls.bind(py);
+            let class_name = cls
+                .name()
+                .map(|n| n.to_string())
+                .unwrap_or_else(|_| "<unknown>".to_string());
+
+            if inspect.call_method1("isabstract", (cls,))?.is_truthy()? {
+                config_problems.push(format!(
+                    "{}: class {} is abstract and cannot be instantiated",
+                    key.type_name(py),
+                    class_name
+                ));
+                reported.insert(key.type_name(py));
+                continue;
+            }
+
+            // Required constructor parameters must be auto-wirable from the
+            // registry, or resolution fails
+            let params = self
+                .constructor_params(py, cls)
+                .map_err(|e| e.into_py_err(py, None))?;
+            let required: Vec<&str> = params
+                .iter()
+                .filter(|param| {
+                    let provided = match (&param.config, &param.flag) {
+                        (Some(config_key), _) => self.config_value(py, config_key).is_some(),
+                        (None, Some(flag)) => self.flag_value(py, flag).is_some(),
+                        (None, None) => param.key.as_ref().is_some_and(|key| self.provides(key)),
+                    };
+                    param.required() && !provided
+                })
+                .map(|param| param.name.as_str())
+                .collect();
+            if !required.is_empty() {
+                config_problems.push(format!(
+                    "{}: class {} requires constructor arguments ({}) that cannot be auto-wired from registered types",
+                    key.type_name(py),
+                    class_name,
+                    required.join(", ")
+                ));
+                reported.insert(key.type_name(py));
+            }
+        }
+
+        let undecorated: Vec<TypeKey> = {
+            let decorators = self.decorators.read().unwrap();
+            decorators
+                .keys()
+                .filter(|key| !entries.iter().any(|(registered, _)| registered == *key))
+                .cloned()
+                .collect()
+        };
+        for key in &undecorated {
+            config_problems.push(format!(
+                "{}: decorators registered but the type is not registered",
+                key.type_name(py)
+            ));
+        }
+
+        match self.validate(py) {
+            Ok(()) => {}
+            Err(ContainerError::ValidationFailed { problems }) => {
+                config_problems.extend(problems.into_iter().filter(|problem| {
+                    !reported
+                        .iter()
+                        .any(|name| problem.starts_with(&format!("{name}: parameter ")))
+                }))
+            }
+            Err(err) => config_problems.push(err.to_string()),
+        }
+
+        config_problems.sort();
+        config_problems.extend(self.check_shadowing(py));
+
+        let mut runtime_problems = Vec::new();
+
+        // A cached instance holding a narrower-lived dependency keeps that
+        // dependency alive past its own lifetime
+        let graph = self
+            .dependency_graph(py)
+            .map_err(|e| e.into_py_err(py, None))?;
+        let lifetimes: HashMap<&TypeKey, &'static str> = entries
+            .iter()
+            .map(|(key, provider)| (key, provider.lifetime_name()))
+            .collect();
+        let mut violations = Vec::new();
+        for (key, dependencies) in &graph {
+            if lifetimes.get(key) != Some(&"singleton") {
+                continue;
+            }
+            for dependency in dependencies {
+                let Some(&lifetime) = lifetimes.get(dependency) else {
+                    continue;
+                };
+                if matches!(lifetime, "tenant" | "thread" | "pooled") {
+                    violations.push(format!(
+                        "{}: singleton depends on {} ({} lifetime), which it would outlive",
+                        key.type_name(py),
+                        dependency.type_name(py),
+                        lifetime
+                    ));
+                }
+            }
+        }
+        violations.sort();
+        runtime_problems.extend(violations);
+
+        let mut failures: Vec<String> = self
+            .construction_failures
+            .read()
+            .unwrap()
+            .values()
+            .map(|failure| format!("construction failed: {failure}"))
+            .collect();
+        failures.sort();
+        runtime_problems.extend(failures);
+
+        let configuration = PyDict::new(py);
+        configuration.set_item("healthy", config_problems.is_empty())?;
+        configuration.set_item("problems", &config_problems)?;
+
+        let runtime = PyDict::new(py);
+        runtime.set_item("healthy", runtime_problems.is_empty())?;
+        runtime.set_item("problems", &runtime_problems)?;
+
+        let healthy = config_problems.is_empty() && runtime_problems.is_empty();
+        let report = PyDict::new(py);
+        report.set_item("status", if healthy { "ok" } else { "error" })?;
+        report.set_item("registrations", entries.len())?;
+        report.set_item("singletons", singleton_count)?;
+        report.set_item("configuration", configuration)?;
+        report.set_item("runtime", runtime)?;
+        Ok(report)
+    }
+
+    /// List every registration that could produce the requested type
+    ///
+    /// A registration matches when its key is the requested type or a subtype
+    /// of it, or when its class/instance provider produces such a subtype.
+    /// The exact key (if registered) comes first, the rest sorted by name.
+    /// Nothing is constructed.
+    pub fn all_bindings<'py>(
+        &self,
+        py: Python<'py>,
+        py_type: &Bound<'py, PyType>,
+    ) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        // Snapshot so subclass checks run without holding registry locks
+        let entries: Vec<(TypeKey, Provider)> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .map(|(key, provider)| (key.clone(), provider.clone()))
+                .collect()
+        };
+
+        let mut matches: Vec<(bool, String, Bound<'py, PyDict>)> = Vec::new();
+        for (key, provider) in &entries {
+            let key_matches = key
+                .as_type()
+                .map(|key_type| key_type.bind(py).is_subclass(py_type).unwrap_or(false))
+                .unwrap_or(false);
+
+            let implementation = match provider {
+                Provider::Instance(obj) | Provider::Prototype(obj) => {
+                    obj.bind(py).get_type().into_any()
+                }
+                Provider::Class(cls, _) => cls.bind(py).clone().into_any(),
+                Provider::SingletonFactory(factory)
+                | Provider::WeakSingleton(factory)
+                | Provider::TransientFactory(factory)
+                | Provider::TenantFactory(factory)
+                | Provider::ThreadFactory(factory)
+                | Provider::AsyncFactory(factory, _)
+                | Provider::GeneratorFactory(factory, _) => factory.bind(py).clone(),
+                Provider::Pooled(pool) => pool.factory.bind(py).clone(),
+                Provider::Env(env) => env.variable.bind(py).clone().into_any(),
+            };
+            let impl_matches = match provider {
+                // A weak singleton's factory may be the class itself
+                Provider::Instance(_)
+                | Provider::Prototype(_)
+                | Provider::Class(..)
+                | Provider::WeakSingleton(_) => implementation
+                    .cast::<PyType>()
+                    .map(|impl_type| impl_type.is_subclass(py_type).unwrap_or(false))
+                    .unwrap_or(false),
+                Provider::SingletonFactory(_)
+                | Provider::TransientFactory(_)
+                | Provider::TenantFactory(_)
+                | Provider::ThreadFactory(_)
+                | Provider::Pooled(_)
+                | Provider::AsyncFactory(..)
+                | Provider::GeneratorFactory(..)
+                | Provider::Env(_) => false,
+            };
+            if !key_matches && !impl_matches {
+                continue;
+            }
+
+            let binding = PyDict::new(py);
+            binding.set_item("key", key.py_object(py))?;
+            binding.set_item("provider", provider.kind_name())?;
+            binding.set_item("implementation", implementation)?;
+            binding.set_item("cached", self.singletons.contains_key(py, key))?;
+
+            let exact = key
+                .as_type()
+                .is_some_and(|key_type| key_type.bind(py).is(py_type));
+            matches.push((exact, key.type_name(py), binding));
+        }
+
+        matches.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+        Ok(matches.into_iter().map(|(_, _, binding)| binding).collect())
+    }
+
+    /// Construct every singleton registration up front
+    ///
+    /// Singletons are resolved in `build_order()`, so configuration and
+    /// connection errors surface at startup instead of on the first request.
+    /// Stops at the first failure; returns the warmed keys in order.
+    pub fn instantiate_all(&self, py: Python) -> Result<Vec<TypeKey>, ContainerError> {
+        let order = self.build_order(py)?;
+        for key in &order {
+            self.resolve(py, key)?;
+        }
+        Ok(order)
+    }
+
+    /// The order `instantiate_all()` constructs singleton registrations in
+    ///
+    /// Singleton classes, factories and generator factories are ordered
+    /// topologically: a class's auto-wired dependencies and the keys its
+    /// `before=`/`after=` hints put first come before it, ties broken by
+    /// type name. Tenant and async providers are left out: they need a
+    /// tenant id or `resolve_async()`.
+    pub fn build_order(&self, py: Python) -> Result<Vec<TypeKey>, ContainerError> {
+        let mut singletons: Vec<(TypeKey, Provider)> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .filter(|(_, provider)| {
+                    matches!(
+                        provider,
+                        Provider::Class(_, Lifetime::Singleton)
+                            | Provider::SingletonFactory(_)
+                            | Provider::GeneratorFactory(_, Lifetime::Singleton)
+                    )
+                })
+                .map(|(key, provider)| (key.clone(), provider.clone()))
+                .collect()
+        };
+        singletons.sort_by_cached_key(|(key, _)| key.type_name(py));
+
+        let dependencies = self.ordering_graph(py)?;
+
+        // Depth-first post-order; a key already on the stack is a cycle,
+        // left for resolve to report with its full path
+        let mut order = Vec::with_capacity(singletons.len());
+        let mut visited = HashSet::new();
+        for (root, _) in &singletons {
+            let mut stack = vec![(root.clone(), false)];
+            while let Some((key, expanded)) = stack.pop() {
+                if expanded {
+                    order.push(key);
+                    continue;
+                }
+                if !visited.insert(key.clone()) {
+                    continue;
+                }
+                stack.push((key.clone(), true));
+                for dependency in dependencies.get(&key).into_iter().flatten().rev() {
+                    let singleton = singletons.iter().any(|(key, _)| key == dependency);
+                    if singleton && !visited.contains(dependency) {
+                        stack.push((dependency.clone(), false));
+                    }
+                }
+            }
+        }
+
+        Ok(order)
+    }
+
+    /// Check that every registration can be resolved, without constructing
+    ///
+    /// Each class provider's required `__init__` parameters are matched
+    /// against the registry (and fallbacks). A parameter whose type is not
+    /// registered is missing, or ambiguous when several registrations could
+    /// stand in for it (named bindings of the type, or subclasses); cycles in
+    /// the auto-wiring graph are reported once each, as are cycles that
+    /// `before=`/`after=` hints add to it. Every problem is collected into a
+    /// single `ValidationFailed` error.
+    pub fn validate(&self, py: Python) -> Result<(), ContainerError> {
+        let (mut classes, registered): (Vec<(TypeKey, Py<PyType>)>, Vec<TypeKey>) = {
+            let providers = self.providers.read().unwrap();
+            let classes = providers
+                .iter()
+                .filter_map(|(key, provider)| match provider {
+                    Provider::Class(cls, _) => Some((key.clone(), cls.clone_ref(py))),
+                    _ => None,
+                })
+                .collect();
+            (classes, providers.keys().cloned().collect())
+        };
+        classes.sort_by_cached_key(|(key, _)| key.type_name(py));
+
+        let mut problems = Vec::new();
+        for (key, cls) in &classes {
+            let params = self.constructor_params(py, cls.bind(py))?;
+            for param in params.iter().filter(|param| param.required()) {
+                if let Some(config_key) = &param.config {
+                    if self.config_value(py, config_key).is_none() {
+                        problems.push(format!(
+                            "{}: parameter '{}' needs configuration value '{}', which is not registered",
+                            key.type_name(py),
+                            param.name,
+                            config_key
+                        ));
+                    }
+                    continue;
+                }
+                if let Some(flag) = &param.flag {
+                    if self.flag_value(py, flag).is_none() {
+                        problems.push(format!(
+                            "{}: parameter '{}' needs feature flag '{}', which is not set",
+                            key.type_name(py),
+                            param.name,
+                            flag
+                        ));
+                    }
+                    continue;
+                }
+                let Some(dependency) = &param.key else {
+                    problems.push(format!(
+                        "{}: parameter '{}' has no type annotation",
+                        key.type_name(py),
+                        param.name
+                    ));
+                    continue;
+                };
+                let consumer = TypeKey::new(cls.clone_ref(py));
+                let overridden = !param.lazy
+                    && !param.deferred
+                    && self.has_consumer_override(&consumer, dependency);
+                if overridden || self.provides(dependency) {
+                    continue;
+                }
+
+                let mut candidates = Vec::new();
+                for candidate in &registered {
+                    let named = candidate.name().is_some()
+                        && candidate.clone().with_name(None) == *dependency;
+                    let subclass = match (candidate.as_type(), dependency.as_type()) {
+                        (Some(sub), Some(base)) if !sub.is(base) => {
+                            sub.bind(py).is_subclass(base.bind(py))?
+                        }
+                        _ => false,
+                    };
+                    if named || subclass {
+                        candidates.push(candidate.type_name(py));
+                    }
+                }
+                candidates.sort();
+                if candidates.len() > 1 {
+                    problems.push(format!(
+                        "{}: parameter '{}' is ambiguous: {} is not registered but {} could provide it",
+                        key.type_name(py),
+                        param.name,
+                        dependency.type_name(py),
+                        candidates.join(", ")
+                    ));
+                } else {
+                    problems.push(format!(
+                        "{}: parameter '{}' needs {}, which is not registered",
+                        key.type_name(py),
+                        param.name,
+                        dependency.type_name(py)
+                    ));
+                }
+            }
+        }
+
+        let graph = self.dependency_graph(py)?;
+        let mut roots: Vec<TypeKey> = classes.into_iter().map(|(key, _)| key).collect();
+        let cycles = find_cycles(py, &graph, &roots);
+        let mut hinted: Vec<TypeKey> = self
+            .ordering_hints
+            .read()
+            .unwrap()
+            .keys()
+            .cloned()
+            .collect();
+        hinted.sort_by_cached_key(|key| key.type_name(py));
+        roots.extend(hinted);
+        let conflicts: Vec<String> = find_cycles(py, &self.ordering_graph(py)?, &roots)
+            .into_iter()
+            .filter(|cycle| !cycles.contains(cycle))
+            .collect();
+        problems.extend(
+            cycles
+                .into_iter()
+                .map(|cycle| format!("circular dependency: {}", cycle)),
+        );
+        problems.extend(
+            conflicts
+                .into_iter()
+                .map(|cycle| format!("conflicting ordering hints: {}", cycle)),
+        );
+
+        if problems.is_empty() {
+            Ok(())
+        } else {
+            Err(ContainerError::ValidationFailed { problems })
+        }
+    }
+
+    /// Render the dependency graph in Graphviz DOT format
+    ///
+    /// Nodes are the registered keys labelled with their lifetime; edges run
+    /// from a class to the auto-wired dependencies of its constructor, from
+    /// the cached signature metadata. Dependencies served by a fallback
+    /// container appear as dashed nodes. Output is sorted, so it is stable
+    /// across runs.
+    pub fn to_dot(&self, py: Python) -> Result<String, ContainerError> {
+        // Display names may run Python (enum `name`, generic `repr`), so
+        // format them only after the guard is released
+        let bindings: Vec<(TypeKey, &'static str)> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .map(|(key, provider)| (key.clone(), provider.lifetime_name()))
+                .collect()
+        };
+        let mut nodes: Vec<(String, &'static str)> = bindings
+            .into_iter()
+            .map(|(key, lifetime)| (key.type_name(py), lifetime))
+            .collect();
+        nodes.sort();
+
+        let graph = self.dependency_graph(py)?;
+        let mut edges: Vec<(String, String)> = Vec::new();
+        let mut external: Vec<String> = Vec::new();
+        for (key, dependencies) in &graph {
+            for dependency in dependencies {
+                if !self.contains(dependency) {
+                    external.push(dependency.type_name(py));
+                }
+                edges.push((key.type_name(py), dependency.type_name(py)));
+            }
+        }
+        edges.sort();
+        edges.dedup();
+        external.sort();
+        external.dedup();
+
+        let escape = |name: &str| name.replace('"', "\\\"");
+        let mut dot = String::from("digraph dioxide {\n");
+        for (name, lifetime) in &nodes {
+            let name = escape(name);
+            dot.push_str(&format!(
+                "    \"{name}\" [label=\"{name}\\n({lifetime})\"];\n"
+            ));
+        }
+        for name in &external {
+            let name = escape(name);
+            dot.push_str(&format!(
+                "    \"{name}\" [label=\"{name}\\n(fallback)\", style=dashed];\n"
+            ));
+        }
+        for (from, to) in &edges {
+            dot.push_str(&format!(
+                "    \"{}\" -> \"{}\";\n",
+                escape(from),
+                escape(to)
+            ));
+        }
+        dot.push_str("}\n");
+        Ok(dot)
+    }
+
+    /// Every binding as (display name, key, provider kind, lifetime)
+    ///
+    /// Display names are formatted after the providers guard is released,
+    /// since they may run Python (enum `name`, generic `repr`).
+    fn named_bindings(&self, py: Python) -> Vec<(String, TypeKey, &'static str, &'static str)> {
+        let bindings: Vec<(TypeKey, &'static str, &'static str)> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .map(|(key, provider)| {
+                    (key.clone(), provider.kind_name(), provider.lifetime_name())
+                })
+                .collect()
+        };
+        bindings
+            .into_iter()
+            .map(|(key, kind, lifetime)| (key.type_name(py), key, kind, lifetime))
+            .collect()
+    }
+
+    /// Describe every binding, sorted by display name
+    ///
+    /// Each record has the `key` (type or enum member), its `name`
+    /// qualifier, the `provider` kind, `lifetime`, sorted `tags`, the
+    /// `description` and `source` (file and line) given when it was
+    /// registered, and whether a `cached` singleton exists. Nothing is
+    /// constructed.
+    pub fn list_registrations<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        let mut entries = self.named_bindings(py);
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+        entries
+            .iter()
+            .map(|(_, key, kind, lifetime)| self.registration_record(py, key, kind, lifetime))
+            .collect()
+    }
+
+    /// Describe the binding of one key like `list_registrations`, or None
+    /// if it is not registered here
+    pub fn registration_info<'py>(
+        &self,
+        py: Python<'py>,
+        type_key: &TypeKey,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let provider = self
+            .providers
+            .read()
+            .unwrap()
+            .get(type_key)
+            .map(|provider| (provider.kind_name(), provider.lifetime_name()));
+        provider
+            .map(|(kind, lifetime)| self.registration_record(py, type_key, kind, lifetime))
+            .transpose()
+    }
+
+    /// One `list_registrations` record
+    fn registration_record<'py>(
+        &self,
+        py: Python<'py>,
+        type_key: &TypeKey,
+        kind: &str,
+        lifetime: &str,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let tags = self.tags(type_key);
+        let metadata = self
+            .metadata
+            .read()
+            .unwrap()
+            .get(type_key)
+            .cloned()
+            .unwrap_or_default();
+        let registration = PyDict::new(py);
+        registration.set_item("key", type_key.py_object(py))?;
+        registration.set_item("name", type_key.name())?;
+        registration.set_item("provider", kind)?;
+        registration.set_item("lifetime", lifetime)?;
+        registration.set_item("tags", tags)?;
+        registration.set_item("description", metadata.description)?;
+        registration.set_item("source", metadata.source)?;
+        registration.set_item("cached", self.singletons.contains_key(py, type_key))?;
+        Ok(registration)
+    }
+
+    /// Describe registrations and dependency edges as JSON for tooling
+    ///
+    /// `registrations` lists every binding with its `key` (the display name,
+    /// qualifier included), bare `type`, `module`, `name` qualifier,
+    /// `provider` kind and `lifetime`; `edges` lists the auto-wiring
+    /// dependencies of class providers as `from`/`to` keys. Both are sorted.
+    /// `indent` is passed to `json.dumps`.
+    pub fn to_json(&self, py: Python, indent: Option<usize>) -> Result<String, ContainerError> {
+        let mut entries = self.named_bindings(py);
+        entries.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let registrations = PyList::empty(py);
+        for (display_name, key, kind, lifetime) in &entries {
+            let module = key
+                .py_object(py)
+                .bind(py)
+                .getattr("__module__")
+                .map(|module| module.to_string())
+                .ok();
+            let registration = PyDict::new(py);
+            registration.set_item("key", display_name)?;
+            registration.set_item("type", key.base_type_name(py))?;
+            registration.set_item("module", module)?;
+            registration.set_item("name", key.name())?;
+            registration.set_item("provider", kind)?;
+            registration.set_item("lifetime", lifetime)?;
+            registrations.append(registration)?;
+        }
+
+        let mut edges: Vec<(String, String)> = self
+            .dependency_graph(py)?
+            .iter()
+            .flat_map(|(key, dependencies)| {
+                dependencies
+                    .iter()
+                    .map(|dependency| (key.type_name(py), dependency.type_name(py)))
+            })
+            .collect();
+        edges.sort();
+        edges.dedup();
+        let edge_list = PyList::empty(py);
+        for (from, to) in edges {
+            let edge = PyDict::new(py);
+            edge.set_item("from", from)?;
+            edge.set_item("to", to)?;
+            edge_list.append(edge)?;
+        }
+
+        let document = PyDict::new(py);
+        document.set_item("registrations", registrations)?;
+        document.set_item("edges", edge_list)?;
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("indent", indent)?;
+        Ok(py
+            .import("json")?
+            .call_method("dumps", (document,), Some(&kwargs))?
+            .extract()?)
+    }
+
+    /// Auto-wiring edges of every class provider: from the class's key to
+    /// the keys of its `__init__` parameters that can be resolved (here or in
+    /// a fallback). Factories are opaque and have no edges; neither do
+    /// `Lazy[X]` parameters, which are resolved after construction.
+    fn dependency_graph(
+        &self,
+        py: Python,
+    ) -> Result<HashMap<TypeKey, Vec<TypeKey>>, ContainerError> {
+        let classes: Vec<(TypeKey, Py<PyType>)> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .filter_map(|(key, provider)| match provider {
+                    Provider::Class(cls, _) => Some((key.clone(), cls.clone_ref(py))),
+                    _ => None,
+                })
+                .collect()
+        };
+
+        let mut graph = HashMap::new();
+        for (key, cls) in classes {
+            let params = self.constructor_params(py, cls.bind(py))?;
+            let edges = params
+                .iter()
+                .filter(|param| !param.lazy && !param.deferred)
+                .filter_map(|param| param.key.clone())
+                .filter(|dependency| self.provides(dependency))
+                .collect();
+            graph.insert(key, edges);
+        }
+        Ok(graph)
+    }
+
+    /// The dependency graph with the `before=`/`after=` hints merged in
+    fn ordering_graph(&self, py: Python) -> Result<HashMap<TypeKey, Vec<TypeKey>>, ContainerError> {
+        let mut graph = self.dependency_graph(py)?;
+        for (key, earlier) in self.ordering_hints.read().unwrap().iter() {
+            let edges = graph.entry(key.clone()).or_default();
+            for dependency in earlier {
+                if !edges.contains(dependency) {
+                    edges.push(dependency.clone());
+                }
+            }
+        }
+        Ok(graph)
+    }
+
+    /// Record that `type_key` is built after the keys in `after` and before
+    /// the keys in `before`, for `build_order()` and disposal
+    pub fn add_ordering_hints(
+        &self,
+        type_key: &TypeKey,
+        before: Vec<TypeKey>,
+        after: Vec<TypeKey>,
+    ) {
+        let mut hints = self.ordering_hints.write().unwrap();
+        let mut add = |key: TypeKey, earlier: TypeKey| {
+            let edges = hints.entry(key).or_default();
+            if !edges.contains(&earlier) {
+                edges.push(earlier);
+            }
+        };
+        for later in before {
+            add(later, type_key.clone());
+        }
+        for earlier in after {
+            add(type_key.clone(), earlier);
+        }
+    }
+
+    /// Return already-cached instances without constructing anything
+    ///
+    /// Looks where a resolve would: the active cache namespace, the tenant's
+    /// partition and this thread's instances. Registered instances count as
+    /// cached unless a decorator still has to wrap them. If any key is cold
+    /// (or unregistered), a single error names every such key so warm-up gaps
+    /// surface all at once.
+    pub fn resolve_cached_only(
+        &self,
+        py: Python,
+        type_keys: &[TypeKey],
+        tenant: Option<&str>,
+    ) -> Result<Vec<Py<PyAny>>, ContainerError> {
+        let namespace = self.active_namespace(py);
+        // Registered instance (if any) per key; `None` when unregistered.
+        // Names are formatted below, after the guard is released.
+        let registered: Vec<Option<Option<Py<PyAny>>>> = {
+            let providers = self.providers.read().unwrap();
+            type_keys
+                .iter()
+                .map(|type_key| {
+                    providers.get(type_key).map(|provider| match provider {
+                        Provider::Instance(obj) => Some(obj.clone_ref(py)),
+                        _ => None,
+                    })
+                })
+                .collect()
+        };
+
+        let mut instances = Vec::with_capacity(type_keys.len());
+        let mut cold = Vec::new();
+        for (type_key, registered) in type_keys.iter().zip(registered) {
+            if let Some(instance) = self.cached_instance(py, type_key, tenant, namespace.as_ref()) {
+                instances.push(instance);
+                continue;
+            }
+            match registered {
+                Some(Some(obj)) if self.decorator_chain(py, type_key).is_empty() => {
+                    instances.push(obj);
+                }
+                Some(_) => cold.push(type_key.type_name(py)),
+                None => cold.push(format!("{} (not registered)", type_key.type_name(py))),
+            }
+        }
+
+        if !cold.is_empty() {
+            return Err(ContainerError::NotCached {
+                type_names: cold.join(", "),
+            });
+        }
+        Ok(instances)
+    }
+
+    /// Names of the named bindings of a type, here or in a fallback, sorted
+    fn binding_names(&self, type_key: &TypeKey) -> BTreeSet<String> {
+        let named = |key: &TypeKey| match key.name() {
+            Some(name) if key.clone().with_name(None) == *type_key => Some(name.to_string()),
+            _ => None,
+        };
+        let mut names: BTreeSet<String> = match self.sealed.get() {
+            Some(sealed) => sealed.providers.keys().filter_map(named).collect(),
+            None => self
+                .providers
+                .read()
+                .unwrap()
+                .keys()
+                .filter_map(named)
+                .collect(),
+        };
+        for fallback in self.fallbacks.read().unwrap().iter() {
+            names.extend(fallback.binding_names(type_key));
+        }
+        names
+    }
+
+    /// Build the collection injected for a `list[X]`, `tuple[X, ...]`,
+    /// `set[X]`, `frozenset[X]` or `dict[str, X]` constructor parameter
+    fn assemble(
+        &self,
+        py: Python,
+        collection: Collection,
+        element: &TypeKey,
+        tenant: Option<&str>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        match collection {
+            Collection::List => Ok(PyList::new(py, self.resolve_all(py, element)?)?
+                .into_any()
+                .unbind()),
+            Collection::Tuple => Ok(PyTuple::new(py, self.resolve_all(py, element)?)?
+                .into_any()
+                .unbind()),
+            Collection::Set => Ok(PySet::new(py, self.resolve_all(py, element)?)?
+                .into_any()
+                .unbind()),
+            Collection::FrozenSet => Ok(PyFrozenSet::new(py, self.resolve_all(py, element)?)?
+                .into_any()
+                .unbind()),
+            Collection::Mapping => {
+                let mapping = PyDict::new(py);
+                for name in self.binding_names(element) {
+                    let key = element.clone().with_name(Some(name.clone()));
+                    mapping.set_item(name, self.resolve_for_tenant(py, &key, tenant)?)?;
+                }
+                Ok(mapping.into_any().unbind())
+            }
+        }
+    }
+
+    /// Whether any provider is contributed to the multi-binding of a type
+    fn has_multi_bindings(&self, type_key: &TypeKey) -> bool {
+        self.multi_bindings
+            .read()
+            .unwrap()
+            .get(type_key)
+            .is_some_and(|bindings| !bindings.is_empty())
+    }
+
+    /// Contribute a provider to the multi-binding of a type
+    ///
+    /// Multi-bindings live apart from the single-provider registry, so a
+    /// type may have both; any number of providers can be contributed.
+    /// `priority` orders them in `resolve_all` (highest first).
+    pub fn add_multi_binding(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        provider: Provider,
+        priority: i32,
+    ) -> Result<(), ContainerError> {
+        if let Provider::TenantFactory(_) = provider {
+            return Err(ContainerError::ProviderRegistrationFailed {
+                type_name: type_key.type_name(py),
+                reason: "multi-bindings cannot use per-tenant factories".to_string(),
+            });
+        }
+        if let Provider::Class(_, Lifetime::Thread) | Provider::ThreadFactory(_) = provider {
+            return Err(ContainerError::ProviderRegistrationFailed {
+                type_name: type_key.type_name(py),
+                reason: "multi-bindings cannot use the thread lifetime".to_string(),
+            });
+        }
+        if let Provider::Pooled(_) = provider {
+            return Err(ContainerError::ProviderRegistrationFailed {
+                type_name: type_key.type_name(py),
+                reason: "multi-bindings cannot use pooled factories".to_string(),
+            });
+        }
+        self.ensure_unfrozen(py, "register", &type_key)?;
+        self.notify_register(py, &type_key, &provider)?;
+        self.multi_bindings
+            .write()
+            .unwrap()
+            .entry(type_key)
+            .or_default()
+            .push(MultiBinding {
+                provider,
+                instance: None,
+                priority,
+            });
+        // Plans collecting `list[X]` parameters may now have more to inject
+        self.touch_registry();
+        Ok(())
+    }
+
+    /// Resolve every provider contributed to a multi-binding
+    ///
+    /// Instances come back by descending priority, then in registration
+    /// order. Each new instance is wrapped by the type's decorators and
+    /// interceptors, as a single resolve would; singleton classes and
+    /// factories are constructed once per binding and cached (weakly for weak
+    /// singletons). A type without multi-bindings resolves to an empty list.
+    pub fn resolve_all(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+    ) -> Result<Vec<Py<PyAny>>, ContainerError> {
+        self.ensure_open(py, type_key)?;
+        // Snapshot so providers run without holding the registry lock
+        // Bindings are stored in registration order; each entry keeps its
+        // index there so a cached instance lands on the right binding
+        let mut entries: Vec<(usize, i32, Provider, Option<Py<PyAny>>)> = {
+            let multi_bindings = self.multi_bindings.read().unwrap();
+            multi_bindings
+                .get(type_key)
+                .map(|bindings| {
+                    bindings
+                        .iter()
+                        .enumerate()
+                        .map(|(index, binding)| {
+                            let instance = binding
+                                .instance
+                                .as_ref()
+                                .and_then(|cached| cached.upgrade(py));
+                            (index, binding.priority, binding.provider.clone(), instance)
+                        })
+                        .collect()
+                })
+                .unwrap_or_default()
+        };
+        entries.sort_by_key(|&(_, priority, ..)| std::cmp::Reverse(priority));
+        let decorators = self.decorator_chain(py, type_key);
+
+        let mut instances = Vec::with_capacity(entries.len());
+        for (index, _, provider, cached) in entries {
+            if let Some(instance) = cached {
+                instances.push(instance);
+                continue;
+            }
+
+            let (instance, cache) = match &provider {
+                // Once decorated, cache the wrapper so the chain runs only once
+                Provider::Instance(obj) => (obj.clone_ref(py), !decorators.is_empty()),
+                Provider::Prototype(template) => {
+                    let copy = py.import("copy")?.call_method1("deepcopy", (template,))?;
+                    (copy.unbind(), false)
+                }
+                Provider::Class(cls, lifetime) => (
+                    self.construct(py, cls.bind(py).as_any(), None)?,
+                    *lifetime == Lifetime::Singleton,
+                ),
+                Provider::SingletonFactory(factory) | Provider::WeakSingleton(factory) => {
+                    let instance = self.construct(py, factory.bind(py), None)?;
+                    match instance.bind(py).cast::<DoNotCache>() {
+                        Ok(wrapper) => (wrapper.get().value.clone_ref(py), false),
+                        Err(_) => (instance, true),
+                    }
+                }
+                Provider::TransientFactory(factory)
+                | Provider::TenantFactory(factory)
+                | Provider::ThreadFactory(factory) => {
+                    let instance = self.construct(py, factory.bind(py), None)?;
+                    match instance.bind(py).cast::<DoNotCache>() {
+                        Ok(wrapper) => (wrapper.get().value.clone_ref(py), false),
+                        Err(_) => (instance, false),
+                    }
+                }
+                Provider::Pooled(pool) => (self.borrow_pooled(py, type_key, pool, None)?.0, false),
+                Provider::AsyncFactory(..) => {
+                    return Err(ContainerError::AsyncProviderRequired {
+                        type_name: type_key.type_name(py),
+                    });
+                }
+                Provider::GeneratorFactory(factory, lifetime) => (
+                    self.start_generator(py, type_key, factory, None, false)?,
+                    *lifetime == Lifetime::Singleton,
+                ),
+                Provider::Env(env) => (env.read(py, type_key)?, env.cached),
+            };
+            let mut instance = instance;
+            for decorator in &decorators {
+                instance = decorator.call1(py, (instance,))?;
+            }
+            if !cache {
+                instances.push(instance);
+                continue;
+            }
+
+            let entry = match provider {
+                Provider::WeakSingleton(_) => {
+                    let reference = PyWeakrefReference::new(instance.bind(py))?.unbind();
+                    CachedInstance::Weak(reference)
+                }
+                _ => CachedInstance::Strong(instance.clone_ref(py)),
+            };
+            // Another thread may have cached this binding meanwhile; keep its
+            // instance (while it is alive) so every caller sees the same one
+            let mut multi_bindings = self.multi_bindings.write().unwrap();
+            let binding = multi_bindings
+                .get_mut(type_key)
+                .and_then(|bindings| bindings.get_mut(index));
+            let instance = match binding {
+                Some(binding) => match binding.instance.as_ref().and_then(|c| c.upgrade(py)) {
+                    Some(cached) => cached,
+                    None => {
+                        binding.instance = Some(entry);
+                        instance
+                    }
+                },
+                None => instance,
+            };
+            instances.push(instance);
+        }
+        Ok(instances)
+    }
+
+    /// Add a decorator that wraps instances resolved for a type
+    ///
+    /// Each decorator receives the instance and returns a (possibly wrapped)
+    /// instance; decorators run in the order they were added. Singletons
+    /// cached before the decorator was added are not re-wrapped.
+    pub fn add_decorator(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        decorator: Py<PyAny>,
+    ) -> Result<(), ContainerError> {
+        let mut decorators = self.decorators.write().unwrap();
+        if self.is_frozen() {
+            drop(decorators);
+            return self.ensure_unfrozen(py, "decorate", &type_key);
+        }
+        decorators.entry(type_key).or_default().push(decorator);
+        Ok(())
+    }
+
+    /// Attach tags to the registration of a type
+    ///
+    /// Tags select which interceptors added with `add_interceptor()` wrap
+    /// the type's instances. Singletons cached before are not re-wrapped.
+    pub fn add_tags(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        tags: Vec<String>,
+    ) -> Result<(), ContainerError> {
+        self.ensure_unfrozen(py, "tag", &type_key)?;
+        self.tags
+            .write()
+            .unwrap()
+            .entry(type_key)
+            .or_default()
+            .extend(tags);
+        Ok(())
+    }
+
+    /// Tags attached to the registration of a type, sorted
+    pub fn tags(&self, type_key: &TypeKey) -> Vec<String> {
+        self.tags
+            .read()
+            .unwrap()
+            .get(type_key)
+            .map(|tags| tags.iter().cloned().collect())
+            .unwrap_or_default()
+    }
+
+    /// Add an interceptor that wraps instances of every type tagged `tag`
+    ///
+    /// Interceptors work like decorators and run after the type's own
+    /// decorators, in the order they were added, for every lifetime.
+    pub fn add_interceptor(
+        &self,
+        tag: String,
+        interceptor: Py<PyAny>,
+    ) -> Result<(), ContainerError> {
+        if self.is_frozen() {
+            return Err(ContainerError::ContainerFrozen {
+                action: format!("intercept tag {tag:?}"),
+            });
+        }
+        self.interceptors.write().unwrap().push((tag, interceptor));
+        Ok(())
+    }
+
+    /// Add configuration values for `Annotated[X, Config(name)]` parameters
+    ///
+    /// Nested mappings are also reachable by dotted name, so `{"db": {"url":
+    /// u}}` provides both `"db"` and `"db.url"`. A name registered again
+    /// takes the new value.
+    pub fn register_config(&self, values: &Bound<'_, PyAny>) -> Result<(), ContainerError> {
+        fn flatten(
+            prefix: Option<&str>,
+            values: &Bound<'_, PyAny>,
+            flat: &mut Vec<(String, Py<PyAny>)>,
+        ) -> PyResult<()> {
+            let abc = values.py().import("collections.abc")?;
+            for item in values.call_method0("items")?.try_iter()? {
+                let (name, value): (String, Bound<'_, PyAny>) = item?.extract()?;
+                let name = match prefix {
+                    Some(prefix) => format!("{prefix}.{name}"),
+                    None => name,
+                };
+                if value.is_instance(&abc.getattr("Mapping")?)? {
+                    flatten(Some(&name), &value, flat)?;
+                }
+                flat.push((name, value.unbind()));
+            }
+            Ok(())
+        }
+
+        if self.is_frozen() {
+            return Err(ContainerError::ContainerFrozen {
+                action: "register config".to_string(),
+            });
+        }
+        let mut flat = Vec::new();
+        flatten(None, values, &mut flat)?;
+        let replaced: Vec<Py<PyAny>> = {
+            let mut config = self.config.write().unwrap();
+            flat.into_iter()
+                .filter_map(|(name, value)| config.insert(name, value))
+                .collect()
+        };
+        self.touch_registry();
+        drop(replaced);
+        Ok(())
+    }
+
+    /// Configuration value registered under `name`, here or in a fallback
+    fn config_value(&self, py: Python, name: &str) -> Option<Py<PyAny>> {
+        if let Some(value) = self.config.read().unwrap().get(name) {
+            return Some(value.clone_ref(py));
+        }
+        let fallbacks = self.fallbacks.read().unwrap();
+        fallbacks
+            .iter()
+            .find_map(|fallback| fallback.config_value(py, name))
+    }
+
+    /// Set the feature flag `name` for `Annotated[X, Flag(name)]` parameters
+    ///
+    /// Instances built earlier keep the value they were given. With
+    /// `invalidate`, cached singletons of classes taking the flag, and of
+    /// classes depending on those, are evicted so the next resolve rebuilds
+    /// them with the new value.
+    pub fn set_flag(
+        &self,
+        py: Python,
+        name: String,
+        value: Py<PyAny>,
+        invalidate: bool,
+    ) -> Result<(), ContainerError> {
+        let replaced = self.flags.write().unwrap().insert(name.clone(), value);
+        // Plans decide between the flag and a parameter's default
+        self.touch_registry();
+        drop(replaced);
+        if !invalidate {
+            return Ok(());
+        }
+
+        let classes: Vec<(TypeKey, Py<PyType>)> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .filter_map(|(key, provider)| match provider {
+                    Provider::Class(cls, _) => Some((key.clone(), cls.clone_ref(py))),
+                    _ => None,
+                })
+                .collect()
+        };
+        let mut stale = Vec::new();
+        for (key, cls) in &classes {
+            let params = self.constructor_params(py, cls.bind(py))?;
+            if params
+                .iter()
+                .any(|param| param.flag.as_ref() == Some(&name))
+            {
+                stale.push(key.clone());
+            }
+        }
+        // Dependents hold the stale instances, so they go too
+        let graph = self.dependency_graph(py)?;
+        let mut index = 0;
+        while index < stale.len() {
+            for (key, dependencies) in &graph {
+                if dependencies.contains(&stale[index]) && !stale.contains(key) {
+                    stale.push(key.clone());
+                }
+            }
+            index += 1;
+        }
+
+        let mut evicted = Vec::new();
+        for key in &stale {
+            evicted.extend(self.evict_singleton(key));
+            evicted.extend(self.evict_tenant_instances(key));
+        }
+        drop(evicted);
+        Ok(())
+    }
+
+    /// Feature flag set under `name`, here or in a fallback
+    fn flag_value(&self, py: Python, name: &str) -> Option<Py<PyAny>> {
+        if let Some(value) = self.flags.read().unwrap().get(name) {
+            return Some(value.clone_ref(py));
+        }
+        let fallbacks = self.fallbacks.read().unwrap();
+        fallbacks
+            .iter()
+            .find_map(|fallback| fallback.flag_value(py, name))
+    }
+
+    /// Notify `hook(requested_type, provider_kind, cache_hit, duration_ms)`
+    /// after every successful resolve, dependencies included
+    ///
+    /// Hooks run in the order they were added, on the resolving thread; an
+    /// exception raised by a hook fails the resolve.
+    pub fn add_resolve_hook(&self, hook: Py<PyAny>) {
+        self.resolve_hooks.write().unwrap().push(hook);
+    }
+
+    /// Stop notifying a resolve hook; returns whether it was registered
+    pub fn remove_resolve_hook(&self, hook: &Bound<'_, PyAny>) -> bool {
+        remove_hook(&self.resolve_hooks, hook)
+    }
+
+    /// Notify `hook(key, provider_kind, lifetime)` before each registration
+    ///
+    /// Registrations skipped as duplicates are not reported. Multi-bindings
+    /// are reported too. An exception raised by a hook rejects the
+    /// registration (for a batch, the whole batch) before anything changes.
+    pub fn add_register_hook(&self, hook: Py<PyAny>) {
+        self.register_hooks.write().unwrap().push(hook);
+    }
+
+    /// Stop notifying a register hook; returns whether it was registered
+    pub fn remove_register_hook(&self, hook: &Bound<'_, PyAny>) -> bool {
+        remove_hook(&self.register_hooks, hook)
+    }
+
+    /// Call every register hook for a registration about to be applied
+    fn notify_register(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        provider: &Provider,
+    ) -> Result<(), ContainerError> {
+        let hooks: Vec<Py<PyAny>> = {
+            let hooks = self.register_hooks.read().unwrap();
+            hooks.iter().map(|hook| hook.clone_ref(py)).collect()
+        };
+        if hooks.is_empty() {
+            return Ok(());
+        }
+        let key = type_key.py_object(py);
+        for hook in hooks {
+            hook.call1(py, (&key, provider.kind_name(), provider.lifetime_name()))?;
+        }
+        Ok(())
+    }
+
+    /// Start counting transient constructions over a sliding window
+    ///
+    /// Re-enabling replaces the window and discards earlier counts.
+    pub fn enable_transient_telemetry(&self, window: Duration) {
+        *self.transient_telemetry.write().unwrap() = Some(TransientTelemetry::new(window));
+    }
+
+    /// Stop counting transient constructions and drop collected counts
+    pub fn disable_transient_telemetry(&self) {
+        *self.transient_telemetry.write().unwrap() = None;
+    }
+
+    fn record_transient(&self, type_key: &TypeKey) {
+        // Cheap read-lock check so resolves pay nothing while disabled
+        if self.transient_telemetry.read().unwrap().is_none() {
+            return;
+        }
+        if let Some(telemetry) = self.transient_telemetry.write().unwrap().as_mut() {
+            telemetry.record(type_key, Instant::now());
+        }
+    }
+
+    /// Transient constructions per second within the window, by key
+    ///
+    /// Empty while telemetry is disabled.
+    pub fn transient_allocation_rates(&self) -> Vec<(TypeKey, f64)> {
+        match self.transient_telemetry.write().unwrap().as_mut() {
+            Some(telemetry) => telemetry.rates(Instant::now()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Seal the registry: copy providers and decorators into an immutable
+    /// snapshot that resolves read without locking
+    ///
+    /// Registering, rebinding, unregistering, decorating and clearing fail
+    /// from then on. Caches are unaffected, so singletons are still created
+    /// on first resolve. Freezing twice is a no-op.
+    pub fn freeze(&self, py: Python) {
+        // Holding both read locks keeps writers out until the snapshot is set
+        let providers = self.providers.read().unwrap();
+        let decorators = self.decorators.read().unwrap();
+        self.sealed.get_or_init(|| FrozenRegistry {
+            providers: providers
+                .iter()
+                .map(|(key, provider)| (key.clone(), provider.clone_ref(py)))
+                .collect(),
+            decorators: decorators
+                .iter()
+                .map(|(key, chain)| {
+                    let chain = chain.iter().map(|d| d.clone_ref(py)).collect();
+                    (key.clone(), chain)
+                })
+                .collect(),
+        });
+    }
+
+    /// Whether `freeze()` has sealed the registry
+    pub fn is_frozen(&self) -> bool {
+        self.sealed.get().is_some()
+    }
+
+    /// Fail with `ContainerFrozen` (naming the action) once frozen
+    fn ensure_unfrozen(
+        &self,
+        py: Python,
+        verb: &str,
+        type_key: &TypeKey,
+    ) -> Result<(), ContainerError> {
+        if !self.is_frozen() {
+            return Ok(());
+        }
+        Err(ContainerError::ContainerFrozen {
+            action: format!("{verb} {}", type_key.type_name(py)),
+        })
+    }
+
+    /// Block (or allow again) singleton factories from running
+    pub fn set_singletons_frozen(&self, frozen: bool) {
+        self.singletons_frozen.store(frozen, Ordering::Release);
+    }
+
+    /// Capture the warm singleton cache in creation order
+    pub fn snapshot_singletons(&self, py: Python) -> Vec<(TypeKey, Py<PyAny>)> {
+        let order = self.singleton_order.read().unwrap();
+        order
+            .iter()
+            .filter_map(|key| {
+                self.singletons
+                    .get(py, key)
+                    .map(|instance| (key.clone(), instance))
+            })
+            .collect()
+    }
+
+    /// Seed the singleton cache from a snapshot so factories do not re-run
+    ///
+    /// Every key must be registered in this container; nothing is adopted
+    /// if one is not. Already-cached singletons are kept.
+    pub fn adopt_singletons(
+        &self,
+        py: Python,
+        entries: &[(TypeKey, Py<PyAny>)],
+    ) -> Result<(), ContainerError> {
+        let missing = {
+            let providers = self.providers.read().unwrap();
+            entries
+                .iter()
+                .find(|(key, _)| !providers.contains_key(key))
+                .map(|(key, _)| (key, Self::registered_siblings(key, &providers)))
+        };
+        if let Some((missing, siblings)) = missing {
+            return Err(Self::not_registered(py, missing, &siblings));
+        }
+
+        let mut order = self.singleton_order.write().unwrap();
+        for (key, instance) in entries {
+            if self
+                .singletons
+                .insert_if_absent(py, key, instance)
+                .is_none()
+            {
+                order.push(key.clone());
+            }
+        }
+        Ok(())
+    }
+
+    /// Capture the registry (providers, decorators and multi-bindings) and
+    /// the singleton cache
+    ///
+    /// The registry read lock is held throughout, so no registration lands
+    /// halfway through the capture.
+    pub fn snapshot(&self, py: Python) -> RegistrySnapshot {
+        let providers = self.providers.read().unwrap();
+        let decorators = self.decorators.read().unwrap();
+        let multi_bindings = self.multi_bindings.read().unwrap();
+        RegistrySnapshot {
+            providers: providers
+                .iter()
+                .map(|(key, provider)| (key.clone(), provider.clone_ref(py)))
+                .collect(),
+            decorators: decorators
+                .iter()
+                .map(|(key, chain)| {
+                    let chain = chain.iter().map(|d| d.clone_ref(py)).collect();
+                    (key.clone(), chain)
+                })
+                .collect(),
+            multi_bindings: multi_bindings
+                .iter()
+                .map(|(key, bindings)| {
+                    let bindings = bindings.iter().map(|b| b.clone_ref(py)).collect();
+                    (key.clone(), bindings)
+                })
+                .collect(),
+            singletons: self.snapshot_singletons(py),
+        }
+    }
+
+    /// Put back the registry and singleton cache captured by `snapshot()`
+    ///
+    /// Registrations, decorators and singletons added since are discarded,
+    /// as are tenant and namespace caches, which may hold instances built
+    /// from bindings that no longer exist. A snapshot can be restored any
+    /// number of times. The registry write lock is held until the caches
+    /// are seeded, so no resolve sees the restored bindings with the
+    /// discarded singletons.
+    pub fn restore(&self, py: Python, snapshot: &RegistrySnapshot) -> Result<(), ContainerError> {
+        let mut providers = self.providers.write().unwrap();
+        if self.is_frozen() {
+            return Err(ContainerError::ContainerFrozen {
+                action: "restore".to_string(),
+            });
+        }
+        let mut decorators = self.decorators.write().unwrap();
+        let evicted = self.take_caches();
+        let mut multi_bindings = self.multi_bindings.write().unwrap();
+        let replaced = (
+            std::mem::replace(
+                &mut *providers,
+                snapshot
+                    .providers
+                    .iter()
+                    .map(|(key, provider)| (key.clone(), provider.clone_ref(py)))
+                    .collect(),
+            ),
+            std::mem::replace(
+                &mut *decorators,
+                snapshot
+                    .decorators
+                    .iter()
+                    .map(|(key, chain)| {
+                        (key.clone(), chain.iter().map(|d| d.clone_ref(py)).collect())
+                    })
+                    .collect(),
+            ),
+            std::mem::replace(
+                &mut *multi_bindings,
+                snapshot
+                    .multi_bindings
+                    .iter()
+                    .map(|(key, bindings)| {
+                        (
+                            key.clone(),
+                            bindings.iter().map(|b| b.clone_ref(py)).collect(),
+                        )
+                    })
+                    .collect(),
+            ),
+        );
+        drop(multi_bindings);
+
+        let mut order = self.singleton_order.write().unwrap();
+        for (key, instance) in &snapshot.singletons {
+            if self
+                .singletons
+                .insert_if_absent(py, key, instance)
+                .is_none()
+            {
+                order.push(key.clone());
+            }
+        }
+        drop(order);
+        self.touch_registry();
+        drop((providers, decorators));
+        drop((replaced, evicted));
+        Ok(())
+    }
+
+    /// Copy another container's registrations into this one
+    ///
+    /// `on_conflict` decides keys registered in both. Each binding taken
+    /// from `other` brings its decorators and cached singleton along (so
+    /// both containers share the instance) and evicts whatever this
+    /// container cached for the key. Register hooks see every binding taken
+    /// first; the merge is then applied under one registry write lock, after
+    /// checking conflicts again, so it lands entirely or not at all.
+    /// Multi-bindings and fallbacks are not merged.
+    pub fn merge(
+        &self,
+        py: Python,
+        other: &RustContainer,
+        on_conflict: MergeConflict,
+    ) -> Result<(), ContainerError> {
+        if self.is_same(other) {
+            return Ok(());
+        }
+        // Copy `other` first so the two registries are never locked together
+        let incoming = other.snapshot(py);
+        // Keys to take from `other`, or the clashing keys if that is an error
+        let plan = |providers: &HashMap<TypeKey, Provider>| {
+            let (clashing, fresh): (Vec<&TypeKey>, Vec<&TypeKey>) = incoming
+                .providers
+                .keys()
+                .partition(|key| providers.contains_key(*key));
+            match on_conflict {
+                MergeConflict::Error if !clashing.is_empty() => {
+                    Err(clashing.into_iter().cloned().collect::<Vec<_>>())
+                }
+                MergeConflict::PreferOther => {
+                    Ok(fresh.into_iter().chain(clashing).cloned().collect())
+                }
+                _ => Ok(fresh.into_iter().cloned().collect::<Vec<_>>()),
+            }
+        };
+        let conflict_error = |clashing: Vec<TypeKey>| {
+            let mut names: Vec<String> = clashing.iter().map(|key| key.type_name(py)).collect();
+            names.sort();
+            ContainerError::DuplicateRegistration {
+                type_name: names.join(", "),
+            }
+        };
+
+        let keys = {
+            let providers = self.providers.read().unwrap();
+            plan(&providers)
+        };
+        for key in &keys.map_err(conflict_error)? {
+            self.ensure_unfrozen(py, "merge", key)?;
+            self.notify_register(py, key, &incoming.providers[key])?;
+        }
+
+        let mut providers = self.providers.write().unwrap();
+        // Registrations may have landed while the hooks ran
+        let keys = match plan(&providers) {
+            Ok(keys) if !keys.is_empty() && self.is_frozen() => {
+                drop(providers);
+                return self.ensure_unfrozen(py, "merge", &keys[0]);
+            }
+            Ok(keys) => keys,
+            Err(clashing) => {
+                drop(providers);
+                return Err(conflict_error(clashing));
+            }
+        };
+
+        let mut decorators = self.decorators.write().unwrap();
+        let mut replaced = Vec::new();
+        let mut evicted = Vec::new();
+        for key in &keys {
+            replaced.extend(providers.insert(key.clone(), incoming.providers[key].clone_ref(py)));
+            let chain = incoming
+                .decorators
+                .get(key)
+                .map(|chain| chain.iter().map(|d| d.clone_ref(py)).collect());
+            let previous = match chain {
+                Some(chain) => decorators.insert(key.clone(), chain),
+                None => decorators.remove(key),
+            };
+            evicted.extend(previous.into_iter().flatten());
+            evicted.extend(self.evict_singleton(key));
+            evicted.extend(self.evict_tenant_instances(key));
+        }
+        drop(decorators);
+
+        let mut order = self.singleton_order.write().unwrap();
+        for (key, instance) in &incoming.singletons {
+            if keys.contains(key)
+                && self
+                    .singletons
+                    .insert_if_absent(py, key, instance)
+                    .is_none()
+            {
+                order.push(key.clone());
+            }
+        }
+        drop(order);
+        self.touch_registry();
+        drop(providers);
+        drop((replaced, evicted));
+        Ok(())
+    }
+
+    /// Check if two containers are wired the same way
+    ///
+    /// Compares the registered keys and each key's provider kind (which also
+    /// fixes its lifetime), ignoring provider targets and live instances, so
+    /// a bootstrap that already ran can be detected and skipped.
+    pub fn is_equivalent_to(&self, other: &RustContainer) -> bool {
+        if self.is_same(other) {
+            return true;
+        }
+
+        let wiring = |container: &RustContainer| -> HashMap<TypeKey, &'static str> {
+            let providers = container.providers.read().unwrap();
+            providers
+                .iter()
+                .map(|(key, provider)| (key.clone(), provider.kind_name()))
+                .collect()
+        };
+        wiring(self) == wiring(other)
+    }
+
+    /// Add a container to consult when a key is not registered here
+    ///
+    /// Fallbacks are consulted in the order they were added, each one
+    /// (including its own fallbacks) before the next. Adding a container
+    /// that already falls back to this one would loop, so it is rejected.
+    pub fn add_fallback(&self, fallback: RustContainer) -> Result<(), ContainerError> {
+        if fallback.is_same(self) || fallback.falls_back_to(self) {
+            return Err(ContainerError::FallbackCycle);
+        }
+        self.fallbacks.write().unwrap().push(fallback);
+        self.touch_registry();
+        Ok(())
+    }
+
+    /// Create an empty container that falls back to this one
+    ///
+    /// The child starts with this container's duplicate policy and profile. Its own
+    /// registrations (overrides included) are cached in the child; keys it
+    /// does not register resolve here, so singletons built from this
+    /// container's bindings are shared with it and wired from its bindings.
+    pub fn create_child(&self) -> RustContainer {
+        let child = RustContainer::new()
+            .with_profile(self.profile())
+            .with_autowire(self.autowire);
+        child.set_duplicate_policy(self.duplicate_policy());
+        child.fallbacks.write().unwrap().push(self.clone());
+        child
+    }
+
+    /// Check if two handles refer to the same container
+    fn is_same(&self, other: &RustContainer) -> bool {
+        Arc::ptr_eq(&self.providers, &other.providers)
+    }
+
+    /// Check if this container (transitively) falls back to `target`
+    fn falls_back_to(&self, target: &RustContainer) -> bool {
+        let fallbacks = self.fallbacks.read().unwrap();
+        fallbacks
+            .iter()
+            .any(|fallback| fallback.is_same(target) || fallback.falls_back_to(target))
+    }
+
+    /// The class autowire mode may bind `type_key` to implicitly
+    ///
+    /// Only unnamed keys of concrete classes qualify: abstract classes,
+    /// Protocols and builtins (`str`, `int`, ...) still need a registration.
+    /// Nothing is bound once the container is frozen.
+    fn autowire_class(&self, py: Python, type_key: &TypeKey) -> Option<Py<PyType>> {
+        if !self.autowire || type_key.name().is_some() || self.is_frozen() {
+            return None;
+        }
+        let cls = type_key.as_type()?.bind(py);
+        let flag = |attr: &str| {
+            cls.getattr(attr)
+                .and_then(|flag| flag.is_truthy())
+                .unwrap_or(false)
+        };
+        let builtin = cls
+            .getattr("__module__")
+            .and_then(|module| module.extract::<String>())
+            .is_ok_and(|module| module == "builtins");
+        let is_abstract = py
+            .import("inspect")
+            .and_then(|inspect| inspect.call_method1("isabstract", (cls,))?.is_truthy())
+            .unwrap_or(true);
+        if builtin || is_abstract || flag("_is_protocol") {
+            return None;
+        }
+        Some(cls.clone().unbind())
+    }
+
+    /// Register `type_key` as a singleton class of itself, for autowire mode
+    ///
+    /// The constructor must compile to a resolution plan first, so a class
+    /// that cannot be built is reported without being registered. Returns
+    /// the provider now registered (another thread's, if it won a race).
+    fn bind_implicitly(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        cls: Py<PyType>,
+    ) -> Result<Provider, ContainerError> {
+        let plan = self.resolution_plan(py, cls.bind(py))?;
+        if let Some(&index) = plan.unresolved.first() {
+            return Err(ContainerError::UnresolvableParameter {
+                type_name: type_key.type_name(py),
+                parameter: plan.params[index].name.clone(),
+            });
+        }
+        self.register_provider(
+            py,
+            type_key.clone(),
+            Provider::Class(cls, Lifetime::Singleton),
+            Some(DuplicatePolicy::Ignore),
+            None,
+        )?;
+        let providers = self.providers.read().unwrap();
+        match providers.get(type_key).cloned() {
+            Some(provider) => Ok(provider),
+            None => {
+                let siblings = Self::registered_siblings(type_key, &providers);
+                drop(providers);
+                Err(Self::not_registered(py, type_key, &siblings))
+            }
+        }
+    }
+
+    /// Check if this container or any of its fallbacks has a registration
+    fn provides(&self, type_key: &TypeKey) -> bool {
+        self.contains(type_key) || self.fallback_for(type_key).is_some()
+    }
+
+    /// First fallback container able to provide the key
+    fn fallback_for(&self, type_key: &TypeKey) -> Option<RustContainer> {
+        let fallbacks = self.fallbacks.read().unwrap();
+        fallbacks
+            .iter()
+            .find(|fallback| fallback.provides(type_key))
+            .cloned()
+    }
+
+    /// Push `type_key` onto this thread's construction stack
+    ///
+    /// Fails with the full cycle path (`A -> B -> A`) when the key is
+    /// already being constructed further up the stack.
+    fn enter_resolving(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+    ) -> Result<ResolvingGuard<'_>, ContainerError> {
+        let mut resolving = self.resolving.write().unwrap();
+        let stack = resolving.entry(thread::current().id()).or_default();
+        if let Some(start) = stack.iter().position(|key| key == type_key) {
+            let cycle: Vec<TypeKey> = stack[start..].to_vec();
+            drop(resolving);
+            let cycle: Vec<String> = cycle
+                .iter()
+                .chain(std::iter::once(type_key))
+                .map(|key| key.type_name(py))
+                .collect();
+            self.record_failure_path(py, Some(type_key));
+            return Err(ContainerError::CircularDependency {
+                cycle: cycle.join(" -> "),
+            });
+        }
+        stack.push(type_key.clone());
+        Ok(ResolvingGuard {
+            container: self,
+            completed: false,
+        })
+    }
+
+    /// Remember this thread's construction stack (plus `failed`, when the
+    /// failing key never got onto it) unless a deeper failure already did
+    fn record_failure_path(&self, py: Python, failed: Option<&TypeKey>) {
+        FAILURE_PATH.with(|path| {
+            let mut path = path.borrow_mut();
+            if path.is_some() {
+                return;
+            }
+            let stack: Vec<TypeKey> = self
+                .resolving
+                .read()
+                .unwrap()
+                .get(&thread::current().id())
+                .cloned()
+                .unwrap_or_default();
+            *path = Some(
+                stack
+                    .iter()
+                    .chain(failed)
+                    .map(|key| (key.py_object(py), key.type_name(py)))
+                    .collect(),
+            );
+        });
+    }
+
+    /// Call a provider target, auto-wiring constructor arguments for classes
+    ///
+    /// Each `__init__` parameter annotated with a resolvable key (here or in
+    /// a fallback) is resolved recursively, under the same tenant. Parameters
+    /// that cannot be resolved keep their default; without one, construction
+    /// fails. Factory functions are called without arguments, unless they
+    /// were registered with bound ones (see `BoundFactory`).
+    fn construct(
+        &self,
+        py: Python,
+        target: &Bound<'_, PyAny>,
+        tenant: Option<&str>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        self.construct_with(py, target, tenant, None)
+    }
+
+    /// `construct`, with `overrides` merged over any bound keyword arguments
+    fn construct_with(
+        &self,
+        py: Python,
+        target: &Bound<'_, PyAny>,
+        tenant: Option<&str>,
+        overrides: Option<&Bound<'_, PyDict>>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        let (target, args, kwargs) = match target.cast::<BoundFactory>() {
+            Ok(bound) => {
+                let bound = bound.get();
+                let args = bound.args.bind(py).iter().map(Bound::unbind).collect();
+                let kwargs = bound.kwargs.bind(py).copy()?;
+                (bound.factory.bind(py).clone(), args, kwargs)
+            }
+            Err(_) => (target.clone(), Vec::new(), PyDict::new(py)),
+        };
+        if let Some(overrides) = overrides {
+            kwargs.update(overrides.as_mapping())?;
+        }
+        self.call_target(py, &target, args, kwargs, tenant)
+    }
+
+    /// Inject `provider` for `type_key` into `consumer` instead of the
+    /// provider registered for `type_key`
+    ///
+    /// Only auto-wiring of `consumer`'s constructor consults the override;
+    /// `resolve(type_key)` and `Lazy`/`Callable` parameters keep the default.
+    /// A class provider is auto-wired on first use and its instance is shared
+    /// by every `consumer` built afterwards. Consumers already cached keep
+    /// what they were given.
+    pub fn register_for_consumer(
+        &self,
+        py: Python,
+        type_key: TypeKey,
+        consumer: TypeKey,
+        provider: Py<PyAny>,
+    ) -> Result<(), ContainerError> {
+        self.ensure_unfrozen(py, "register", &type_key)?;
+        let replaced = self
+            .consumer_overrides
+            .write()
+            .unwrap()
+            .entry(consumer)
+            .or_default()
+            .insert(
+                type_key,
+                ConsumerOverride {
+                    provider,
+                    instance: None,
+                },
+            );
+        self.touch_registry();
+        drop(replaced);
+        Ok(())
+    }
+
+    /// Whether a provider is registered for `type_key` in `consumer`
+    fn has_consumer_override(&self, consumer: &TypeKey, type_key: &TypeKey) -> bool {
+        self.consumer_overrides
+            .read()
+            .unwrap()
+            .get(consumer)
+            .is_some_and(|overrides| overrides.contains_key(type_key))
+    }
+
+    /// The instance of `type_key` registered for `consumer`, built (and
+    /// decorated) on first use
+    fn consumer_override(
+        &self,
+        py: Python,
+        consumer: &TypeKey,
+        type_key: &TypeKey,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        let provider = {
+            let overrides = self.consumer_overrides.read().unwrap();
+            let entry = overrides
+                .get(consumer)
+                .and_then(|overrides| overrides.get(type_key));
+            match entry {
+                Some(ConsumerOverride {
+                    instance: Some(instance),
+                    ..
+                }) => return Ok(instance.clone_ref(py)),
+                Some(entry) => entry.provider.clone_ref(py),
+                // Removed by clear() since the plan was compiled
+                None => return self.resolve(py, type_key),
+            }
+        };
+        let provider = provider.bind(py);
+        let mut instance = match provider.cast::<PyType>() {
+            Ok(_) => self.construct(py, provider, None)?,
+            Err(_) => provider.clone().unbind(),
+        };
+        for decorator in self.decorator_chain(py, type_key) {
+            instance = decorator.call1(py, (instance,))?;
+        }
+        // Another thread may have built it meanwhile; keep the first
+        let mut overrides = self.consumer_overrides.write().unwrap();
+        let cached = overrides
+            .get_mut(consumer)
+            .and_then(|overrides| overrides.get_mut(type_key))
+            .map(|entry| {
+                entry
+                    .instance
+                    .get_or_insert(instance.clone_ref(py))
+                    .clone_ref(py)
+            });
+        Ok(cached.unwrap_or(instance))
+    }
+
+    /// Call a provider target with explicit arguments, auto-wiring the rest
+    ///
+    /// For a class, explicit arguments take the place of the auto-wired
+    /// parameters they fill: positional ones the leading parameters of the
+    /// signature, keyword ones the parameter of that name. Unresolvable
+    /// parameters must be among the explicit ones.
+    fn call_target(
+        &self,
+        py: Python,
+        target: &Bound<'_, PyAny>,
+        mut args: Vec<Py<PyAny>>,
+        kwargs: Bound<'_, PyDict>,
+        tenant: Option<&str>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        let explicit = !args.is_empty() || !kwargs.is_empty();
+        let Ok(cls) = target.cast::<PyType>() else {
+            if !explicit {
+                return Ok(target.call0()?.unbind());
+            }
+            let args = PyTuple::new(py, args)?;
+            return Ok(target.call(args, Some(&kwargs))?.unbind());
+        };
+        let plan = self.resolution_plan(py, cls)?;
+        if plan.slots.is_empty()
+            && plan.configured.is_empty()
+            && plan.flagged.is_empty()
+            && plan.defaulted.is_empty()
+            && plan.unbound.is_empty()
+            && plan.unresolved.is_empty()
+            && !explicit
+        {
+            return self.post_inject(cls, cls.call0()?);
+        }
+        let positional = args.len();
+        for &index in &plan.unresolved {
+            let param = &plan.params[index];
+            if !param.given(positional, &kwargs)? {
+                let type_name = TypeKey::new(cls.clone().unbind()).type_name(py);
+                return Err(match (&param.config, &param.flag) {
+                    (Some(config_key), _) => ContainerError::MissingConfigValue {
+                        type_name,
+                        parameter: param.name.clone(),
+                        config_key: config_key.clone(),
+                    },
+                    (None, Some(flag)) => ContainerError::MissingFlag {
+                        type_name,
+                        parameter: param.name.clone(),
+                        flag: flag.clone(),
+                    },
+                    (None, None) => ContainerError::UnresolvableParameter {
+                        type_name,
+                        parameter: param.name.clone(),
+                    },
+                });
+            }
+        }
+
+        // Positional-only values after the explicit arguments, by position
+        let mut trailing: Vec<Option<Py<PyAny>>> = Vec::new();
+        let mut pass = |param: &ConstructorParam, value: Py<PyAny>| -> PyResult<()> {
+            match param.position.filter(|_| param.positional_only) {
+                Some(position) => {
+                    let offset = position - positional;
+                    if trailing.len() <= offset {
+                        trailing.resize_with(offset + 1, || None);
+                    }
+                    trailing[offset] = Some(value);
+                    Ok(())
+                }
+                None => kwargs.set_item(&param.name, value),
+            }
+        };
+        for slot in &plan.slots {
+            let param = &plan.params[slot.param];
+            if explicit && param.given(positional, &kwargs)? {
+                continue;
+            }
+            let value = if let Some(collection) = slot.collection {
+                self.assemble(py, collection, &slot.key, tenant)?
+            } else if slot.overridden {
+                let consumer = TypeKey::new(cls.clone().unbind());
+                self.consumer_override(py, &consumer, &slot.key)?
+            } else if param.lazy {
+                Py::new(py, Lazy::new(self.clone(), slot.key.clone(), tenant))?.into_any()
+            } else if param.deferred {
+                Py::new(py, Resolver::new(self.clone(), slot.key.clone(), tenant))?.into_any()
+            } else {
+                self.resolve_for_tenant(py, &slot.key, tenant)?
+            };
+            pass(param, value)?;
+        }
+        for &index in &plan.configured {
+            let param = &plan.params[index];
+            if explicit && param.given(positional, &kwargs)? {
+                continue;
+            }
+            let name = param.config.as_deref().unwrap_or_default();
+            // The plan saw the value; a clear() since then removed it
+            let value =
+                self.config_value(py, name)
+                    .ok_or_else(|| ContainerError::MissingConfigValue {
+                        type_name: TypeKey::new(cls.clone().unbind()).type_name(py),
+                        parameter: param.name.clone(),
+                        config_key: name.to_string(),
+                    })?;
+            pass(param, value)?;
+        }
+        for &index in &plan.flagged {
+            let param = &plan.params[index];
+            if explicit && param.given(positional, &kwargs)? {
+                continue;
+            }
+            let name = param.flag.as_deref().unwrap_or_default();
+            let value = self
+                .flag_value(py, name)
+                .ok_or_else(|| ContainerError::MissingFlag {
+                    type_name: TypeKey::new(cls.clone().unbind()).type_name(py),
+                    parameter: param.name.clone(),
+                    flag: name.to_string(),
+                })?;
+            pass(param, value)?;
+        }
+        for &index in &plan.defaulted {
+            let param = &plan.params[index];
+            if let (false, Some(value)) = (param.given(positional, &kwargs)?, &param.fallback) {
+                pass(param, value.clone_ref(py))?;
+            }
+        }
+        for &index in &plan.unbound {
+            let param = &plan.params[index];
+            if !param.given(positional, &kwargs)? {
+                pass(param, py.None())?;
+            }
+        }
+        // A positional-only parameter left to its default still takes its
+        // place when a later one is passed, so it gets the default value
+        for (offset, value) in trailing.into_iter().enumerate() {
+            let value = value.unwrap_or_else(|| {
+                plan.params
+                    .iter()
+                    .find(|param| param.position == Some(positional + offset))
+                    .and_then(|param| param.default.as_ref())
+                    .map_or_else(|| py.None(), |default| default.clone_ref(py))
+            });
+            args.push(value);
+        }
+
+        let args = PyTuple::new(py, args)?;
+        self.post_inject(cls, cls.call(args, Some(&kwargs))?)
+    }
+
+    /// Run the post-construct hooks of a freshly constructed instance
+    ///
+    /// `__post_inject__()` and methods marked with `@post_inject` are called
+    /// without arguments once the constructor returns, so they can finish
+    /// initialization that needs the injected dependencies.
+    fn post_inject(
+        &self,
+        cls: &Bound<'_, PyType>,
+        instance: Bound<'_, PyAny>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        for hook in self.post_inject_hooks(cls)?.iter() {
+            instance.call_method0(hook.as_str())?;
+        }
+        Ok(instance.unbind())
+    }
+
+    /// Discover (once per class) the methods `post_inject` calls
+    ///
+    /// `__post_inject__` comes first, then marked methods in definition
+    /// order, base classes before subclasses. A marked method overridden
+    /// without the marker is not called.
+    fn post_inject_hooks(
+        &self,
+        cls: &Bound<'_, PyType>,
+    ) -> Result<Arc<Vec<String>>, ContainerError> {
+        let class_key = TypeKey::new(cls.clone().unbind());
+        if let Some(hooks) = self.post_inject_hooks.read().unwrap().get(&class_key) {
+            return Ok(Arc::clone(hooks));
+        }
+
+        let mut hooks = Vec::new();
+        if cls.hasattr(POST_INJECT_HOOK)? {
+            hooks.push(POST_INJECT_HOOK.to_string());
+        }
+        let mro = cls.getattr("__mro__")?;
+        for base in mro.try_iter()?.collect::<PyResult<Vec<_>>>()?.iter().rev() {
+            for name in base.getattr("__dict__")?.try_iter()? {
+                let name: String = name?.extract()?;
+                if hooks.contains(&name) {
+                    continue;
+                }
+                let marked = cls
+                    .getattr(name.as_str())
+                    .and_then(|method| method.getattr(POST_INJECT_MARKER))
+                    .is_ok_and(|marker| marker.is_truthy().unwrap_or(false));
+                if marked {
+                    hooks.push(name);
+                }
+            }
+        }
+
+        let hooks = Arc::new(hooks);
+        let hooks = Arc::clone(
+            self.post_inject_hooks
+                .write()
+                .unwrap()
+                .entry(class_key)
+                .or_insert(hooks),
+        );
+        Ok(hooks)
+    }
+
+    /// Compiled constructor call for `cls`, recompiled once stale
+    ///
+    /// Parameters whose key is provided (here or in a fallback) become
+    /// slots; the rest keep their default, so a parameter whose type is
+    /// unbound is simply omitted. A parameter with neither is recorded as
+    /// unresolved, for `call_target` to report unless it is given explicitly.
+    fn resolution_plan(
+        &self,
+        py: Python,
+        cls: &Bound<'_, PyType>,
+    ) -> Result<Arc<ResolutionPlan>, ContainerError> {
+        let class_key = TypeKey::new(cls.clone().unbind());
+        // Read before compiling: a change made meanwhile leaves the plan stale
+        let generation = self.registry_generation();
+        if let Some(plan) = self.plans.read().unwrap().get(&class_key) {
+            if plan.generation == generation {
+                return Ok(Arc::clone(plan));
+            }
+        }
+
+        let params = self.constructor_params(py, cls)?;
+        let mut slots = Vec::new();
+        let mut configured = Vec::new();
+        let mut flagged = Vec::new();
+        let mut defaulted = Vec::new();
+        let mut unresolved = Vec::new();
+        let mut unbound = Vec::new();
+        for (index, param) in params.iter().enumerate() {
+            if let Some(name) = &param.config {
+                match self.config_value(py, name) {
+                    Some(_) => configured.push(index),
+                    None if param.fallback.is_some() => defaulted.push(index),
+                    None if param.default.is_some() => {}
+                    None if param.optional => unbound.push(index),
+                    None => unresolved.push(index),
+                }
+                continue;
+            }
+            if let Some(name) = &param.flag {
+                match self.flag_value(py, name) {
+                    Some(_) => flagged.push(index),
+                    None if param.fallback.is_some() => defaulted.push(index),
+                    None if param.default.is_some() => {}
+                    None if param.optional => unbound.push(index),
+                    None => unresolved.push(index),
+                }
+                continue;
+            }
+            // A consumer-specific provider wins over the dependency's own;
+            // Lazy and Callable parameters resolve the key itself later
+            let overridden = !param.lazy
+                && !param.deferred
+                && param
+                    .key
+                    .as_ref()
+                    .is_some_and(|key| self.has_consumer_override(&class_key, key));
+            // Autowire mode only binds what is needed: a parameter with a
+            // default keeps it rather than implicitly binding its class
+            let resolvable = |key: &&TypeKey| {
+                overridden
+                    || self.provides(key)
+                    || (param.required() && self.autowire_class(py, key).is_some())
+            };
+            // A bound collection wins over collecting X; an empty collection
+            // only stands in for a missing default
+            let collected = param.collection.as_ref().filter(|(collection, element)| {
+                let bound = match collection {
+                    Collection::Mapping => !self.binding_names(element).is_empty(),
+                    _ => self.has_multi_bindings(element),
+                };
+                bound || param.required_collection()
+            });
+            match (param.key.as_ref().filter(resolvable), collected) {
+                (Some(key), _) => slots.push(PlanSlot {
+                    param: index,
+                    key: key.clone(),
+                    collection: None,
+                    overridden,
+                }),
+                (None, Some((collection, element))) => slots.push(PlanSlot {
+                    param: index,
+                    key: element.clone(),
+                    collection: Some(*collection),
+                    overridden: false,
+                }),
+                (None, None) if param.fallback.is_some() => defaulted.push(index),
+                (None, None) if param.default.is_some() => {}
+                (None, None) if param.optional => unbound.push(index),
+                (None, None) => unresolved.push(index),
+            }
+        }
+
+        let plan = Arc::new(ResolutionPlan {
+            generation,
+            slots,
+            configured,
+            flagged,
+            defaulted,
+            unresolved,
+            unbound,
+            params,
+        });
+        let replaced = self
+            .plans
+            .write()
+            .unwrap()
+            .insert(class_key, Arc::clone(&plan));
+        drop(replaced);
+        Ok(plan)
+    }
+
+    /// Mark the registry changed, invalidating compiled plans here and in
+    /// containers falling back to this one
+    fn touch_registry(&self) {
+        let generation = REGISTRY_GENERATION.fetch_add(1, Ordering::Relaxed) + 1;
+        self.generation.store(generation, Ordering::Release);
+    }
+
+    /// Latest registry generation of this container and its fallbacks
+    ///
+    /// Generations are drawn from one global counter, so any change below
+    /// raises the maximum.
+    fn registry_generation(&self) -> u64 {
+        let own = self.generation.load(Ordering::Acquire);
+        let fallbacks = self.fallbacks.read().unwrap();
+        fallbacks
+            .iter()
+            .map(RustContainer::registry_generation)
+            .fold(own, u64::max)
+    }
+
+    /// Inspect (once per class) the `__init__` parameters to auto-wire
+    ///
+    /// Annotations come from `typing.get_type_hints`, falling back to the raw
+    /// signature when forward references cannot be evaluated. `*args` and
+    /// `**kwargs` are never filled.
+    fn constructor_params(
+        &self,
+        py: Python,
+        cls: &Bound<'_, PyType>,
+    ) -> Result<Arc<Vec<ConstructorParam>>, ContainerError> {
+        let class_key = TypeKey::new(cls.clone().unbind());
+        if let Some(params) = self.constructor_params.read().unwrap().get(&class_key) {
+            return Ok(Arc::clone(params));
+        }
+
+        let inspect = py.import("inspect")?;
+        let mut params = Vec::new();
+        // Builtins and C types may have no introspectable signature
+        if let Ok(signature) = inspect.call_method1("signature", (cls,)) {
+            let mut positional = 0;
+            let hints = cls
+                .getattr("__init__")
+                .and_then(|init| {
+                    let kwargs = PyDict::new(py);
+                    kwargs.set_item("include_extras", true)?;
+                    py.import("typing")?
+                        .call_method("get_type_hints", (init,), Some(&kwargs))
+                })
+                .unwrap_or_else(|_| PyDict::new(py).into_any());
+            let empty = inspect.getattr("Parameter")?.getattr("empty")?;
+
+            for param in signature
+                .getattr("parameters")?
+                .call_method0("values")?
+                .try_iter()?
+            {
+                let param = param?;
+                let kind = param.getattr("kind")?.getattr("name")?.to_string();
+                if kind == "VAR_POSITIONAL" || kind == "VAR_KEYWORD" {
+                    continue;
+                }
+                let name = param.getattr("name")?.to_string();
+                let annotation = match hints.call_method1("get", (&name,))? {
+                    hint if !hint.is_none() => hint,
+                    _ => param.getattr("annotation")?,
+                };
+                let position =
+                    (kind == "POSITIONAL_ONLY" || kind == "POSITIONAL_OR_KEYWORD").then(|| {
+                        positional += 1;
+                        positional - 1
+                    });
+                let flag =
+                    annotated_marker::<FlagRef>(&annotation)?.map(|flag| flag.get().name.clone());
+                let fallback = annotated_marker::<DefaultRef>(&annotation)?
+                    .map(|default| default.get().value.clone_ref(py));
+                let (annotation, config) = config_target(&annotation)?;
+                let optional = optional_target(&annotation)?;
+                let optional_param = optional.is_some();
+                let annotation = optional.unwrap_or(annotation);
+                let lazy = lazy_target(&annotation);
+                let lazy_param = lazy.is_some();
+                let annotation = lazy.unwrap_or(annotation);
+                let deferred = callable_target(&annotation)?;
+                let deferred_param = deferred.is_some();
+                let annotation = deferred.unwrap_or(annotation);
+                let collection = collection_target(&annotation)?
+                    .and_then(|(kind, element)| Some((kind, TypeKey::from_object(&element).ok()?)));
+                params.push(ConstructorParam {
+                    key: if annotation.is(&empty) || config.is_some() || flag.is_some() {
+                        None
+                    } else {
+                        TypeKey::from_object(&annotation).ok()
+                    },
+                    default: Some(param.getattr("default")?)
+                        .filter(|default| !default.is(&empty))
+                        .map(Bound::unbind),
+                    optional: optional_param,
+                    positional_only: kind == "POSITIONAL_ONLY",
+                    position,
+                    lazy: lazy_param,
+                    deferred: deferred_param,
+                    collection,
+                    config,
+                    flag,
+                    fallback,
+                    name,
+                });
+            }
+        }
+
+        let params = Arc::new(params);
+        let params = Arc::clone(
+            self.constructor_params
+                .write()
+                .unwrap()
+                .entry(class_key)
+                .or_insert(params),
+        );
+        Ok(params)
+    }
+
+    /// Resolve a dependency, reporting how the call was served
+    ///
+    /// `constructions` counts every instance created during the call,
+    /// including dependencies resolved by factories; `elapsed` covers the
+    /// whole call.
+    pub fn resolve_verbose(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+    ) -> Result<(Py<PyAny>, ResolveInfo), ContainerError> {
+        let cache_hit = self.is_cached(py, type_key, tenant);
+        let before = CONSTRUCTIONS.with(Cell::get);
+        let started = Instant::now();
+
+        let instance = self.resolve_for_tenant(py, type_key, tenant)?;
+
+        let info = ResolveInfo {
+            cache_hit,
+            constructions: CONSTRUCTIONS.with(Cell::get) - before,
+            elapsed: started.elapsed(),
+            provider: self.provider_kind(type_key).unwrap_or("unknown"),
+        };
+        Ok((instance, info))
+    }
+
+    /// Whether resolving `type_key` would be served from a cache
+    fn is_cached(&self, py: Python, type_key: &TypeKey, tenant: Option<&str>) -> bool {
+        if !self.contains(type_key) {
+            return self
+                .fallback_for(type_key)
+                .is_some_and(|fallback| fallback.is_cached(py, type_key, tenant));
+        }
+
+        let cached = match self.active_namespace(py) {
+            Some(namespace) => self
+                .namespace_singletons
+                .read()
+                .unwrap()
+                .get(&namespace)
+                .is_some_and(|singletons| singletons.contains_key(type_key)),
+            None => self.singletons.contains_key(py, type_key),
+        };
+        cached
+            || tenant.is_some_and(|tenant| {
+                self.tenant_singletons
+                    .read()
+                    .unwrap()
+                    .get(type_key)
+                    .is_some_and(|partitions| partitions.contains_key(tenant))
+            })
+            || self.thread_instance(py, type_key).is_some()
+    }
+
+    /// Provider kind serving `type_key`, here or in a fallback
+    fn provider_kind(&self, type_key: &TypeKey) -> Option<&'static str> {
+        let kind = match self.sealed.get() {
+            Some(sealed) => sealed.providers.get(type_key).map(Provider::kind_name),
+            None => self
+                .providers
+                .read()
+                .unwrap()
+                .get(type_key)
+                .map(Provider::kind_name),
+        };
+        if kind.is_some() {
+            return kind;
+        }
+        self.fallback_for(type_key)
+            .and_then(|fallback| fallback.provider_kind(type_key))
+    }
+
+    /// Resolve a dependency and assert the result is an instance of `expected`
+    pub fn resolve_as(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        expected: &Bound<'_, PyType>,
+    ) -> Result<Py<PyAny>, ContainerError> {
+        let instance = self.resolve(py, type_key)?;
+        if instance.bind(py).is_instance(expected)? {
+            return Ok(instance);
+        }
+
+        let type_name_of = |py_type: &Bound<'_, PyType>| {
+            py_type
+                .name()
+                .map(|n| n.to_string())
+                .unwrap_or_else(|_| "<unknown>".to_string())
+        };
+        Err(ContainerError::UnexpectedType {
+            type_name: type_key.type_name(py),
+            expected: type_name_of(expected),
+            actual: type_name_of(&instance.bind(py).get_type()),
+        })
+    }
+
+    /// Build the error for a key with no provider
+    fn not_registered(py: Python, type_key: &TypeKey, siblings: &[TypeKey]) -> ContainerError {
+        match &type_key.key {
+            Key::EnumMember { .. } => {
+                // List the sibling members that are registered to help spot typos
+                let mut registered: Vec<String> =
+                    siblings.iter().map(|key| key.type_name(py)).collect();
+                registered.sort();
+
+                ContainerError::EnumMemberNotRegistered {
+                    member_name: type_key.type_name(py),
+                    registered: if registered.is_empty() {
+                        "none".to_string()
+                    } else {
+                        registered.join(", ")
+                    },
+                }
+            }
+            Key::Type(_) | Key::Generic { .. } => {
+                let suggestions = Self::near_misses(py, type_key, siblings);
+                ContainerError::DependencyNotRegistered {
+                    type_name: type_key.type_name(py),
+                    suggestions: match suggestions.is_empty() {
+                        true => String::new(),
+                        false => format!(" (did you mean {}?)", suggestions.join(" or ")),
+                    },
+                }
+            }
+        }
+    }
+
+    /// Registered type keys `type_key` was likely meant to be, best first
+    ///
+    /// The same type under another name qualifier, a type of the same name
+    /// from another module (shown with its module), then types whose names
+    /// are within a few edits of the requested one. At most three.
+    fn near_misses(py: Python, type_key: &TypeKey, registered: &[TypeKey]) -> Vec<String> {
+        let wanted = type_key.base_type_name(py);
+        let wanted_lower = wanted.to_lowercase();
+        let max_distance = (wanted.chars().count() / 3).max(1);
+        let unnamed = type_key.clone().with_name(None);
+        let mut matches: Vec<(usize, String)> = registered
+            .iter()
+            .filter_map(|candidate| {
+                if candidate.clone().with_name(None) == unnamed {
+                    return Some((0, candidate.type_name(py)));
+                }
+                let name = candidate.base_type_name(py);
+                if name == wanted {
+                    let module = candidate
+                        .as_type()
+                        .and_then(|cls| cls.bind(py).module().ok())
+                        .map(|module| module.to_string())
+                        .unwrap_or_default();
+                    return Some((0, format!("{module}.{}", candidate.type_name(py))));
+                }
+                let distance = edit_distance(&wanted_lower, &name.to_lowercase());
+                (distance <= max_distance).then(|| (distance, candidate.type_name(py)))
+            })
+            .collect();
+        matches.sort();
+        matches.dedup_by(|a, b| a.1 == b.1);
+        matches.into_iter().take(3).map(|(_, name)| name).collect()
+    }
+
+    /// Registered keys `not_registered` may point to: members of the same
+    /// enum as `type_key`, or every type key for a type; taken under the
+    /// registry lock, formatted after it
+    fn registered_siblings(
+        type_key: &TypeKey,
+        providers: &HashMap<TypeKey, Provider>,
+    ) -> Vec<TypeKey> {
+        match &type_key.key {
+            Key::EnumMember { enum_type, .. } => providers
+                .keys()
+                .filter(|key| key.is_member_of(enum_type))
+                .cloned()
+                .collect(),
+            Key::Type(_) | Key::Generic { .. } => providers
+                .keys()
+                .filter(|key| key.as_type().is_some())
+                .cloned()
+                .collect(),
+        }
+    }
+
+    /// Check if container is empty
+    pub fn is_empty(&self) -> bool {
+        self.providers.read().unwrap().is_empty()
+    }
+
+    /// Get count of registered providers
+    pub fn len(&self) -> usize {
+        self.providers.read().unwrap().len()
+    }
+
+    /// Snapshot the resolution and construction counters with the current
+    /// cache and registry sizes
+    pub fn stats(&self) -> ContainerStats {
+        let mut instances_created: Vec<(&'static str, u64)> = self
+            .counters
+            .created
+            .lock()
+            .unwrap()
+            .iter()
+            .map(|(kind, count)| (*kind, *count))
+            .collect();
+        instances_created.sort();
+
+        let tenant_instances: usize = self
+            .tenant_singletons
+            .read()
+            .unwrap()
+            .values()
+            .map(|partitions| partitions.len())
+            .sum();
+        let namespace_instances: usize = self
+            .namespace_singletons
+            .read()
+            .unwrap()
+            .values()
+            .map(|singletons| singletons.len())
+            .sum();
+
+        let transient_allocation_rates = self
+            .transient_telemetry
+            .write()
+            .unwrap()
+            .as_mut()
+            .map(|telemetry| telemetry.rates(Instant::now()));
+
+        let gates = &self.construction_gates;
+        ContainerStats {
+            resolutions: self.counters.resolutions.load(Ordering::Relaxed),
+            cache_hits: self.counters.cache_hits.load(Ordering::Relaxed),
+            cache_misses: self.counters.cache_misses.load(Ordering::Relaxed),
+            instances_created,
+            cache_size: self.singletons.len() + tenant_instances + namespace_instances,
+            registrations: self.len(),
+            max_concurrent_constructions: gates.max_in_flight.load(Ordering::Relaxed),
+            single_flight_waits: gates.waits.load(Ordering::Relaxed),
+            transient_allocation_rates,
+        }
+    }
+
+    /// Zero the resolution counters; caches and registrations are untouched
+    pub fn reset_stats(&self) {
+        self.counters.resolutions.store(0, Ordering::Relaxed);
+        self.counters.cache_hits.store(0, Ordering::Relaxed);
+        self.counters.cache_misses.store(0, Ordering::Relaxed);
+        self.counters.created.lock().unwrap().clear();
+        let gates = &self.construction_gates;
+        gates.max_in_flight.store(0, Ordering::Relaxed);
+        gates.waits.store(0, Ordering::Relaxed);
+    }
+
+    /// Remove one binding together with the instances it cached
+    ///
+    /// The registry write lock is held while the caches are evicted, so no
+    /// resolve sees the binding gone but its singleton still cached.
+    pub fn unregister(&self, py: Python, type_key: &TypeKey) -> Result<(), ContainerError> {
+        let mut providers = self.providers.write().unwrap();
+        if self.is_frozen() {
+            drop(providers);
+            return self.ensure_unfrozen(py, "unregister", type_key);
+        }
+        let Some(provider) = providers.remove(type_key) else {
+            let siblings = Self::registered_siblings(type_key, &providers);
+            drop(providers);
+            return Err(Self::not_registered(py, type_key, &siblings));
+        };
+        let decorators = self.decorators.write().unwrap().remove(type_key);
+        self.tags.write().unwrap().remove(type_key);
+        self.metadata.write().unwrap().remove(type_key);
+        self.touch_registry();
+        let mut evicted = self.evict_singleton(type_key);
+        evicted.extend(self.evict_tenant_instances(type_key));
+        drop(providers);
+        drop((provider, decorators, evicted));
+        Ok(())
+    }
+
+    /// Remove every binding, decorator and cached instance
+    ///
+    /// Fallback containers and container settings (duplicate policy,
+    /// telemetry, singleton freeze) are kept. A frozen container cannot be
+    /// cleared.
+    pub fn clear(&self) -> Result<(), ContainerError> {
+        let mut providers = self.providers.write().unwrap();
+        if self.is_frozen() {
+            return Err(ContainerError::ContainerFrozen {
+                action: "clear".to_string(),
+            });
+        }
+        let removed = (
+            std::mem::take(&mut *providers),
+            std::mem::take(&mut *self.decorators.write().unwrap()),
+            std::mem::take(&mut *self.tags.write().unwrap()),
+            std::mem::take(&mut *self.metadata.write().unwrap()),
+            std::mem::take(&mut *self.interceptors.write().unwrap()),
+            std::mem::take(&mut *self.config.write().unwrap()),
+            std::mem::take(&mut *self.multi_bindings.write().unwrap()),
+            std::mem::take(&mut *self.consumer_overrides.write().unwrap()),
+            std::mem::take(&mut *self.ordering_hints.write().unwrap()),
+            std::mem::take(&mut *self.constructor_params.write().unwrap()),
+            std::mem::take(&mut *self.post_inject_hooks.write().unwrap()),
+            std::mem::take(&mut *self.plans.write().unwrap()),
+            std::mem::take(&mut self.overrides.write().unwrap().entries),
+        );
+        self.touch_registry();
+        let evicted = self.take_caches();
+        drop(providers);
+        drop((removed, evicted));
+        Ok(())
+    }
+
+    /// Clear the singleton instance cache (keep provider registrations)
+    pub fn reset(&self) {
+        self.take_caches();
+    }
+
+    /// Empty every instance cache, returning the instances so they are
+    /// dropped after the locks are released
+    fn take_caches(&self) -> Vec<Py<PyAny>> {
+        self.construction_failures.write().unwrap().clear();
+        let mut evicted = self.singletons.take_all();
+        self.singleton_order.write().unwrap().clear();
+        let tenant_singletons = std::mem::take(&mut *self.tenant_singletons.write().unwrap());
+        evicted.extend(
+            tenant_singletons
+                .into_values()
+                .flat_map(HashMap::into_values),
+        );
+        self.tenant_order.write().unwrap().clear();
+        let thread_singletons = std::mem::take(&mut *self.thread_singletons.write().unwrap());
+        evicted.extend(
+            thread_singletons
+                .into_values()
+                .flat_map(HashMap::into_values),
+        );
+        let namespace_singletons = std::mem::take(&mut *self.namespace_singletons.write().unwrap());
+        evicted.extend(
+            namespace_singletons
+                .into_values()
+                .flat_map(HashMap::into_values),
+        );
+        for bindings in self.multi_bindings.write().unwrap().values_mut() {
+            evicted.extend(
+                bindings
+                    .iter_mut()
+                    .filter_map(|binding| binding.instance.take())
+                    .map(CachedInstance::into_object),
+            );
+        }
+        for overrides in self.consumer_overrides.write().unwrap().values_mut() {
+            evicted.extend(
+                overrides
+                    .values_mut()
+                    .filter_map(|entry| entry.instance.take()),
+            );
+        }
+        evicted
+    }
+
+    /// Route singleton caching in the current context into the named namespace
+    ///
+    /// The namespace is held in a `contextvars.ContextVar`, so it follows the
+    /// current thread and asyncio task: tasks started inside see it, while
+    /// concurrent tasks entering their own namespaces do not see each other's.
+    /// Returns the token that `exit_cache_namespace()` resets.
+    pub fn enter_cache_namespace(&self, py: Python, name: &str) -> PyResult<Py<PyAny>> {
+        let var = match self.namespace_var.get() {
+            Some(var) => var,
+            None => {
+                let var = py
+                    .import("contextvars")?
+                    .getattr("ContextVar")?
+                    .call1(("dioxide_cache_namespace",))?
+                    .unbind();
+                self.namespace_var.get_or_init(|| var)
+            }
+        };
+        var.call_method1(py, "set", (name,))
+    }
+
+    /// Leave the namespace entered with `token`, restoring the previous one
+    pub fn exit_cache_namespace(&self, py: Python, token: &Py<PyAny>) -> PyResult<()> {
+        if let Some(var) = self.namespace_var.get() {
+            var.call_method1(py, "reset", (token,))?;
+        }
+        Ok(())
+    }
+
+    fn active_namespace(&self, py: Python) -> Option<String> {
+        let var = self.namespace_var.get()?;
+        var.call_method1(py, "get", (py.None(),))
+            .and_then(|name| name.extract(py))
+            .ok()
+            .flatten()
+    }
+
+    /// Remove every tenant's cached instance of one type, returning the
+    /// instances for the caller to drop once it holds no lock
+    fn evict_tenant_instances(&self, type_key: &TypeKey) -> Vec<Py<PyAny>> {
+        let Some(partitions) = self.tenant_singletons.write().unwrap().remove(type_key) else {
+            return Vec::new();
+        };
+        self.tenant_order
+            .write()
+            .unwrap()
+            .retain(|(key, _)| key != type_key);
+        partitions.into_values().collect()
+    }
+
+    /// Names of singletons in the order they finished constructing
+    ///
+    /// A singleton whose factory resolves its dependencies finishes after
+    /// them, so the log follows topological order. Unlike the cache, the log
+    /// survives `reset()`; clear it with `clear_construction_log()`.
+    pub fn construction_log(&self) -> Vec<String> {
+        self.construction_log.read().unwrap().clone()
+    }
+
+    /// Forget the recorded construction order
+    pub fn clear_construction_log(&self) {
+        self.construction_log.write().unwrap().clear();
+    }
+
+    /// Remove one cached singleton (from every cache namespace too),
+    /// returning the instances for the caller to drop once it holds no lock
+    fn evict_singleton(&self, type_key: &TypeKey) -> Vec<Py<PyAny>> {
+        let mut evicted: Vec<Py<PyAny>> = self
+            .singletons
+            .remove(type_key)
+            .map(CachedInstance::into_object)
+            .into_iter()
+            .collect();
+        if !evicted.is_empty() {
+            self.singleton_order
+                .write()
+                .unwrap()
+                .retain(|key| key != type_key);
+        }
+        for singletons in self.namespace_singletons.write().unwrap().values_mut() {
+            evicted.extend(singletons.remove(type_key));
+        }
+        for instances in self.thread_singletons.write().unwrap().values_mut() {
+            evicted.extend(instances.remove(type_key));
+        }
+        evicted
+    }
+
+    /// This thread's cached thread-lifetime instance of `type_key`
+    fn thread_instance(&self, py: Python, type_key: &TypeKey) -> Option<Py<PyAny>> {
+        let thread_singletons = self.thread_singletons.read().unwrap();
+        if thread_singletons.is_empty() {
+            return None;
+        }
+        thread_singletons
+            .get(&thread::current().id())?
+            .get(type_key)
+            .map(|instance| instance.clone_ref(py))
+    }
+
+    /// Arrange for this thread's thread-lifetime instances to be dropped
+    /// when the thread ends
+    ///
+    /// CPython clears a `threading.local` on thread exit, dropping the
+    /// `ThreadRelease` stored in it for this thread.
+    fn release_on_thread_exit(&self, py: Python) -> PyResult<()> {
+        let local = match self.thread_exit.get() {
+            Some(local) => local,
+            None => {
+                let local = py.import("threading")?.getattr("local")?.call0()?.unbind();
+                self.thread_exit.get_or_init(|| local)
+            }
+        };
+        let local = local.bind(py);
+        if !local.hasattr("release")? {
+            let release = ThreadRelease {
+                partitions: Arc::downgrade(&self.thread_singletons),
+                thread_id: thread::current().id(),
+            };
+            local.setattr("release", Py::new(py, release)?)?;
+        }
+        Ok(())
+    }
+
+    /// Drop this thread's thread-lifetime instances, returning how many
+    /// were cached
+    ///
+    /// For pooled threads that outlive a unit of work; the next resolve on
+    /// this thread constructs fresh instances.
+    pub fn release_thread_instances(&self) -> usize {
+        let released = self
+            .thread_singletons
+            .write()
+            .unwrap()
+            .remove(&thread::current().id());
+        released.map_or(0, |instances| instances.len())
+    }
+
+    /// Dispose all cached singletons within a time budget, then clear the cache
+    ///
+    /// Tracked tenant transients and every tenant partition are disposed in
+    /// reverse creation order, then the singletons in reverse dependency
+    /// order (see `disposal_order`).
+    /// Each instance is disposed through the first of
+    /// `dispose()`, `aclose()`, `close()`, `__aexit__` or `__exit__` it has;
+    /// coroutines are driven on a private event loop. Each disposer gets an
+    /// equal share of the remaining budget: async disposers are cancelled
+    /// when they exceed it, sync ones are reported after the fact. Returns
+    /// one message per failed or slow disposal; failures never prevent the
+    /// cache from being cleared.
+    pub fn shutdown(&self, py: Python, timeout: Duration) -> PyResult<Vec<String>> {
+        // Tenant instances are created on demand after the global singletons
+        // they use, so every tenant partition is disposed first
+        let mut instances = self.take_tenant_transients(py, None);
+        instances.extend(self.tenant_instances(py));
+        let singletons = self
+            .snapshot_singletons(py)
+            .into_iter()
+            .map(|(key, instance)| (Some(key.clone()), (key.type_name(py), instance)))
+            .collect();
+        instances.extend(self.disposal_order(py, singletons));
+
+        let reports = Self::dispose_within(py, instances, timeout)?;
+        self.reset();
+        Ok(reports)
+    }
+
+    /// Finalize everything the container created, exactly once, and close it
+    ///
+    /// Started components are stopped first (see `stop_lifecycle`) and then
+    /// left alone. Tracked transients and every tenant, thread and namespace
+    /// instance go next, most recent first; then the singletons and
+    /// generator teardowns in reverse dependency order. Async disposers are
+    /// awaited to completion. Afterwards the caches are empty and every
+    /// resolve fails with `ContainerClosed`; closing again does nothing.
+    /// Returns one message per failed stop, disposal or teardown.
+    pub fn close(&self, py: Python) -> PyResult<Vec<String>> {
+        let Some((finalizers, mut reports)) =
+            self.closing_finalizers(py, |container| container.stop_lifecycle(py))
+        else {
+            return Ok(Vec::new());
+        };
+        let asyncio = py.import("asyncio")?;
+        let mut event_loop: Option<Bound<'_, PyAny>> = None;
+        for finalizer in finalizers {
+            match finalizer {
+                Finalizer::Teardown(teardown) => reports.extend(self.finish_teardown(py, teardown)),
+                Finalizer::Dispose(type_name, instance) => {
+                    if let Err(err) =
+                        Self::dispose_instance(py, &asyncio, &mut event_loop, instance, None)
+                    {
+                        reports.push(format!("{}: disposal failed: {}", type_name, err));
+                    }
+                }
+            }
+        }
+        if let Some(event_loop) = event_loop {
+            event_loop.call_method0("close")?;
+        }
+        drop(self.take_caches());
+        Ok(reports)
+    }
+
+    /// Close the container like `close()`, awaiting async stops together
+    /// (see `stop_lifecycle_async`), then each async disposer on the running
+    /// event loop before the next finalizer runs
+    ///
+    /// The awaitable returns the failure reports, or warns about them and
+    /// returns False when `warn` is set (for `__aexit__`).
+    pub fn close_async(&self, py: Python, warn: bool) -> PyResult<AsyncResolve> {
+        let (finalizers, (stopping, reports)) = self
+            .closing_finalizers(py, |container| container.stop_lifecycle_async(py))
+            .unwrap_or_default();
+        let close = AsyncClose {
+            container: self.clone(),
+            current: String::new(),
+            remaining: finalizers.into_iter(),
+            reports,
+            warn,
+        };
+        if stopping.is_empty() {
+            return AsyncResolve::close(py, close);
+        }
+        let (names, coroutines) = stopping.into_iter().unzip();
+        AsyncResolve::gather(
+            py,
+            coroutines,
+            AsyncFinish::Stop {
+                names,
+                close: Box::new(close),
+            },
+        )
+    }
+
+    /// Mark the container closed and stop the lifecycle with `stop`,
+    /// returning what `close()` finalizes, in order, with the result of
+    /// `stop`; None if it was already closed
+    fn closing_finalizers<T>(
+        &self,
+        py: Python,
+        stop: impl FnOnce(&Self) -> T,
+    ) -> Option<(Vec<Finalizer>, T)> {
+        if self.closed.swap(true, Ordering::AcqRel) {
+            return None;
+        }
+        let stopped: HashSet<*mut pyo3::ffi::PyObject> = self
+            .started
+            .read()
+            .unwrap()
+            .iter()
+            .map(|(_, instance)| instance.as_ptr())
+            .collect();
+        let stopped_with = stop(self);
+
+        let mut finalizers: Vec<Finalizer> = self
+            .take_tenant_transients(py, None)
+            .into_iter()
+            .chain(self.take_resources(py))
+            .chain(self.tenant_instances(py))
+            .chain(self.thread_and_namespace_instances(py))
+            .map(|(name, instance)| Finalizer::Dispose(name, instance))
+            .collect();
+        let mut teardowns = std::mem::take(&mut *self.teardowns.write().unwrap());
+        let mut ordered: Vec<(Option<TypeKey>, Finalizer)> = self
+            .snapshot_singletons(py)
+            .into_iter()
+            .map(|(key, instance)| {
+                let position = teardowns
+                    .iter()
+                    .position(|teardown| teardown.cached && teardown.type_key == key);
+                let finalizer = match position {
+                    Some(position) => Finalizer::Teardown(teardowns.remove(position)),
+                    None => Finalizer::Dispose(key.type_name(py), instance),
+                };
+                (Some(key), finalizer)
+            })
+            .collect();
+        // Transient generators were started after the singletons they use
+        ordered.extend(
+            teardowns
+                .into_iter()
+                .map(|teardown| (None, Finalizer::Teardown(teardown))),
+        );
+        finalizers.extend(self.disposal_order(py, ordered));
+        // Stopped components were finalized by their stop
+        finalizers.retain(|finalizer| match finalizer {
+            Finalizer::Dispose(_, instance) => !stopped.contains(&instance.as_ptr()),
+            Finalizer::Teardown(_) => true,
+        });
+        Some((finalizers, stopped_with))
+    }
+
+    /// Whether `close()` has closed the container
+    pub fn is_closed(&self) -> bool {
+        self.closed.load(Ordering::Acquire)
+    }
+
+    /// Fail with `ContainerClosed` once `close()` has run
+    fn ensure_open(&self, py: Python, type_key: &TypeKey) -> Result<(), ContainerError> {
+        if !self.is_closed() {
+            return Ok(());
+        }
+        Err(ContainerError::ContainerClosed {
+            type_name: type_key.type_name(py),
+        })
+    }
+
+    /// Take the tracked transients created outside a tenant that are still
+    /// alive, most recent first
+    fn take_resources(&self, py: Python) -> Vec<(String, Py<PyAny>)> {
+        let resources = std::mem::take(&mut *self.resources.write().unwrap());
+        resources
+            .into_iter()
+            .rev()
+            .filter_map(|(key, reference)| {
+                let instance = reference.bind(py).upgrade()?;
+                Some((key.type_name(py), instance.unbind()))
+            })
+            .collect()
+    }
+
+    /// Cached instances of every tenant partition, most recent first
+    fn tenant_instances(&self, py: Python) -> Vec<(String, Py<PyAny>)> {
+        let instances: Vec<(TypeKey, String, Py<PyAny>)> = {
+            let tenant_singletons = self.tenant_singletons.read().unwrap();
+            let order = self.tenant_order.read().unwrap();
+            order
+                .iter()
+                .rev()
+                .filter_map(|(key, tenant)| {
+                    tenant_singletons
+                        .get(key)
+                        .and_then(|partitions| partitions.get(tenant))
+                        .map(|instance| (key.clone(), tenant.clone(), instance.clone_ref(py)))
+                })
+                .collect()
+        };
+        instances
+            .into_iter()
+            .map(|(key, tenant, instance)| {
+                (
+                    format!("{}[tenant={}]", key.type_name(py), tenant),
+                    instance,
+                )
+            })
+            .collect()
+    }
+
+    /// Cached instances of every thread and cache namespace
+    fn thread_and_namespace_instances(&self, py: Python) -> Vec<(String, Py<PyAny>)> {
+        let instances: Vec<(TypeKey, Option<String>, Py<PyAny>)> = {
+            let thread_singletons = self.thread_singletons.read().unwrap();
+            let namespace_singletons = self.namespace_singletons.read().unwrap();
+            let threads = thread_singletons.values().flat_map(|instances| {
+                instances
+                    .iter()
+                    .map(|(key, instance)| (key.clone(), None, instance.clone_ref(py)))
+            });
+            let namespaces = namespace_singletons
+                .iter()
+                .flat_map(|(namespace, instances)| {
+                    instances.iter().map(move |(key, instance)| {
+                        (key.clone(), Some(namespace.clone()), instance.clone_ref(py))
+                    })
+                });
+            threads.chain(namespaces).collect()
+        };
+        instances
+            .into_iter()
+            .map(|(key, namespace, instance)| match namespace {
+                Some(namespace) => (
+                    format!("{}[namespace={}]", key.type_name(py), namespace),
+                    instance,
+                ),
+                None => (key.type_name(py), instance),
+            })
+            .collect()
+    }
+
+    /// Record a disposable transient so it is finalized instead of leaked:
+    /// by `dispose_tenant()` when created for a tenant, else by `close()`
+    ///
+    /// Keys marked unmanaged and instances without a disposer are skipped.
+    /// Outside a tenant the instance is held weakly (and not tracked if it
+    /// cannot be); references to collected instances are pruned as the
+    /// registry grows.
+    fn track_transient(
+        &self,
+        py: Python,
+        type_key: &TypeKey,
+        tenant: Option<&str>,
+        instance: &Py<PyAny>,
+    ) {
+        if !Self::is_disposable(instance.bind(py))
+            || self.unmanaged.read().unwrap().contains(type_key)
+        {
+            return;
+        }
+        let Some(tenant) = tenant else {
+            let Ok(reference) = PyWeakrefReference::new(instance.bind(py)) else {
+                return;
+            };
+            let mut resources = self.resources.write().unwrap();
+            if resources.len() >= 64 && resources.len().is_power_of_two() {
+                resources.retain(|(_, reference)| reference.bind(py).upgrade().is_some());
+            }
+            resources.push((type_key.clone(), reference.unbind()));
+            return;
+        };
+        self.tenant_transients
+            .write()
+            .unwrap()
+            .entry(tenant.to_string())
+            .or_default()
+            .push((type_key.clone(), instance.clone_ref(py)));
+    }
+
+    /// Never track transients of `type_key` for disposal
+    ///
+    /// For objects whose lifetime is managed elsewhere (e.g. handed over to
+    /// a caller who closes them).
+    pub fn mark_unmanaged(&self, type_key: TypeKey) {
+        self.unmanaged.write().unwrap().insert(type_key);
+    }
+
+    /// Take the tracked transients of one tenant (or of every tenant), most
+    /// recent first
+    fn take_tenant_transients(&self, py: Python, tenant: Option<&str>) -> Vec<(String, Py<PyAny>)> {
+        let mut tenant_transients = self.tenant_transients.write().unwrap();
+        let taken: TenantTransients = match tenant {
+            Some(tenant) => tenant_transients.remove_entry(tenant).into_iter().collect(),
+            None => std::mem::take(&mut *tenant_transients),
+        };
+        drop(tenant_transients);
+        taken
+            .into_iter()
+            .flat_map(|(tenant, instances)| {
+                instances.into_iter().rev().map(move |(key, instance)| {
+                    (
+                        format!("{}[tenant={}]", key.type_name(py), tenant),
+                        instance,
+                    )
+                })
+            })
+            .collect()
+    }
+
+    /// Dispose one tenant's instances and drop its partition
+    ///
+    /// Tracked transients go first, then the cached instances, each in
+    /// reverse creation order, under the same time budget rules as
+    /// `shutdown`; other tenants are untouched.
+    pub fn dispose_tenant(
+        &self,
+        py: Python,
+        tenant: &str,
+        timeout: Duration,
+    ) -> PyResult<Vec<String>> {
+        let mut instances = self.take_tenant_transients(py, Some(tenant));
+        let cached: Vec<(TypeKey, Py<PyAny>)> = {
+            let mut tenant_singletons = self.tenant_singletons.write().unwrap();
+            let mut order = self.tenant_order.write().unwrap();
+            let instances = order
+                .iter()
+                .rev()
+                .filter(|(_, owner)| owner == tenant)
+                .filter_map(|(key, _)| {
+                    tenant_singletons
+                        .get_mut(key)
+                        .and_then(|partitions| partitions.remove(tenant))
+                        .map(|instance| (key.clone(), instance))
+                })
+                .collect();
+            order.retain(|(_, owner)| owner != tenant);
+            tenant_singletons.retain(|_, partitions| !partitions.is_empty());
+            instances
+        };
+        instances.extend(cached.into_iter().map(|(key, instance)| {
+            (
+                format!("{}[tenant={}]", key.type_name(py), tenant),
+                instance,
+            )
+        }));
+
+        Self::dispose_within(py, instances, timeout)
+    }
+
+    /// Order items for disposal so dependents go before their dependencies
+    ///
+    /// `items` come in creation order, which already puts a dependency
+    /// before the dependents that resolved it while constructing. The
+    /// auto-wiring edges of the keyed items catch the rest, such as a
+    /// `Lazy[X]` dependency constructed after its dependent, and so do the
+    /// `before=`/`after=` hints. Among the items free to go, the most
+    /// recently created goes first; items caught in a cycle end the order,
+    /// most recent first.
+    fn disposal_order<T>(&self, py: Python, items: Vec<(Option<TypeKey>, T)>) -> Vec<T> {
+        let index: HashMap<&TypeKey, usize> = items
+            .iter()
+            .enumerate()
+            .filter_map(|(position, (key, _))| key.as_ref().map(|key| (key, position)))
+            .collect();
+        let hints = self.ordering_hints.read().unwrap().clone();
+        // dependencies[i]: items that item i was wired with
+        let dependencies: Vec<Vec<usize>> = items
+            .iter()
+            .enumerate()
+            .map(|(position, (key, _))| {
+                let hinted = key.as_ref().and_then(|key| hints.get(key));
+                let params = key
+                    .as_ref()
+                    .and_then(|key| self.constructed_class(py, key))
+                    .and_then(|cls| self.constructor_params(py, cls.bind(py)).ok())
+                    .unwrap_or_default();
+                params
+                    .iter()
+                    .filter_map(|param| param.key.as_ref())
+                    .chain(hinted.into_iter().flatten())
+                    .filter_map(|dependency| index.get(dependency).copied())
+                    .filter(|&dependency| dependency != position)
+                    .collect()
+            })
+            .collect();
+        let mut dependents = vec![0usize; items.len()];
+        for &dependency in dependencies.iter().flatten() {
+            dependents[dependency] += 1;
+        }
+
+        let mut ready: BinaryHeap<usize> = (0..items.len())
+            .filter(|&position| dependents[position] == 0)
+            .collect();
+        let mut order = Vec::with_capacity(items.len());
+        let mut done = vec![false; items.len()];
+        while let Some(position) = ready.pop() {
+            order.push(position);
+            done[position] = true;
+            for &dependency in &dependencies[position] {
+                dependents[dependency] -= 1;
+                if dependents[dependency] == 0 {
+                    ready.push(dependency);
+                }
+            }
+        }
+        order.extend((0..items.len()).rev().filter(|&position| !done[position]));
+
+        let mut items: Vec<Option<T>> = items.into_iter().map(|(_, item)| Some(item)).collect();
+        order
+            .into_iter()
+            .filter_map(|position| items[position].take())
+            .collect()
+    }
+
+    /// The class this container constructs for `type_key`, if its provider
+    /// is a class (or a factory that is one)
+    fn constructed_class(&self, py: Python, type_key: &TypeKey) -> Option<Py<PyType>> {
+        let target = |provider: &Provider| match provider {
+            Provider::Class(cls, _) => Some(cls.clone_ref(py)),
+            Provider::SingletonFactory(factory)
+            | Provider::WeakSingleton(factory)
+            | Provider::TransientFactory(factory)
+            | Provider::TenantFactory(factory)
+            | Provider::ThreadFactory(factory) => factory
+                .bind(py)
+                .cast::<PyType>()
+                .ok()
+                .map(|cls| cls.clone().unbind()),
+            _ => None,
+        };
+        match self.sealed.get() {
+            Some(sealed) => sealed.providers.get(type_key).and_then(target),
+            None => self
+                .providers
+                .read()
+                .unwrap()
+                .get(type_key)
+                .and_then(target),
+        }
+    }
+
+    /// Dispose instances in the given order, sharing a time budget
+    fn dispose_within(
+        py: Python,
+        instances: Vec<(String, Py<PyAny>)>,
+        timeout: Duration,
+    ) -> PyResult<Vec<String>> {
+        let asyncio = py.import("asyncio")?;
+        let mut event_loop: Option<Bound<'_, PyAny>> = None;
+        let mut reports = Vec::new();
+        let deadline = Instant::now() + timeout;
+        let total = instances.len();
+
+        for (index, (type_name, instance)) in instances.into_iter().enumerate() {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                reports.push(format!(
+                    "{}: not disposed, shutdown timeout exhausted",
+                    type_name
+                ));
+                continue;
+            }
+            let share = remaining / (total - index) as u32;
+
+            let started = Instant::now();
+            let outcome =
+                Self::dispose_instance(py, &asyncio, &mut event_loop, instance, Some(share));
+            match outcome {
+                Ok(()) if started.elapsed() > share => reports.push(format!(
+                    "{}: disposal took {}ms, exceeding its {}ms share of the shutdown budget",
+                    type_name,
+                    started.elapsed().as_millis(),
+                    share.as_millis()
+                )),
+                Ok(()) => {}
+                Err(err) if err.is_instance_of::<PyTimeoutError>(py) => reports.push(format!(
+                    "{}: disposal cancelled after exceeding its {}ms share of the shutdown budget",
+                    type_name,
+                    share.as_millis()
+                )),
+                Err(err) => reports.push(format!("{}: disposal failed: {}", type_name, err)),
+            }
+        }
+
+        if let Some(event_loop) = event_loop {
+            event_loop.call_method0("close")?;
+        }
+        Ok(reports)
+    }
+
+    /// Whether `dispose_instance` has a disposer to call on `instance`
+    fn is_disposable(instance: &Bound<'_, PyAny>) -> bool {
+        DISPOSERS
+            .into_iter()
+            .chain(["__aexit__", "__exit__"])
+            .any(|name| instance.hasattr(name).unwrap_or(false))
+    }
+
+    /// Call an instance's disposer, awaiting it (with a timeout, if given)
+    /// if it is async
+    fn dispose_instance<'py>(
+        py: Python<'py>,
+        asyncio: &Bound<'py, PyModule>,
+        event_loop: &mut Option<Bound<'py, PyAny>>,
+        instance: Py<PyAny>,
+        timeout: Option<Duration>,
+    ) -> PyResult<()> {
+        let Some(result) = Self::start_disposal(asyncio, instance.bind(py))? else {
+            return Ok(());
+        };
+
+        let event_loop = match event_loop {
+            Some(event_loop) => event_loop,
+            None => event_loop.insert(asyncio.call_method0("new_event_loop")?),
+        };
+        let timeout = timeout.map(|timeout| timeout.as_secs_f64());
+        let awaitable = asyncio.call_method1("wait_for", (&result, timeout))?;
+        match event_loop.call_method1("run_until_complete", (&awaitable,)) {
+            Ok(_) => Ok(()),
+            Err(err) => {
+                // Never-started coroutines would otherwise warn "never awaited"
+                let _ = awaitable.call_method0("close");
+                let _ = result.call_method0("close");
+                Err(err)
+            }
+        }
+    }
+
+    /// Call an instance's disposer, returning its coroutine if it is async
+    fn start_disposal<'py>(
+        asyncio: &Bound<'py, PyModule>,
+        instance: &Bound<'py, PyAny>,
+    ) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let none = instance.py().None();
+        let result = if let Some(method) = DISPOSERS
+            .into_iter()
+            .find(|name| instance.hasattr(*name).unwrap_or(false))
+        {
+            instance.call_method0(method)?
+        } else if instance.hasattr("__aexit__")? {
+            instance.call_method1("__aexit__", (&none, &none, &none))?
+        } else if instance.hasattr("__exit__")? {
+            instance.call_method1("__exit__", (&none, &none, &none))?
+        } else {
+            return Ok(None);
+        };
+        let coroutine = asyncio
+            .call_method1("iscoroutine", (&result,))?
+            .is_truthy()?;
+        Ok(coroutine.then_some(result))
+    }
+
+    /// Start the async singleton factories, by type name, for
+    /// `async_instantiate_all()`
+    pub fn start_async_singletons(
+        &self,
+        py: Python,
+    ) -> Result<Vec<(TypeKey, AsyncResolve)>, ContainerError> {
+        let mut keys: Vec<TypeKey> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .filter(|(_, provider)| {
+                    matches!(provider, Provider::AsyncFactory(_, Lifetime::Singleton))
+                })
+                .map(|(key, _)| key.clone())
+                .collect()
+        };
+        keys.sort_by_cached_key(|key| key.type_name(py));
+        keys.into_iter()
+            .map(|key| {
+                let resolve = self.start_async(py, &key, None)?;
+                Ok((key, resolve))
+            })
+            .collect()
+    }
+
+    /// Get list of all registered keys (types and enum members)
+    pub fn get_registered_types(&self, py: Python) -> Vec<Py<PyAny>> {
+        let providers = self.providers.read().unwrap();
+        providers.keys().map(|key| key.py_object(py)).collect()
+    }
+
+    /// Check if a type is registered
+    pub fn contains(&self, type_key: &TypeKey) -> bool {
+        if let Some(sealed) = self.sealed.get() {
+            return sealed.providers.contains_key(type_key);
+        }
+        let providers = self.providers.read().unwrap();
+        providers.contains_key(type_key)
+    }
+
+    /// Check if a type is registered here or in a fallback container
+    pub fn is_registered(&self, type_key: &TypeKey) -> bool {
+        self.contains(type_key) || self.fallback_for(type_key).is_some()
+    }
+
+    /// Report registrations that shadow a more specific registration
+    ///
+    /// Resolution matches keys exactly, so when `Interface` is bound to
+    /// `Impl` (as a class or an instance) and `Impl`, or a subtype of
+    /// `Interface` that `Impl` satisfies, has its own registration, resolving
+    /// `Interface` silently bypasses that more specific provider.
+    pub fn check_shadowing(&self, py: Python) -> Vec<String> {
+        // Snapshot the registry so no lock is held while Python code
+        // (e.g. ABCMeta.__subclasscheck__) runs
+        let entries: Vec<(TypeKey, Provider)> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .map(|(key, provider)| (key.clone(), provider.clone()))
+                .collect()
+        };
+
+        let is_subclass = |sub: &Bound<'_, PyType>, parent: &Bound<'_, PyType>| {
+            sub.is_subclass(parent).unwrap_or(false)
+        };
+
+        let mut reports = Vec::new();
+        for (key, provider) in &entries {
+            let Some(key_type) = key.as_type() else {
+                continue;
+            };
+            let key_type = key_type.bind(py);
+
+            // Only class and instance providers have a known concrete type
+            let impl_type = match provider {
+                Provider::Class(cls, _) => cls.bind(py).clone(),
+                Provider::Instance(obj) | Provider::Prototype(obj) => obj.bind(py).get_type(),
+                Provider::WeakSingleton(factory) => match factory.bind(py).cast::<PyType>() {
+                    Ok(cls) => cls.clone(),
+                    Err(_) => continue,
+                },
+                Provider::SingletonFactory(_)
+                | Provider::TransientFactory(_)
+                | Provider::TenantFactory(_)
+                | Provider::ThreadFactory(_)
+                | Provider::Pooled(_)
+                | Provider::AsyncFactory(..)
+                | Provider::GeneratorFactory(..)
+                | Provider::Env(_) => continue,
+            };
+
+            for (other_key, _) in &entries {
+                if other_key == key {
+                    continue;
+                }
+                let Some(other_type) = other_key.as_type() else {
+                    continue;
+                };
+                let other_type = other_type.bind(py);
+
+                let more_specific = other_type.is(&impl_type)
+                    || (is_subclass(&impl_type, other_type) && is_subclass(other_type, key_type));
+                if more_specific {
+                    reports.push(format!(
+                        "{} is bound to {}, which bypasses the more specific registration for {}",
+                        key.type_name(py),
+                        impl_type
+                            .name()
+                            .map(|n| n.to_string())
+                            .unwrap_or_else(|_| "<unknown>".to_string()),
+                        other_key.type_name(py),
+                    ));
+                }
+            }
+        }
+
+        reports.sort();
+        reports
+    }
+
+    /// Group singletons held under more than one key by object identity
+    ///
+    /// Covers the singleton cache and registered instances. Aliasing one
+    /// object under several keys may be intended (one adapter serving two
+    /// ports) or a sign of over-registration. Keys within a group, and the
+    /// groups themselves, are sorted by type name.
+    pub fn duplicate_instances(&self, py: Python) -> Vec<(Py<PyAny>, Vec<TypeKey>)> {
+        let mut held: HashMap<TypeKey, Py<PyAny>> = {
+            let providers = self.providers.read().unwrap();
+            providers
+                .iter()
+                .filter_map(|(key, provider)| match provider {
+                    Provider::Instance(obj) => Some((key.clone(), obj.clone_ref(py))),
+                    _ => None,
+                })
+                .collect()
+        };
+        // A cached (e.g. decorated) instance is what resolves actually return
+        for (key, instance) in self.singletons.entries(py) {
+            held.insert(key, instance);
+        }
+
+        let mut groups: HashMap<usize, (Py<PyAny>, Vec<TypeKey>)> = HashMap::new();
+        for (key, instance) in held {
+            let identity = instance.as_ptr() as usize;
+            groups
+                .entry(identity)
+                .or_insert_with(|| (instance, Vec::new()))
+                .1
+                .push(key);
+        }
+
+        let mut duplicates: Vec<(Py<PyAny>, Vec<TypeKey>)> = groups
+            .into_values()
+            .filter(|(_, keys)| keys.len() > 1)
+            .map(|(instance, mut keys)| {
+                keys.sort_by_key(|key| key.type_name(py));
+                (instance, keys)
+            })
+            .collect();
+        duplicates.sort_by_key(|(_, keys)| keys[0].type_name(py));
+        duplicates
+    }
+}
+
+impl Default for RustContainer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Factory return wrapper that skips singleton caching for one resolve
+///
+/// The wrapped value is returned to the caller, but the next resolve runs the
+/// factory again (e.g. after returning a degraded fallback).
+#[pyclass(frozen)]
+struct DoNotCache {
+    #[pyo3(get)]
+    value: Py<PyAny>,
+}
+
+#[pymethods]
+impl DoNotCache {
+    #[new]
+    fn new(value: Py<PyAny>) -> Self {
+        DoNotCache { value }
+    }
+}
+
+/// Factory registered with bound `args` / `kwargs`
+///
+/// Calling it calls the factory with the bound positional arguments
+/// followed by any given ones, and the bound keyword arguments with given
+/// ones merged on top. `rebind_factory()` replaces the factory together
+/// with its bindings.
+#[pyclass(frozen)]
+struct BoundFactory {
+    #[pyo3(get, name = "__wrapped__")]
+    factory: Py<PyAny>,
+    args: Py<PyTuple>,
+    kwargs: Py<PyDict>,
+}
+
+impl BoundFactory {
+    /// Wrap `factory` when there is anything to bind
+    fn wrap(
+        py: Python,
+        factory: Py<PyAny>,
+        args: Option<Vec<Py<PyAny>>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<PyAny>> {
+        if args.is_none() && kwargs.is_none() {
+            return Ok(factory);
+        }
+        let bound = BoundFactory {
+            factory,
+            args: PyTuple::new(py, args.unwrap_or_default())?.unbind(),
+            kwargs: match kwargs {
+                Some(kwargs) => kwargs.copy()?.unbind(),
+                None => PyDict::new(py).unbind(),
+            },
+        };
+        Ok(Py::new(py, bound)?.into_any())
+    }
+}
+
+#[pymethods]
+impl BoundFactory {
+    #[pyo3(signature = (*args, **kwargs))]
+    fn __call__(
+        &self,
+        py: Python,
+        args: &Bound<'_, PyTuple>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<PyAny>> {
+        let bound_args = self.args.bind(py);
+        let args = if args.is_empty() {
+            bound_args.clone()
+        } else {
+            PyTuple::new(py, bound_args.iter().chain(args.iter()).collect::<Vec<_>>())?
+        };
+        let merged = self.kwargs.bind(py).copy()?;
+        if let Some(kwargs) = kwargs {
+            merged.update(kwargs.as_mapping())?;
+        }
+        Ok(self.factory.bind(py).call(args, Some(&merged))?.unbind())
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let factory = self.factory.bind(py);
+        let name = factory.getattr("__qualname__").map_or_else(
+            |_| factory.repr().map(|r| r.to_string()),
+            |n| Ok(n.to_string()),
+        )?;
+        let mut bound: Vec<String> = Vec::new();
+        for arg in self.args.bind(py).iter() {
+            bound.push(arg.repr()?.to_string());
+        }
+        for (key, value) in self.kwargs.bind(py).iter() {
+            bound.push(format!("{key}={}", value.repr()?));
+        }
+        Ok(format!("BoundFactory({name}, {})", bound.join(", ")))
+    }
+}
+
+/// Marker returned by `Container.freeze_singletons()`
+///
+/// While active, resolving a singleton that is not cached yet raises instead
+/// of running its factory. `release()` (or leaving the `with` block) lifts it.
+#[pyclass]
+struct SingletonFreeze {
+    container: RustContainer,
+}
+
+#[pymethods]
+impl SingletonFreeze {
+    /// Allow singleton factories to run again
+    fn release(&self) {
+        self.container.set_singletons_frozen(false);
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(&self, _exc_type: Py<PyAny>, _exc_value: Py<PyAny>, _traceback: Py<PyAny>) -> bool {
+        self.release();
+        false
+    }
+}
+
+/// Find the cycles of a dependency graph, searching from `roots` in order
+///
+/// Each cycle is rendered as `A -> B -> A`, rotated to start at its
+/// alphabetically first key so a cycle reached from several roots is
+/// reported once.
+fn find_cycles(
+    py: Python,
+    graph: &HashMap<TypeKey, Vec<TypeKey>>,
+    roots: &[TypeKey],
+) -> Vec<String> {
+    fn visit(
+        py: Python,
+        graph: &HashMap<TypeKey, Vec<TypeKey>>,
+        key: &TypeKey,
+        path: &mut Vec<TypeKey>,
+        done: &mut HashSet<TypeKey>,
+        cycles: &mut Vec<String>,
+    ) {
+        if done.contains(key) {
+            return;
+        }
+        if let Some(start) = path.iter().position(|on_path| on_path == key) {
+            let mut names: Vec<String> = path[start..].iter().map(|k| k.type_name(py)).collect();
+            let first = (0..names.len()).min_by_key(|&i| &names[i]).unwrap_or(0);
+            names.rotate_left(first);
+            names.push(names[0].clone());
+            let cycle = names.join(" -> ");
+            if !cycles.contains(&cycle) {
+                cycles.push(cycle);
+            }
+            return;
+        }
+        path.push(key.clone());
+        for dependency in graph.get(key).into_iter().flatten() {
+            visit(py, graph, dependency, path, done, cycles);
+        }
+        path.pop();
+        done.insert(key.clone());
+    }
+
+    let mut done = HashSet::new();
+    let mut cycles = Vec::new();
+    for root in roots {
+        visit(py, graph, root, &mut Vec::new(), &mut done, &mut cycles);
+    }
+    cycles
+}
+
+/// Remove the first registration of `hook`; returns whether it was found
+fn remove_hook(hooks: &RwLock<Vec<Py<PyAny>>>, hook: &Bound<'_, PyAny>) -> bool {
+    let removed = {
+        let mut hooks = hooks.write().unwrap();
+        hooks
+            .iter()
+            .position(|registered| registered.is(hook))
+            .map(|index| hooks.remove(index))
+    };
+    removed.is_some()
+}
+
+/// A new future on the running event loop, with the loop; None outside one
+fn running_loop_future(py: Python) -> PyResult<Option<(Py<PyAny>, Py<PyAny>)>> {
+    let Ok(event_loop) = py.import("asyncio")?.call_method0("get_running_loop") else {
+        return Ok(None);
+    };
+    let future = event_loop.call_method0("create_future")?;
+    Ok(Some((future.unbind(), event_loop.unbind())))
+}
+
+/// Levenshtein distance between two names, counted in characters
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a_char) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a_char != *b_char);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Emit each lifecycle failure message as a `RuntimeWarning`
+fn warn_reports(py: Python, reports: Vec<String>) -> PyResult<()> {
+    let category = py.get_type::<PyRuntimeWarning>();
+    for report in reports {
+        let message = CString::new(report).unwrap_or_default();
+        PyErr::warn(py, &category, &message, 1)?;
+    }
+    Ok(())
+}
+
+/// Finalizers left to run by `close_async()`
+struct AsyncClose {
+    container: RustContainer,
+    /// Instance whose async disposer is being awaited, named for reports
+    current: String,
+    remaining: std::vec::IntoIter<Finalizer>,
+    reports: Vec<String>,
+    /// Warn about the reports and return False instead of returning them
+    warn: bool,
+}
+
+impl AsyncClose {
+    /// Run finalizers until one starts an async disposer, returning the
+    /// iterator to await it with; None once every finalizer has run
+    fn run<'py>(&mut self, py: Python<'py>) -> PyResult<Option<Bound<'py, PyAny>>> {
+        let asyncio = py.import("asyncio")?;
+        for finalizer in self.remaining.by_ref() {
+            let (type_name, instance) = match finalizer {
+                Finalizer::Teardown(teardown) => {
+                    self.reports
+                        .extend(self.container.finish_teardown(py, teardown));
+                    continue;
+                }
+                Finalizer::Dispose(type_name, instance) => (type_name, instance),
+            };
+            let started = RustContainer::start_disposal(&asyncio, instance.bind(py))
+                .and_then(|coroutine| coroutine.map(|c| c.call_method0("__await__")).transpose());
+            match started {
+                Ok(Some(iterator)) => {
+                    self.current = type_name;
+                    return Ok(Some(iterator));
+                }
+                Ok(None) => {}
+                Err(err) => self
+                    .reports
+                    .push(format!("{}: disposal failed: {}", type_name, err)),
+            }
+        }
+        Ok(None)
+    }
+
+    /// Empty the caches and produce the awaitable's result
+    fn finish(self, py: Python) -> PyResult<Py<PyAny>> {
+        drop(self.container.take_caches());
+        if self.warn {
+            warn_reports(py, self.reports)?;
+            return Ok(false.into_pyobject(py)?.to_owned().into_any().unbind());
+        }
+        Ok(self.reports.into_pyobject(py)?.into_any().unbind())
+    }
+}
+
+/// What an awaitable does with the result of the coroutine it drove
+enum AsyncFinish {
+    /// Decorate and cache the awaited result of an async factory (in
+    /// `namespace`'s cache, if any), then settle `in_flight` for the
+    /// resolves that raced it
+    Resolve {
+        container: Box<RustContainer>,
+        type_key: TypeKey,
+        namespace: Option<String>,
+        cache: bool,
+        in_flight: Option<Py<PyAny>>,
+    },
+
+    /// Return the instance another resolve awaited, as it is
+    Shared,
+
+    /// Raise the first failure among the gathered async singletons, if
+    /// any, then return `result`
+    Warm { result: Py<PyAny> },
+
+    /// Check the gathered async starts of `components`, then return `result`
+    Start {
+        container: Box<RustContainer>,
+        components: Vec<Py<PyAny>>,
+        result: Py<PyAny>,
+    },
+
+    /// Turn the gathered async stops of `names` into failure reports,
+    /// then run the finalizers of `close`
+    Stop {
+        names: Vec<String>,
+        close: Box<AsyncClose>,
+    },
+}
+
+/// Progress of an awaitable returned by `Container.resolve_async()` or the
+/// async lifecycle methods
+enum AsyncState {
+    /// Finished without awaiting; returned on the first step
+    Ready(Py<PyAny>),
+
+    /// Driving the `__await__` iterator of a coroutine (or gathered ones)
+    Pending {
+        iterator: Py<PyAny>,
+        finish: AsyncFinish,
+    },
+
+    /// Awaiting an async disposer of `close_async()`, with the
+    /// finalizers that run after it
+    Closing {
+        iterator: Py<PyAny>,
+        close: Box<AsyncClose>,
+    },
+
+    /// Already awaited
+    Done,
+}
+
+/// Awaitable returned by `Container.resolve_async()`, `__aenter__`,
+/// `__aexit__`, `aclose()`, `async_instantiate_all()` and `async_dispose()`
+///
+/// It forwards the event loop's `send()`/`throw()` to the coroutine it
+/// wraps; when the coroutine returns, the result is post-processed (an async
+/// factory's instance is decorated and, for singletons, cached) before it is
+/// handed to the awaiting code. Like a coroutine, it can be awaited only once.
+#[pyclass]
+pub struct AsyncResolve {
+    state: AsyncState,
+}
+
+impl AsyncResolve {
+    fn ready(instance: Py<PyAny>) -> Self {
+        AsyncResolve {
+            state: AsyncState::Ready(instance),
+        }
+    }
+
+    /// Turn one step of the coroutine into a yielded value or the final result
+    fn advance(&mut self, py: Python, step: PyResult<Py<PyAny>>) -> PyResult<Py<PyAny>> {
+        let err = match step {
+            Ok(yielded) => return Ok(yielded),
+            Err(err) => err,
+        };
+        let state = std::mem::replace(&mut self.state, AsyncState::Done);
+        let finish = match state {
+            AsyncState::Pending { finish, .. } => finish,
+            AsyncState::Closing { close, .. } => return self.continue_close(py, err, close),
+            AsyncState::Ready(_) | AsyncState::Done => return Err(err),
+        };
+        if !err.is_instance_of::<PyStopIteration>(py) {
+            if let AsyncFinish::Resolve {
+                container,
+                type_key,
+                namespace,
+                in_flight,
+                ..
+            } = finish
+            {
+                let flight = (namespace, type_key);
+                container.settle_async(py, &flight, in_flight, Err(Some(&err)))?;
+            }
+            return Err(err);
+        }
+        let value = err.value(py).getattr("value")?;
+        let result = match finish {
+            AsyncFinish::Resolve {
+                container,
+                type_key,
+                namespace,
+                cache,
+                in_flight,
+            } => {
+                let instance = container.finish_async(
+                    py,
+                    &type_key,
+                    namespace.as_ref(),
+                    value.unbind(),
+                    cache,
+                );
+                let outcome = instance.as_ref().map_err(Some);
+                let flight = (namespace, type_key);
+                container.settle_async(py, &flight, in_flight, outcome)?;
+                instance?
+            }
+            AsyncFinish::Shared => value.unbind(),
+            AsyncFinish::Warm { result } => {
+                let base_exception = py.get_type::<pyo3::exceptions::PyBaseException>();
+                for outcome in value.try_iter()? {
+                    let outcome = outcome?;
+                    if outcome.is_instance(&base_exception)? {
+                        return Err(PyErr::from_value(outcome));
+                    }
+                }
+                result
+            }
+            AsyncFinish::Start {
+                container,
+                components,
+                result,
+            } => {
+                container.finish_async_start(py, components, &value)?;
+                result
+            }
+            AsyncFinish::Stop { names, mut close } => {
+                RustContainer::report_async_stops(py, names, &value, &mut close.reports)?;
+                return self.run_close(py, close);
+            }
+        };
+        Err(PyStopIteration::new_err((result,)))
+    }
+
+    /// Run the finalizers of an async `close()`, awaiting the first
+    /// async disposer, if any
+    fn close(py: Python, mut close: AsyncClose) -> PyResult<Self> {
+        let state = match close.run(py)? {
+            Some(iterator) => AsyncState::Closing {
+                iterator: iterator.unbind(),
+                close: Box::new(close),
+            },
+            None => AsyncState::Ready(close.finish(py)?),
+        };
+        Ok(AsyncResolve { state })
+    }
+
+    /// Note how an async disposer ended, then run the next finalizers,
+    /// driving the next async disposer in place of the finished one
+    fn continue_close(
+        &mut self,
+        py: Python,
+        err: PyErr,
+        mut close: Box<AsyncClose>,
+    ) -> PyResult<Py<PyAny>> {
+        if !err.is_instance_of::<PyStopIteration>(py) {
+            // Cancellation and the like stop the remaining finalizers
+            if !err.is_instance_of::<PyException>(py) {
+                return Err(err);
+            }
+            let report = format!("{}: disposal failed: {}", close.current, err);
+            close.reports.push(report);
+        }
+        self.run_close(py, close)
+    }
+
+    /// Run the next finalizers, driving the next async disposer in place
+    /// of the current coroutine
+    fn run_close(&mut self, py: Python, mut close: Box<AsyncClose>) -> PyResult<Py<PyAny>> {
+        match close.run(py)? {
+            Some(iterator) => {
+                let step = iterator
+                    .call_method1("send", (py.None(),))
+                    .map(Bound::unbind);
+                self.state = AsyncState::Closing {
+                    iterator: iterator.unbind(),
+                    close,
+                };
+                self.advance(py, step)
+            }
+            None => Err(PyStopIteration::new_err((close.finish(py)?,))),
+        }
+    }
+
+    /// Await `coroutines` together, then finish as `finish` says
+    fn gather(py: Python, coroutines: Vec<Py<PyAny>>, finish: AsyncFinish) -> PyResult<Self> {
+        let kwargs = PyDict::new(py);
+        kwargs.set_item("return_exceptions", true)?;
+        let gathered = py
+            .import("asyncio")?
+            .getattr("gather")?
+            .call(pyo3::types::PyTuple::new(py, coroutines)?, Some(&kwargs))?;
+        Ok(AsyncResolve {
+            state: AsyncState::Pending {
+                iterator: gathered.call_method0("__await__")?.unbind(),
+                finish,
+            },
+        })
+    }
+}
+
+impl Drop for AsyncResolve {
+    /// Release an in-flight singleton never awaited to the end, cancelling
+    /// the resolves that wait for it
+    fn drop(&mut self) {
+        let state = std::mem::replace(&mut self.state, AsyncState::Done);
+        if let AsyncState::Pending {
+            finish:
+                AsyncFinish::Resolve {
+                    container,
+                    type_key,
+                    namespace,
+                    in_flight: Some(future),
+                    ..
+                },
+            ..
+        } = state
+        {
+            Python::attach(|py| {
+                let flight = (namespace, type_key);
+                if let Err(err) = container.settle_async(py, &flight, Some(future), Err(None)) {
+                    err.write_unraisable(py, None);
+                }
+            });
+        }
+    }
+}
+
+#[pymethods]
+impl AsyncResolve {
+    fn __await__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python) -> PyResult<Py<PyAny>> {
+        self.send(py, py.None())
+    }
+
+    fn send(&mut self, py: Python, value: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        match &self.state {
+            AsyncState::Ready(instance) => {
+                let instance = instance.clone_ref(py);
+                self.state = AsyncState::Done;
+                Err(PyStopIteration::new_err((instance,)))
+            }
+            AsyncState::Pending { iterator, .. } | AsyncState::Closing { iterator, .. } => {
+                let step = iterator.call_method1(py, "send", (value,));
+                self.advance(py, step)
+            }
+            AsyncState::Done => Err(PyRuntimeError::new_err(
+                "cannot reuse already awaited resolve_async()",
+            )),
+        }
+    }
+
+    fn throw(&mut self, py: Python, exc: Py<PyAny>) -> PyResult<Py<PyAny>> {
+        match &self.state {
+            AsyncState::Pending { iterator, .. } | AsyncState::Closing { iterator, .. } => {
+                let step = iterator.call_method1(py, "throw", (exc,));
+                self.advance(py, step)
+            }
+            AsyncState::Ready(_) | AsyncState::Done => {
+                self.state = AsyncState::Done;
+                Err(PyErr::from_value(exc.into_bound(py)))
+            }
+        }
+    }
+}
+
+/// Async context manager returned by `lifespan_context()`, for an ASGI
+/// application's lifespan
+///
+/// Entering warms every singleton (`async_instantiate_all()`) and returns
+/// the container; exiting closes it (`async_dispose()`), reporting failed
+/// disposals as `RuntimeWarning`s.
+#[pyclass(frozen)]
+struct Lifespan {
+    container: Py<Container>,
+}
+
+#[pymethods]
+impl Lifespan {
+    fn __aenter__(&self, py: Python) -> PyResult<AsyncResolve> {
+        let container = self.container.bind(py);
+        container
+            .borrow()
+            .warm_async(py, container.clone().into_any().unbind())
+    }
+
+    fn __aexit__(
+        &self,
+        py: Python,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<AsyncResolve> {
+        self.container.borrow(py).rust_core.close_async(py, true)
+    }
+}
+
+/// Context manager returned by `Container.cache_namespace()`
+///
+/// Inside the `with` block, resolves in the current context (thread or
+/// asyncio task) read and write the namespace's own singleton cache instead
+/// of the shared one. Registrations
+/// are shared; the namespace's instances persist until `reset()`, so entering
+/// the same name again sees the same singletons.
+#[pyclass]
+struct CacheNamespace {
+    container: RustContainer,
+    name: String,
+    /// Context variable tokens of the entries not yet exited, innermost last
+    tokens: Vec<Py<PyAny>>,
+}
+
+#[pymethods]
+impl CacheNamespace {
+    #[getter]
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn __enter__<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        py: Python<'py>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        let token = slf.container.enter_cache_namespace(py, &slf.name)?;
+        slf.tokens.push(token);
+        Ok(slf)
+    }
+
+    fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<bool> {
+        if let Some(token) = self.tokens.pop() {
+            self.container.exit_cache_namespace(py, &token)?;
+        }
+        Ok(false)
+    }
+}
+
+/// Drops one thread's thread-lifetime instances when it is dropped
+///
+/// Kept in a `threading.local`, so CPython drops it when its thread exits.
+#[pyclass(frozen)]
+struct ThreadRelease {
+    partitions: Weak<RwLock<ThreadPartitions>>,
+    thread_id: ThreadId,
+}
+
+impl Drop for ThreadRelease {
+    fn drop(&mut self) {
+        if let Some(partitions) = self.partitions.upgrade() {
+            let released = partitions.write().unwrap().remove(&self.thread_id);
+            drop(released);
+        }
+    }
+}
+
+/// Instance borrowed from a pooled provider
+///
+/// `value` is the pooled object. `release()`, leaving the `with` block or
+/// dropping the handle returns it to the pool; it must not be used after.
+#[pyclass]
+struct PooledInstance {
+    pool: Arc<ObjectPool>,
+    /// The borrowed object, until it is returned
+    instance: Option<Py<PyAny>>,
+}
+
+#[pymethods]
+impl PooledInstance {
+    #[getter]
+    fn value(&self, py: Python) -> PyResult<Py<PyAny>> {
+        match &self.instance {
+            Some(instance) => Ok(instance.clone_ref(py)),
+            None => Err(PyRuntimeError::new_err(
+                "pooled instance was already returned to its pool",
+            )),
+        }
+    }
+
+    /// Whether the instance was returned to its pool
+    #[getter]
+    fn released(&self) -> bool {
+        self.instance.is_none()
+    }
+
+    /// Return the instance to its pool (idempotent)
+    fn release(&mut self) {
+        if let Some(instance) = self.instance.take() {
+            self.pool.give_back(instance);
+        }
+    }
+
+    fn __enter__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        self.value(py)
+    }
+
+    fn __exit__(
+        &mut self,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> bool {
+        self.release();
+        false
+    }
+}
+
+impl Drop for PooledInstance {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+/// The `X` of an `Optional[X]` or `X | None` annotation, if `annotation`
+/// is one; unions of several classes are not unwrapped
+fn optional_target<'py>(annotation: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = annotation.py();
+    let typing = py.import("typing")?;
+    let origin = typing.call_method1("get_origin", (annotation,))?;
+    if !origin.is(typing.getattr("Union")?)
+        && !origin.is(py.import("types")?.getattr("UnionType")?)
+    {
+        return Ok(None);
+    }
+    let none_type = py.None().bind(py).get_type();
+    let args = typing.call_method1("get_args", (annotation,))?;
+    let mut members = Vec::new();
+    let mut nullable = false;
+    for arg in args.try_iter()? {
+        let arg = arg?;
+        if arg.is(&none_type) {
+            nullable = true;
+        } else {
+            members.push(arg);
+        }
+    }
+    Ok(match (nullable, members.len()) {
+        (true, 1) => members.pop(),
+        _ => None,
+    })
+}
+
+/// The `X` of a `list[X]` / `Sequence[X]`, `tuple[X, ...]`, `set[X]`,
+/// `frozenset[X]` or `dict[str, X]` / `Mapping[str, X]` annotation
+/// (including the `typing` aliases), if `annotation` is one
+fn collection_target<'py>(
+    annotation: &Bound<'py, PyAny>,
+) -> PyResult<Option<(Collection, Bound<'py, PyAny>)>> {
+    let py = annotation.py();
+    let typing = py.import("typing")?;
+    let abc = py.import("collections.abc")?;
+    let origin = typing.call_method1("get_origin", (annotation,))?;
+    let args = typing.call_method1("get_args", (annotation,))?;
+    let sequence = if origin.is(py.get_type::<PyList>()) || origin.is(abc.getattr("Sequence")?) {
+        Some(Collection::List)
+    } else if origin.is(py.get_type::<PySet>()) || origin.is(abc.getattr("MutableSet")?) {
+        Some(Collection::Set)
+    } else if origin.is(py.get_type::<PyFrozenSet>()) || origin.is(abc.getattr("Set")?) {
+        Some(Collection::FrozenSet)
+    } else {
+        None
+    };
+    if let Some(collection) = sequence {
+        if args.len()? == 1 {
+            return Ok(Some((collection, args.get_item(0)?)));
+        }
+    } else if origin.is(py.get_type::<PyTuple>()) {
+        // `tuple[X, ...]` is the homogeneous form; `tuple[X]` is accepted too
+        let homogeneous = match args.len()? {
+            1 => true,
+            2 => args.get_item(1)?.is(py.Ellipsis()),
+            _ => false,
+        };
+        if homogeneous {
+            return Ok(Some((Collection::Tuple, args.get_item(0)?)));
+        }
+    } else if (origin.is(py.get_type::<PyDict>()) || origin.is(abc.getattr("Mapping")?))
+        && args.len()? == 2
+        && args.get_item(0)?.is(py.get_type::<PyString>())
+    {
+        return Ok(Some((Collection::Mapping, args.get_item(1)?)));
+    }
+    Ok(None)
+}
+
+/// Strip `Annotated[X, ...]` to `X`, with the name of its `Config` marker
+fn config_target<'py>(
+    annotation: &Bound<'py, PyAny>,
+) -> PyResult<(Bound<'py, PyAny>, Option<String>)> {
+    let py = annotation.py();
+    let typing = py.import("typing")?;
+    let origin = typing.call_method1("get_origin", (annotation,))?;
+    if !origin.is(typing.getattr("Annotated")?) {
+        return Ok((annotation.clone(), None));
+    }
+    let config = annotation
+        .getattr("__metadata__")?
+        .try_iter()?
+        .filter_map(|metadata| metadata.ok()?.cast_into::<ConfigRef>().ok())
+        .last()
+        .map(|config| config.get().key.clone());
+    Ok((annotation.getattr("__origin__")?, config))
+}
+
+/// Marks an `Annotated` constructor parameter as a configuration value
+///
+/// `Annotated[str, Config("db.url")]` is injected with the value registered
+/// as `"db.url"` through `Container.register_config()`.
+#[pyclass(frozen, name = "Config")]
+struct ConfigRef {
+    #[pyo3(get)]
+    key: String,
+}
+
+#[pymethods]
+impl ConfigRef {
+    #[new]
+    fn new(key: String) -> Self {
+        ConfigRef { key }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Config({:?})", self.key)
+    }
+}
+
+/// The last `T` marker of an `Annotated[X, ...]` annotation
+fn annotated_marker<'py, T: PyTypeCheck>(
+    annotation: &Bound<'py, PyAny>,
+) -> PyResult<Option<Bound<'py, T>>> {
+    let py = annotation.py();
+    let typing = py.import("typing")?;
+    let origin = typing.call_method1("get_origin", (annotation,))?;
+    if !origin.is(typing.getattr("Annotated")?) {
+        return Ok(None);
+    }
+    Ok(annotation
+        .getattr("__metadata__")?
+        .try_iter()?
+        .filter_map(|metadata| metadata.ok()?.cast_into::<T>().ok())
+        .last())
+}
+
+/// Marks an `Annotated` constructor parameter as a feature flag
+///
+/// `Annotated[bool, Flag("feature_x")]` is injected with the value set as
+/// `"feature_x"` through `Container.set_flag()`.
+#[pyclass(frozen, name = "Flag")]
+struct FlagRef {
+    #[pyo3(get)]
+    name: String,
+}
+
+#[pymethods]
+impl FlagRef {
+    #[new]
+    fn new(name: String) -> Self {
+        FlagRef { name }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Flag({:?})", self.name)
+    }
+}
+
+/// Marks an `Annotated` constructor parameter with a container default
+///
+/// `Annotated[int, Default(30)]` is injected with 30 when no provider,
+/// configuration value or flag supplies the parameter. Unlike a default in
+/// the signature, it is passed by the container and visible to it.
+#[pyclass(frozen, name = "Default")]
+struct DefaultRef {
+    #[pyo3(get)]
+    value: Py<PyAny>,
+}
+
+#[pymethods]
+impl DefaultRef {
+    #[new]
+    fn new(value: Py<PyAny>) -> Self {
+        DefaultRef { value }
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        Ok(format!("Default({})", self.value.bind(py).repr()?))
+    }
+}
+
+/// The `X` of a `Lazy[X]` annotation, if `annotation` is one
+fn lazy_target<'py>(annotation: &Bound<'py, PyAny>) -> Option<Bound<'py, PyAny>> {
+    let origin = annotation.getattr("__origin__").ok()?;
+    if !origin.is(annotation.py().get_type::<Lazy>()) {
+        return None;
+    }
+    annotation.getattr("__args__").ok()?.get_item(0).ok()
+}
+
+/// Proxy injected for a constructor parameter annotated `Lazy[X]`
+///
+/// Construction only records the key; the first attribute access (or call)
+/// resolves it from the container, under the tenant of the original
+/// resolve, and later accesses reuse that instance. Injecting a proxy on
+/// one side of a constructor cycle lets both sides be built. The proxy
+/// keeps its container alive.
+#[pyclass(frozen)]
+struct Lazy {
+    container: RustContainer,
+    key: TypeKey,
+    tenant: Option<String>,
+    target: OnceLock<Py<PyAny>>,
+}
+
+impl Lazy {
+    fn new(container: RustContainer, key: TypeKey, tenant: Option<&str>) -> Self {
+        Lazy {
+            container,
+            key,
+            tenant: tenant.map(str::to_owned),
+            target: OnceLock::new(),
+        }
+    }
+
+    /// The proxied instance, resolved on first use
+    fn target<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        if let Some(target) = self.target.get() {
+            return Ok(target.bind(py).clone());
+        }
+        let requested = self.key.py_object(py);
+        let instance = self
+            .container
+            .resolve_for_tenant(py, &self.key, self.tenant.as_deref())
+            .map_err(|e| e.into_py_err(py, Some(requested.bind(py))))?;
+        // A racing first access may have resolved it too; keep the winner
+        Ok(self.target.get_or_init(|| instance).bind(py).clone())
+    }
+}
+
+#[pymethods]
+impl Lazy {
+    /// `Lazy[X]`: annotation marking a parameter for proxy injection
+    #[classmethod]
+    fn __class_getitem__(cls: &Bound<'_, PyType>, key: &Bound<'_, PyAny>) -> PyResult<Py<PyAny>> {
+        let alias = cls.py().import("types")?.getattr("GenericAlias")?;
+        Ok(alias.call1((cls, key))?.unbind())
+    }
+
+    fn __getattr__<'py>(&self, py: Python<'py>, name: &str) -> PyResult<Bound<'py, PyAny>> {
+        self.target(py)?.getattr(name)
+    }
+
+    fn __setattr__(&self, py: Python, name: &str, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.target(py)?.setattr(name, value)
+    }
+
+    fn __delattr__(&self, py: Python, name: &str) -> PyResult<()> {
+        self.target(py)?.delattr(name)
+    }
+
+    #[pyo3(signature = (*args, **kwargs))]
+    fn __call__<'py>(
+        &self,
+        py: Python<'py>,
+        args: &Bound<'py, pyo3::types::PyTuple>,
+        kwargs: Option<&Bound<'py, PyDict>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.target(py)?.call(args, kwargs)
+    }
+
+    fn __repr__(&self, py: Python) -> PyResult<String> {
+        let name = self.key.type_name(py);
+        Ok(match self.target.get() {
+            Some(target) => format!("<Lazy {name}: {}>", target.bind(py).repr()?),
+            None => format!("<Lazy {name} (unresolved)>"),
+        })
+    }
+}
+
+/// The `X` of a `Callable[[], X]` annotation, if `annotation` is one;
+/// callables taking arguments are not unwrapped
+fn callable_target<'py>(annotation: &Bound<'py, PyAny>) -> PyResult<Option<Bound<'py, PyAny>>> {
+    let py = annotation.py();
+    let typing = py.import("typing")?;
+    let origin = typing.call_method1("get_origin", (annotation,))?;
+    if !origin.is(py.import("collections.abc")?.getattr("Callable")?) {
+        return Ok(None);
+    }
+    let args = typing.call_method1("get_args", (annotation,))?;
+    if args.len()? != 2 {
+        return Ok(None);
+    }
+    let parameters = args.get_item(0)?;
+    match parameters.cast::<PyList>() {
+        Ok(parameters) if parameters.is_empty() => Ok(Some(args.get_item(1)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Callable injected for a constructor parameter annotated `Callable[[], X]`
+///
+/// Each call resolves X from the container, under the tenant of the
+/// original resolve, so a transient X is built anew every time. Like
+/// `Lazy`, nothing is resolved while the consumer is being constructed.
+/// The callable keeps its container alive.
+#[pyclass(frozen)]
+struct Resolver {
+    container: RustContainer,
+    key: TypeKey,
+    tenant: Option<String>,
+}
+
+impl Resolver {
+    fn new(container: RustContainer, key: TypeKey, tenant: Option<&str>) -> Self {
+        Resolver {
+            container,
+            key,
+            tenant: tenant.map(str::to_owned),
+        }
+    }
+}
+
+#[pymethods]
+impl Resolver {
+    fn __call__(&self, py: Python) -> PyResult<Py<PyAny>> {
+        let requested = self.key.py_object(py);
+        self.container
+            .resolve_for_tenant(py, &self.key, self.tenant.as_deref())
+            .map_err(|e| e.into_py_err(py, Some(requested.bind(py))))
+    }
+
+    fn __repr__(&self, py: Python) -> String {
+        format!("<Resolver {}>", self.key.type_name(py))
+    }
+}
+
+/// Context manager returned by `Container.override()`
+///
+/// Entering the `with` block binds the key to the instance; leaving it, even
+/// by an exception, puts back the original provider and cached singleton.
+/// Overrides nest, including overrides of the same key.
+#[pyclass]
+struct ProviderOverride {
+    container: RustContainer,
+    key: TypeKey,
+    instance: Py<PyAny>,
+    /// Revert token while the override is active
+    active: Option<u64>,
+}
+
+#[pymethods]
+impl ProviderOverride {
+    fn __enter__(&mut self, py: Python) -> PyResult<Py<PyAny>> {
+        if self.active.is_none() {
+            let id = self
+                .container
+                .push_override(py, &self.key, self.instance.clone_ref(py))
+                .map_err(|e| e.into_py_err(py, Some(self.key.py_object(py).bind(py))))?;
+            self.active = Some(id);
+        }
+        Ok(self.instance.clone_ref(py))
+    }
+
+    fn __exit__(
+        &mut self,
+        py: Python,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<bool> {
+        if let Some(id) = self.active.take() {
+            self.container
+                .pop_override(py, id)
+                .map_err(|e| e.into_py_err(py, Some(self.key.py_object(py).bind(py))))?;
+        }
+        Ok(false)
+    }
+}
+
+/// Warm singleton cache captured by `Container.snapshot_for_fork()`
+///
+/// Forked workers (or containers rebuilt after the fork) adopt it with
+/// `Container.restore_fork_snapshot()` instead of re-running factories.
+/// Only share fork-safe objects this way: sockets, database connections,
+/// threads and locks held by a singleton are not safe to use in both the
+/// parent and the child, and should be created after the fork instead.
+#[pyclass(frozen)]
+struct ForkSnapshot {
+    entries: Vec<(TypeKey, Py<PyAny>)>,
+}
+
+#[pymethods]
+impl ForkSnapshot {
+    fn __len__(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Keys of the captured singletons, in creation order
+    fn keys(&self, py: Python) -> Vec<Py<PyAny>> {
+        self.entries
+            .iter()
+            .map(|(key, _)| key.py_object(py))
+            .collect()
+    }
+}
+
+/// Registry and singleton cache captured by `Container.snapshot()`
+///
+/// Hand it to `Container.restore()` (typically in test teardown) to undo
+/// every registration change and singleton created since.
+#[pyclass(frozen)]
+struct ContainerSnapshot {
+    snapshot: RegistrySnapshot,
+}
+
+#[pymethods]
+impl ContainerSnapshot {
+    /// Number of captured registrations
+    fn __len__(&self) -> usize {
+        self.snapshot.providers.len()
+    }
+}
+
+/// Provider kind chosen on a `RegistrationBuilder`
+enum BuilderSource {
+    Class(Py<PyType>),
+    Instance(Py<PyAny>),
+    Factory(Py<PyAny>),
+}
+
+impl BuilderSource {
+    fn kind_name(&self) -> &'static str {
+        match self {
+            BuilderSource::Class(_) => "class",
+            BuilderSource::Instance(_) => "instance",
+            BuilderSource::Factory(_) => "factory",
+        }
+    }
+
+    /// Map the source and an optional scope onto a concrete provider variant
+    ///
+    /// Every source defaults to singleton.
+    fn provider(&self, py: Python, scope: Option<&'static str>) -> Result<Provider, String> {
+        let provider = match (self, scope) {
+            (BuilderSource::Class(cls), None | Some("singleton")) => {
+                Provider::Class(cls.clone_ref(py), Lifetime::Singleton)
+            }
+            (BuilderSource::Class(cls), Some("transient")) => {
+                Provider::Class(cls.clone_ref(py), Lifetime::Transient)
+            }
+            (BuilderSource::Class(cls), Some("thread")) => {
+                Provider::Class(cls.clone_ref(py), Lifetime::Thread)
+            }
+            (BuilderSource::Class(cls), Some(_)) => {
+                Provider::TenantFactory(cls.clone_ref(py).into_any())
+            }
+            (BuilderSource::Instance(obj), None | Some("singleton")) => {
+                Provider::Instance(obj.clone_ref(py))
+            }
+            (BuilderSource::Instance(_), Some(scope)) => {
+                return Err(format!("an instance provider cannot use {scope} scope"))
+            }
+            (BuilderSource::Factory(factory), None | Some("singleton")) => {
+                Provider::SingletonFactory(factory.clone_ref(py))
+            }
+            (BuilderSource::Factory(factory), Some("transient")) => {
+                Provider::TransientFactory(factory.clone_ref(py))
+            }
+            (BuilderSource::Factory(factory), Some("thread")) => {
+                Provider::ThreadFactory(factory.clone_ref(py))
+            }
+            (BuilderSource::Factory(factory), Some(_)) => {
+                Provider::TenantFactory(factory.clone_ref(py))
+            }
+        };
+        Ok(provider)
+    }
+}
+
+/// Normalize a `dioxide.Scope` member or scope string
+///
+/// `factory` and `request` map to transient providers, `tenant` to
+/// per-tenant caching and `thread` to per-thread caching.
+fn parse_scope(scope: &Bound<'_, PyAny>) -> PyResult<&'static str> {
+    let value = match scope.getattr("value") {
+        Ok(value) => value,
+        Err(_) => scope.clone(),
+    };
+    let name: String = value.extract()?;
+    match name.as_str() {
+        "singleton" => Ok("singleton"),
+        "factory" | "transient" | "request" => Ok("transient"),
+        "tenant" => Ok("tenant"),
+        "thread" => Ok("thread"),
+        _ => Err(PyTypeError::new_err(format!(
+            "Unknown scope {name:?}; expected singleton, factory, request, tenant or thread"
+        ))),
+    }
+}
+
+/// Fluent registration returned by `Container.register()`
+///
+/// Accumulates a provider kind and scope, then registers on `done()` or when
+/// its `with` block exits without an exception. Exactly one of `as_class()`,
+/// `as_instance()` or `as_factory()` must be chosen.
+#[pyclass]
+struct RegistrationBuilder {
+    container: RustContainer,
+    key: TypeKey,
+    sources: Vec<BuilderSource>,
+    scope: Option<&'static str>,
+    name: Option<String>,
+    tags: Vec<String>,
+    committed: bool,
+}
+
+impl RegistrationBuilder {
+    fn registration_failed(&self, py: Python, reason: String) -> PyErr {
+        let err = ContainerError::ProviderRegistrationFailed {
+            type_name: self.key.type_name(py),
+            reason,
+        };
+        err.into_py_err(py, Some(self.key.py_object(py).bind(py)))
+    }
+
+    /// Check exactly one source was chosen and build its provider
+    fn build_provider(&self, py: Python) -> PyResult<Provider> {
+        let source = match self.sources.as_slice() {
+            [source] => source,
+            [] => {
+                return Err(self.registration_failed(
+                    py,
+                    "no provider chosen; call as_class(), as_instance() or as_factory()"
+                        .to_string(),
+                ))
+            }
+            sources => {
+                let kinds: Vec<&str> = sources.iter().map(BuilderSource::kind_name).collect();
+                return Err(self.registration_failed(
+                    py,
+                    format!("more than one provider chosen ({})", kinds.join(", ")),
+                ));
+            }
+        };
+
+        source
+            .provider(py, self.scope)
+            .map_err(|reason| self.registration_failed(py, reason))
+    }
+}
+
+#[pymethods]
+impl RegistrationBuilder {
+    /// Provide the key with an auto-wired instance of `cls` (singleton unless scoped)
+    fn as_class(mut slf: PyRefMut<'_, Self>, cls: Py<PyType>) -> PyRefMut<'_, Self> {
+        slf.sources.push(BuilderSource::Class(cls));
+        slf
+    }
+
+    /// Provide the key with an existing object
+    fn as_instance(mut slf: PyRefMut<'_, Self>, instance: Py<PyAny>) -> PyRefMut<'_, Self> {
+        slf.sources.push(BuilderSource::Instance(instance));
+        slf
+    }
+
+    /// Provide the key with a zero-argument factory (singleton unless scoped)
+    fn as_factory(mut slf: PyRefMut<'_, Self>, factory: Py<PyAny>) -> PyRefMut<'_, Self> {
+        slf.sources.push(BuilderSource::Factory(factory));
+        slf
+    }
+
+    /// Set the lifecycle scope
+    ///
+    /// Accepts a `dioxide.Scope` member or its string value. `factory` and
+    /// `request` map to transient providers, `tenant` to per-tenant caching.
+    fn scoped<'py>(
+        mut slf: PyRefMut<'py, Self>,
+        scope: &Bound<'py, PyAny>,
+    ) -> PyResult<PyRefMut<'py, Self>> {
+        slf.scope = Some(parse_scope(scope)?);
+        Ok(slf)
+    }
+
+    /// Register under a binding name, resolved with `resolve(key, name=...)`
+    fn named(mut slf: PyRefMut<'_, Self>, name: String) -> PyRefMut<'_, Self> {
+        slf.name = Some(name);
+        slf
+    }
+
+    /// Attach tags to the registration
+    fn with_tags(mut slf: PyRefMut<'_, Self>, tags: Vec<String>) -> PyRefMut<'_, Self> {
+        slf.tags.extend(tags);
+        slf
+    }
+
+    /// Validate the accumulated configuration and register it
+    fn done(&mut self, py: Python) -> PyResult<()> {
+        if self.committed {
+            return Err(self.registration_failed(py, "builder already committed".to_string()));
+        }
+        let provider = self.build_provider(py)?;
+        let type_key = self.key.clone().with_name(self.name.clone());
+        let to_py_err =
+            |e: ContainerError| e.into_py_err(py, Some(self.key.py_object(py).bind(py)));
+        self.container
+            .register_provider(py, type_key.clone(), provider, None, None)
+            .map_err(to_py_err)?;
+        if !self.tags.is_empty() {
+            self.container
+                .add_tags(py, type_key, self.tags.clone())
+                .map_err(to_py_err)?;
+        }
+        self.committed = true;
+        Ok(())
+    }
+
+    fn __enter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __exit__(
+        &mut self,
+        py: Python,
+        exc_type: &Bound<'_, PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<bool> {
+        if exc_type.is_none() && !self.committed {
+            self.done(py)?;
+        }
+        Ok(false)
+    }
+}
+
+/// Collects the registrations a bundle declares for `Container.install()`
+///
+/// A bundle is any object with a `configure(binder)` method. Bindings are
+/// only applied once the whole bundle, including the bundles it installs,
+/// has been configured.
+#[pyclass]
+struct Binder {
+    entries: Vec<(TypeKey, Provider)>,
+    installed: Vec<Py<PyAny>>,
+}
+
+impl Binder {
+    fn bind(
+        &mut self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        source: BuilderSource,
+        scope: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+    ) -> PyResult<()> {
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        let provider = source
+            .provider(py, scope.map(parse_scope).transpose()?)
+            .map_err(|reason| {
+                ContainerError::ProviderRegistrationFailed {
+                    type_name: type_key.type_name(py),
+                    reason,
+                }
+                .into_py_err(py, Some(key))
+            })?;
+        self.entries.push((type_key, provider));
+        Ok(())
+    }
+
+    /// Run `bundle.configure(binder)` unless this bundle was already installed
+    fn configure(binder: &Bound<'_, Self>, bundle: &Bound<'_, PyAny>) -> PyResult<()> {
+        {
+            let mut this = binder.borrow_mut();
+            if this.installed.iter().any(|seen| seen.is(bundle)) {
+                return Ok(());
+            }
+            this.installed.push(bundle.clone().unbind());
+        }
+        let Ok(configure) = bundle.getattr("configure") else {
+            return Err(PyTypeError::new_err(format!(
+                "{} has no configure(binder) method",
+                bundle.repr()?
+            )));
+        };
+        configure.call1((binder,))?;
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl Binder {
+    /// Bind `key` to an auto-wired `cls` (singleton unless scoped)
+    #[pyo3(signature = (key, cls, scope = None, name = None))]
+    fn bind_class(
+        &mut self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        cls: Py<PyType>,
+        scope: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+    ) -> PyResult<()> {
+        self.bind(py, key, BuilderSource::Class(cls), scope, name)
+    }
+
+    /// Bind `key` to an existing object
+    #[pyo3(signature = (key, instance, name = None))]
+    fn bind_instance(
+        &mut self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        instance: Py<PyAny>,
+        name: Option<String>,
+    ) -> PyResult<()> {
+        self.bind(py, key, BuilderSource::Instance(instance), None, name)
+    }
+
+    /// Bind `key` to a zero-argument factory (singleton unless scoped)
+    #[pyo3(signature = (key, factory, scope = None, name = None))]
+    fn bind_factory(
+        &mut self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        scope: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+    ) -> PyResult<()> {
+        self.bind(py, key, BuilderSource::Factory(factory), scope, name)
+    }
+
+    /// Include another bundle's bindings; each bundle is configured once
+    fn install(slf: &Bound<'_, Self>, bundle: &Bound<'_, PyAny>) -> PyResult<()> {
+        Binder::configure(slf, bundle)
+    }
+}
+
+/// Disposal methods called without arguments, in order of preference;
+/// `__aexit__` and `__exit__` are the fallbacks
+const DISPOSERS: [&str; 3] = ["dispose", "aclose", "close"];
+
+/// Method called on every instance the container constructs
+const POST_INJECT_HOOK: &str = "__post_inject__";
+
+/// Attribute `@post_inject` sets on the methods it marks
+const POST_INJECT_MARKER: &str = "__dioxide_post_inject__";
+
+/// Tie a container to an ASGI application's lifespan
+///
+/// Returns an async context manager that warms every singleton on entry
+/// and closes the container on exit.
+#[pyfunction]
+fn lifespan_context(container: Py<Container>) -> Lifespan {
+    Lifespan { container }
+}
+
+/// Mark a method to be called once the container has constructed an
+/// instance, like `__post_inject__`
+#[pyfunction]
+fn post_inject(method: Bound<'_, PyAny>) -> PyResult<Bound<'_, PyAny>> {
+    method.setattr(POST_INJECT_MARKER, true)?;
+    Ok(method)
+}
+
+/// Attribute `@provider` stores its `ProviderSpec` under
+const PROVIDER_MARKER: &str = "__dioxide_provider__";
+
+/// Options declared by `@provider`, read by `Container.register_module()`
+#[pyclass(frozen)]
+struct ProviderSpec {
+    #[pyo3(get)]
+    key: Option<Py<PyAny>>,
+    #[pyo3(get)]
+    scope: Option<&'static str>,
+    #[pyo3(get)]
+    name: Option<String>,
+}
+
+/// Mark a class or zero-argument factory for `Container.register_module()`
+///
+/// Use bare (`@provider`) or with options (`@provider(key=Port, scope="factory",
+/// name="primary")`). Classes default to their own type as key; functions
+/// default to their return annotation. `name` registers a named binding.
+#[pyfunction]
+#[pyo3(signature = (target = None, *, key = None, scope = None, name = None))]
+fn provider(
+    py: Python,
+    target: Option<Bound<'_, PyAny>>,
+    key: Option<Py<PyAny>>,
+    scope: Option<Bound<'_, PyAny>>,
+    name: Option<String>,
+) -> PyResult<Py<PyAny>> {
+    let spec = Py::new(
+        py,
+        ProviderSpec {
+            key,
+            scope: scope.as_ref().map(parse_scope).transpose()?,
+            name,
+        },
+    )?;
+    if let Some(target) = target {
+        target.setattr(PROVIDER_MARKER, spec)?;
+        return Ok(target.unbind());
+    }
+
+    let mark = PyCFunction::new_closure(py, None, None, move |args, _kwargs| {
+        let target = args.get_item(0)?;
+        target.setattr(PROVIDER_MARKER, spec.clone_ref(args.py()))?;
+        Ok::<_, PyErr>(target.unbind())
+    })?;
+    Ok(mark.into_any().unbind())
+}
+
+/// Build the key and provider for one `@provider`-marked module member
+fn module_provider(
+    py: Python,
+    member: &Bound<'_, PyAny>,
+    spec: &ProviderSpec,
+) -> PyResult<(TypeKey, Provider)> {
+    let qualname = || {
+        member
+            .getattr("__qualname__")
+            .and_then(|name| name.extract::<String>())
+            .unwrap_or_else(|_| "<unknown>".to_string())
+    };
+    let failed = |type_name: String, reason: String| {
+        ContainerError::ProviderRegistrationFailed { type_name, reason }.into_py_err(py, None)
+    };
+
+    let (source, default_key) = match member.cast::<PyType>() {
+        Ok(cls) => {
+            let inspect = py.import("inspect")?;
+            if inspect.call_method1("isabstract", (cls,))?.is_truthy()? {
+                return Err(failed(
+                    qualname(),
+                    "abstract classes cannot be instantiated".to_string(),
+                ));
+            }
+            (
+                BuilderSource::Class(cls.clone().unbind()),
+                Some(cls.clone().into_any()),
+            )
+        }
+        Err(_) => {
+            let hints = py
+                .import("typing")?
+                .call_method1("get_type_hints", (member,))?;
+            (
+                BuilderSource::Factory(member.clone().unbind()),
+                hints.call_method1("get", ("return",))?.extract()?,
+            )
+        }
+    };
+
+    let key = match (&spec.key, default_key) {
+        (Some(key), _) => key.bind(py).clone(),
+        (None, Some(key)) if !key.is_none() => key,
+        _ => {
+            return Err(failed(
+                qualname(),
+                "factory needs key= or a return annotation".to_string(),
+            ))
+        }
+    };
+    let type_key = TypeKey::from_object(&key)?.with_name(spec.name.clone());
+    let provider = source
+        .provider(py, spec.scope)
+        .map_err(|reason| failed(type_key.type_name(py), reason))?;
+    Ok((type_key, provider))
+}
+
+/// Marker attributes `@service`/`@component` and `@adapter.for_()` set on classes
+const SCOPE_MARKER: &str = "__dioxide_scope__";
+const QUALIFIER_MARKER: &str = "__dioxide_qualifier__";
+const TAGS_MARKER: &str = "__dioxide_tags__";
+const PORT_MARKER: &str = "__dioxide_port__";
+const PROFILES_MARKER: &str = "__dioxide_profiles__";
+const MULTI_MARKER: &str = "__dioxide_multi__";
+const PRIORITY_MARKER: &str = "__dioxide_priority__";
+
+/// Mark a class as a component with a scope, a qualifier name and tags
+///
+/// Use bare (`@component`) or with options (`@component(scope="factory",
+/// name="primary", tags=["db"])`). `register_class()` and `scan()` apply
+/// the declared options instead of requiring them at registration.
+#[pyfunction]
+#[pyo3(signature = (target = None, *, scope = None, name = None, tags = None))]
+fn component(
+    py: Python,
+    target: Option<Bound<'_, PyType>>,
+    scope: Option<Bound<'_, PyAny>>,
+    name: Option<String>,
+    tags: Option<Vec<String>>,
+) -> PyResult<Py<PyAny>> {
+    let scope = match scope {
+        Some(scope) => {
+            parse_scope(&scope)?;
+            scope.unbind()
+        }
+        None => PyString::new(py, "singleton").into_any().unbind(),
+    };
+    let tags = tags.unwrap_or_default();
+    let mark = move |target: &Bound<'_, PyAny>| -> PyResult<()> {
+        let py = target.py();
+        target.setattr(SCOPE_MARKER, scope.clone_ref(py))?;
+        target.setattr(QUALIFIER_MARKER, name.clone())?;
+        target.setattr(TAGS_MARKER, PyTuple::new(py, &tags)?)?;
+        Ok(())
+    };
+    if let Some(target) = target {
+        mark(target.as_any())?;
+        return Ok(target.into_any().unbind());
+    }
+    let decorator = PyCFunction::new_closure(py, None, None, move |args, _kwargs| {
+        let target = args.get_item(0)?;
+        mark(&target)?;
+        Ok::<_, PyErr>(target.unbind())
+    })?;
+    Ok(decorator.into_any().unbind())
+}
+
+/// Options a class declares through its own marker attributes
+struct ComponentMarkers {
+    scope: Option<&'static str>,
+    qualifier: Option<String>,
+    tags: Vec<String>,
+}
+
+impl ComponentMarkers {
+    /// Read the markers in `cls.__dict__`, ignoring inherited ones
+    fn read(cls: &Bound<'_, PyType>) -> PyResult<Self> {
+        let own_attrs = cls.getattr("__dict__")?;
+        let marker = |name: &str| -> PyResult<Option<Bound<'_, PyAny>>> {
+            let value = own_attrs.call_method1("get", (name,))?;
+            Ok((!value.is_none()).then_some(value))
+        };
+        Ok(ComponentMarkers {
+            scope: marker(SCOPE_MARKER)?
+                .as_ref()
+                .map(parse_scope)
+                .transpose()?,
+            qualifier: marker(QUALIFIER_MARKER)?
+                .map(|name| name.extract())
+                .transpose()?,
+            tags: match marker(TAGS_MARKER)? {
+                Some(tags) => tags.extract()?,
+                None => Vec::new(),
+            },
+        })
+    }
+}
+
+/// A class `scan()` found, keyed by its port for adapters
+struct ScannedClass {
+    key: TypeKey,
+    provider: Provider,
+    multi: Option<i32>,
+    tags: Vec<String>,
+}
+
+/// Classify a class by the markers in its own `__dict__`
+///
+/// Inherited markers are ignored, so subclasses of decorated classes are
+/// not picked up. Returns `None` for undecorated classes and for classes
+/// whose profiles exclude `profile`.
+fn scanned_class(
+    py: Python,
+    cls: &Bound<'_, PyType>,
+    profile: Option<&str>,
+) -> PyResult<Option<ScannedClass>> {
+    let own_attrs = cls.getattr("__dict__")?;
+    let marker = |name: &str| -> PyResult<Option<Bound<'_, PyAny>>> {
+        let value = own_attrs.call_method1("get", (name,))?;
+        Ok((!value.is_none()).then_some(value))
+    };
+    let markers = ComponentMarkers::read(cls)?;
+    let Some(scope) = markers.scope else {
+        return Ok(None);
+    };
+
+    if let (Some(profile), Some(profiles)) = (profile, marker(PROFILES_MARKER)?) {
+        let mut active = false;
+        for tag in profiles.try_iter()? {
+            let tag: String = tag?.extract()?;
+            active |= tag == "*" || tag.eq_ignore_ascii_case(profile);
+        }
+        if !active {
+            return Ok(None);
+        }
+    }
+
+    let (key, multi) = match marker(PORT_MARKER)? {
+        Some(port) => {
+            let multi = match marker(MULTI_MARKER)? {
+                Some(multi) if multi.is_truthy()? => Some(match marker(PRIORITY_MARKER)? {
+                    Some(priority) => priority.extract()?,
+                    None => 0,
+                }),
+                _ => None,
+            };
+            (TypeKey::from_object(&port)?, multi)
+        }
+        None => (TypeKey::from_object(cls.as_any())?, None),
+    };
+    let key = key.with_name(markers.qualifier);
+    let provider = BuilderSource::Class(cls.clone().unbind())
+        .provider(py, Some(scope))
+        .map_err(|reason| {
+            ContainerError::ProviderRegistrationFailed {
+                type_name: key.type_name(py),
+                reason,
+            }
+            .into_py_err(py, None)
+        })?;
+    Ok(Some(ScannedClass {
+        key,
+        provider,
+        multi,
+        tags: markers.tags,
+    }))
+}
+
+/// Keys a wiring file binding may set
+const WIRING_FIELDS: [&str; 5] = ["interface", "implementation", "lifetime", "name", "profile"];
+
+/// Import the object at `path`, written `package.module:attr` or
+/// `package.module.attr`
+fn import_object<'py>(py: Python<'py>, path: &str) -> PyResult<Bound<'py, PyAny>> {
+    let (module, attrs) = match path.split_once(':') {
+        Some((module, attrs)) => (module, attrs),
+        None => path.rsplit_once('.').ok_or_else(|| {
+            PyValueError::new_err(format!("{path:?} is not a module:attribute path"))
+        })?,
+    };
+    let mut object = py.import(module)?.into_any();
+    for attr in attrs.split('.') {
+        object = object.getattr(attr)?;
+    }
+    Ok(object)
+}
+
+/// Read the bindings of a TOML or YAML wiring file
+///
+/// The file holds a `bindings` list; each binding maps an `interface`
+/// import path to an `implementation` (a class or a factory) with an
+/// optional `lifetime`, qualifier `name` and `profile`. Bindings for
+/// profiles other than the container's are skipped. Errors name the file
+/// and the offending entry.
+fn wiring_bindings(
+    py: Python,
+    container: &RustContainer,
+    file: &Bound<'_, PyAny>,
+) -> Result<Vec<(TypeKey, Provider)>, ContainerError> {
+    let file: String = py
+        .import("os")?
+        .call_method1("fspath", (file,))?
+        .extract()?;
+    let invalid = |entry: Option<String>, reason: String| ContainerError::InvalidWiring {
+        file: file.clone(),
+        entry,
+        reason,
+    };
+
+    let extension = file.rsplit_once('.').map(|(_, extension)| extension);
+    let document = match extension {
+        Some("toml") => {
+            let source = py
+                .import("io")?
+                .call_method1("open", (&file, "rb"))
+                .map_err(|err| invalid(None, err.to_string()))?;
+            let parsed = py.import("tomllib")?.call_method1("load", (&source,));
+            source.call_method0("close")?;
+            parsed.map_err(|err| invalid(None, err.value(py).to_string()))?
+        }
+        Some("yaml" | "yml") => {
+            let yaml = py.import("yaml").map_err(|_| {
+                invalid(
+                    None,
+                    "reading YAML wiring needs PyYAML installed".to_string(),
+                )
+            })?;
+            let source = py
+                .import("io")?
+                .call_method1("open", (&file, "r"))
+                .map_err(|err| invalid(None, err.to_string()))?;
+            let parsed = yaml.call_method1("safe_load", (&source,));
+            source.call_method0("close")?;
+            parsed.map_err(|err| invalid(None, err.value(py).to_string()))?
+        }
+        _ => {
+            return Err(invalid(
+                None,
+                "expected a .toml, .yaml or .yml file".to_string(),
+            ))
+        }
+    };
+
+    let bindings = match document.cast::<PyDict>() {
+        Ok(document) => document.get_item("bindings")?,
+        Err(_) => None,
+    };
+    let Some(bindings) = bindings.filter(|bindings| bindings.is_instance_of::<PyList>()) else {
+        return Err(invalid(None, "expected a list of bindings".to_string()));
+    };
+
+    let mut entries = Vec::new();
+    for (index, binding) in bindings.try_iter()?.enumerate() {
+        let binding = binding?;
+        let at = |field: Option<&str>| match field {
+            Some(field) => format!("bindings[{index}].{field}"),
+            None => format!("bindings[{index}]"),
+        };
+        let Ok(binding) = binding.cast::<PyDict>() else {
+            return Err(invalid(Some(at(None)), "expected a table".to_string()));
+        };
+        let text = |field: &str| -> Result<Option<String>, ContainerError> {
+            match binding.get_item(field)? {
+                None => Ok(None),
+                Some(value) => value
+                    .extract()
+                    .map(Some)
+                    .map_err(|_| invalid(Some(at(Some(field))), "expected a string".to_string())),
+            }
+        };
+        for field in binding.keys() {
+            let field: String = field.extract()?;
+            if !WIRING_FIELDS.contains(&field.as_str()) {
+                return Err(invalid(
+                    Some(at(Some(&field))),
+                    format!(
+                        "unknown field; expected one of {}",
+                        WIRING_FIELDS.join(", ")
+                    ),
+                ));
+            }
+        }
+        if !container.profile_matches(text("profile")?.as_deref()) {
+            continue;
+        }
+        let import = |field: &str| -> Result<Bound<'_, PyAny>, ContainerError> {
+            let Some(path) = text(field)? else {
+                return Err(invalid(Some(at(None)), format!("missing {field}")));
+            };
+            import_object(py, &path).map_err(|err| {
+                invalid(
+                    Some(at(Some(field))),
+                    format!("cannot import {path:?}: {}", err.value(py)),
+                )
+            })
+        };
+
+        let interface = import("interface")?;
+        let implementation = import("implementation")?;
+        let type_key = TypeKey::from_object(&interface)
+            .map_err(|err| invalid(Some(at(Some("interface"))), err.value(py).to_string()))?
+            .with_name(text("name")?);
+        let scope = match text("lifetime")? {
+            Some(lifetime) => Some(
+                parse_scope(PyString::new(py, &lifetime).as_any()).map_err(|err| {
+                    invalid(Some(at(Some("lifetime"))), err.value(py).to_string())
+                })?,
+            ),
+            None => None,
+        };
+        let source = match implementation.cast::<PyType>() {
+            Ok(cls) => BuilderSource::Class(cls.clone().unbind()),
+            Err(_) if implementation.is_callable() => {
+                BuilderSource::Factory(implementation.unbind())
+            }
+            Err(_) => {
+                return Err(invalid(
+                    Some(at(Some("implementation"))),
+                    "expected a class or a factory".to_string(),
+                ))
+            }
+        };
+        let provider = source
+            .provider(py, scope)
+            .map_err(|reason| invalid(Some(at(Some("lifetime"))), reason))?;
+        entries.push((type_key, provider));
+    }
+    Ok(entries)
+}
+
+/// Python-exposed Container class
+#[pyclass(name = "Container")]
+struct Container {
+    rust_core: RustContainer,
+}
+
+#[allow(non_local_definitions)]
+#[pymethods]
+impl Container {
+    /// `on_duplicate` (a `DuplicatePolicy` or its string value) sets what
+    /// registering an already-registered key does; it defaults to error.
+    /// `profile` makes registrations tagged for other profiles no-ops.
+    #[new]
+    #[pyo3(signature = (on_duplicate = None, profile = None, autowire = false))]
+    fn new(
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        profile: Option<&str>,
+        autowire: bool,
+    ) -> PyResult<Self> {
+        let rust_core = RustContainer::new()
+            .with_profile(profile)
+            .with_autowire(autowire);
+        if let Some(policy) = DuplicatePolicy::from_optional(on_duplicate)? {
+            rust_core.set_duplicate_policy(policy);
+        }
+        Ok(Container { rust_core })
+    }
+
+    /// Create a container wired from a TOML or YAML file, see `load_config()`
+    #[staticmethod]
+    #[pyo3(signature = (path, on_duplicate = None, profile = None, autowire = false))]
+    fn from_config(
+        py: Python,
+        path: &Bound<'_, PyAny>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        profile: Option<&str>,
+        autowire: bool,
+    ) -> PyResult<Self> {
+        let container = Container::new(on_duplicate, profile, autowire)?;
+        container.load_config(py, path)?;
+        Ok(container)
+    }
+
+    /// Apply the bindings of a TOML or YAML wiring file as one batch
+    ///
+    /// The file holds a `bindings` list whose entries map an `interface`
+    /// import path (`package.module:Name`) to an `implementation` class or
+    /// factory, with an optional `lifetime`, qualifier `name` and
+    /// `profile`. Bindings for other profiles than the container's are
+    /// skipped. Nothing is registered unless every binding is valid; a
+    /// `WiringError` names the offending entry. Returns the registered keys.
+    fn load_config(&self, py: Python, path: &Bound<'_, PyAny>) -> PyResult<Vec<Py<PyAny>>> {
+        let entries =
+            wiring_bindings(py, &self.rust_core, path).map_err(|e| e.into_py_err(py, None))?;
+        let keys = entries.iter().map(|(key, _)| key.py_object(py)).collect();
+        self.rust_core
+            .register_providers(py, entries)
+            .map_err(|e| e.into_py_err(py, None))?;
+        Ok(keys)
+    }
+
+    /// Policy applied to registrations that pass no `on_duplicate`
+    fn duplicate_policy(&self) -> DuplicatePolicy {
+        self.rust_core.duplicate_policy()
+    }
+
+    /// Active profile (lowercase), or None when every profile applies
+    fn profile(&self) -> Option<String> {
+        self.rust_core.profile().map(str::to_string)
+    }
+
+    /// Whether unregistered concrete classes are bound on first resolve
+    fn autowire(&self) -> bool {
+        self.rust_core.autowire()
+    }
+
+    /// Change the policy applied to registrations that pass no `on_duplicate`
+    fn set_duplicate_policy(&self, policy: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.rust_core
+            .set_duplicate_policy(DuplicatePolicy::from_object(policy)?);
+        Ok(())
+    }
+
+    /// Register an instance for a given type
+    ///
+    /// With `type_check=True` the instance must be an instance of the key type.
+    /// With `copy=True` the instance is a template and every resolve returns
+    /// an independent deep copy of it (prototype semantics).
+    ///
+    /// Like every `register_*` method, it takes `tags` and a `description`,
+    /// reported with the file and line of the call by `list_registrations()`.
+    #[pyo3(signature = (key, instance, type_check = false, copy = false, on_duplicate = None, name = None, profile = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_instance(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        instance: Py<PyAny>,
+        type_check: bool,
+        copy: bool,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        if type_check {
+            RustContainer::check_provider_type(py, &type_key, instance.bind(py), false)
+                .map_err(|e| e.into_py_err(py, Some(key)))?;
+        }
+        let result = if copy {
+            self.rust_core
+                .register_prototype(py, type_key.clone(), instance, on_duplicate, profile)
+        } else {
+            self.rust_core
+                .register_instance(py, type_key.clone(), instance, on_duplicate, profile)
+        };
+        result.map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register a class for a given type
+    ///
+    /// With `type_check=True` the class must be a subclass of the key type.
+    /// `lifetime` (a `Lifetime` or its string value) defaults to singleton;
+    /// transient classes are constructed on every resolve. `cache="weak"`
+    /// keeps only a weak reference to the singleton, so it is rebuilt once
+    /// Python has collected it. `strict=True` implies the type check and
+    /// also requires the class to implement the key's interface in full.
+    /// `before` and `after` list keys to construct this one ahead of or
+    /// behind in `build_order()`, disposing in the reverse order, even
+    /// without a constructor dependency between them.
+    ///
+    /// A class decorated with `@component` supplies the lifetime and name
+    /// it declares when they are not passed, and its tags are attached.
+    #[pyo3(signature = (key, class, type_check = false, lifetime = None, on_duplicate = None, name = None, profile = None, cache = None, strict = false, tags = None, description = None, before = None, after = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_class(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        class: &Bound<'_, PyType>,
+        type_check: bool,
+        lifetime: Option<&Bound<'_, PyAny>>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        cache: Option<&str>,
+        strict: bool,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+        before: Option<Vec<Bound<'_, PyAny>>>,
+        after: Option<Vec<Bound<'_, PyAny>>>,
+    ) -> PyResult<()> {
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let markers = ComponentMarkers::read(class)?;
+        let hint_keys = |keys: Option<Vec<Bound<'_, PyAny>>>| -> PyResult<Vec<TypeKey>> {
+            keys.into_iter()
+                .flatten()
+                .map(|key| TypeKey::from_object(&key))
+                .collect()
+        };
+        let (before, after) = (hint_keys(before)?, hint_keys(after)?);
+        let weak = parse_weak_cache(cache)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name.or(markers.qualifier));
+        let lifetime = match (lifetime, markers.scope) {
+            (Some(lifetime), _) => Lifetime::from_object(lifetime)?,
+            (None, None | Some("singleton")) => Lifetime::Singleton,
+            (None, Some("transient")) => Lifetime::Transient,
+            (None, Some("thread")) => Lifetime::Thread,
+            (None, Some(scope)) => {
+                return Err(ContainerError::ProviderRegistrationFailed {
+                    type_name: type_key.type_name(py),
+                    reason: format!("register_class() cannot use the {scope} scope"),
+                }
+                .into_py_err(py, Some(key)))
+            }
+        };
+        if strict {
+            RustContainer::check_interface(py, &type_key, class)
+                .map_err(|e| e.into_py_err(py, Some(key)))?;
+        } else if type_check {
+            RustContainer::check_provider_type(py, &type_key, class.as_any(), true)
+                .map_err(|e| e.into_py_err(py, Some(key)))?;
+        }
+        if weak && lifetime != Lifetime::Singleton {
+            return Err(ContainerError::ProviderRegistrationFailed {
+                type_name: type_key.type_name(py),
+                reason: "weak caching requires the singleton lifetime".to_string(),
+            }
+            .into_py_err(py, Some(key)));
+        }
+        let registered = if weak {
+            self.rust_core.register_weak_singleton(
+                py,
+                type_key.clone(),
+                class.clone().unbind().into_any(),
+                on_duplicate,
+                profile,
+            )
+        } else {
+            self.rust_core.register_class(
+                py,
+                type_key.clone(),
+                class.clone().unbind(),
+                lifetime,
+                on_duplicate,
+                profile,
+            )
+        };
+        registered.map_err(|e| e.into_py_err(py, Some(key)))?;
+        if !before.is_empty() || !after.is_empty() {
+            self.rust_core.add_ordering_hints(&type_key, before, after);
+        }
+        let tags = markers.tags.into_iter().chain(tags.into_iter().flatten());
+        self.annotate(py, key, &type_key, profile, tags.collect(), description)
+    }
+
+    /// Register a singleton factory function for a given type (caches result)
+    ///
+    /// `cache="weak"` caches the result through a weak reference instead.
+    ///
+    /// `args` and `kwargs` are bound to the factory and passed on every call.
+    #[pyo3(signature = (key, factory, on_duplicate = None, name = None, profile = None, cache = None, args = None, kwargs = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_singleton_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        cache: Option<&str>,
+        args: Option<Vec<Py<PyAny>>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        let factory = BoundFactory::wrap(py, factory, args, kwargs)?;
+        let result = if parse_weak_cache(cache)? {
+            self.rust_core.register_weak_singleton(
+                py,
+                type_key.clone(),
+                factory,
+                on_duplicate,
+                profile,
+            )
+        } else {
+            self.rust_core.register_singleton_factory(
+                py,
+                type_key.clone(),
+                factory,
+                on_duplicate,
+                profile,
+            )
+        };
+        result.map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register a transient factory function for a given type (creates new instance each time)
+    ///
+    /// `args` and `kwargs` are bound to the factory and passed on every call.
+    #[pyo3(signature = (key, factory, on_duplicate = None, name = None, profile = None, args = None, kwargs = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_transient_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        args: Option<Vec<Py<PyAny>>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        let factory = BoundFactory::wrap(py, factory, args, kwargs)?;
+        self.rust_core
+            .register_transient_factory(py, type_key.clone(), factory, on_duplicate, profile)
+            .map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register an environment variable read when `key` is resolved
+    ///
+    /// `cast` converts the raw string, with `bool` accepting words such as
+    /// "true"/"false" and "1"/"0". An unset variable resolves to `default`
+    /// when one is given, else to None unless `required`, else raises
+    /// `EnvironmentVariableError`. `cache=True` reads the variable once.
+    #[pyo3(signature = (key, variable, cast = None, required = true, default = None, cache = false, on_duplicate = None, name = None, profile = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_env(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        variable: &str,
+        cast: Option<Py<PyAny>>,
+        required: bool,
+        default: Option<Py<PyAny>>,
+        cache: bool,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        self.rust_core
+            .register_env(
+                py,
+                type_key.clone(),
+                variable,
+                cast,
+                required,
+                default,
+                cache,
+                on_duplicate,
+                profile,
+            )
+            .map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register a coroutine function resolved with `resolve_async()`
+    ///
+    /// `lifetime` defaults to singleton: the awaited result is cached and
+    /// later served by `resolve()` too. Transient async factories are awaited
+    /// on every `resolve_async()`.
+    #[pyo3(signature = (key, factory, lifetime = None, on_duplicate = None, name = None, profile = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_async_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        lifetime: Option<&Bound<'_, PyAny>>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let lifetime = match lifetime {
+            Some(lifetime) => Lifetime::from_object(lifetime)?,
+            None => Lifetime::Singleton,
+        };
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        self.rust_core
+            .register_async_factory(
+                py,
+                type_key.clone(),
+                factory,
+                lifetime,
+                on_duplicate,
+                profile,
+            )
+            .map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register a generator function as a factory with teardown
+    ///
+    /// The generator's first yield is the instance; the code after the
+    /// `yield` runs when the container is closed. `lifetime` defaults to
+    /// singleton; transient generators start (and tear down) once per resolve.
+    #[pyo3(signature = (key, factory, lifetime = None, on_duplicate = None, name = None, profile = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_generator_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        lifetime: Option<&Bound<'_, PyAny>>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let lifetime = match lifetime {
+            Some(lifetime) => Lifetime::from_object(lifetime)?,
+            None => Lifetime::Singleton,
+        };
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        self.rust_core
+            .register_generator_factory(
+                py,
+                type_key.clone(),
+                factory,
+                lifetime,
+                on_duplicate,
+                profile,
+            )
+            .map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register a factory whose result is cached once per tenant id
+    ///
+    /// `args` and `kwargs` are bound to the factory and passed on every call.
+    #[pyo3(signature = (key, factory, on_duplicate = None, name = None, profile = None, args = None, kwargs = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_tenant_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        args: Option<Vec<Py<PyAny>>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        let factory = BoundFactory::wrap(py, factory, args, kwargs)?;
+        self.rust_core
+            .register_tenant_factory(py, type_key.clone(), factory, on_duplicate, profile)
+            .map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register a factory whose result is cached once per OS thread
+    ///
+    /// Each thread gets its own instance (e.g. a non-thread-safe database
+    /// session); a thread's instances are dropped when it exits or calls
+    /// `release_thread_instances()`.
+    ///
+    /// `args` and `kwargs` are bound to the factory and passed on every call.
+    #[pyo3(signature = (key, factory, on_duplicate = None, name = None, profile = None, args = None, kwargs = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_thread_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        args: Option<Vec<Py<PyAny>>>,
+        kwargs: Option<&Bound<'_, PyDict>>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        let factory = BoundFactory::wrap(py, factory, args, kwargs)?;
+        self.rust_core
+            .register_thread_factory(py, type_key.clone(), factory, on_duplicate, profile)
+            .map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register a factory whose instances are pooled, at most `size` at once
+    ///
+    /// `resolve()` returns a `PooledInstance` handle; use it as a context
+    /// manager (or call `release()`) to return the instance. When every
+    /// instance is borrowed, resolving waits up to `timeout_ms` (forever when
+    /// None, not at all when 0) before raising `PoolExhaustedError`.
+    #[pyo3(signature = (key, factory, size, timeout_ms = None, on_duplicate = None, name = None, profile = None, tags = None, description = None))]
+    #[allow(clippy::too_many_arguments)]
+    fn register_pooled_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        size: usize,
+        timeout_ms: Option<u64>,
+        on_duplicate: Option<&Bound<'_, PyAny>>,
+        name: Option<String>,
+        profile: Option<&str>,
+        tags: Option<Vec<String>>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        let on_duplicate = DuplicatePolicy::from_optional(on_duplicate)?;
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        let timeout = timeout_ms.map(Duration::from_millis);
+        self.rust_core
+            .register_pooled_factory(
+                py,
+                type_key.clone(),
+                factory,
+                size,
+                timeout,
+                on_duplicate,
+                profile,
+            )
+            .map_err(|e| e.into_py_err(py, Some(key)))?;
+        self.annotate(
+            py,
+            key,
+            &type_key,
+            profile,
+            tags.unwrap_or_default(),
+            description,
+        )
+    }
+
+    /// Register every `@provider` defined in `module`, all or nothing
+    ///
+    /// Members imported from other modules are skipped. If any provider is
+    /// invalid (abstract, missing key, duplicate), none are registered.
+    /// Returns the registered keys in definition order.
+    fn register_module(
+        &self,
+        py: Python,
+        module: &Bound<'_, PyModule>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let module_name = module.name()?;
+        let mut entries = Vec::new();
+        for (_, member) in module.dict().iter() {
+            let Ok(own_attrs) = member.getattr("__dict__") else {
+                continue;
+            };
+            let marker = own_attrs.call_method1("get", (PROVIDER_MARKER,))?;
+            let Ok(spec) = marker.cast::<ProviderSpec>() else {
+                continue;
+            };
+            let defined_here = member
+                .getattr("__module__")
+                .is_ok_and(|name| name.eq(&module_name).unwrap_or(false));
+            if defined_here {
+                entries.push(module_provider(py, &member, spec.get())?);
+            }
+        }
+
+        let keys = entries.iter().map(|(key, _)| key.py_object(py)).collect();
+        self.rust_core
+            .register_providers(py, entries)
+            .map_err(|e| e.into_py_err(py, None))?;
+        Ok(keys)
+    }
+
+    /// Import `package` and its submodules and register their decorated classes
+    ///
+    /// Classes carrying `@service`/`@component` markers are registered under
+    /// themselves and `@adapter.for_()` classes under their port; multi
+    /// adapters contribute to the port's multi-binding. `profile` (the
+    /// container's profile when omitted) skips classes tagged for other
+    /// profiles. Keys that are already registered are left alone. Returns the
+    /// registered classes in discovery order.
+    #[pyo3(signature = (package, profile = None))]
+    fn scan(&self, py: Python, package: &str, profile: Option<&str>) -> PyResult<Vec<Py<PyType>>> {
+        let profile = profile.or(self.rust_core.profile());
+        let importlib = py.import("importlib")?;
+        let root = importlib.call_method1("import_module", (package,))?;
+        let mut modules = vec![root.clone()];
+        if let Ok(path) = root.getattr("__path__") {
+            let prefix = format!("{}.", root.getattr("__name__")?);
+            let walk = py
+                .import("pkgutil")?
+                .call_method1("walk_packages", (path, prefix))?;
+            for info in walk.try_iter()? {
+                let name = info?.getattr("name")?;
+                modules.push(importlib.call_method1("import_module", (name,))?);
+            }
+        }
+
+        let mut classes = Vec::new();
+        let mut entries = Vec::new();
+        let mut multi = Vec::new();
+        let mut tags = Vec::new();
+        for module in modules {
+            let module_name = module.getattr("__name__")?;
+            for (_, member) in module.getattr("__dict__")?.cast::<PyDict>()?.iter() {
+                let Ok(cls) = member.cast::<PyType>() else {
+                    continue;
+                };
+                let defined_here = cls
+                    .getattr("__module__")
+                    .is_ok_and(|name| name.eq(&module_name).unwrap_or(false));
+                if !defined_here {
+                    continue;
+                }
+                let Some(scanned) = scanned_class(py, cls, profile)? else {
+                    continue;
+                };
+                if !scanned.tags.is_empty() && scanned.multi.is_none() {
+                    tags.push((scanned.key.clone(), scanned.tags));
+                }
+                match scanned.multi {
+                    Some(priority) => multi.push((scanned.key, scanned.provider, priority)),
+                    None if self.rust_core.contains(&scanned.key) => continue,
+                    None => entries.push((scanned.key, scanned.provider)),
+                }
+                classes.push(cls.clone().unbind());
+            }
+        }
+
+        self.rust_core
+            .register_providers(py, entries)
+            .map_err(|e| e.into_py_err(py, None))?;
+        for (key, provider, priority) in multi {
+            self.rust_core
+                .add_multi_binding(py, key, provider, priority)
+                .map_err(|e| e.into_py_err(py, None))?;
+        }
+        for (key, tags) in tags {
+            self.rust_core
+                .add_tags(py, key, tags)
+                .map_err(|e| e.into_py_err(py, None))?;
+        }
+        Ok(classes)
+    }
+
+    /// Apply the registrations declared by `bundle.configure(binder)` as one batch
+    ///
+    /// Bundles may install other bundles through the binder; nothing is
+    /// registered unless every binding can be. Returns the registered keys
+    /// in declaration order.
+    fn install(&self, py: Python, bundle: &Bound<'_, PyAny>) -> PyResult<Vec<Py<PyAny>>> {
+        let binder = Bound::new(
+            py,
+            Binder {
+                entries: Vec::new(),
+                installed: Vec::new(),
+            },
+        )?;
+        Binder::configure(&binder, bundle)?;
+        let entries = std::mem::take(&mut binder.borrow_mut().entries);
+
+        let keys = entries.iter().map(|(key, _)| key.py_object(py)).collect();
+        self.rust_core
+            .register_providers(py, entries)
+            .map_err(|e| e.into_py_err(py, None))?;
+        Ok(keys)
+    }
+
+    /// Start a fluent registration for `key`, committed by `done()`
+    fn register(&self, key: &Bound<'_, PyAny>) -> PyResult<RegistrationBuilder> {
+        Ok(RegistrationBuilder {
+            container: self.rust_core.clone(),
+            key: TypeKey::from_object(key)?,
+            sources: Vec::new(),
+            scope: None,
+            name: None,
+            tags: Vec::new(),
+            committed: false,
+        })
+    }
+
+    /// Replace the factory of an existing factory registration (for module reloads)
+    fn rebind_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+    ) -> PyResult<()> {
+        let type_key = TypeKey::from_object(key)?;
+        self.rust_core
+            .rebind_factory(py, &type_key, factory)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
+
+    /// Contribute an instance to the multi-binding of `key`
+    ///
+    /// Contributions with a higher `priority` resolve first.
+    #[pyo3(signature = (key, instance, priority = 0))]
+    fn register_multi_instance(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        instance: Py<PyAny>,
+        priority: i32,
+    ) -> PyResult<()> {
+        let type_key = TypeKey::from_object(key)?;
+        self.rust_core
+            .add_multi_binding(py, type_key, Provider::Instance(instance), priority)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
+
+    /// Contribute a class to the multi-binding of `key`
+    ///
+    /// `lifetime` defaults to singleton, as for `register_class`.
+    #[pyo3(signature = (key, class, lifetime = None, priority = 0))]
+    fn register_multi_class(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        class: &Bound<'_, PyType>,
+        lifetime: Option<&Bound<'_, PyAny>>,
+        priority: i32,
+    ) -> PyResult<()> {
+        let lifetime = match lifetime {
+            Some(lifetime) => Lifetime::from_object(lifetime)?,
+            None => Lifetime::Singleton,
+        };
+        let type_key = TypeKey::from_object(key)?;
+        let provider = Provider::Class(class.clone().unbind(), lifetime);
+        self.rust_core
+            .add_multi_binding(py, type_key, provider, priority)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
+
+    /// Contribute a factory to the multi-binding of `key`
+    ///
+    /// `lifetime` defaults to singleton (the result is cached; through a
+    /// weakref with `cache="weak"`); transient factories run on every
+    /// `resolve_all`.
+    #[pyo3(signature = (key, factory, lifetime = None, priority = 0, cache = None))]
+    fn register_multi_factory(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        factory: Py<PyAny>,
+        lifetime: Option<&Bound<'_, PyAny>>,
+        priority: i32,
+        cache: Option<&str>,
+    ) -> PyResult<()> {
+        let lifetime = match lifetime {
+            Some(lifetime) => Lifetime::from_object(lifetime)?,
+            None => Lifetime::Singleton,
+        };
+        let type_key = TypeKey::from_object(key)?;
+        let weak = parse_weak_cache(cache)?;
+        if weak && lifetime != Lifetime::Singleton {
+            return Err(ContainerError::ProviderRegistrationFailed {
+                type_name: type_key.type_name(py),
+                reason: "weak caching requires the singleton lifetime".to_string(),
+            }
+            .into_py_err(py, Some(key)));
+        }
+        let provider = match lifetime {
+            Lifetime::Singleton if weak => Provider::WeakSingleton(factory),
+            Lifetime::Singleton => Provider::SingletonFactory(factory),
+            Lifetime::Transient => Provider::TransientFactory(factory),
+            Lifetime::Thread => Provider::ThreadFactory(factory),
+        };
+        self.rust_core
+            .add_multi_binding(py, type_key, provider, priority)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
+
+    /// Inject `provider` for `key` when auto-wiring `consumer`'s constructor
+    ///
+    /// A class is auto-wired once and shared by every `consumer`; anything
+    /// else is injected as is. Other consumers keep `key`'s own provider.
+    fn register_for_consumer(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        consumer: &Bound<'_, PyType>,
+        provider: Py<PyAny>,
+    ) -> PyResult<()> {
+        let type_key = TypeKey::from_object(key)?;
+        let consumer_key = TypeKey::new(consumer.clone().unbind());
+        self.rust_core
+            .register_for_consumer(py, type_key, consumer_key, provider)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
 
-        providers.insert(type_key, Provider::TransientFactory(factory));
-        Ok(())
+    /// Resolve a dependency, awaiting it if its provider is async
+    ///
+    /// Returns an awaitable; keys with synchronous providers are resolved
+    /// immediately and the awaitable just hands back the instance.
+    #[pyo3(signature = (key, tenant = None, name = None))]
+    fn resolve_async(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        tenant: Option<&str>,
+        name: Option<String>,
+    ) -> PyResult<AsyncResolve> {
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        self.rust_core
+            .start_async(py, &type_key, tenant)
+            .map_err(|e| e.into_py_err(py, Some(key)))
     }
 
-    /// Resolve a dependency by type
-    pub fn resolve(&self, py: Python, type_key: &TypeKey) -> Result<Py<PyAny>, ContainerError> {
-        // Check singleton cache first
-        {
-            let singletons = self.singletons.read().unwrap();
-            if let Some(instance) = singletons.get(type_key) {
-                return Ok(instance.clone_ref(py));
-            }
-        }
+    /// Resolve every provider contributed to `key`, highest priority first,
+    /// then in registration order
+    fn resolve_all(&self, py: Python, key: &Bound<'_, PyAny>) -> PyResult<Vec<Py<PyAny>>> {
+        let type_key = TypeKey::from_object(key)?;
+        self.rust_core
+            .resolve_all(py, &type_key)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
 
-        // Get provider
-        let provider = {
-            let providers = self.providers.read().unwrap();
-            providers.get(type_key).cloned().ok_or_else(|| {
-                ContainerError::DependencyNotRegistered {
-                    type_name: type_key.type_name(py),
-                }
-            })?
+    /// Resolve a dependency by type or enum member
+    ///
+    /// `tenant` selects the partition for per-tenant providers; `name`
+    /// selects a named binding instead of the unnamed one. `overrides`
+    /// passes explicit values for selected constructor (or factory)
+    /// parameters and always builds a fresh, uncached instance.
+    #[pyo3(signature = (key, tenant = None, name = None, overrides = None))]
+    fn resolve(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        tenant: Option<&str>,
+        name: Option<String>,
+        overrides: Option<&Bound<'_, PyDict>>,
+    ) -> PyResult<Py<PyAny>> {
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        let result = match overrides {
+            Some(overrides) => self
+                .rust_core
+                .resolve_with_overrides(py, &type_key, tenant, overrides),
+            None => self.rust_core.resolve_for_tenant(py, &type_key, tenant),
         };
+        result.map_err(|e| e.into_py_err(py, Some(key)))
+    }
 
-        // Create instance based on provider type
-        let instance = match provider {
-            Provider::Instance(obj) => {
-                // Instance providers are always singletons (pre-created)
-                obj.clone_ref(py)
-            }
-            Provider::Class(cls) => {
-                // Class providers create new instances each time (transient)
-                cls.call0(py)?
-            }
-            Provider::SingletonFactory(factory) => {
-                // Singleton factory - call once and cache result
-                let instance = factory.call0(py)?;
+    /// Resolve a dependency, or return None if nothing provides it
+    ///
+    /// Cheaper than catching `DependencyNotRegisteredError` when probing
+    /// for optional keys. Failures of a provided key are still raised.
+    #[pyo3(signature = (key, tenant = None, name = None))]
+    fn try_resolve(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        tenant: Option<&str>,
+        name: Option<String>,
+    ) -> PyResult<Option<Py<PyAny>>> {
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        self.rust_core
+            .try_resolve(py, &type_key, tenant)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
 
-                // Cache the factory result
-                let mut singletons = self.singletons.write().unwrap();
-                singletons.insert(type_key.clone(), instance.clone_ref(py));
+    /// Resolve several dependencies in one call, returning a tuple
+    ///
+    /// Each key resolves as `resolve(key, tenant=tenant)` would, in order;
+    /// the first failure is raised for the key that failed.
+    #[pyo3(signature = (keys, tenant = None))]
+    fn resolve_many<'py>(
+        &self,
+        py: Python<'py>,
+        keys: &Bound<'py, PyAny>,
+        tenant: Option<&str>,
+    ) -> PyResult<Bound<'py, PyTuple>> {
+        let keys = keys.try_iter()?.collect::<PyResult<Vec<_>>>()?;
+        let type_keys = keys
+            .iter()
+            .map(TypeKey::from_object)
+            .collect::<PyResult<Vec<_>>>()?;
+        let instances = self
+            .rust_core
+            .resolve_many(py, &type_keys, tenant)
+            .map_err(|(index, e)| e.into_py_err(py, Some(&keys[index])))?;
+        PyTuple::new(py, instances)
+    }
 
-                instance
-            }
-            Provider::TransientFactory(factory) => {
-                // Transient factory - create new instance each time (no caching)
-                factory.call0(py)?
-            }
-        };
+    /// Describe every binding as a dict, sorted by key name
+    fn list_registrations<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.rust_core.list_registrations(py)
+    }
 
-        Ok(instance)
+    /// Describe the binding of `key` like `list_registrations()`, or return
+    /// None if it is not registered here
+    #[pyo3(signature = (key, name = None))]
+    fn registration_info<'py>(
+        &self,
+        py: Python<'py>,
+        key: &Bound<'py, PyAny>,
+        name: Option<String>,
+    ) -> PyResult<Option<Bound<'py, PyDict>>> {
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        self.rust_core.registration_info(py, &type_key)
     }
 
-    /// Check if container is empty
-    pub fn is_empty(&self) -> bool {
-        self.providers.read().unwrap().is_empty()
+    /// Describe registrations and dependency edges as a JSON string
+    #[pyo3(signature = (indent=None))]
+    fn to_json(&self, py: Python, indent: Option<usize>) -> PyResult<String> {
+        self.rust_core
+            .to_json(py, indent)
+            .map_err(|e| e.into_py_err(py, None))
     }
 
-    /// Get count of registered providers
-    pub fn len(&self) -> usize {
-        self.providers.read().unwrap().len()
+    /// Render the dependency graph as a Graphviz DOT string
+    fn to_dot(&self, py: Python) -> PyResult<String> {
+        self.rust_core
+            .to_dot(py)
+            .map_err(|e| e.into_py_err(py, None))
     }
 
-    /// Clear the singleton instance cache (keep provider registrations)
-    pub fn reset(&self) {
-        self.singletons.write().unwrap().clear();
+    /// Check every registration statically, raising one `ValidationError`
+    fn validate(&self, py: Python) -> PyResult<()> {
+        self.rust_core
+            .validate(py)
+            .map_err(|e| e.into_py_err(py, None))
     }
 
-    /// Get list of all registered types
-    pub fn get_registered_types(&self, py: Python) -> Vec<Py<PyType>> {
-        let providers = self.providers.read().unwrap();
-        providers
-            .keys()
-            .map(|key| key.py_type.clone_ref(py))
-            .collect()
+    /// Construct every singleton now, in dependency order; returns the keys
+    fn instantiate_all(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        let keys = self
+            .rust_core
+            .instantiate_all(py)
+            .map_err(|e| e.into_py_err(py, None))?;
+        Ok(keys.iter().map(|key| key.py_object(py)).collect())
     }
 
-    /// Check if a type is registered
-    pub fn contains(&self, type_key: &TypeKey) -> bool {
-        let providers = self.providers.read().unwrap();
-        providers.contains_key(type_key)
+    /// The keys `instantiate_all()` would construct, in order
+    fn build_order(&self, py: Python) -> PyResult<Vec<Py<PyAny>>> {
+        let keys = self
+            .rust_core
+            .build_order(py)
+            .map_err(|e| e.into_py_err(py, None))?;
+        Ok(keys.iter().map(|key| key.py_object(py)).collect())
     }
-}
 
-impl Default for RustContainer {
-    fn default() -> Self {
-        Self::new()
+    /// Construct every singleton now, then await the async singleton
+    /// factories together
+    ///
+    /// The awaitable returns the warmed keys: those of `instantiate_all()`,
+    /// then the async singletons by type name. The first failure is raised
+    /// once every async factory has finished.
+    fn async_instantiate_all(&self, py: Python) -> PyResult<AsyncResolve> {
+        self.warm_async(py, py.None())
     }
-}
 
-/// Python-exposed Container class
-#[pyclass(name = "Container")]
-struct Container {
-    rust_core: RustContainer,
-}
+    /// Close the container like `close()`, awaiting async disposers on
+    /// the running event loop one at a time
+    ///
+    /// The awaitable returns one message per failed stop, disposal or
+    /// teardown.
+    fn async_dispose(&self, py: Python) -> PyResult<AsyncResolve> {
+        self.rust_core.close_async(py, false)
+    }
 
-#[allow(non_local_definitions)]
-#[pymethods]
-impl Container {
-    #[new]
-    fn new() -> Self {
-        Container {
-            rust_core: RustContainer::new(),
-        }
+    /// Report container readiness as a dict, without constructing anything
+    fn healthcheck<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.rust_core.healthcheck(py)
     }
 
-    /// Register an instance for a given type
-    fn register_instance(
+    /// List every registration that could produce the requested type
+    fn all_bindings<'py>(
+        &self,
+        py: Python<'py>,
+        py_type: &Bound<'py, PyType>,
+    ) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.rust_core.all_bindings(py, py_type)
+    }
+
+    /// Return cached instances for each key, erroring on any that are cold
+    #[pyo3(signature = (keys, tenant = None))]
+    fn resolve_cached_only(
         &self,
         py: Python,
-        py_type: &Bound<'_, PyType>,
-        instance: Py<PyAny>,
-    ) -> PyResult<()> {
-        let type_key = TypeKey::new(py_type.clone().unbind());
+        keys: Vec<Bound<'_, PyAny>>,
+        tenant: Option<&str>,
+    ) -> PyResult<Vec<Py<PyAny>>> {
+        let type_keys = keys
+            .iter()
+            .map(TypeKey::from_object)
+            .collect::<PyResult<Vec<_>>>()?;
         self.rust_core
-            .register_instance(py, type_key, instance)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyKeyError, _>(e.to_string()))
+            .resolve_cached_only(py, &type_keys, tenant)
+            .map_err(|e| e.into_py_err(py, None))
     }
 
-    /// Register a class for a given type
-    fn register_class(
+    /// Call `hook(requested_type, provider_kind, cache_hit, duration_ms)`
+    /// after every resolve; returns the hook so it can be used as a decorator
+    fn on_resolve<'py>(&self, hook: Bound<'py, PyAny>) -> Bound<'py, PyAny> {
+        self.rust_core.add_resolve_hook(hook.clone().unbind());
+        hook
+    }
+
+    /// Remove a hook added with `on_resolve()`; returns whether it was found
+    fn remove_resolve_hook(&self, hook: &Bound<'_, PyAny>) -> bool {
+        self.rust_core.remove_resolve_hook(hook)
+    }
+
+    /// Call `hook(key, provider_kind, lifetime)` before each registration; a
+    /// hook that raises rejects it. Returns the hook, for use as a decorator
+    fn on_register<'py>(&self, hook: Bound<'py, PyAny>) -> Bound<'py, PyAny> {
+        self.rust_core.add_register_hook(hook.clone().unbind());
+        hook
+    }
+
+    /// Remove a hook added with `on_register()`; returns whether it was found
+    fn remove_register_hook(&self, hook: &Bound<'_, PyAny>) -> bool {
+        self.rust_core.remove_register_hook(hook)
+    }
+
+    /// Add a decorator that wraps instances resolved for a type
+    fn add_decorator(
         &self,
         py: Python,
-        py_type: &Bound<'_, PyType>,
-        class: &Bound<'_, PyType>,
+        key: &Bound<'_, PyAny>,
+        decorator: Py<PyAny>,
     ) -> PyResult<()> {
-        let type_key = TypeKey::new(py_type.clone().unbind());
+        let type_key = TypeKey::from_object(key)?;
         self.rust_core
-            .register_class(py, type_key, class.clone().unbind())
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyKeyError, _>(e.to_string()))
+            .add_decorator(py, type_key, decorator)
+            .map_err(|e| e.into_py_err(py, Some(key)))
     }
 
-    /// Register a singleton factory function for a given type (caches result)
-    fn register_singleton_factory(
+    /// Add an interceptor for one type (`key`) or every type tagged `tag`
+    ///
+    /// `interceptor(instance)` returns the object to cache and hand out;
+    /// exactly one of `key` and `tag` must be given.
+    #[pyo3(signature = (interceptor, key = None, tag = None))]
+    fn add_interceptor(
         &self,
         py: Python,
-        py_type: &Bound<'_, PyType>,
-        factory: Py<PyAny>,
+        interceptor: Py<PyAny>,
+        key: Option<&Bound<'_, PyAny>>,
+        tag: Option<String>,
     ) -> PyResult<()> {
-        let type_key = TypeKey::new(py_type.clone().unbind());
+        match (key, tag) {
+            (Some(key), None) => self.add_decorator(py, key, interceptor),
+            (None, Some(tag)) => self
+                .rust_core
+                .add_interceptor(tag, interceptor)
+                .map_err(|e| e.into_py_err(py, None)),
+            _ => Err(PyTypeError::new_err(
+                "add_interceptor() takes exactly one of key and tag",
+            )),
+        }
+    }
+
+    /// Add configuration values for `Annotated[X, Config(name)]` parameters
+    ///
+    /// Nested mappings are also reachable by dotted name.
+    fn register_config(&self, py: Python, values: &Bound<'_, PyAny>) -> PyResult<()> {
         self.rust_core
-            .register_singleton_factory(py, type_key, factory)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyKeyError, _>(e.to_string()))
+            .register_config(values)
+            .map_err(|e| e.into_py_err(py, None))
     }
 
-    /// Register a transient factory function for a given type (creates new instance each time)
-    fn register_transient_factory(
+    /// Set a feature flag for `Annotated[X, Flag(name)]` parameters
+    ///
+    /// With `invalidate`, cached singletons built with the old value (and
+    /// their dependents) are rebuilt on next resolve.
+    #[pyo3(signature = (name, value, invalidate = false))]
+    fn set_flag(
         &self,
         py: Python,
-        py_type: &Bound<'_, PyType>,
-        factory: Py<PyAny>,
+        name: String,
+        value: Py<PyAny>,
+        invalidate: bool,
     ) -> PyResult<()> {
-        let type_key = TypeKey::new(py_type.clone().unbind());
         self.rust_core
-            .register_transient_factory(py, type_key, factory)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyKeyError, _>(e.to_string()))
+            .set_flag(py, name, value, invalidate)
+            .map_err(|e| e.into_py_err(py, None))
     }
 
-    /// Resolve a dependency by type
-    fn resolve(&self, py: Python, py_type: &Bound<'_, PyType>) -> PyResult<Py<PyAny>> {
-        let type_key = TypeKey::new(py_type.clone().unbind());
+    /// Attach tags to the registration of `key`
+    #[pyo3(signature = (key, *tags))]
+    fn tag(&self, py: Python, key: &Bound<'_, PyAny>, tags: Vec<String>) -> PyResult<()> {
+        let type_key = TypeKey::from_object(key)?;
+        self.rust_core
+            .add_tags(py, type_key, tags)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
+
+    /// Tags attached to the registration of `key`, sorted
+    fn tags(&self, key: &Bound<'_, PyAny>) -> PyResult<Vec<String>> {
+        Ok(self.rust_core.tags(&TypeKey::from_object(key)?))
+    }
+
+    /// Check if another container has the same registrations and provider kinds
+    fn is_equivalent_to(&self, other: PyRef<'_, Container>) -> bool {
+        self.rust_core.is_equivalent_to(&other.rust_core)
+    }
+
+    /// Copy another container's registrations into this one
+    ///
+    /// `on_conflict` is "error" (the default), "prefer_self" or "prefer_other".
+    #[pyo3(signature = (other, on_conflict = "error"))]
+    fn merge(&self, py: Python, other: PyRef<'_, Container>, on_conflict: &str) -> PyResult<()> {
+        let on_conflict = MergeConflict::from_value(on_conflict)?;
+        self.rust_core
+            .merge(py, &other.rust_core, on_conflict)
+            .map_err(|e| e.into_py_err(py, None))
+    }
+
+    /// Create an empty child container that falls back to this one
+    fn create_child(&self) -> Container {
+        Container {
+            rust_core: self.rust_core.create_child(),
+        }
+    }
+
+    /// Consult another container when a type is not registered here
+    fn add_fallback_container(&self, other: PyRef<'_, Container>) -> PyResult<()> {
+        self.rust_core
+            .add_fallback(other.rust_core.clone())
+            .map_err(|e| e.into_py_err(other.py(), None))
+    }
+
+    /// Resolve a dependency and return `(instance, info)` describing the call
+    ///
+    /// `info` holds `cache_hit`, `constructions`, `elapsed_ms` and `provider`.
+    #[pyo3(signature = (key, tenant = None))]
+    fn resolve_verbose<'py>(
+        &self,
+        py: Python<'py>,
+        key: &Bound<'py, PyAny>,
+        tenant: Option<&str>,
+    ) -> PyResult<(Py<PyAny>, Bound<'py, PyDict>)> {
+        let type_key = TypeKey::from_object(key)?;
+        let (instance, info) = self
+            .rust_core
+            .resolve_verbose(py, &type_key, tenant)
+            .map_err(|e| e.into_py_err(py, Some(key)))?;
+
+        let details = PyDict::new(py);
+        details.set_item("cache_hit", info.cache_hit)?;
+        details.set_item("constructions", info.constructions)?;
+        details.set_item("elapsed_ms", info.elapsed.as_secs_f64() * 1000.0)?;
+        details.set_item("provider", info.provider)?;
+        Ok((instance, details))
+    }
+
+    /// Resolve a dependency and check it is an instance of `expected_type`
+    ///
+    /// Raises TypeError when the resolved instance has the wrong type.
+    fn resolve_as(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        expected_type: &Bound<'_, PyType>,
+    ) -> PyResult<Py<PyAny>> {
+        let type_key = TypeKey::from_object(key)?;
         self.rust_core
-            .resolve(py, &type_key)
-            .map_err(|e| PyErr::new::<pyo3::exceptions::PyKeyError, _>(e.to_string()))
+            .resolve_as(py, &type_key, expected_type)
+            .map_err(|e| match e {
+                ContainerError::UnexpectedType { .. } => PyTypeError::new_err(e.to_string()),
+                _ => e.into_py_err(py, Some(key)),
+            })
     }
 
     /// Check if container is empty
@@ -378,21 +10574,477 @@ impl Container {
         self.rust_core.reset();
     }
 
-    /// Get list of all registered types for introspection
-    fn get_registered_types(&self, py: Python) -> Vec<Py<PyType>> {
+    /// Clear cached singleton and tenant instances, keeping every binding
+    fn clear_singletons(&self) {
+        self.rust_core.reset();
+    }
+
+    /// Drop the calling thread's thread-lifetime instances; returns how many
+    fn release_thread_instances(&self) -> usize {
+        self.rust_core.release_thread_instances()
+    }
+
+    /// Remove a binding and the instances it cached
+    #[pyo3(signature = (key, name = None))]
+    fn unregister(&self, py: Python, key: &Bound<'_, PyAny>, name: Option<String>) -> PyResult<()> {
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        self.rust_core
+            .unregister(py, &type_key)
+            .map_err(|e| e.into_py_err(py, Some(key)))
+    }
+
+    /// Remove every binding and cached instance
+    fn clear(&self, py: Python) -> PyResult<()> {
+        self.rust_core.clear().map_err(|e| e.into_py_err(py, None))
+    }
+
+    /// Dispose all singletons within a time budget and clear the cache
+    ///
+    /// Returns a message for every disposal that failed or overran its share.
+    fn shutdown(&self, py: Python, timeout_ms: u64) -> PyResult<Vec<String>> {
+        self.rust_core
+            .shutdown(py, Duration::from_millis(timeout_ms))
+    }
+
+    /// Stop, dispose and tear down everything the container created, once
+    ///
+    /// Afterwards every resolve raises `ContainerClosedError`. Returns a
+    /// message for every stop, disposal or teardown that failed.
+    fn close(&self, py: Python) -> PyResult<Vec<String>> {
+        self.rust_core.close(py)
+    }
+
+    /// Run the teardown of every generator factory, dependents first,
+    /// leaving the container open
+    ///
+    /// Returns a message for every teardown that failed.
+    fn close_generators(&self, py: Python) -> Vec<String> {
+        self.rust_core.close_generators(py)
+    }
+
+    /// Whether `close()` has closed the container
+    fn is_closed(&self) -> bool {
+        self.rust_core.is_closed()
+    }
+
+    /// Start components (`start()` or `__enter__()`) among cached singletons
+    ///
+    /// Until `stop()`, singletons created later are started as they are
+    /// cached.
+    fn start(&self, py: Python) -> PyResult<()> {
+        self.rust_core.start_lifecycle(py)
+    }
+
+    /// Stop started components in reverse order
+    ///
+    /// Returns a message for every component that failed to stop.
+    fn stop(&self, py: Python) -> Vec<String> {
+        self.rust_core.stop_lifecycle(py)
+    }
+
+    /// Start the lifecycle for an `async with` block
+    ///
+    /// Components with `astart()` or `__aenter__()` are awaited together;
+    /// the others start synchronously. If an async start fails, its error is
+    /// raised once every start has finished, and the components that did
+    /// start stay running until `aclose()`.
+    fn __aenter__(slf: Bound<'_, Self>) -> PyResult<AsyncResolve> {
+        let py = slf.py();
+        let container = slf.borrow().rust_core.clone();
+        let started = container.start_lifecycle_async(py)?;
+        if started.is_empty() {
+            return Ok(AsyncResolve::ready(slf.into_any().unbind()));
+        }
+        let (components, coroutines) = started.into_iter().unzip();
+        AsyncResolve::gather(
+            py,
+            coroutines,
+            AsyncFinish::Start {
+                container: Box::new(container),
+                components,
+                result: slf.into_any().unbind(),
+            },
+        )
+    }
+
+    /// Close the container like `aclose()`; failures are reported as
+    /// `RuntimeWarning`s
+    fn __aexit__(
+        &self,
+        py: Python,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<AsyncResolve> {
+        self.rust_core.close_async(py, true)
+    }
+
+    /// Close the container like `close()`, awaiting `astop()`,
+    /// `__aexit__()` and async disposers on the running event loop
+    ///
+    /// The awaitable returns a message for every stop, disposal or teardown
+    /// that failed.
+    fn aclose(&self, py: Python) -> PyResult<AsyncResolve> {
+        self.rust_core.close_async(py, false)
+    }
+
+    /// Start the lifecycle for the duration of a `with` block
+    fn __enter__(slf: PyRef<'_, Self>) -> PyResult<PyRef<'_, Self>> {
+        slf.rust_core.start_lifecycle(slf.py())?;
+        Ok(slf)
+    }
+
+    /// Close the container like `close()`
+    ///
+    /// Failures are reported as `RuntimeWarning`s so they never mask an
+    /// exception raised inside the `with` block.
+    fn __exit__(
+        &self,
+        py: Python,
+        _exc_type: Py<PyAny>,
+        _exc_value: Py<PyAny>,
+        _traceback: Py<PyAny>,
+    ) -> PyResult<bool> {
+        let reports = self.rust_core.close(py)?;
+        warn_reports(py, reports)?;
+        Ok(false)
+    }
+
+    /// Exclude transients of `key` from tenant disposal tracking
+    fn mark_unmanaged(&self, key: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.rust_core.mark_unmanaged(TypeKey::from_object(key)?);
+        Ok(())
+    }
+
+    /// Dispose one tenant's instances within a time budget
+    fn dispose_tenant(&self, py: Python, tenant: &str, timeout_ms: u64) -> PyResult<Vec<String>> {
+        self.rust_core
+            .dispose_tenant(py, tenant, Duration::from_millis(timeout_ms))
+    }
+
+    /// Seal the registry for a lock-free resolve path; further registration fails
+    fn freeze(&self, py: Python) {
+        self.rust_core.freeze(py);
+    }
+
+    /// Whether `freeze()` has sealed the registry
+    fn is_frozen(&self) -> bool {
+        self.rust_core.is_frozen()
+    }
+
+    /// Stop singleton factories from running; returns a releasable marker
+    fn freeze_singletons(&self) -> SingletonFreeze {
+        self.rust_core.set_singletons_frozen(true);
+        SingletonFreeze {
+            container: self.rust_core.clone(),
+        }
+    }
+
+    /// Isolate singleton caching under `name` for the duration of a `with` block
+    fn cache_namespace(&self, name: String) -> CacheNamespace {
+        CacheNamespace {
+            container: self.rust_core.clone(),
+            name,
+            tokens: Vec::new(),
+        }
+    }
+
+    /// Bind `key` to `instance` for the duration of a `with` block
+    ///
+    /// The key's cached singleton is evicted on entry; the original provider
+    /// and cached singleton are restored on exit. `__enter__` returns
+    /// `instance`.
+    #[pyo3(signature = (key, instance, name = None))]
+    fn r#override(
+        &self,
+        key: &Bound<'_, PyAny>,
+        instance: Py<PyAny>,
+        name: Option<String>,
+    ) -> PyResult<ProviderOverride> {
+        Ok(ProviderOverride {
+            container: self.rust_core.clone(),
+            key: TypeKey::from_object(key)?.with_name(name),
+            instance,
+            active: None,
+        })
+    }
+
+    /// Capture the current warm singleton cache for forked workers
+    fn snapshot_for_fork(&self, py: Python) -> ForkSnapshot {
+        ForkSnapshot {
+            entries: self.rust_core.snapshot_singletons(py),
+        }
+    }
+
+    /// Seed the singleton cache from a fork snapshot
+    fn restore_fork_snapshot(&self, py: Python, snapshot: &ForkSnapshot) -> PyResult<()> {
+        self.rust_core
+            .adopt_singletons(py, &snapshot.entries)
+            .map_err(|e| e.into_py_err(py, None))
+    }
+
+    /// Capture the registry and singleton cache for a later `restore()`
+    fn snapshot(&self, py: Python) -> ContainerSnapshot {
+        ContainerSnapshot {
+            snapshot: self.rust_core.snapshot(py),
+        }
+    }
+
+    /// Put back the registry and singleton cache captured by `snapshot()`
+    fn restore(&self, py: Python, snapshot: &ContainerSnapshot) -> PyResult<()> {
+        self.rust_core
+            .restore(py, &snapshot.snapshot)
+            .map_err(|e| e.into_py_err(py, None))
+    }
+
+    /// Names of singletons in the order they were constructed
+    fn construction_log(&self) -> Vec<String> {
+        self.rust_core.construction_log()
+    }
+
+    /// Forget the recorded construction order
+    fn clear_construction_log(&self) {
+        self.rust_core.clear_construction_log();
+    }
+
+    /// Count transient constructions per key over a sliding window
+    #[pyo3(signature = (window_ms = 1000))]
+    fn enable_transient_telemetry(&self, window_ms: u64) -> PyResult<()> {
+        if window_ms == 0 {
+            return Err(PyErr::new::<pyo3::exceptions::PyValueError, _>(
+                "window_ms must be positive",
+            ));
+        }
+        self.rust_core
+            .enable_transient_telemetry(Duration::from_millis(window_ms));
+        Ok(())
+    }
+
+    /// Stop counting transient constructions
+    fn disable_transient_telemetry(&self) {
+        self.rust_core.disable_transient_telemetry();
+    }
+
+    /// Resolution, construction concurrency and cache/registry counters as a dict
+    ///
+    /// Includes `transient_allocation_rates` while transient telemetry is enabled.
+    fn stats<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let stats = self.rust_core.stats();
+        let instances_created = PyDict::new(py);
+        for (kind, count) in stats.instances_created {
+            instances_created.set_item(kind, count)?;
+        }
+        let dict = PyDict::new(py);
+        dict.set_item("resolutions", stats.resolutions)?;
+        dict.set_item("cache_hits", stats.cache_hits)?;
+        dict.set_item("cache_misses", stats.cache_misses)?;
+        dict.set_item("instances_created", instances_created)?;
+        dict.set_item("cache_size", stats.cache_size)?;
+        dict.set_item("registrations", stats.registrations)?;
+        dict.set_item(
+            "max_concurrent_constructions",
+            stats.max_concurrent_constructions,
+        )?;
+        dict.set_item("single_flight_waits", stats.single_flight_waits)?;
+        if let Some(rates) = stats.transient_allocation_rates {
+            let by_key = PyDict::new(py);
+            for (key, rate) in rates {
+                by_key.set_item(key.py_object(py), rate)?;
+            }
+            dict.set_item("transient_allocation_rates", by_key)?;
+        }
+        Ok(dict)
+    }
+
+    /// Zero the counters reported by `stats()`
+    fn reset_stats(&self) {
+        self.rust_core.reset_stats();
+    }
+
+    /// Transient constructions per second within the telemetry window, by key
+    fn transient_allocation_rates<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let rates = PyDict::new(py);
+        for (key, rate) in self.rust_core.transient_allocation_rates() {
+            rates.set_item(key.py_object(py), rate)?;
+        }
+        Ok(rates)
+    }
+
+    /// Get list of all registered types (and enum members) for introspection
+    fn get_registered_types(&self, py: Python) -> Vec<Py<PyAny>> {
         self.rust_core.get_registered_types(py)
     }
 
-    /// Check if a type is registered
-    fn contains(&self, py_type: &Bound<'_, PyType>) -> bool {
-        let type_key = TypeKey::new(py_type.clone().unbind());
-        self.rust_core.contains(&type_key)
+    /// Report registrations that shadow a more specific registration
+    fn check_shadowing(&self, py: Python) -> Vec<String> {
+        self.rust_core.check_shadowing(py)
+    }
+
+    /// Report singletons held under more than one key
+    ///
+    /// Returns one dict per shared object, with `instance` and its `keys`.
+    fn duplicate_instances<'py>(&self, py: Python<'py>) -> PyResult<Vec<Bound<'py, PyDict>>> {
+        self.rust_core
+            .duplicate_instances(py)
+            .into_iter()
+            .map(|(instance, keys)| {
+                let group = PyDict::new(py);
+                group.set_item("instance", instance)?;
+                group.set_item(
+                    "keys",
+                    keys.iter().map(|key| key.py_object(py)).collect::<Vec<_>>(),
+                )?;
+                Ok(group)
+            })
+            .collect()
+    }
+
+    /// Check if a type (or enum member) is registered, optionally by name
+    #[pyo3(signature = (key, name = None))]
+    fn contains(&self, key: &Bound<'_, PyAny>, name: Option<String>) -> PyResult<bool> {
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        Ok(self.rust_core.contains(&type_key))
+    }
+
+    /// Check if a type (or enum member) is registered here or in a
+    /// fallback container, optionally by name
+    #[pyo3(signature = (key, name = None))]
+    fn is_registered(&self, key: &Bound<'_, PyAny>, name: Option<String>) -> PyResult<bool> {
+        let type_key = TypeKey::from_object(key)?.with_name(name);
+        Ok(self.rust_core.is_registered(&type_key))
+    }
+
+    /// `key in container`: whether the unnamed binding of `key` is
+    /// registered here or in a fallback; False for objects that cannot be keys
+    fn __contains__(&self, key: &Bound<'_, PyAny>) -> bool {
+        TypeKey::from_object(key).is_ok_and(|type_key| self.rust_core.is_registered(&type_key))
+    }
+}
+
+impl Container {
+    /// Attach the `tags` and `description` given to a `register_*` call,
+    /// unless the registration was skipped for another profile
+    fn annotate(
+        &self,
+        py: Python,
+        key: &Bound<'_, PyAny>,
+        type_key: &TypeKey,
+        profile: Option<&str>,
+        tags: Vec<String>,
+        description: Option<String>,
+    ) -> PyResult<()> {
+        if !self.rust_core.profile_matches(profile) {
+            return Ok(());
+        }
+        if !tags.is_empty() {
+            self.rust_core
+                .add_tags(py, type_key.clone(), tags)
+                .map_err(|e| e.into_py_err(py, Some(key)))?;
+        }
+        if let Some(description) = description {
+            self.rust_core.describe(type_key, description);
+        }
+        Ok(())
+    }
+
+    /// Stop the lifecycle asynchronously; shared by `__aexit__` and `aclose()`
+    /// Warm every singleton for `async_instantiate_all()`, finishing with
+    /// `result` (None for the warmed keys)
+    fn warm_async(&self, py: Python, result: Py<PyAny>) -> PyResult<AsyncResolve> {
+        let mut keys = self
+            .rust_core
+            .instantiate_all(py)
+            .map_err(|e| e.into_py_err(py, None))?;
+        let started = self
+            .rust_core
+            .start_async_singletons(py)
+            .map_err(|e| e.into_py_err(py, None))?;
+        let mut awaitables = Vec::with_capacity(started.len());
+        for (key, resolve) in started {
+            keys.push(key);
+            awaitables.push(Py::new(py, resolve)?.into_any());
+        }
+        let result = match result.is_none(py) {
+            true => PyList::new(py, keys.iter().map(|key| key.py_object(py)))?
+                .into_any()
+                .unbind(),
+            false => result,
+        };
+        if awaitables.is_empty() {
+            return Ok(AsyncResolve::ready(result));
+        }
+        AsyncResolve::gather(py, awaitables, AsyncFinish::Warm { result })
     }
 }
 
+/// Error of this interpreter's `exception` type carrying `message`
+///
+/// The exception types live in the module's state, so they are looked up
+/// through the module object the current interpreter imported.
+fn module_error(py: Python, exception: &str, message: String) -> PyErr {
+    let exception = py
+        .import("dioxide._dioxide_core")
+        .and_then(|module| module.getattr(exception))
+        .and_then(|exception| Ok(exception.cast_into::<PyType>()?));
+    match exception {
+        Ok(exception) => PyErr::from_type(exception, message),
+        Err(err) => err,
+    }
+}
+
+/// Create this module instance's exception types
+fn add_exceptions(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    for (name, base, doc) in EXCEPTIONS {
+        let base = match base {
+            "KeyError" => py.get_type::<PyKeyError>(),
+            base => m.getattr(base)?.cast_into::<PyType>()?,
+        };
+        let qualified = CString::new(format!("_dioxide_core.{name}"))?;
+        let doc = CString::new(doc)?;
+        let exception = PyErr::new_type(py, &qualified, Some(&doc), Some(&base), None)?;
+        m.add(name, exception)?;
+    }
+    Ok(())
+}
+
 /// Rust-backed dependency injection core
-#[pymodule]
+///
+/// Initialized per module instance (multi-phase init), so every interpreter
+/// that imports it, a subinterpreter included, gets its own module with its
+/// own exception types, and containers built in one interpreter are never
+/// seen by another. The class types are PyO3's and shared by the process,
+/// which limits the module to interpreters that share the main GIL: Python
+/// refuses to load it in one with its own GIL. Containers keep no Python
+/// state outside their own instances apart from the per-thread failure
+/// path, which is taken when its error is raised.
+#[pymodule(gil_used = false)]
 fn _dioxide_core(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Container>()?;
+    m.add_class::<DoNotCache>()?;
+    m.add_class::<BoundFactory>()?;
+    m.add_class::<SingletonFreeze>()?;
+    m.add_class::<ForkSnapshot>()?;
+    m.add_class::<ContainerSnapshot>()?;
+    m.add_class::<CacheNamespace>()?;
+    m.add_class::<ProviderOverride>()?;
+    m.add_class::<PooledInstance>()?;
+    m.add_class::<Lazy>()?;
+    m.add_class::<ProviderSpec>()?;
+    m.add_class::<Lifetime>()?;
+    m.add_class::<DuplicatePolicy>()?;
+    m.add_class::<AsyncResolve>()?;
+    m.add_function(wrap_pyfunction!(provider, m)?)?;
+    m.add_function(wrap_pyfunction!(post_inject, m)?)?;
+    m.add_function(wrap_pyfunction!(component, m)?)?;
+    m.add_function(wrap_pyfunction!(lifespan_context, m)?)?;
+    m.add_class::<Lifespan>()?;
+    m.add_class::<RegistrationBuilder>()?;
+    m.add_class::<Binder>()?;
+    m.add_class::<ConfigRef>()?;
+    m.add_class::<FlagRef>()?;
+    m.add_class::<DefaultRef>()?;
+    m.add_class::<Resolver>()?;
+    add_exceptions(m)?;
     Ok(())
 }
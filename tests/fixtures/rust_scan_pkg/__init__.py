@@ -0,0 +1 @@
+"""Package scanned by the Rust container's scan()."""
@@ -0,0 +1,20 @@
+"""Profile-specific adapters of the scanned package."""
+
+from dioxide import Profile, adapter
+from tests.fixtures.rust_scan_pkg.services import Clock, Mailer
+
+
+@adapter.for_(Mailer, profile=Profile.PRODUCTION)
+class SmtpMailer:
+    def send(self, to: str) -> str:
+        return f'smtp:{to}'
+
+
+@adapter.for_(Mailer, profile=Profile.TEST)
+class FakeMailer:
+    def send(self, to: str) -> str:
+        return f'fake:{to}'
+
+
+# Re-exported, so only registered from its own module
+ReexportedClock = Clock
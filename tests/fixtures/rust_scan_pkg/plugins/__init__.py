@@ -0,0 +1 @@
+"""Multi-binding plugins in a sub-package."""
@@ -0,0 +1,21 @@
+"""Exporter plugins contributed to one port."""
+
+from typing import Protocol
+
+from dioxide import adapter
+
+
+class Exporter(Protocol):
+    def export(self) -> str: ...
+
+
+@adapter.for_(Exporter, multi=True, priority=1)
+class CsvExporter:
+    def export(self) -> str:
+        return 'csv'
+
+
+@adapter.for_(Exporter, multi=True, priority=5)
+class JsonExporter:
+    def export(self) -> str:
+        return 'json'
@@ -0,0 +1,30 @@
+"""Services and ports of the scanned package."""
+
+from typing import Protocol
+
+from dioxide import Scope, service
+
+
+class Mailer(Protocol):
+    def send(self, to: str) -> str: ...
+
+
+@service
+class Clock:
+    pass
+
+
+@service(scope=Scope.FACTORY)
+class RequestId:
+    pass
+
+
+@service
+class Notifier:
+    def __init__(self, mailer: Mailer, clock: Clock) -> None:
+        self.mailer = mailer
+        self.clock = clock
+
+
+class SubClock(Clock):
+    """Inherits the markers of Clock without being decorated itself."""
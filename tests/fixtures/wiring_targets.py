@@ -0,0 +1,28 @@
+"""Interfaces and implementations referenced by wiring file tests."""
+
+from typing import Protocol
+
+
+class Mailer(Protocol):
+    def send(self, to: str) -> str: ...
+
+
+class SmtpMailer:
+    def send(self, to: str) -> str:
+        return f'smtp:{to}'
+
+
+class FakeMailer:
+    def send(self, to: str) -> str:
+        return f'fake:{to}'
+
+
+class Clock:
+    pass
+
+
+def make_clock() -> Clock:
+    return Clock()
+
+
+NOT_CALLABLE = 42
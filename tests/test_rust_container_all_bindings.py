@@ -0,0 +1,88 @@
+"""Tests for listing every binding that could satisfy a type."""
+
+from abc import ABC, abstractmethod
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Notifier(ABC):
+    @abstractmethod
+    def send(self, message: str) -> None: ...
+
+
+class EmailNotifier(Notifier):
+    def send(self, message: str) -> None:
+        pass
+
+
+class SmsNotifier(Notifier):
+    def send(self, message: str) -> None:
+        pass
+
+
+class Clock:
+    pass
+
+
+class DescribeAllBindings:
+    """all_bindings() lists candidate registrations without constructing."""
+
+    def it_lists_the_interface_and_all_implementations(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, EmailNotifier)
+        container.register_class(SmsNotifier, SmsNotifier)
+        container.register_singleton_factory(EmailNotifier, EmailNotifier)
+        container.register_class(Clock, Clock)
+
+        bindings = container.all_bindings(Notifier)
+
+        assert [binding['key'] for binding in bindings] == [Notifier, EmailNotifier, SmsNotifier]
+
+    def it_describes_each_binding(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, EmailNotifier)
+        container.register_singleton_factory(SmsNotifier, SmsNotifier)
+        container.resolve(SmsNotifier)
+
+        interface_binding, sms_binding = container.all_bindings(Notifier)
+
+        assert interface_binding == {
+            'key': Notifier,
+            'provider': 'class',
+            'implementation': EmailNotifier,
+            'cached': False,
+        }
+        assert sms_binding['provider'] == 'singleton_factory'
+        assert sms_binding['implementation'] is SmsNotifier
+        assert sms_binding['cached'] is True
+
+    def it_matches_providers_whose_implementation_is_a_subtype(self) -> None:
+        container = RustContainer()
+        sms = SmsNotifier()
+        container.register_instance(object, sms)
+
+        bindings = container.all_bindings(Notifier)
+
+        assert len(bindings) == 1
+        assert bindings[0]['key'] is object
+        assert bindings[0]['implementation'] is SmsNotifier
+
+    def it_does_not_construct_anything(self) -> None:
+        container = RustContainer()
+        calls: list[str] = []
+
+        def make_notifier() -> Notifier:
+            calls.append('built')
+            return EmailNotifier()
+
+        container.register_singleton_factory(Notifier, make_notifier)
+
+        container.all_bindings(Notifier)
+
+        assert calls == []
+
+    def it_returns_an_empty_list_for_unknown_types(self) -> None:
+        container = RustContainer()
+        container.register_class(Clock, Clock)
+
+        assert container.all_bindings(Notifier) == []
@@ -0,0 +1,133 @@
+"""Tests for async factory providers and resolve_async()."""
+
+import asyncio
+
+import pytest
+
+from dioxide._dioxide_core import AsyncProviderError, SingletonsFrozenError
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Pool:
+    pass
+
+
+class Client:
+    pass
+
+
+class Config:
+    pass
+
+
+async def make_pool() -> Pool:
+    await asyncio.sleep(0)
+    return Pool()
+
+
+class DescribeAsyncFactories:
+    """Coroutine factories are awaited by resolve_async()."""
+
+    @pytest.mark.asyncio
+    async def it_awaits_the_factory(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+
+        pool = await container.resolve_async(Pool)
+
+        assert isinstance(pool, Pool)
+
+    @pytest.mark.asyncio
+    async def it_caches_singletons(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+
+        pool = await container.resolve_async(Pool)
+
+        assert await container.resolve_async(Pool) is pool
+        assert container.resolve(Pool) is pool
+
+    @pytest.mark.asyncio
+    async def it_awaits_transient_factories_every_time(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool, lifetime='transient')
+
+        assert await container.resolve_async(Pool) is not await container.resolve_async(Pool)
+
+    @pytest.mark.asyncio
+    async def it_keeps_one_singleton_under_concurrent_resolves(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+
+        first, second = await asyncio.gather(container.resolve_async(Pool), container.resolve_async(Pool))
+
+        assert first is second
+
+    @pytest.mark.asyncio
+    async def it_resolves_synchronous_providers(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+
+        assert await container.resolve_async(Config) is container.resolve(Config)
+
+    @pytest.mark.asyncio
+    async def it_applies_decorators(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+        container.add_decorator(Pool, lambda pool: (pool,))
+
+        wrapped = await container.resolve_async(Pool)
+
+        assert isinstance(wrapped, tuple)
+        assert isinstance(wrapped[0], Pool)
+
+    @pytest.mark.asyncio
+    async def it_propagates_factory_errors_without_caching(self) -> None:
+        attempts = []
+
+        async def flaky() -> Client:
+            attempts.append(1)
+            if len(attempts) == 1:
+                raise ConnectionError('unreachable')
+            return Client()
+
+        container = RustContainer()
+        container.register_async_factory(Client, flaky)
+
+        with pytest.raises(ConnectionError, match='unreachable'):
+            await container.resolve_async(Client)
+
+        assert isinstance(await container.resolve_async(Client), Client)
+
+    @pytest.mark.asyncio
+    async def it_cannot_be_awaited_twice(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+        awaitable = container.resolve_async(Pool)
+        await awaitable
+
+        with pytest.raises(RuntimeError, match='already awaited'):
+            await awaitable
+
+    @pytest.mark.asyncio
+    async def it_respects_frozen_singletons(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+
+        with container.freeze_singletons(), pytest.raises(SingletonsFrozenError):
+            await container.resolve_async(Pool)
+
+    def it_rejects_resolving_an_uncached_async_provider_synchronously(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+
+        with pytest.raises(AsyncProviderError, match='use resolve_async'):
+            container.resolve(Pool)
+
+    def it_reports_the_provider_kind(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+        container.register_async_factory(Client, Client, lifetime='transient')
+
+        assert container.all_bindings(Pool)[0]['provider'] == 'async_factory'
+        assert container.all_bindings(Client)[0]['provider'] == 'transient_async_factory'
@@ -0,0 +1,170 @@
+"""Tests for the async container lifecycle (async with / aclose())."""
+
+import asyncio
+from collections.abc import AsyncGenerator, Generator
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Events(list):
+    pass
+
+
+class Broker:
+    def __init__(self, events: Events) -> None:
+        self.events = events
+
+    async def astart(self) -> None:
+        await asyncio.sleep(0)
+        self.events.append('broker started')
+
+    async def astop(self) -> None:
+        await asyncio.sleep(0)
+        self.events.append('broker stopped')
+
+
+class Pool:
+    def __init__(self, events: Events) -> None:
+        self.events = events
+
+    async def __aenter__(self) -> 'Pool':
+        self.events.append('pool entered')
+        return self
+
+    async def __aexit__(self, *exc_info: object) -> None:
+        self.events.append('pool exited')
+
+
+class Cache:
+    def __init__(self, events: Events) -> None:
+        self.events = events
+
+    def start(self) -> None:
+        self.events.append('cache started')
+
+    def stop(self) -> None:
+        self.events.append('cache stopped')
+
+
+class FailingBroker:
+    async def astart(self) -> None:
+        raise ConnectionError('broker unreachable')
+
+
+class StuckBroker:
+    async def astart(self) -> None:
+        pass
+
+    async def astop(self) -> None:
+        raise TimeoutError('drain timed out')
+
+
+class Connection:
+    pass
+
+
+def make_container(events: Events, *keys: type) -> RustContainer:
+    container = RustContainer()
+    container.register_instance(Events, events)
+    for key in keys:
+        container.register_class(key, key)
+        container.resolve(key)
+    return container
+
+
+class DescribeAsyncContainerLifecycle:
+    """async with awaits astart()/astop() and async context managers."""
+
+    @pytest.mark.asyncio
+    async def it_awaits_astart_and_astop(self) -> None:
+        events = Events()
+        container = make_container(events, Broker)
+
+        async with container as entered:
+            assert entered is container
+            assert events == ['broker started']
+
+        assert events == ['broker started', 'broker stopped']
+
+    @pytest.mark.asyncio
+    async def it_enters_and_exits_async_context_managers(self) -> None:
+        events = Events()
+        container = make_container(events, Pool)
+
+        async with container:
+            pass
+
+        assert events == ['pool entered', 'pool exited']
+
+    @pytest.mark.asyncio
+    async def it_stops_synchronous_components_before_awaiting_async_ones(self) -> None:
+        events = Events()
+        container = make_container(events, Cache, Broker)
+
+        async with container:
+            assert events == ['cache started', 'broker started']
+
+        assert events[2:] == ['cache stopped', 'broker stopped']
+
+    @pytest.mark.asyncio
+    async def it_raises_the_error_of_a_failed_astart(self) -> None:
+        container = RustContainer()
+        container.register_class(FailingBroker, FailingBroker)
+        container.resolve(FailingBroker)
+
+        with pytest.raises(ConnectionError, match='broker unreachable'):
+            await container.__aenter__()
+
+        assert await container.aclose() == []
+
+    @pytest.mark.asyncio
+    async def it_returns_failed_stops_from_aclose(self) -> None:
+        events = Events()
+        container = make_container(events, Broker)
+        container.register_class(StuckBroker, StuckBroker)
+        container.resolve(StuckBroker)
+        await container.__aenter__()
+
+        reports = await container.aclose()
+
+        assert reports == ['StuckBroker: stop failed: TimeoutError: drain timed out']
+        assert events == ['broker started', 'broker stopped']
+
+    @pytest.mark.asyncio
+    async def it_warns_about_failed_stops_on_exit(self) -> None:
+        container = RustContainer()
+        container.register_class(StuckBroker, StuckBroker)
+        container.resolve(StuckBroker)
+
+        with pytest.warns(RuntimeWarning, match='drain timed out'):
+            async with container:
+                pass
+
+    @pytest.mark.asyncio
+    async def it_closes_generator_factories_on_exit(self) -> None:
+        closed = []
+
+        def connect() -> Generator[Connection, None, None]:
+            yield Connection()
+            closed.append(True)
+
+        container = RustContainer()
+        container.register_generator_factory(Connection, connect)
+
+        async with container:
+            container.resolve(Connection)
+
+        assert closed == [True]
+
+    @pytest.mark.asyncio
+    async def it_does_not_await_async_generators_as_components(self) -> None:
+        async def numbers() -> AsyncGenerator[int, None]:
+            yield 1
+
+        container = RustContainer()
+        container.register_instance(Connection, numbers())
+
+        async with container:
+            pass
@@ -0,0 +1,140 @@
+"""Tests for async factory singletons resolved by racing asyncio tasks."""
+
+import asyncio
+import gc
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Pool:
+    pass
+
+
+class DescribeAsyncSingletonRaces:
+    """Racing resolves of an async singleton await one factory call."""
+
+    @pytest.mark.asyncio
+    async def it_calls_the_factory_once_for_racing_tasks(self) -> None:
+        calls = []
+
+        async def make_pool() -> Pool:
+            calls.append(1)
+            await asyncio.sleep(0.01)
+            return Pool()
+
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+
+        pools = await asyncio.gather(*(container.resolve_async(Pool) for _ in range(10)))
+
+        assert len(calls) == 1
+        assert all(pool is pools[0] for pool in pools)
+        assert container.resolve(Pool) is pools[0]
+
+    @pytest.mark.asyncio
+    async def it_hands_racing_tasks_the_decorated_instance(self) -> None:
+        async def make_pool() -> Pool:
+            await asyncio.sleep(0.01)
+            return Pool()
+
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+        container.add_decorator(Pool, lambda pool: (pool,))
+
+        first, second = await asyncio.gather(container.resolve_async(Pool), container.resolve_async(Pool))
+
+        assert isinstance(first, tuple)
+        assert second is first
+
+    @pytest.mark.asyncio
+    async def it_raises_the_factory_error_in_every_racing_task(self) -> None:
+        calls = []
+
+        async def broken() -> Pool:
+            calls.append(1)
+            await asyncio.sleep(0.01)
+            raise ConnectionError('unreachable')
+
+        container = RustContainer()
+        container.register_async_factory(Pool, broken)
+
+        results = await asyncio.gather(
+            container.resolve_async(Pool), container.resolve_async(Pool), return_exceptions=True
+        )
+
+        assert len(calls) == 1
+        assert all(isinstance(result, ConnectionError) for result in results)
+
+    @pytest.mark.asyncio
+    async def it_retries_after_the_racing_construction_fails(self) -> None:
+        attempts = []
+
+        async def flaky() -> Pool:
+            attempts.append(1)
+            await asyncio.sleep(0.01)
+            if len(attempts) == 1:
+                raise ConnectionError('unreachable')
+            return Pool()
+
+        container = RustContainer()
+        container.register_async_factory(Pool, flaky)
+        await asyncio.gather(container.resolve_async(Pool), container.resolve_async(Pool), return_exceptions=True)
+
+        assert isinstance(await container.resolve_async(Pool), Pool)
+        assert len(attempts) == 2
+
+    @pytest.mark.asyncio
+    async def it_keeps_other_tasks_waiting_when_one_waiter_is_cancelled(self) -> None:
+        async def make_pool() -> Pool:
+            await asyncio.sleep(0.02)
+            return Pool()
+
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+
+        async def resolve() -> Pool:
+            return await container.resolve_async(Pool)
+
+        owner = asyncio.ensure_future(resolve())
+        cancelled = asyncio.ensure_future(resolve())
+        waiter = asyncio.ensure_future(resolve())
+        await asyncio.sleep(0)
+        cancelled.cancel()
+
+        assert await waiter is await owner
+        assert cancelled.cancelled()
+
+    @pytest.mark.asyncio
+    async def it_builds_again_when_the_first_resolve_is_never_awaited(self) -> None:
+        calls = []
+
+        async def make_pool() -> Pool:
+            calls.append(1)
+            return Pool()
+
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool)
+        abandoned = container.resolve_async(Pool)
+        with pytest.warns(RuntimeWarning, match='never awaited'):
+            del abandoned
+            gc.collect()
+
+        pool = await asyncio.wait_for(container.resolve_async(Pool), timeout=1)
+
+        assert isinstance(pool, Pool)
+        assert len(calls) == 1
+
+    @pytest.mark.asyncio
+    async def it_still_builds_transients_for_every_task(self) -> None:
+        async def make_pool() -> Pool:
+            await asyncio.sleep(0.01)
+            return Pool()
+
+        container = RustContainer()
+        container.register_async_factory(Pool, make_pool, lifetime='transient')
+
+        first, second = await asyncio.gather(container.resolve_async(Pool), container.resolve_async(Pool))
+
+        assert first is not second
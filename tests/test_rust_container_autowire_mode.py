@@ -0,0 +1,103 @@
+"""Tests for autowire mode, which binds unregistered concrete classes on resolve."""
+
+from abc import ABC, abstractmethod
+from typing import Protocol
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Repository:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Service:
+    def __init__(self, repository: Repository, retries: int = 3) -> None:
+        self.repository = repository
+        self.retries = retries
+
+
+class Notifier(ABC):
+    @abstractmethod
+    def notify(self) -> None: ...
+
+
+class Sender(Protocol):
+    def send(self) -> None: ...
+
+
+class Greeter:
+    def __init__(self, greeting: str) -> None:
+        self.greeting = greeting
+
+
+class DescribeAutowireMode:
+    """With autowire=True, concrete classes need no registration."""
+
+    def it_is_off_by_default(self) -> None:
+        container = RustContainer()
+
+        assert container.autowire() is False
+        with pytest.raises(KeyError, match='Dependency not registered: Config'):
+            container.resolve(Config)
+
+    def it_binds_an_unregistered_class_as_a_singleton(self) -> None:
+        container = RustContainer(autowire=True)
+
+        config = container.resolve(Config)
+
+        assert isinstance(config, Config)
+        assert container.resolve(Config) is config
+        assert container.contains(Config)
+
+    def it_autowires_unregistered_dependencies(self) -> None:
+        container = RustContainer(autowire=True)
+
+        service = container.resolve(Service)
+
+        assert service.repository.config is container.resolve(Config)
+        assert service.retries == 3
+
+    def it_keeps_explicit_registrations(self) -> None:
+        container = RustContainer(autowire=True)
+        config = Config()
+        container.register_instance(Config, config)
+
+        assert container.resolve(Repository).config is config
+
+    def it_still_rejects_abstract_classes_and_protocols(self) -> None:
+        container = RustContainer(autowire=True)
+
+        with pytest.raises(KeyError, match='Dependency not registered: Notifier'):
+            container.resolve(Notifier)
+        with pytest.raises(KeyError, match='Dependency not registered: Sender'):
+            container.resolve(Sender)
+
+    def it_does_not_bind_classes_it_cannot_construct(self) -> None:
+        container = RustContainer(autowire=True)
+
+        with pytest.raises(KeyError, match="parameter 'greeting'"):
+            container.resolve(Greeter)
+
+        assert not container.contains(Greeter)
+
+    def it_is_inherited_by_child_containers(self) -> None:
+        parent = RustContainer(autowire=True)
+
+        child = parent.create_child()
+
+        assert child.autowire() is True
+        assert isinstance(child.resolve(Config), Config)
+
+    def it_binds_nothing_once_frozen(self) -> None:
+        container = RustContainer(autowire=True)
+        container.freeze()
+
+        with pytest.raises(KeyError, match='Dependency not registered: Config'):
+            container.resolve(Config)
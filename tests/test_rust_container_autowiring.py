@@ -0,0 +1,131 @@
+"""Tests for constructor auto-wiring in the Rust container."""
+
+from __future__ import annotations
+
+from enum import Enum
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Repository:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Service:
+    def __init__(self, repository: Repository, config: Config) -> None:
+        self.repository = repository
+        self.config = config
+
+
+class Cache:
+    def __init__(self, config: Config, ttl: int = 30) -> None:
+        self.config = config
+        self.ttl = ttl
+
+
+class Mailer:
+    def __init__(self, host: str) -> None:
+        self.host = host
+
+
+class Region(Enum):
+    EU = 'eu'
+
+
+class RegionalService:
+    def __init__(self, region: Region.EU) -> None:  # type: ignore[valid-type]
+        self.region = region
+
+
+class DescribeConstructorAutoWiring:
+    """Class providers resolve their __init__ dependencies from the registry."""
+
+    def it_wires_dependencies_recursively(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+        container.register_class(Repository, Repository)
+        container.register_singleton_factory(Config, Config)
+
+        service = container.resolve(Service)
+
+        assert isinstance(service.repository, Repository)
+        assert service.repository.config is service.config
+
+    def it_wires_classes_registered_as_factories(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Repository, Repository)
+        container.register_instance(Config, Config())
+
+        assert container.resolve(Repository).config is container.resolve(Config)
+
+    def it_keeps_defaults_for_unregistered_parameters(self) -> None:
+        container = RustContainer()
+        container.register_class(Cache, Cache)
+        container.register_class(Config, Config)
+
+        assert container.resolve(Cache).ttl == 30
+
+    def it_resolves_dependencies_from_fallback_containers(self) -> None:
+        base = RustContainer()
+        base.register_singleton_factory(Config, Config)
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.add_fallback_container(base)
+
+        assert container.resolve(Repository).config is base.resolve(Config)
+
+    def it_resolves_enum_member_annotations(self) -> None:
+        container = RustContainer()
+        container.register_class(RegionalService, RegionalService)
+        container.register_instance(Region.EU, 'eu-west-1')
+
+        assert container.resolve(RegionalService).region == 'eu-west-1'
+
+    def it_resolves_dependencies_for_the_same_tenant(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Repository, Repository)
+        container.register_tenant_factory(Config, Config)
+
+        acme = container.resolve(Repository, tenant='acme')
+
+        assert acme.config is container.resolve(Config, tenant='acme')
+        assert acme.config is not container.resolve(Config, tenant='globex')
+
+    def it_reports_parameters_that_cannot_be_wired(self) -> None:
+        container = RustContainer()
+        container.register_class(Mailer, Mailer)
+
+        with pytest.raises(
+            KeyError, match="Cannot auto-wire Mailer: parameter 'host' has no registered type annotation and no default"
+        ):
+            container.resolve(Mailer)
+
+    def it_reports_unregistered_dependencies(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+
+        with pytest.raises(KeyError, match="parameter 'config'"):
+            container.resolve(Repository)
+
+    def it_counts_wired_dependencies_as_constructions(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+        container.register_class(Repository, Repository)
+        container.register_class(Config, Config)
+
+        _, info = container.resolve_verbose(Service)
+
+        assert info['constructions'] == 3
+
+    def it_still_calls_factory_functions_without_arguments(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Mailer, lambda: Mailer('smtp.example.com'))
+
+        assert container.resolve(Mailer).host == 'smtp.example.com'
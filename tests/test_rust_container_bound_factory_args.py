@@ -0,0 +1,88 @@
+"""Tests for args/kwargs bound to factory registrations."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Cache:
+    def __init__(self, size: int, policy: str = 'lru') -> None:
+        self.size = size
+        self.policy = policy
+
+
+class Client:
+    def __init__(self, config: Config, timeout: float) -> None:
+        self.config = config
+        self.timeout = timeout
+
+
+def make_cache(size: int, policy: str = 'lru') -> Cache:
+    return Cache(size, policy)
+
+
+class DescribeBoundFactoryArguments:
+    """Factories receive the arguments bound at registration on every call."""
+
+    def it_calls_the_factory_with_bound_kwargs(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Cache, make_cache, kwargs={'size': 1024})
+
+        cache = container.resolve(Cache)
+
+        assert (cache.size, cache.policy) == (1024, 'lru')
+
+    def it_calls_the_factory_with_bound_positional_args(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Cache, make_cache, args=[16], kwargs={'policy': 'fifo'})
+
+        cache = container.resolve(Cache)
+
+        assert (cache.size, cache.policy) == (16, 'fifo')
+        assert container.resolve(Cache) is not cache
+
+    def it_copies_the_kwargs_at_registration(self) -> None:
+        container = RustContainer()
+        kwargs = {'size': 1}
+        container.register_transient_factory(Cache, make_cache, kwargs=kwargs)
+
+        kwargs['size'] = 2
+
+        assert container.resolve(Cache).size == 1
+
+    def it_fills_class_parameters_that_cannot_be_auto_wired(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_transient_factory(Client, Client, kwargs={'timeout': 2.5})
+
+        client = container.resolve(Client)
+
+        assert client.timeout == 2.5
+        assert client.config is container.resolve(Config)
+
+    def it_still_reports_parameters_left_unbound(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Client, Client, kwargs={'timeout': 2.5})
+
+        with pytest.raises(KeyError, match="parameter 'config'"):
+            container.resolve(Client)
+
+    def it_binds_arguments_for_tenant_and_thread_factories(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Cache, make_cache, kwargs={'size': 8})
+        container.register_thread_factory(Client, Client, args=[Config()], kwargs={'timeout': 1.0})
+
+        assert container.resolve(Cache, tenant='acme').size == 8
+        assert container.resolve(Client).timeout == 1.0
+
+    def it_drops_the_bound_arguments_on_rebind(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Cache, make_cache, kwargs={'size': 8})
+
+        container.rebind_factory(Cache, lambda: Cache(32))
+
+        assert container.resolve(Cache).size == 32
@@ -0,0 +1,151 @@
+"""Tests for fluent registration through Container.register()."""
+
+import pytest
+
+from dioxide import Scope
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Repository:
+    pass
+
+
+class SqlRepository(Repository):
+    pass
+
+
+def binding_for(container: RustContainer, key: type) -> dict:
+    [binding] = container.all_bindings(key)
+    return binding
+
+
+class DescribeRegistrationBuilder:
+    """register(key) accumulates a provider and scope, committing on done()."""
+
+    def it_registers_a_singleton_class_by_default(self) -> None:
+        container = RustContainer()
+
+        container.register(Repository).as_class(SqlRepository).done()
+
+        binding = binding_for(container, Repository)
+        assert binding['provider'] == 'class'
+        assert binding['implementation'] is SqlRepository
+        assert container.resolve(Repository) is container.resolve(Repository)
+
+    def it_builds_a_class_scoped_as_factory_on_every_resolve(self) -> None:
+        container = RustContainer()
+
+        container.register(Repository).as_class(SqlRepository).scoped(Scope.FACTORY).done()
+
+        assert binding_for(container, Repository)['provider'] == 'transient_class'
+        assert isinstance(container.resolve(Repository), SqlRepository)
+        assert container.resolve(Repository) is not container.resolve(Repository)
+
+    def it_maps_factory_scope_to_a_transient_factory(self) -> None:
+        container = RustContainer()
+
+        container.register(Repository).as_factory(SqlRepository).scoped('factory').done()
+
+        assert binding_for(container, Repository)['provider'] == 'transient_factory'
+
+    def it_maps_tenant_scope_to_a_tenant_factory(self) -> None:
+        container = RustContainer()
+
+        container.register(Repository).as_factory(SqlRepository).scoped('tenant').done()
+
+        assert binding_for(container, Repository)['provider'] == 'tenant_factory'
+        assert container.resolve(Repository, tenant='a') is container.resolve(Repository, tenant='a')
+
+    def it_registers_instances(self) -> None:
+        container = RustContainer()
+        repository = SqlRepository()
+
+        container.register(Repository).as_instance(repository).done()
+
+        assert binding_for(container, Repository)['provider'] == 'instance'
+        assert container.resolve(Repository) is repository
+
+    def it_registers_under_a_name(self) -> None:
+        container = RustContainer()
+
+        container.register(Repository).as_class(SqlRepository).named('primary').done()
+
+        assert container.registration_info(Repository, name='primary')['name'] == 'primary'
+        assert container.registration_info(Repository) is None
+        assert isinstance(container.resolve(Repository, name='primary'), SqlRepository)
+
+    def it_attaches_tags(self) -> None:
+        container = RustContainer()
+
+        container.register(Repository).as_class(SqlRepository).with_tags(['storage', 'sql']).done()
+
+        assert container.registration_info(Repository)['tags'] == ['sql', 'storage']
+
+    def it_tags_the_named_registration(self) -> None:
+        container = RustContainer()
+
+        container.register(Repository).as_class(SqlRepository).named('primary').with_tags(['sql']).done()
+
+        info = container.registration_info(Repository, name='primary')
+        assert info['name'] == 'primary'
+        assert info['tags'] == ['sql']
+
+    def it_commits_when_the_with_block_exits(self) -> None:
+        container = RustContainer()
+
+        with container.register(Repository) as builder:
+            builder.as_class(SqlRepository)
+
+        assert isinstance(container.resolve(Repository), SqlRepository)
+
+    def it_does_not_commit_when_the_with_block_raises(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(RuntimeError), container.register(Repository) as builder:
+            builder.as_class(SqlRepository)
+            raise RuntimeError('abort')
+
+        assert container.is_empty()
+
+    def it_rejects_a_builder_without_a_provider(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='no provider chosen'):
+            container.register(Repository).scoped(Scope.SINGLETON).done()
+
+        assert container.is_empty()
+
+    def it_rejects_more_than_one_provider_kind(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match=r'more than one provider chosen \(class, factory\)'):
+            container.register(Repository).as_class(SqlRepository).as_factory(SqlRepository).done()
+
+        assert container.is_empty()
+
+    def it_rejects_scoping_an_instance_as_transient(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='cannot use transient scope'):
+            container.register(Repository).as_instance(SqlRepository()).scoped(Scope.FACTORY).done()
+
+    def it_rejects_unknown_scopes(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(TypeError, match='Unknown scope'):
+            container.register(Repository).scoped('session')
+
+    def it_rejects_committing_twice(self) -> None:
+        container = RustContainer()
+        builder = container.register(Repository).as_class(SqlRepository)
+        builder.done()
+
+        with pytest.raises(KeyError, match='already committed'):
+            builder.done()
+
+    def it_rejects_duplicate_registrations(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, SqlRepository)
+
+        with pytest.raises(KeyError, match='Duplicate'):
+            container.register(Repository).as_class(SqlRepository).done()
@@ -0,0 +1,167 @@
+"""Tests for isolating singleton caches with cache namespaces."""
+
+import asyncio
+import threading
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Database:
+    pass
+
+
+class Config:
+    pass
+
+
+class Client:
+    pass
+
+
+async def connect() -> Client:
+    await asyncio.sleep(0)
+    return Client()
+
+
+class DescribeCacheNamespace:
+    """cache_namespace() gives resolves an isolated singleton cache."""
+
+    def it_builds_independent_singletons_per_namespace(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+
+        with container.cache_namespace('test_1'):
+            first = container.resolve(Database)
+            assert container.resolve(Database) is first
+        with container.cache_namespace('test_2'):
+            second = container.resolve(Database)
+
+        assert first is not second
+        assert container.resolve(Database) is not first
+        assert container.resolve(Database) is not second
+
+    def it_reuses_a_namespace_cache_when_entered_again(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+
+        with container.cache_namespace('test_1'):
+            first = container.resolve(Database)
+        with container.cache_namespace('test_1'):
+            assert container.resolve(Database) is first
+
+    def it_shares_explicitly_registered_instances(self) -> None:
+        container = RustContainer()
+        config = Config()
+        container.register_instance(Config, config)
+
+        with container.cache_namespace('test_1'):
+            assert container.resolve(Config) is config
+
+    def it_uses_the_innermost_namespace(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+
+        with container.cache_namespace('outer'):
+            outer = container.resolve(Database)
+            with container.cache_namespace('inner'):
+                inner = container.resolve(Database)
+            assert container.resolve(Database) is outer
+
+        assert inner is not outer
+
+    def it_applies_only_to_the_entering_thread(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+        shared = container.resolve(Database)
+        seen = []
+
+        with container.cache_namespace('test_1'):
+            isolated = container.resolve(Database)
+            worker = threading.Thread(target=lambda: seen.append(container.resolve(Database)))
+            worker.start()
+            worker.join()
+
+        assert isolated is not shared
+        assert seen == [shared]
+
+    def it_logs_namespaced_constructions(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+
+        with container.cache_namespace('test_1') as namespace:
+            container.resolve(Database)
+
+        assert namespace.name == 'test_1'
+        assert container.construction_log() == ['Database[namespace=test_1]']
+
+    def it_clears_namespace_caches_on_reset(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+        with container.cache_namespace('test_1'):
+            first = container.resolve(Database)
+
+        container.reset()
+
+        with container.cache_namespace('test_1'):
+            assert container.resolve(Database) is not first
+
+    @pytest.mark.asyncio
+    async def it_isolates_concurrent_asyncio_tasks(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+
+        async def request(name: str) -> tuple[Database, Database]:
+            with container.cache_namespace(name):
+                first = container.resolve(Database)
+                await asyncio.sleep(0)
+                return first, container.resolve(Database)
+
+        (a_first, a_second), (b_first, b_second) = await asyncio.gather(request('a'), request('b'))
+
+        assert a_first is a_second
+        assert b_first is b_second
+        assert a_first is not b_first
+
+    @pytest.mark.asyncio
+    async def it_is_inherited_by_tasks_started_inside(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+
+        async def resolve() -> Database:
+            return container.resolve(Database)
+
+        with container.cache_namespace('request'):
+            namespaced = container.resolve(Database)
+            task = asyncio.create_task(resolve())
+        inherited = await task
+
+        assert inherited is namespaced
+
+    @pytest.mark.asyncio
+    async def it_isolates_async_factory_singletons(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Client, connect)
+
+        with container.cache_namespace('test-a'):
+            namespaced = await container.resolve_async(Client)
+            assert await container.resolve_async(Client) is namespaced
+        shared = await container.resolve_async(Client)
+
+        assert namespaced is not shared
+        assert await container.resolve_async(Client) is shared
+
+    @pytest.mark.asyncio
+    async def it_shares_in_flight_async_singletons_only_within_a_namespace(self) -> None:
+        container = RustContainer()
+        container.register_async_factory(Client, connect)
+
+        async def request(name: str) -> Client:
+            with container.cache_namespace(name):
+                return await container.resolve_async(Client)
+
+        first, second, third = await asyncio.gather(request('a'), request('a'), request('b'))
+
+        assert first is second
+        assert first is not third
@@ -0,0 +1,79 @@
+"""Tests for Callable[[], X] parameters injected as callables resolving X."""
+
+from __future__ import annotations
+
+from collections.abc import Callable
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import Resolver
+
+
+class Connection:
+    pass
+
+
+class Worker:
+    def __init__(self, connect: Callable[[], Connection]) -> None:
+        self.connect = connect
+
+
+class Scheduler:
+    def __init__(self, on_tick: Callable[[int], None] | None = None) -> None:
+        self.on_tick = on_tick
+
+
+class DescribeCallableInjection:
+    """A Callable[[], X] parameter receives a callable that resolves X when invoked."""
+
+    def it_injects_a_callable_resolving_the_return_type(self) -> None:
+        container = RustContainer()
+        container.register_class(Connection, Connection, lifetime='transient')
+        container.register_class(Worker, Worker)
+
+        connect = container.resolve(Worker).connect
+
+        assert isinstance(connect, Resolver)
+        assert isinstance(connect(), Connection)
+
+    def it_resolves_on_every_call(self) -> None:
+        container = RustContainer()
+        container.register_class(Connection, Connection, lifetime='transient')
+        container.register_class(Worker, Worker)
+
+        connect = container.resolve(Worker).connect
+
+        assert connect() is not connect()
+
+    def it_serves_singletons_from_the_cache(self) -> None:
+        container = RustContainer()
+        container.register_class(Connection, Connection)
+        container.register_class(Worker, Worker)
+
+        assert container.resolve(Worker).connect() is container.resolve(Connection)
+
+    def it_defers_creation_until_called(self) -> None:
+        container = RustContainer()
+        built: list[Connection] = []
+        container.register_singleton_factory(Connection, lambda: built.append(Connection()) or built[-1])
+        container.register_class(Worker, Worker)
+
+        worker = container.resolve(Worker)
+        assert built == []
+
+        worker.connect()
+        assert len(built) == 1
+
+    def it_leaves_callables_taking_arguments_alone(self) -> None:
+        container = RustContainer()
+        container.register_class(Scheduler, Scheduler)
+
+        assert container.resolve(Scheduler).on_tick is None
+
+    def it_fails_when_the_return_type_cannot_be_resolved(self) -> None:
+        container = RustContainer()
+        container.register_class(Worker, Worker)
+
+        with pytest.raises(KeyError, match="parameter 'connect'"):
+            container.resolve(Worker)
@@ -0,0 +1,96 @@
+"""Tests for child containers created with create_child()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DuplicatePolicy
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class FakeDatabase(Database):
+    pass
+
+
+class Repository:
+    def __init__(self, database: Database) -> None:
+        self.database = database
+
+
+class DescribeCreateChild:
+    """A child checks its own registrations first, then its parent."""
+
+    def it_resolves_parent_registrations(self) -> None:
+        parent = RustContainer()
+        parent.register_class(Config, Config)
+        child = parent.create_child()
+
+        assert child.resolve(Config) is parent.resolve(Config)
+
+    def it_overrides_a_binding_without_touching_the_parent(self) -> None:
+        parent = RustContainer()
+        parent.register_class(Database, Database)
+        child = parent.create_child()
+
+        child.register_class(Database, FakeDatabase)
+
+        assert isinstance(child.resolve(Database), FakeDatabase)
+        assert type(parent.resolve(Database)) is Database
+
+    def it_caches_overridden_singletons_in_the_child(self) -> None:
+        parent = RustContainer()
+        parent.register_class(Database, Database)
+        first = parent.create_child()
+        second = parent.create_child()
+        first.register_class(Database, FakeDatabase)
+        second.register_class(Database, FakeDatabase)
+
+        assert first.resolve(Database) is first.resolve(Database)
+        assert first.resolve(Database) is not second.resolve(Database)
+        assert parent.construction_log() == []
+
+    def it_wires_child_registrations_from_the_child(self) -> None:
+        parent = RustContainer()
+        parent.register_class(Database, Database)
+        child = parent.create_child()
+        child.register_class(Database, FakeDatabase)
+        child.register_transient_factory(Repository, Repository)
+
+        assert isinstance(child.resolve(Repository).database, FakeDatabase)
+
+    def it_leaves_parent_singletons_wired_by_the_parent(self) -> None:
+        parent = RustContainer()
+        parent.register_class(Database, Database)
+        parent.register_class(Repository, Repository)
+        child = parent.create_child()
+        child.register_class(Database, FakeDatabase)
+
+        assert type(child.resolve(Repository).database) is Database
+
+    def it_sees_registrations_added_to_the_parent_later(self) -> None:
+        parent = RustContainer()
+        child = parent.create_child()
+
+        parent.register_class(Config, Config)
+
+        assert child.contains(Config) is False
+        assert isinstance(child.resolve(Config), Config)
+
+    def it_inherits_the_duplicate_policy(self) -> None:
+        parent = RustContainer(on_duplicate='replace')
+        child = parent.create_child()
+
+        assert child.duplicate_policy() == DuplicatePolicy.REPLACE
+
+    def it_cannot_become_a_fallback_of_its_parent(self) -> None:
+        parent = RustContainer()
+        child = parent.create_child()
+
+        with pytest.raises(KeyError, match='cycle'):
+            parent.add_fallback_container(child)
@@ -0,0 +1,113 @@
+"""Tests for circular dependency detection with cycle paths."""
+
+from __future__ import annotations
+
+import threading
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Alpha:
+    def __init__(self, beta: Beta) -> None:
+        self.beta = beta
+
+
+class Beta:
+    def __init__(self, alpha: Alpha) -> None:
+        self.alpha = alpha
+
+
+class Root:
+    def __init__(self, alpha: Alpha) -> None:
+        self.alpha = alpha
+
+
+class Leaf:
+    pass
+
+
+class Branch:
+    def __init__(self, left: Leaf, right: Leaf) -> None:
+        self.left = left
+        self.right = right
+
+
+class DescribeCircularDependencies:
+    """Resolving a key already under construction reports the cycle."""
+
+    def it_reports_the_cycle_path_for_auto_wired_classes(self) -> None:
+        container = RustContainer()
+        container.register_class(Alpha, Alpha)
+        container.register_class(Beta, Beta)
+
+        with pytest.raises(KeyError, match='Circular dependency detected: Alpha -> Beta -> Alpha'):
+            container.resolve(Alpha)
+
+    def it_reports_only_the_cycle_not_the_path_leading_into_it(self) -> None:
+        container = RustContainer()
+        container.register_class(Root, Root)
+        container.register_class(Alpha, Alpha)
+        container.register_class(Beta, Beta)
+
+        with pytest.raises(KeyError, match=r'detected: Alpha -> Beta -> Alpha\W*$'):
+            container.resolve(Root)
+
+    def it_detects_cycles_through_factories(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Alpha, lambda: Alpha(container.resolve(Beta)))
+        container.register_singleton_factory(Beta, lambda: Beta(container.resolve(Alpha)))
+
+        with pytest.raises(KeyError, match='Alpha -> Beta -> Alpha'):
+            container.resolve(Alpha)
+
+    def it_allows_the_same_dependency_twice_in_one_graph(self) -> None:
+        container = RustContainer()
+        container.register_class(Branch, Branch)
+        container.register_class(Leaf, Leaf, lifetime='transient')
+
+        branch = container.resolve(Branch)
+
+        assert branch.left is not branch.right
+
+    def it_recovers_after_a_cycle_error(self) -> None:
+        container = RustContainer()
+        container.register_class(Alpha, Alpha)
+        container.register_class(Beta, Beta)
+        container.register_class(Leaf, Leaf)
+
+        with pytest.raises(KeyError):
+            container.resolve(Alpha)
+
+        assert isinstance(container.resolve(Leaf), Leaf)
+        with pytest.raises(KeyError, match='Alpha -> Beta -> Alpha'):
+            container.resolve(Alpha)
+
+    def it_does_not_treat_concurrent_resolves_on_other_threads_as_cycles(self) -> None:
+        container = RustContainer()
+        started = threading.Event()
+        release = threading.Event()
+        errors = []
+
+        def slow_leaf() -> Leaf:
+            started.set()
+            release.wait(timeout=5)
+            return Leaf()
+
+        container.register_transient_factory(Leaf, slow_leaf)
+
+        def resolve() -> None:
+            try:
+                container.resolve(Leaf)
+            except Exception as error:
+                errors.append(error)
+
+        worker = threading.Thread(target=resolve)
+        worker.start()
+        started.wait(timeout=5)
+        release.set()
+        container.resolve(Leaf)
+        worker.join()
+
+        assert errors == []
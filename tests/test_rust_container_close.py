@@ -0,0 +1,185 @@
+"""Tests for close(), which finalizes everything and closes the container."""
+
+from collections.abc import Generator
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import ContainerClosedError
+
+
+events: list[str] = []
+
+
+class Database:
+    def close(self) -> None:
+        events.append('database closed')
+
+
+class Repository:
+    def __init__(self, database: Database) -> None:
+        self.database = database
+
+    def close(self) -> None:
+        events.append('repository closed')
+
+
+class Request:
+    def __exit__(self, *args: object) -> None:
+        events.append('request exited')
+
+
+class Broker:
+    async def aclose(self) -> None:
+        events.append('broker closed')
+
+
+class Server:
+    def start(self) -> None:
+        events.append('server started')
+
+    def stop(self) -> None:
+        events.append('server stopped')
+
+    def close(self) -> None:
+        events.append('server closed')
+
+
+class DescribeClose:
+    """close() finalizes every created instance exactly once."""
+
+    def setup_method(self) -> None:
+        events.clear()
+
+    def it_disposes_singletons_dependents_first(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_class(Repository, Repository)
+        container.resolve(Repository)
+
+        assert container.close() == []
+
+        assert events == ['repository closed', 'database closed']
+
+    def it_disposes_tracked_transients(self) -> None:
+        container = RustContainer()
+        container.register_class(Request, Request, lifetime='transient')
+        request = container.resolve(Request)  # tracked weakly, so kept alive here
+
+        container.close()
+
+        assert request is not None
+        assert events == ['request exited']
+
+    def it_awaits_async_disposers(self) -> None:
+        container = RustContainer()
+        container.register_class(Broker, Broker)
+        container.resolve(Broker)
+
+        container.close()
+
+        assert events == ['broker closed']
+
+    def it_runs_generator_teardowns(self) -> None:
+        def connect() -> Generator[Database, None, None]:
+            yield Database()
+            events.append('teardown')
+
+        container = RustContainer()
+        container.register_generator_factory(Database, connect)
+        container.resolve(Database)
+
+        container.close()
+
+        assert events == ['teardown']
+
+    def it_stops_started_components_without_disposing_them_again(self) -> None:
+        container = RustContainer()
+        container.register_class(Server, Server)
+        container.resolve(Server)
+        container.start()
+
+        container.close()
+
+        assert events == ['server started', 'server stopped']
+
+    def it_finalizes_only_once(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.resolve(Database)
+
+        container.close()
+
+        assert container.close() == []
+        assert events == ['database closed']
+
+    def it_rejects_resolves_once_closed(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.close()
+
+        assert container.is_closed() is True
+        with pytest.raises(ContainerClosedError, match='Cannot resolve .*Database: container is closed'):
+            container.resolve(Database)
+
+    def it_reports_failed_disposals(self) -> None:
+        class Broken:
+            def close(self) -> None:
+                raise RuntimeError('boom')
+
+        container = RustContainer()
+        container.register_class(Broken, Broken)
+        container.resolve(Broken)
+
+        assert container.close() == ['Broken: disposal failed: RuntimeError: boom']
+
+    def it_closes_on_exit(self) -> None:
+        with RustContainer() as container:
+            container.register_class(Database, Database)
+            container.resolve(Database)
+
+        assert events == ['database closed']
+        assert container.is_closed() is True
+
+    @pytest.mark.asyncio
+    async def it_closes_on_async_exit(self) -> None:
+        async with RustContainer() as container:
+            container.register_class(Broker, Broker)
+            container.resolve(Broker)
+
+        assert events == ['broker closed']
+        assert container.is_closed() is True
+
+    @pytest.mark.asyncio
+    async def it_closes_on_aclose(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.resolve(Database)
+
+        assert await container.aclose() == []
+
+        assert events == ['database closed']
+        with pytest.raises(ContainerClosedError):
+            container.resolve(Database)
+
+
+class DescribeCloseGenerators:
+    """close_generators() runs generator teardowns and leaves the container open."""
+
+    def setup_method(self) -> None:
+        events.clear()
+
+    def it_keeps_the_container_open(self) -> None:
+        def connect() -> Generator[Database, None, None]:
+            yield Database()
+            events.append('teardown')
+
+        container = RustContainer()
+        container.register_generator_factory(Database, connect)
+        container.resolve(Database)
+
+        container.close_generators()
+
+        assert events == ['teardown']
+        assert container.is_closed() is False
+        assert isinstance(container.resolve(Database), Database)
@@ -0,0 +1,162 @@
+"""Tests for injecting list[X], Sequence[X], tuple[X, ...], set[X] and frozenset[X] parameters from multi-bindings."""
+
+from __future__ import annotations
+
+from collections.abc import Sequence
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class EventHandler:
+    pass
+
+
+class AuditHandler(EventHandler):
+    pass
+
+
+class MailHandler(EventHandler):
+    pass
+
+
+class Dispatcher:
+    def __init__(self, handlers: list[EventHandler]) -> None:
+        self.handlers = handlers
+
+
+class SequenceDispatcher:
+    def __init__(self, handlers: Sequence[EventHandler]) -> None:
+        self.handlers = handlers
+
+
+class TupleDispatcher:
+    def __init__(self, handlers: tuple[EventHandler, ...]) -> None:
+        self.handlers = handlers
+
+
+class SetDispatcher:
+    def __init__(self, handlers: set[EventHandler]) -> None:
+        self.handlers = handlers
+
+
+class FrozenSetDispatcher:
+    def __init__(self, handlers: frozenset[EventHandler]) -> None:
+        self.handlers = handlers
+
+
+class DefaultedDispatcher:
+    def __init__(self, handlers: list[EventHandler] | None = None) -> None:
+        self.handlers = handlers
+
+
+class DescribeCollectionInjection:
+    """A list[X] parameter receives every provider multi-bound to X."""
+
+    def it_injects_every_handler_in_registration_order(self) -> None:
+        container = RustContainer()
+        container.register_multi_class(EventHandler, AuditHandler)
+        container.register_multi_class(EventHandler, MailHandler)
+        container.register_transient_factory(Dispatcher, Dispatcher)
+
+        handlers = container.resolve(Dispatcher).handlers
+
+        assert [type(handler) for handler in handlers] == [AuditHandler, MailHandler]
+
+    def it_injects_sequence_parameters(self) -> None:
+        container = RustContainer()
+        audit = AuditHandler()
+        container.register_multi_instance(EventHandler, audit)
+        container.register_transient_factory(SequenceDispatcher, SequenceDispatcher)
+
+        assert container.resolve(SequenceDispatcher).handlers == [audit]
+
+    def it_orders_handlers_by_priority(self) -> None:
+        container = RustContainer()
+        container.register_multi_class(EventHandler, AuditHandler)
+        container.register_multi_class(EventHandler, MailHandler, priority=10)
+        container.register_transient_factory(Dispatcher, Dispatcher)
+
+        handlers = container.resolve(Dispatcher).handlers
+
+        assert [type(handler) for handler in handlers] == [MailHandler, AuditHandler]
+        assert container.resolve_all(EventHandler) == handlers
+
+    def it_shares_singleton_handlers_with_resolve_all(self) -> None:
+        container = RustContainer()
+        container.register_multi_class(EventHandler, AuditHandler)
+        container.register_transient_factory(Dispatcher, Dispatcher)
+
+        assert container.resolve(Dispatcher).handlers[0] is container.resolve_all(EventHandler)[0]
+
+    def it_injects_an_empty_list_without_handlers(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Dispatcher, Dispatcher)
+
+        assert container.resolve(Dispatcher).handlers == []
+
+    def it_keeps_the_default_without_handlers(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(DefaultedDispatcher, DefaultedDispatcher)
+
+        assert container.resolve(DefaultedDispatcher).handlers is None
+
+    def it_picks_up_handlers_contributed_after_the_first_resolve(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Dispatcher, Dispatcher)
+        assert container.resolve(Dispatcher).handlers == []
+
+        container.register_multi_class(EventHandler, AuditHandler)
+
+        assert [type(handler) for handler in container.resolve(Dispatcher).handlers] == [AuditHandler]
+
+    def it_prefers_a_binding_of_the_list_itself(self) -> None:
+        container = RustContainer()
+        handlers = [MailHandler()]
+        container.register_multi_class(EventHandler, AuditHandler)
+        container.register_instance(list[EventHandler], handlers)
+        container.register_transient_factory(Dispatcher, Dispatcher)
+
+        assert container.resolve(Dispatcher).handlers is handlers
+
+
+class DescribeOtherCollectionTypes:
+    """tuple[X, ...], set[X] and frozenset[X] parameters are assembled like list[X]."""
+
+    def it_injects_a_tuple_in_registration_order(self) -> None:
+        container = RustContainer()
+        container.register_multi_class(EventHandler, AuditHandler)
+        container.register_multi_class(EventHandler, MailHandler)
+        container.register_transient_factory(TupleDispatcher, TupleDispatcher)
+
+        handlers = container.resolve(TupleDispatcher).handlers
+
+        assert isinstance(handlers, tuple)
+        assert [type(handler) for handler in handlers] == [AuditHandler, MailHandler]
+
+    def it_injects_a_set(self) -> None:
+        container = RustContainer()
+        audit, mail = AuditHandler(), MailHandler()
+        container.register_multi_instance(EventHandler, audit)
+        container.register_multi_instance(EventHandler, mail)
+        container.register_transient_factory(SetDispatcher, SetDispatcher)
+
+        handlers = container.resolve(SetDispatcher).handlers
+
+        assert type(handlers) is set
+        assert handlers == {audit, mail}
+
+    def it_injects_a_frozenset(self) -> None:
+        container = RustContainer()
+        audit = AuditHandler()
+        container.register_multi_instance(EventHandler, audit)
+        container.register_transient_factory(FrozenSetDispatcher, FrozenSetDispatcher)
+
+        assert container.resolve(FrozenSetDispatcher).handlers == frozenset({audit})
+
+    def it_injects_empty_collections_without_handlers(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(TupleDispatcher, TupleDispatcher)
+        container.register_transient_factory(FrozenSetDispatcher, FrozenSetDispatcher)
+
+        assert container.resolve(TupleDispatcher).handlers == ()
+        assert container.resolve(FrozenSetDispatcher).handlers == frozenset()
@@ -0,0 +1,89 @@
+"""Tests for registering @component classes with the options they declare."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import component
+
+
+class Database:
+    pass
+
+
+@component(scope='factory')
+class RequestContext:
+    pass
+
+
+@component(name='replica', tags=['db', 'readonly'])
+class ReplicaDatabase(Database):
+    pass
+
+
+@component
+class Clock:
+    pass
+
+
+class DescribeComponentMarkers:
+    """register_class() applies the scope, name and tags a class declares."""
+
+    def it_applies_the_declared_scope(self) -> None:
+        container = RustContainer()
+        container.register_class(RequestContext, RequestContext)
+
+        assert container.resolve(RequestContext) is not container.resolve(RequestContext)
+
+    def it_defaults_to_singleton(self) -> None:
+        container = RustContainer()
+        container.register_class(Clock, Clock)
+
+        assert container.resolve(Clock) is container.resolve(Clock)
+
+    def it_registers_under_the_declared_name(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, ReplicaDatabase)
+
+        assert isinstance(container.resolve(Database, name='replica'), ReplicaDatabase)
+        assert container.contains(Database) is False
+
+    def it_attaches_the_declared_tags(self) -> None:
+        container = RustContainer()
+        tagged = []
+        container.register_class(Database, ReplicaDatabase)
+        container.add_interceptor(lambda instance: tagged.append(instance) or instance, tag='db')
+
+        database = container.resolve(Database, name='replica')
+
+        assert tagged == [database]
+
+    def it_lets_registration_arguments_win(self) -> None:
+        container = RustContainer()
+        container.register_class(RequestContext, RequestContext, lifetime='singleton')
+        container.register_class(Database, ReplicaDatabase, name='primary')
+
+        assert container.resolve(RequestContext) is container.resolve(RequestContext)
+        assert isinstance(container.resolve(Database, name='primary'), ReplicaDatabase)
+
+    def it_ignores_markers_inherited_from_a_component(self) -> None:
+        class Plain(RequestContext):
+            pass
+
+        container = RustContainer()
+        container.register_class(Plain, Plain)
+
+        assert container.resolve(Plain) is container.resolve(Plain)
+
+    def it_rejects_unknown_scopes(self) -> None:
+        with pytest.raises(TypeError, match='Unknown scope'):
+            component(scope='forever')
+
+    def it_rejects_scopes_register_class_cannot_provide(self) -> None:
+        @component(scope='tenant')
+        class PerTenant:
+            pass
+
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='cannot use the tenant scope'):
+            container.register_class(PerTenant, PerTenant)
@@ -0,0 +1,94 @@
+"""Tests for injecting Annotated[X, Config(name)] parameters from register_config()."""
+
+from __future__ import annotations
+
+from typing import Annotated
+
+import pytest
+
+from dioxide._dioxide_core import Config
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Database:
+    def __init__(self, url: Annotated[str, Config('db.url')], pool: Annotated[int, Config('db.pool')] = 5) -> None:
+        self.url = url
+        self.pool = pool
+
+
+class Cache:
+    def __init__(self, ttl: Annotated[int | None, Config('cache.ttl')]) -> None:
+        self.ttl = ttl
+
+
+class Settings:
+    def __init__(self, db: Annotated[dict, Config('db')]) -> None:
+        self.db = db
+
+
+class DescribeConfigValues:
+    """Parameters annotated with Config(name) receive the registered value."""
+
+    def it_injects_a_registered_value(self) -> None:
+        container = RustContainer()
+        container.register_config({'db.url': 'postgres://primary'})
+        container.register_class(Database, Database)
+
+        assert container.resolve(Database).url == 'postgres://primary'
+
+    def it_reaches_nested_values_by_dotted_name(self) -> None:
+        container = RustContainer()
+        container.register_config({'db': {'url': 'postgres://nested', 'pool': 10}})
+        container.register_class(Database, Database)
+        container.register_class(Settings, Settings)
+
+        database = container.resolve(Database)
+
+        assert (database.url, database.pool) == ('postgres://nested', 10)
+        assert container.resolve(Settings).db == {'url': 'postgres://nested', 'pool': 10}
+
+    def it_keeps_the_default_of_a_missing_value(self) -> None:
+        container = RustContainer()
+        container.register_config({'db.url': 'postgres://primary'})
+        container.register_class(Database, Database)
+
+        assert container.resolve(Database).pool == 5
+
+    def it_passes_none_for_a_missing_optional_value(self) -> None:
+        container = RustContainer()
+        container.register_class(Cache, Cache)
+
+        assert container.resolve(Cache).ttl is None
+
+    def it_names_the_missing_value(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        with pytest.raises(KeyError, match="parameter 'url' needs configuration value 'db.url'"):
+            container.resolve(Database)
+
+    def it_picks_up_values_registered_after_the_first_resolve(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Database, Database)
+        container.register_config({'db.url': 'postgres://primary'})
+        assert container.resolve(Database).pool == 5
+
+        container.register_config({'db.pool': 20})
+
+        assert container.resolve(Database).pool == 20
+
+    def it_reads_values_from_the_parent_of_a_child(self) -> None:
+        parent = RustContainer()
+        parent.register_config({'db.url': 'postgres://parent'})
+        child = parent.create_child()
+        child.register_class(Database, Database)
+
+        assert child.resolve(Database).url == 'postgres://parent'
+
+    def it_does_not_resolve_the_annotated_type(self) -> None:
+        container = RustContainer()
+        container.register_instance(str, 'registered string')
+        container.register_config({'db.url': 'postgres://primary'})
+        container.register_class(Database, Database)
+
+        assert container.resolve(Database).url == 'postgres://primary'
@@ -0,0 +1,81 @@
+"""Tests for recording the order singletons are constructed in."""
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class UserRepository:
+    def __init__(self, database: Database) -> None:
+        self.database = database
+
+
+class RequestHandler:
+    pass
+
+
+def wire(container: RustContainer) -> None:
+    container.register_singleton_factory(Config, Config)
+    container.register_singleton_factory(Database, lambda: Database(container.resolve(Config)))
+    container.register_singleton_factory(UserRepository, lambda: UserRepository(container.resolve(Database)))
+    container.register_transient_factory(RequestHandler, RequestHandler)
+
+
+class DescribeConstructionLog:
+    """construction_log() records singleton construction order."""
+
+    def it_starts_empty(self) -> None:
+        assert RustContainer().construction_log() == []
+
+    def it_records_dependencies_before_their_dependents(self) -> None:
+        container = RustContainer()
+        wire(container)
+
+        container.resolve(UserRepository)
+
+        assert container.construction_log() == ['Config', 'Database', 'UserRepository']
+
+    def it_records_each_singleton_once(self) -> None:
+        container = RustContainer()
+        wire(container)
+
+        container.resolve(Config)
+        container.resolve(UserRepository)
+        container.resolve(UserRepository)
+
+        assert container.construction_log() == ['Config', 'Database', 'UserRepository']
+
+    def it_ignores_transients(self) -> None:
+        container = RustContainer()
+        wire(container)
+
+        container.resolve(RequestHandler)
+
+        assert container.construction_log() == []
+
+    def it_records_rebuilds_after_reset(self) -> None:
+        container = RustContainer()
+        wire(container)
+        container.resolve(Config)
+
+        container.reset()
+        container.resolve(Config)
+
+        assert container.construction_log() == ['Config', 'Config']
+
+    def it_can_be_cleared(self) -> None:
+        container = RustContainer()
+        wire(container)
+        container.resolve(Database)
+
+        container.clear_construction_log()
+        container.resolve(UserRepository)
+
+        assert container.construction_log() == ['UserRepository']
@@ -0,0 +1,82 @@
+"""Tests for constructor defaults used when a parameter's type is unbound."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Clock:
+    pass
+
+
+class Cache:
+    pass
+
+
+DEFAULT_CLOCK = Clock()
+
+
+class Scheduler:
+    def __init__(self, clock: Clock = DEFAULT_CLOCK, interval: float = 1.5) -> None:
+        self.clock = clock
+        self.interval = interval
+
+
+class Worker:
+    def __init__(self, clock: Clock = DEFAULT_CLOCK, cache: Cache | None = None, /) -> None:
+        self.clock = clock
+        self.cache = cache
+
+
+class DescribeConstructorDefaults:
+    """A parameter with a default keeps it when nothing provides its type."""
+
+    def it_uses_the_default_for_an_unbound_type(self) -> None:
+        container = RustContainer()
+        container.register_class(Scheduler, Scheduler)
+
+        scheduler = container.resolve(Scheduler)
+
+        assert scheduler.clock is DEFAULT_CLOCK
+        assert scheduler.interval == 1.5
+
+    def it_injects_the_bound_type_over_the_default(self) -> None:
+        container = RustContainer()
+        container.register_class(Clock, Clock)
+        container.register_class(Scheduler, Scheduler)
+
+        assert container.resolve(Scheduler).clock is container.resolve(Clock)
+
+    def it_keeps_positional_only_defaults_in_place(self) -> None:
+        container = RustContainer()
+        container.register_class(Cache, Cache)
+        container.register_class(Worker, Worker)
+
+        worker = container.resolve(Worker)
+
+        assert worker.clock is DEFAULT_CLOCK
+        assert worker.cache is container.resolve(Cache)
+
+    def it_prefers_the_default_over_autowiring(self) -> None:
+        container = RustContainer(autowire=True)
+        container.register_class(Scheduler, Scheduler)
+
+        assert container.resolve(Scheduler).clock is DEFAULT_CLOCK
+        assert not container.contains(Clock)
+
+    def it_passes_validation(self) -> None:
+        container = RustContainer()
+        container.register_class(Scheduler, Scheduler)
+
+        container.validate()
+
+    def it_still_fails_for_parameters_without_a_default(self) -> None:
+        class Needy:
+            def __init__(self, cache: Cache) -> None:
+                self.cache = cache
+
+        container = RustContainer()
+        container.register_class(Needy, Needy)
+
+        with pytest.raises(KeyError, match="parameter 'cache'"):
+            container.resolve(Needy)
@@ -0,0 +1,81 @@
+"""Tests for providers registered for one consumer with register_for_consumer()."""
+
+from __future__ import annotations
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Logger:
+    def __init__(self, verbose: bool = False) -> None:
+        self.verbose = verbose
+
+
+class VerboseLogger(Logger):
+    def __init__(self) -> None:
+        super().__init__(verbose=True)
+
+
+class Billing:
+    def __init__(self, logger: Logger) -> None:
+        self.logger = logger
+
+
+class Shipping:
+    def __init__(self, logger: Logger) -> None:
+        self.logger = logger
+
+
+class DescribeConsumerOverrides:
+    """A consumer-specific provider is injected into that consumer only."""
+
+    def it_injects_different_instances_into_two_consumers(self) -> None:
+        container = RustContainer()
+        verbose, quiet = Logger(verbose=True), Logger()
+        container.register_for_consumer(Logger, Billing, verbose)
+        container.register_for_consumer(Logger, Shipping, quiet)
+        container.register_class(Billing, Billing)
+        container.register_class(Shipping, Shipping)
+
+        assert container.resolve(Billing).logger is verbose
+        assert container.resolve(Shipping).logger is quiet
+
+    def it_keeps_the_default_for_other_consumers(self) -> None:
+        container = RustContainer()
+        default, verbose = Logger(), Logger(verbose=True)
+        container.register_instance(Logger, default)
+        container.register_for_consumer(Logger, Billing, verbose)
+        container.register_class(Billing, Billing)
+        container.register_class(Shipping, Shipping)
+
+        assert container.resolve(Billing).logger is verbose
+        assert container.resolve(Shipping).logger is default
+        assert container.resolve(Logger) is default
+
+    def it_constructs_a_class_provider_once(self) -> None:
+        container = RustContainer()
+        container.register_for_consumer(Logger, Billing, VerboseLogger)
+        container.register_class(Billing, Billing, lifetime='transient')
+
+        first, second = container.resolve(Billing), container.resolve(Billing)
+
+        assert isinstance(first.logger, VerboseLogger)
+        assert first is not second
+        assert first.logger is second.logger
+
+    def it_applies_to_consumers_registered_before_the_override(self) -> None:
+        container = RustContainer()
+        container.register_instance(Logger, Logger())
+        container.register_class(Billing, Billing, lifetime='transient')
+        container.resolve(Billing)
+        verbose = Logger(verbose=True)
+
+        container.register_for_consumer(Logger, Billing, verbose)
+
+        assert container.resolve(Billing).logger is verbose
+
+    def it_satisfies_validation(self) -> None:
+        container = RustContainer()
+        container.register_for_consumer(Logger, Billing, Logger())
+        container.register_class(Billing, Billing)
+
+        container.validate()
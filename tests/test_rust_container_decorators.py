@@ -0,0 +1,87 @@
+"""Tests for instance decorators applied by the Rust container."""
+
+from typing import Any
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Repository:
+    def get(self) -> str:
+        return 'value'
+
+
+class Wrapper:
+    def __init__(self, label: str, inner: Any) -> None:
+        self.label = label
+        self.inner = inner
+
+
+def wrap_with(label: str) -> Any:
+    def decorator(instance: Any) -> Wrapper:
+        return Wrapper(label, instance)
+
+    return decorator
+
+
+class DescribeAddDecorator:
+    """add_decorator() wraps resolved instances in order."""
+
+    def it_applies_decorators_in_the_order_they_were_added(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.add_decorator(Repository, wrap_with('caching'))
+        container.add_decorator(Repository, wrap_with('retry'))
+
+        resolved = container.resolve(Repository)
+
+        assert resolved.label == 'retry'
+        assert resolved.inner.label == 'caching'
+        assert isinstance(resolved.inner.inner, Repository)
+
+    def it_caches_the_decorated_singleton(self) -> None:
+        container = RustContainer()
+        calls = []
+
+        def decorator(instance: Any) -> Wrapper:
+            calls.append(instance)
+            return Wrapper('cached', instance)
+
+        container.register_singleton_factory(Repository, Repository)
+        container.add_decorator(Repository, decorator)
+
+        first = container.resolve(Repository)
+        second = container.resolve(Repository)
+
+        assert first is second
+        assert len(calls) == 1
+
+    def it_decorates_every_transient_instance(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+        container.add_decorator(Repository, wrap_with('transient'))
+
+        first = container.resolve(Repository)
+        second = container.resolve(Repository)
+
+        assert first is not second
+        assert first.label == second.label == 'transient'
+
+    def it_decorates_instance_providers_once(self) -> None:
+        container = RustContainer()
+        repository = Repository()
+        container.register_instance(Repository, repository)
+        container.add_decorator(Repository, wrap_with('audit'))
+
+        first = container.resolve(Repository)
+        second = container.resolve(Repository)
+
+        assert first is second
+        assert first.inner is repository
+
+    def it_only_decorates_the_registered_type(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_instance(str, 'plain')
+        container.add_decorator(Repository, wrap_with('only-repo'))
+
+        assert container.resolve(str) == 'plain'
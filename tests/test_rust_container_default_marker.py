@@ -0,0 +1,77 @@
+"""Tests for Annotated[X, Default(value)] parameters filled when nothing else supplies them."""
+
+from __future__ import annotations
+
+from typing import Annotated
+
+from dioxide._dioxide_core import Config
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import Default
+
+
+class Timeout(int):
+    pass
+
+
+class Client:
+    def __init__(self, timeout: Annotated[Timeout, Default(30)]) -> None:
+        self.timeout = timeout
+
+
+class Pool:
+    def __init__(self, size: Annotated[int, Config('pool.size'), Default(4)]) -> None:
+        self.size = size
+
+
+class Retry:
+    def __init__(self, attempts: Annotated[int, Default(3)] = 1) -> None:
+        self.attempts = attempts
+
+
+class DescribeDefaultMarker:
+    """Default(value) is injected only when no provider or configuration value applies."""
+
+    def it_injects_the_marker_value_without_a_provider(self) -> None:
+        container = RustContainer()
+        container.register_class(Client, Client)
+
+        assert container.resolve(Client).timeout == 30
+
+    def it_prefers_a_registered_provider(self) -> None:
+        container = RustContainer()
+        container.register_instance(Timeout, Timeout(5))
+        container.register_class(Client, Client)
+
+        assert container.resolve(Client).timeout == 5
+
+    def it_prefers_a_registered_configuration_value(self) -> None:
+        container = RustContainer()
+        container.register_class(Pool, Pool, lifetime='transient')
+
+        assert container.resolve(Pool).size == 4
+
+        container.register_config({'pool': {'size': 16}})
+
+        assert container.resolve(Pool).size == 16
+
+    def it_takes_precedence_over_the_signature_default(self) -> None:
+        container = RustContainer()
+        container.register_class(Retry, Retry)
+
+        assert container.resolve(Retry).attempts == 3
+
+    def it_lets_explicit_arguments_win(self) -> None:
+        container = RustContainer()
+        container.register_class(Client, Client)
+
+        assert container.resolve(Client, overrides={'timeout': Timeout(60)}).timeout == 60
+
+    def it_counts_as_satisfied_when_validating(self) -> None:
+        container = RustContainer()
+        container.register_class(Client, Client)
+
+        container.validate()
+
+    def it_exposes_the_marker_value(self) -> None:
+        assert Default(30).value == 30
+        assert repr(Default('fast')) == "Default('fast')"
@@ -0,0 +1,70 @@
+"""Tests for "did you mean" suggestions on DependencyNotRegisteredError."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DependencyNotRegisteredError
+
+
+class Database:
+    pass
+
+
+class Databse:
+    pass
+
+
+class Notifier:
+    pass
+
+
+class Mailer:
+    pass
+
+
+def message(container: RustContainer, key: type, name: str | None = None) -> str:
+    with pytest.raises(DependencyNotRegisteredError) as caught:
+        container.resolve(key, name=name)
+    return str(caught.value)
+
+
+class DescribeDidYouMean:
+    """A missing type's error names registrations it was likely meant to be."""
+
+    def it_suggests_a_registered_type_with_a_similar_name(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        assert 'Dependency not registered: Databse (did you mean Database?)' in message(container, Databse)
+
+    def it_suggests_the_same_type_under_another_name(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database, name='replica')
+
+        assert '(did you mean Database[name=replica]?)' in message(container, Database)
+
+    def it_suggests_the_unnamed_binding_for_an_unknown_name(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        assert '(did you mean Database?)' in message(container, Database, name='primary')
+
+    def it_points_at_a_same_named_type_from_another_module(self) -> None:
+        other = type('Notifier', (), {'__module__': 'plugins.alerts'})
+        container = RustContainer()
+        container.register_class(other, other)
+
+        assert '(did you mean plugins.alerts.Notifier?)' in message(container, Notifier)
+
+    def it_lists_several_suggestions_best_first(self) -> None:
+        container = RustContainer()
+        container.register_class(Databse, Databse)
+        container.register_class(Database, Database, name='replica')
+
+        assert '(did you mean Database[name=replica] or Databse?)' in message(container, Database)
+
+    def it_leaves_unrelated_names_out(self) -> None:
+        container = RustContainer()
+        container.register_class(Mailer, Mailer)
+
+        assert 'did you mean' not in message(container, Notifier)
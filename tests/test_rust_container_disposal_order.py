@@ -0,0 +1,96 @@
+"""Tests for disposing singletons in reverse dependency order."""
+
+from __future__ import annotations
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import Lazy
+
+disposed: list[str] = []
+
+
+class Disposable:
+    def close(self) -> None:
+        disposed.append(type(self).__name__)
+
+
+class Pool(Disposable):
+    pass
+
+
+class Cache(Disposable):
+    pass
+
+
+class Service(Disposable):
+    def __init__(self, pool: Lazy[Pool]) -> None:
+        self.pool = pool
+
+
+class Gateway(Disposable):
+    def __init__(self, service: Service, cache: Cache) -> None:
+        self.service = service
+
+
+class Left(Disposable):
+    def __init__(self, right: Lazy[Right]) -> None:
+        self.right = right
+
+
+class Right(Disposable):
+    def __init__(self, left: Left) -> None:
+        self.left = left
+
+
+class DescribeDisposalOrder:
+    """Dependents are disposed before the dependencies they use."""
+
+    def setup_method(self) -> None:
+        disposed.clear()
+
+    def it_disposes_a_lazy_dependency_after_its_dependent(self) -> None:
+        container = RustContainer()
+        container.register_class(Pool, Pool)
+        container.register_class(Service, Service)
+        service = container.resolve(Service)
+        service.pool.close  # noqa: B018 - constructs the pool after the service
+
+        assert container.shutdown(1000) == []
+
+        assert disposed == ['Service', 'Pool']
+
+    def it_keeps_reverse_creation_order_for_unrelated_singletons(self) -> None:
+        container = RustContainer()
+        container.register_class(Pool, Pool)
+        container.register_class(Cache, Cache)
+        container.resolve(Pool)
+        container.resolve(Cache)
+
+        container.shutdown(1000)
+
+        assert disposed == ['Cache', 'Pool']
+
+    def it_orders_a_whole_dependency_chain(self) -> None:
+        container = RustContainer()
+        container.register_class(Pool, Pool)
+        container.register_class(Cache, Cache)
+        container.register_class(Service, Service)
+        container.register_class(Gateway, Gateway)
+        container.resolve(Pool)
+        gateway = container.resolve(Gateway)
+        gateway.service.pool.close  # noqa: B018
+
+        container.shutdown(1000)
+
+        assert disposed.index('Gateway') < disposed.index('Service') < disposed.index('Pool')
+        assert disposed.index('Gateway') < disposed.index('Cache')
+
+    def it_disposes_every_singleton_on_a_lazy_cycle(self) -> None:
+        container = RustContainer()
+        container.register_class(Left, Left)
+        container.register_class(Right, Right)
+        right = container.resolve(Right)
+        right.left.right.close  # noqa: B018
+
+        assert container.shutdown(1000) == []
+
+        assert sorted(disposed) == ['Left', 'Right']
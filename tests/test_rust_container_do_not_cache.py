@@ -0,0 +1,47 @@
+"""Tests for factories that opt out of singleton caching per call."""
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DoNotCache
+
+
+class Connection:
+    def __init__(self, degraded: bool) -> None:
+        self.degraded = degraded
+
+
+class DescribeDoNotCache:
+    """Singleton factories can return DoNotCache to skip caching."""
+
+    def it_returns_the_wrapped_value_without_caching_it(self) -> None:
+        container = RustContainer()
+        attempts = {'count': 0}
+
+        def connect() -> object:
+            attempts['count'] += 1
+            if attempts['count'] == 1:
+                return DoNotCache(Connection(degraded=True))
+            return Connection(degraded=False)
+
+        container.register_singleton_factory(Connection, connect)
+
+        fallback = container.resolve(Connection)
+        real = container.resolve(Connection)
+        cached = container.resolve(Connection)
+
+        assert fallback.degraded is True
+        assert real.degraded is False
+        assert cached is real
+        assert attempts['count'] == 2
+
+    def it_unwraps_values_from_transient_factories(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Connection, lambda: DoNotCache(Connection(degraded=True)))
+
+        resolved = container.resolve(Connection)
+
+        assert isinstance(resolved, Connection)
+
+    def it_exposes_the_wrapped_value(self) -> None:
+        connection = Connection(degraded=True)
+
+        assert DoNotCache(connection).value is connection
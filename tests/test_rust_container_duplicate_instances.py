@@ -0,0 +1,57 @@
+"""Tests for reporting singletons shared across keys."""
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class EmailPort:
+    pass
+
+
+class SmsPort:
+    pass
+
+
+class NotificationAdapter(EmailPort, SmsPort):
+    pass
+
+
+class Database:
+    pass
+
+
+class DescribeDuplicateInstances:
+    """duplicate_instances() groups objects held under several keys."""
+
+    def it_reports_an_instance_aliased_under_two_keys(self) -> None:
+        container = RustContainer()
+        adapter = NotificationAdapter()
+        container.register_instance(SmsPort, adapter)
+        container.register_instance(EmailPort, adapter)
+
+        [group] = container.duplicate_instances()
+
+        assert group['instance'] is adapter
+        assert group['keys'] == [EmailPort, SmsPort]
+
+    def it_reports_nothing_for_distinct_instances(self) -> None:
+        container = RustContainer()
+        container.register_instance(EmailPort, NotificationAdapter())
+        container.register_instance(SmsPort, NotificationAdapter())
+
+        assert container.duplicate_instances() == []
+
+    def it_includes_cached_singletons(self) -> None:
+        container = RustContainer()
+        adapter = NotificationAdapter()
+        container.register_instance(EmailPort, adapter)
+        container.register_singleton_factory(SmsPort, lambda: container.resolve(EmailPort))
+        container.register_singleton_factory(Database, Database)
+
+        assert container.duplicate_instances() == []
+
+        container.resolve(SmsPort)
+        container.resolve(Database)
+
+        [group] = container.duplicate_instances()
+        assert group['instance'] is adapter
+        assert group['keys'] == [EmailPort, SmsPort]
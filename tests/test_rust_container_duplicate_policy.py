@@ -0,0 +1,110 @@
+"""Tests for the duplicate-registration policy."""
+
+from types import ModuleType
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DuplicatePolicy, DuplicateRegistrationError, provider
+
+
+class Database:
+    pass
+
+
+class FakeDatabase(Database):
+    pass
+
+
+class DescribeDuplicatePolicy:
+    """A second registration errors, replaces or is ignored per the policy."""
+
+    def it_rejects_duplicates_by_default(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        assert container.duplicate_policy() == DuplicatePolicy.ERROR
+        with pytest.raises(DuplicateRegistrationError):
+            container.register_class(Database, FakeDatabase)
+
+    def it_replaces_per_call(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        container.register_class(Database, FakeDatabase, on_duplicate=DuplicatePolicy.REPLACE)
+
+        assert isinstance(container.resolve(Database), FakeDatabase)
+
+    def it_evicts_the_singleton_of_a_replaced_provider(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+        original = container.resolve(Database)
+
+        container.register_singleton_factory(Database, FakeDatabase, on_duplicate='replace')
+
+        replacement = container.resolve(Database)
+        assert replacement is not original
+        assert isinstance(replacement, FakeDatabase)
+
+    def it_evicts_tenant_instances_of_a_replaced_provider(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Database, Database)
+        container.resolve(Database, tenant='acme')
+
+        container.register_tenant_factory(Database, FakeDatabase, on_duplicate='replace')
+
+        assert isinstance(container.resolve(Database, tenant='acme'), FakeDatabase)
+
+    def it_ignores_per_call(self) -> None:
+        container = RustContainer()
+        database = Database()
+        container.register_instance(Database, database)
+
+        container.register_instance(Database, FakeDatabase(), on_duplicate='ignore')
+
+        assert container.resolve(Database) is database
+
+    def it_applies_the_container_policy(self) -> None:
+        container = RustContainer(on_duplicate='replace')
+        container.register_transient_factory(Database, Database)
+
+        container.register_transient_factory(Database, FakeDatabase)
+
+        assert container.duplicate_policy() == DuplicatePolicy.REPLACE
+        assert isinstance(container.resolve(Database), FakeDatabase)
+
+    def it_lets_a_call_override_the_container_policy(self) -> None:
+        container = RustContainer()
+        container.set_duplicate_policy(DuplicatePolicy.IGNORE)
+        container.register_class(Database, Database)
+
+        with pytest.raises(DuplicateRegistrationError):
+            container.register_class(Database, FakeDatabase, on_duplicate='error')
+
+    def it_applies_the_container_policy_to_builders(self) -> None:
+        container = RustContainer(on_duplicate=DuplicatePolicy.REPLACE)
+        container.register_class(Database, Database)
+
+        container.register(Database).as_class(FakeDatabase).done()
+
+        assert isinstance(container.resolve(Database), FakeDatabase)
+
+    def it_applies_the_container_policy_to_modules(self) -> None:
+        @provider
+        class Settings:
+            pass
+
+        Settings.__module__ = 'app.settings'
+        module = ModuleType('app.settings')
+        module.Settings = Settings
+        container = RustContainer(on_duplicate='ignore')
+        settings = Settings()
+        container.register_instance(Settings, settings)
+
+        container.register_module(module)
+
+        assert container.resolve(Settings) is settings
+
+    def it_rejects_unknown_policies(self) -> None:
+        with pytest.raises(TypeError, match='Unknown duplicate policy'):
+            RustContainer(on_duplicate='overwrite')
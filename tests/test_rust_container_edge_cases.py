@@ -42,7 +42,7 @@ class DescribeRustContainerSingletonCaching:
         assert first is second is third
 
     def it_calls_transient_factory_every_time(self) -> None:
-        """Class provider creates new instance on every resolution."""
+        """Transient factory creates new instance on every resolution."""
         container = RustContainer()
         call_count = {'count': 0}
 
@@ -51,7 +51,7 @@ class DescribeRustContainerSingletonCaching:
                 call_count['count'] += 1
                 self.instance_num = call_count['count']
 
-        container.register_class(Service, Service)
+        container.register_transient_factory(Service, Service)
 
         # Resolve multiple times
         first = container.resolve(Service)
@@ -280,7 +280,6 @@ class DescribeRustContainerDeepDependencyChains:
 class DescribeRustContainerCircularDependencies:
     """Tests for circular dependency detection."""
 
-    @pytest.mark.skip(reason='Circular dependency detection not yet implemented in Rust container')
     def it_detects_direct_circular_dependencies(self) -> None:
         """Detects when service A depends on service B which depends on A."""
         container = RustContainer()
@@ -310,7 +309,6 @@ class DescribeRustContainerCircularDependencies:
         error_msg = str(exc_info.value).lower()
         assert 'circular' in error_msg or 'recursion' in error_msg or 'cycle' in error_msg
 
-    @pytest.mark.skip(reason='Circular dependency detection not yet implemented in Rust container')
     def it_detects_indirect_circular_dependencies(self) -> None:
         """Detects circular dependencies through multiple services (A -> B -> C -> A)."""
         container = RustContainer()
@@ -347,7 +345,6 @@ class DescribeRustContainerCircularDependencies:
         error_msg = str(exc_info.value).lower()
         assert 'circular' in error_msg or 'recursion' in error_msg or 'cycle' in error_msg
 
-    @pytest.mark.skip(reason='Circular dependency detection not yet implemented in Rust container')
     def it_detects_self_dependency(self) -> None:
         """Detects when a service tries to resolve itself."""
         container = RustContainer()
@@ -0,0 +1,107 @@
+"""Tests for registering providers keyed by enum members in the Rust container."""
+
+from enum import Enum, Flag, auto
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Environment(Enum):
+    DEV = 'dev'
+    STAGING = 'staging'
+    PROD = 'prod'
+
+
+class Permission(Flag):
+    READ = auto()
+    WRITE = auto()
+
+
+class DatabaseConfig:
+    def __init__(self, url: str) -> None:
+        self.url = url
+
+
+class DescribeEnumMemberKeys:
+    """Providers registered per enum value."""
+
+    def it_resolves_each_enum_member_to_its_config(self) -> None:
+        container = RustContainer()
+        dev_config = DatabaseConfig('sqlite://')
+        prod_config = DatabaseConfig('postgres://prod')
+
+        container.register_instance(Environment.DEV, dev_config)
+        container.register_instance(Environment.PROD, prod_config)
+
+        assert container.resolve(Environment.DEV) is dev_config
+        assert container.resolve(Environment.PROD) is prod_config
+
+    def it_supports_factories_keyed_by_enum_member(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Environment.PROD, lambda: DatabaseConfig('postgres://prod'))
+
+        first = container.resolve(Environment.PROD)
+        second = container.resolve(Environment.PROD)
+
+        assert first.url == 'postgres://prod'
+        assert first is second
+
+    def it_keeps_enum_member_keys_separate_from_type_keys(self) -> None:
+        container = RustContainer()
+        config = DatabaseConfig('sqlite://')
+        container.register_instance(Environment.DEV, config)
+
+        assert container.contains(Environment.DEV)
+        assert not container.contains(Environment)
+        assert not container.contains(Environment.PROD)
+
+    def it_looks_up_members_by_value(self) -> None:
+        container = RustContainer()
+        config = DatabaseConfig('postgres://prod')
+        container.register_instance(Environment.PROD, config)
+
+        assert container.resolve(Environment('prod')) is config
+
+    def it_supports_flag_members(self) -> None:
+        container = RustContainer()
+        container.register_instance(Permission.READ, 'read-only')
+        container.register_instance(Permission.READ | Permission.WRITE, 'read-write')
+
+        assert container.resolve(Permission.READ) == 'read-only'
+        assert container.resolve(Permission.READ | Permission.WRITE) == 'read-write'
+
+    def it_lists_registered_members_when_member_is_missing(self) -> None:
+        container = RustContainer()
+        container.register_instance(Environment.PROD, DatabaseConfig('postgres://prod'))
+        container.register_instance(Environment.DEV, DatabaseConfig('sqlite://'))
+
+        with pytest.raises(KeyError) as exc_info:
+            container.resolve(Environment.STAGING)
+
+        message = str(exc_info.value)
+        assert 'Environment.STAGING' in message
+        assert 'Environment.DEV, Environment.PROD' in message
+
+    def it_rejects_keys_that_are_neither_types_nor_enum_members(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(TypeError, match='type or an enum member'):
+            container.register_instance('not-a-key', object())
+
+    def it_rejects_duplicate_enum_member_registration(self) -> None:
+        container = RustContainer()
+        container.register_instance(Environment.DEV, DatabaseConfig('sqlite://'))
+
+        with pytest.raises(KeyError, match='Duplicate'):
+            container.register_instance(Environment.DEV, DatabaseConfig('sqlite://other'))
+
+    def it_includes_enum_members_in_registered_types(self) -> None:
+        container = RustContainer()
+        container.register_instance(Environment.DEV, DatabaseConfig('sqlite://'))
+        container.register_instance(DatabaseConfig, DatabaseConfig('sqlite://'))
+
+        registered = container.get_registered_types()
+
+        assert Environment.DEV in registered
+        assert DatabaseConfig in registered
@@ -0,0 +1,109 @@
+"""Tests for environment-variable providers registered with register_env()."""
+
+import os
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import EnvironmentVariableError
+
+
+class ApiKey(str):
+    pass
+
+
+class Port(int):
+    pass
+
+
+class Debug:
+    pass
+
+
+class Client:
+    def __init__(self, api_key: ApiKey, port: Port) -> None:
+        self.api_key = api_key
+        self.port = port
+
+
+class DescribeRegisterEnv:
+    """An env provider reads its variable on resolve and casts the value."""
+
+    def setup_method(self) -> None:
+        for variable in ('DIOXIDE_TEST_API_KEY', 'DIOXIDE_TEST_PORT', 'DIOXIDE_TEST_DEBUG'):
+            os.environ.pop(variable, None)
+
+    def it_reads_and_casts_the_variable(self) -> None:
+        os.environ['DIOXIDE_TEST_API_KEY'] = 'sk_test'
+        container = RustContainer()
+        container.register_env(ApiKey, 'DIOXIDE_TEST_API_KEY', cast=ApiKey)
+
+        api_key = container.resolve(ApiKey)
+
+        assert api_key == 'sk_test'
+        assert isinstance(api_key, ApiKey)
+
+    def it_injects_values_into_constructors(self) -> None:
+        os.environ['DIOXIDE_TEST_API_KEY'] = 'sk_test'
+        os.environ['DIOXIDE_TEST_PORT'] = '8080'
+        container = RustContainer()
+        container.register_env(ApiKey, 'DIOXIDE_TEST_API_KEY')
+        container.register_env(Port, 'DIOXIDE_TEST_PORT', cast=int)
+        container.register_transient_factory(Client, Client)
+
+        client = container.resolve(Client)
+
+        assert (client.api_key, client.port) == ('sk_test', 8080)
+
+    def it_rereads_the_variable_unless_cached(self) -> None:
+        container = RustContainer()
+        container.register_env(ApiKey, 'DIOXIDE_TEST_API_KEY')
+        container.register_env(Port, 'DIOXIDE_TEST_PORT', cast=int, cache=True)
+        os.environ['DIOXIDE_TEST_API_KEY'] = 'first'
+        os.environ['DIOXIDE_TEST_PORT'] = '1'
+        container.resolve(ApiKey)
+        container.resolve(Port)
+
+        os.environ['DIOXIDE_TEST_API_KEY'] = 'second'
+        os.environ['DIOXIDE_TEST_PORT'] = '2'
+
+        assert container.resolve(ApiKey) == 'second'
+        assert container.resolve(Port) == 1
+
+    def it_parses_boolean_words(self) -> None:
+        container = RustContainer()
+        container.register_env(Debug, 'DIOXIDE_TEST_DEBUG', cast=bool)
+
+        os.environ['DIOXIDE_TEST_DEBUG'] = 'false'
+        assert container.resolve(Debug) is False
+        os.environ['DIOXIDE_TEST_DEBUG'] = 'Yes'
+        assert container.resolve(Debug) is True
+
+    def it_names_a_missing_required_variable(self) -> None:
+        container = RustContainer()
+        container.register_env(ApiKey, 'DIOXIDE_TEST_API_KEY')
+
+        with pytest.raises(EnvironmentVariableError, match='DIOXIDE_TEST_API_KEY is not set'):
+            container.resolve(ApiKey)
+
+    def it_falls_back_to_the_default_or_none(self) -> None:
+        container = RustContainer()
+        container.register_env(Port, 'DIOXIDE_TEST_PORT', cast=int, default=5432)
+        container.register_env(ApiKey, 'DIOXIDE_TEST_API_KEY', required=False)
+
+        assert container.resolve(Port) == 5432
+        assert container.resolve(ApiKey) is None
+
+    def it_reports_a_value_the_cast_rejects(self) -> None:
+        os.environ['DIOXIDE_TEST_PORT'] = 'eighty'
+        container = RustContainer()
+        container.register_env(Port, 'DIOXIDE_TEST_PORT', cast=int)
+
+        with pytest.raises(EnvironmentVariableError, match="DIOXIDE_TEST_PORT is invalid: ValueError: invalid literal"):
+            container.resolve(Port)
+
+    def it_reports_the_env_provider_kind(self) -> None:
+        container = RustContainer()
+        container.register_env(ApiKey, 'DIOXIDE_TEST_API_KEY')
+
+        assert container.all_bindings(ApiKey)[0]['provider'] == 'env'
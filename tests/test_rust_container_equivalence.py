@@ -0,0 +1,66 @@
+"""Tests for comparing container wiring to detect repeated bootstrap."""
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class Handler:
+    pass
+
+
+def bootstrap(container: RustContainer) -> None:
+    container.register_instance(Config, Config())
+    container.register_singleton_factory(Database, lambda: Database())
+    container.register_transient_factory(Handler, lambda: Handler())
+
+
+class DescribeIsEquivalentTo:
+    """is_equivalent_to() compares wiring, not live instances."""
+
+    def it_matches_identically_wired_containers(self) -> None:
+        first = RustContainer()
+        second = RustContainer()
+        bootstrap(first)
+        bootstrap(second)
+
+        assert first.is_equivalent_to(second)
+        assert second.is_equivalent_to(first)
+
+    def it_ignores_live_instances(self) -> None:
+        first = RustContainer()
+        second = RustContainer()
+        bootstrap(first)
+        bootstrap(second)
+
+        first.resolve(Database)
+
+        assert first.is_equivalent_to(second)
+
+    def it_matches_itself(self) -> None:
+        container = RustContainer()
+        bootstrap(container)
+
+        assert container.is_equivalent_to(container)
+
+    def it_detects_missing_registrations(self) -> None:
+        first = RustContainer()
+        second = RustContainer()
+        bootstrap(first)
+        second.register_instance(Config, Config())
+
+        assert not first.is_equivalent_to(second)
+
+    def it_detects_a_different_provider_kind(self) -> None:
+        first = RustContainer()
+        second = RustContainer()
+        first.register_singleton_factory(Database, Database)
+        second.register_transient_factory(Database, Database)
+
+        assert not first.is_equivalent_to(second)
@@ -0,0 +1,85 @@
+"""Tests for keeping the original exception as the __cause__ of container errors."""
+
+import traceback
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DioxideError
+
+
+class Database:
+    pass
+
+
+class Repository:
+    def __init__(self, database: Database) -> None:
+        self.database = database
+
+
+def connect() -> Database:
+    raise ConnectionRefusedError('database is down')
+
+
+class Exploding:
+    def __init__(self) -> None:
+        raise ValueError('bad settings')
+
+
+class DescribeExceptionCauses:
+    """A failing factory or constructor is chained, not only stringified."""
+
+    def it_chains_the_factory_exception(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, connect)
+
+        with pytest.raises(DioxideError) as caught:
+            container.resolve(Database)
+
+        assert isinstance(caught.value.__cause__, ConnectionRefusedError)
+        assert str(caught.value.__cause__) == 'database is down'
+
+    def it_chains_the_constructor_exception(self) -> None:
+        container = RustContainer()
+        container.register_class(Exploding, Exploding)
+
+        with pytest.raises(DioxideError) as caught:
+            container.resolve(Exploding)
+
+        assert isinstance(caught.value.__cause__, ValueError)
+
+    def it_keeps_the_original_traceback(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, connect)
+
+        with pytest.raises(DioxideError) as caught:
+            container.resolve(Database)
+
+        frames = traceback.extract_tb(caught.value.__cause__.__traceback__)
+        assert frames[-1].name == 'connect'
+
+    def it_chains_failures_of_dependencies(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, connect)
+        container.register_class(Repository, Repository)
+
+        with pytest.raises(DioxideError) as caught:
+            container.resolve(Repository)
+
+        cause = caught.value.__cause__
+        while isinstance(cause, DioxideError):
+            cause = cause.__cause__
+        assert isinstance(cause, ConnectionRefusedError)
+
+    def it_keeps_the_message_of_the_original_exception(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, connect)
+
+        with pytest.raises(DioxideError, match='ConnectionRefusedError: database is down'):
+            container.resolve(Database)
+
+    def it_does_not_chain_container_errors_of_its_own(self) -> None:
+        with pytest.raises(DioxideError) as caught:
+            RustContainer().resolve(Database)
+
+        assert caught.value.__cause__ is None
@@ -0,0 +1,192 @@
+"""Tests for the exception hierarchy raised by the Rust container core."""
+
+import pytest
+
+from dioxide._dioxide_core import CircularDependencyError
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import (
+    DependencyNotRegisteredError,
+    DioxideError,
+    DuplicateRegistrationError,
+    FallbackCycleError,
+    NotCachedError,
+    ProviderRegistrationError,
+    SingletonsFrozenError,
+    TenantRequiredError,
+    UnresolvableParameterError,
+)
+
+
+class Config:
+    pass
+
+
+class Repository:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Service:
+    def __init__(self, repository: Repository) -> None:
+        self.repository = repository
+
+
+class Mailer:
+    def __init__(self, host: str) -> None:
+        self.host = host
+
+
+class Left:
+    def __init__(self, right: 'Right') -> None:
+        self.right = right
+
+
+class Right:
+    def __init__(self, left: Left) -> None:
+        self.left = left
+
+
+class DescribeExceptionHierarchy:
+    """Every core error is a DioxideError, which is a KeyError."""
+
+    @pytest.mark.parametrize(
+        'error',
+        [
+            DependencyNotRegisteredError,
+            DuplicateRegistrationError,
+            ProviderRegistrationError,
+            CircularDependencyError,
+            UnresolvableParameterError,
+            NotCachedError,
+            SingletonsFrozenError,
+            TenantRequiredError,
+            FallbackCycleError,
+        ],
+    )
+    def it_derives_every_error_from_the_base(self, error: type[DioxideError]) -> None:
+        assert issubclass(error, DioxideError)
+        assert issubclass(error, KeyError)
+
+    def it_raises_dependency_not_registered(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(DependencyNotRegisteredError) as excinfo:
+            container.resolve(Config)
+
+        assert excinfo.value.requested_type is Config
+        assert excinfo.value.resolution_path == [Config]
+
+    def it_stays_catchable_as_key_error(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='Dependency not registered: Config'):
+            container.resolve(Config)
+
+    def it_raises_duplicate_registration(self) -> None:
+        container = RustContainer()
+        container.register_instance(Config, Config())
+
+        with pytest.raises(DuplicateRegistrationError) as excinfo:
+            container.register_instance(Config, Config())
+
+        assert excinfo.value.requested_type is Config
+        assert excinfo.value.resolution_path == []
+
+    def it_raises_unresolvable_parameter(self) -> None:
+        container = RustContainer()
+        container.register_class(Mailer, Mailer)
+
+        with pytest.raises(UnresolvableParameterError):
+            container.resolve(Mailer)
+
+    def it_raises_tenant_required(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Config, Config)
+
+        with pytest.raises(TenantRequiredError):
+            container.resolve(Config)
+
+    def it_raises_fallback_cycle(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(FallbackCycleError):
+            container.add_fallback_container(container)
+
+    def it_raises_not_cached(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+
+        with pytest.raises(NotCachedError):
+            container.resolve_cached_only([Config])
+
+
+class DescribeResolutionPath:
+    """resolution_path lists the keys under construction when resolve failed."""
+
+    def it_reports_the_chain_to_an_unwirable_dependency(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+        container.register_class(Repository, Repository)
+
+        with pytest.raises(UnresolvableParameterError) as excinfo:
+            container.resolve(Service)
+
+        assert excinfo.value.requested_type is Service
+        assert excinfo.value.resolution_path == [Service, Repository]
+
+    def it_reports_the_chain_to_a_failing_provider(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+        container.register_class(Repository, Repository)
+        container.register_singleton_factory(Config, lambda: container.resolve(Mailer))
+
+        with pytest.raises(DioxideError) as excinfo:
+            container.resolve(Service)
+
+        assert excinfo.value.resolution_path == [Service, Repository, Config]
+
+    def it_reports_the_cycle(self) -> None:
+        container = RustContainer()
+        container.register_class(Left, Left)
+        container.register_class(Right, Right)
+
+        with pytest.raises(CircularDependencyError) as excinfo:
+            container.resolve(Left)
+
+        assert excinfo.value.resolution_path == [Left, Right, Left]
+
+    def it_does_not_leak_into_later_errors(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+        with pytest.raises(DioxideError):
+            container.resolve(Service)
+
+        with pytest.raises(DuplicateRegistrationError) as excinfo:
+            container.register_class(Service, Service)
+
+        assert excinfo.value.resolution_path == []
+
+    def it_renders_the_chain_in_the_message(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+        container.register_class(Repository, Repository)
+
+        with pytest.raises(
+            UnresolvableParameterError, match=r'\(while resolving Service, needed Repository\)'
+        ):
+            container.resolve(Service)
+
+    def it_renders_the_chain_to_a_failing_provider(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+        container.register_class(Repository, Repository)
+        container.register_singleton_factory(Config, lambda: container.resolve(Mailer))
+
+        with pytest.raises(DioxideError, match=r'\(while resolving Service, needed Repository, needed Config\)'):
+            container.resolve(Service)
+
+    def it_leaves_direct_failures_unannotated(self) -> None:
+        with pytest.raises(DependencyNotRegisteredError) as excinfo:
+            RustContainer().resolve(Config)
+
+        assert 'while resolving' not in str(excinfo.value)
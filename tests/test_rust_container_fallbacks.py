@@ -0,0 +1,83 @@
+"""Tests for resolving through a chain of fallback containers."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Settings:
+    def __init__(self, source: str) -> None:
+        self.source = source
+
+
+class Database:
+    pass
+
+
+class Clock:
+    pass
+
+
+class DescribeFallbackContainers:
+    """add_fallback_container() layers containers without merging them."""
+
+    def it_resolves_from_the_first_fallback_that_has_the_type(self) -> None:
+        runtime = RustContainer()
+        environment = RustContainer()
+        defaults = RustContainer()
+        environment.register_instance(Settings, Settings('environment'))
+        defaults.register_instance(Settings, Settings('defaults'))
+        defaults.register_instance(Clock, Clock())
+        runtime.add_fallback_container(environment)
+        runtime.add_fallback_container(defaults)
+
+        assert runtime.resolve(Settings).source == 'environment'
+        assert isinstance(runtime.resolve(Clock), Clock)
+
+    def it_prefers_its_own_registrations(self) -> None:
+        runtime = RustContainer()
+        defaults = RustContainer()
+        runtime.register_instance(Settings, Settings('runtime'))
+        defaults.register_instance(Settings, Settings('defaults'))
+        runtime.add_fallback_container(defaults)
+
+        assert runtime.resolve(Settings).source == 'runtime'
+
+    def it_caches_singletons_in_the_originating_fallback(self) -> None:
+        runtime = RustContainer()
+        defaults = RustContainer()
+        defaults.register_singleton_factory(Database, Database)
+        runtime.add_fallback_container(defaults)
+
+        via_runtime = runtime.resolve(Database)
+
+        assert defaults.resolve(Database) is via_runtime
+        with pytest.raises(KeyError):
+            runtime.resolve_cached_only([Database])
+
+    def it_follows_nested_fallbacks(self) -> None:
+        runtime = RustContainer()
+        environment = RustContainer()
+        defaults = RustContainer()
+        defaults.register_instance(Settings, Settings('defaults'))
+        environment.add_fallback_container(defaults)
+        runtime.add_fallback_container(environment)
+
+        assert runtime.resolve(Settings).source == 'defaults'
+
+    def it_errors_when_no_layer_has_the_type(self) -> None:
+        runtime = RustContainer()
+        runtime.add_fallback_container(RustContainer())
+
+        with pytest.raises(KeyError, match='Dependency not registered: Clock'):
+            runtime.resolve(Clock)
+
+    def it_rejects_fallback_cycles(self) -> None:
+        first = RustContainer()
+        second = RustContainer()
+        first.add_fallback_container(second)
+
+        with pytest.raises(KeyError, match='cycle'):
+            second.add_fallback_container(first)
+        with pytest.raises(KeyError, match='cycle'):
+            first.add_fallback_container(first)
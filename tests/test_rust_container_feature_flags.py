@@ -0,0 +1,112 @@
+"""Tests for injecting Annotated[X, Flag(name)] parameters from set_flag()."""
+
+from __future__ import annotations
+
+from typing import Annotated
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import Flag, ValidationError
+
+
+class Checkout:
+    def __init__(self, express: Annotated[bool, Flag('express_checkout')]) -> None:
+        self.express = express
+
+
+class Banner:
+    def __init__(self, theme: Annotated[str, Flag('theme')] = 'light') -> None:
+        self.theme = theme
+
+
+class Storefront:
+    def __init__(self, checkout: Checkout) -> None:
+        self.checkout = checkout
+
+
+class DescribeFeatureFlags:
+    """Parameters annotated with Flag(name) receive the flag's current value."""
+
+    def it_injects_a_flag(self) -> None:
+        container = RustContainer()
+        container.set_flag('express_checkout', True)
+        container.register_class(Checkout, Checkout)
+
+        assert container.resolve(Checkout).express is True
+
+    def it_keeps_the_default_until_the_flag_is_set(self) -> None:
+        container = RustContainer()
+        container.register_class(Banner, Banner, lifetime='transient')
+
+        assert container.resolve(Banner).theme == 'light'
+
+        container.set_flag('theme', 'dark')
+
+        assert container.resolve(Banner).theme == 'dark'
+
+    def it_fails_for_an_unset_flag_without_a_default(self) -> None:
+        container = RustContainer()
+        container.register_class(Checkout, Checkout)
+
+        with pytest.raises(KeyError, match="needs feature flag 'express_checkout', which is not set"):
+            container.resolve(Checkout)
+
+    def it_reports_unset_flags_when_validating(self) -> None:
+        container = RustContainer()
+        container.register_class(Checkout, Checkout)
+
+        with pytest.raises(ValidationError) as excinfo:
+            container.validate()
+
+        assert excinfo.value.problems == [
+            "Checkout: parameter 'express' needs feature flag 'express_checkout', which is not set"
+        ]
+
+        container.set_flag('express_checkout', False)
+        container.validate()
+
+    def it_keeps_cached_singletons_when_not_invalidating(self) -> None:
+        container = RustContainer()
+        container.set_flag('express_checkout', False)
+        container.register_class(Checkout, Checkout)
+        checkout = container.resolve(Checkout)
+
+        container.set_flag('express_checkout', True)
+
+        assert container.resolve(Checkout) is checkout
+        assert checkout.express is False
+
+    def it_rebuilds_dependent_singletons_when_invalidating(self) -> None:
+        container = RustContainer()
+        container.set_flag('express_checkout', False)
+        container.register_class(Checkout, Checkout)
+        container.register_class(Storefront, Storefront)
+        storefront = container.resolve(Storefront)
+
+        container.set_flag('express_checkout', True, invalidate=True)
+
+        rebuilt = container.resolve(Storefront)
+        assert rebuilt is not storefront
+        assert rebuilt.checkout.express is True
+        assert rebuilt.checkout is container.resolve(Checkout)
+
+    def it_leaves_unrelated_singletons_cached_when_invalidating(self) -> None:
+        container = RustContainer()
+        container.set_flag('express_checkout', False)
+        container.register_class(Checkout, Checkout)
+        container.register_class(Banner, Banner)
+        banner = container.resolve(Banner)
+
+        container.set_flag('express_checkout', True, invalidate=True)
+
+        assert container.resolve(Banner) is banner
+
+    def it_reads_flags_from_a_fallback(self) -> None:
+        parent = RustContainer()
+        parent.set_flag('express_checkout', True)
+        child = RustContainer()
+        child.add_fallback_container(parent)
+        child.register_class(Checkout, Checkout, lifetime='transient')
+
+        assert child.resolve(Checkout).express is True
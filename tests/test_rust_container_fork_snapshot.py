@@ -0,0 +1,147 @@
+"""Tests for pre-fork singleton freezing and cache snapshots."""
+
+import os
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class Handler:
+    pass
+
+
+def make_counting_factory(cls: type, calls: list[str]) -> object:
+    def factory() -> object:
+        calls.append(cls.__name__)
+        return cls()
+
+    return factory
+
+
+class DescribeFreezeSingletons:
+    """freeze_singletons() blocks new singleton construction."""
+
+    def it_serves_singletons_that_are_already_cached(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        config = container.resolve(Config)
+
+        container.freeze_singletons()
+
+        assert container.resolve(Config) is config
+
+    def it_blocks_construction_of_uncached_singletons(self) -> None:
+        container = RustContainer()
+        calls: list[str] = []
+        container.register_singleton_factory(Database, make_counting_factory(Database, calls))
+
+        container.freeze_singletons()
+
+        with pytest.raises(KeyError, match='Cannot create singleton Database'):
+            container.resolve(Database)
+        assert calls == []
+
+    def it_still_builds_transients(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Handler, Handler)
+
+        container.freeze_singletons()
+
+        assert isinstance(container.resolve(Handler), Handler)
+
+    def it_lifts_the_freeze_when_released(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+
+        with container.freeze_singletons():
+            with pytest.raises(KeyError):
+                container.resolve(Database)
+
+        assert isinstance(container.resolve(Database), Database)
+
+
+class DescribeSnapshotForFork:
+    """snapshot_for_fork() lets workers reuse warmed singletons."""
+
+    def it_captures_the_warm_cache_in_creation_order(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        container.register_singleton_factory(Database, Database)
+        container.resolve(Database)
+        container.resolve(Config)
+
+        snapshot = container.snapshot_for_fork()
+
+        assert len(snapshot) == 2
+        assert snapshot.keys() == [Database, Config]
+
+    def it_lets_a_rebuilt_container_reuse_inherited_singletons(self) -> None:
+        calls: list[str] = []
+
+        def build_container() -> RustContainer:
+            container = RustContainer()
+            container.register_singleton_factory(Config, make_counting_factory(Config, calls))
+            return container
+
+        parent = build_container()
+        config = parent.resolve(Config)
+        snapshot = parent.snapshot_for_fork()
+
+        worker = build_container()
+        worker.restore_fork_snapshot(snapshot)
+
+        assert worker.resolve(Config) is config
+        assert calls == ['Config']
+
+    def it_rejects_snapshots_with_unregistered_types(self) -> None:
+        parent = RustContainer()
+        parent.register_singleton_factory(Config, Config)
+        parent.register_singleton_factory(Database, Database)
+        parent.resolve(Config)
+        parent.resolve(Database)
+        worker = RustContainer()
+        worker.register_singleton_factory(Config, Config)
+
+        with pytest.raises(KeyError, match='Database'):
+            worker.restore_fork_snapshot(parent.snapshot_for_fork())
+
+        fresh = worker.resolve(Config)
+        assert fresh is not parent.resolve(Config)
+
+    @pytest.mark.skipif(not hasattr(os, 'fork'), reason='requires os.fork')
+    def it_reuses_inherited_singletons_in_a_forked_worker(self) -> None:
+        calls: list[str] = []
+        container = RustContainer()
+        container.register_singleton_factory(Config, make_counting_factory(Config, calls))
+        container.register_singleton_factory(Database, make_counting_factory(Database, calls))
+        parent_config = container.resolve(Config)
+        container.freeze_singletons()
+
+        read_fd, write_fd = os.pipe()
+        pid = os.fork()
+        if pid == 0:  # pragma: no cover - runs in the child process
+            os.close(read_fd)
+            reused = container.resolve(Config) is parent_config and calls == ['Config']
+            try:
+                container.resolve(Database)
+                blocked = False
+            except KeyError:
+                blocked = True
+            os.write(write_fd, b'ok' if reused and blocked else b'fail')
+            os._exit(0)
+
+        os.close(write_fd)
+        result = os.read(read_fd, 16)
+        os.close(read_fd)
+        os.waitpid(pid, 0)
+
+        assert result == b'ok'
@@ -0,0 +1,137 @@
+"""Stress tests for resolve() racing from many threads, with or without the GIL."""
+
+import threading
+import time
+
+from dioxide._dioxide_core import Container as RustContainer
+
+THREADS = 16
+
+
+class Config:
+    pass
+
+
+class Repository:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+def race(target, count: int = THREADS) -> list[BaseException]:
+    """Run target from count threads released at the same moment, returning their errors."""
+    barrier = threading.Barrier(count)
+    errors: list[BaseException] = []
+
+    def worker() -> None:
+        barrier.wait()
+        try:
+            target()
+        except BaseException as error:
+            errors.append(error)
+
+    threads = [threading.Thread(target=worker) for _ in range(count)]
+    for thread in threads:
+        thread.start()
+    for thread in threads:
+        thread.join()
+    return errors
+
+
+class DescribeFreeThreadedResolution:
+    """Shared singletons are built once however many threads resolve them."""
+
+    def it_builds_a_slow_singleton_factory_once(self) -> None:
+        container = RustContainer()
+        calls = []
+
+        def build() -> Config:
+            calls.append(1)
+            time.sleep(0.05)
+            return Config()
+
+        container.register_singleton_factory(Config, build)
+        seen = []
+
+        assert race(lambda: seen.append(container.resolve(Config))) == []
+
+        assert len(calls) == 1
+        assert len({id(instance) for instance in seen}) == 1
+
+    def it_builds_a_slow_singleton_class_once(self) -> None:
+        container = RustContainer()
+        calls = []
+
+        class Slow:
+            def __init__(self) -> None:
+                calls.append(1)
+                time.sleep(0.05)
+
+        container.register_class(Slow, Slow)
+        seen = []
+
+        assert race(lambda: seen.append(container.resolve(Slow))) == []
+
+        assert len(calls) == 1
+        assert len({id(instance) for instance in seen}) == 1
+
+    def it_shares_singleton_dependencies_of_racing_transients(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_transient_factory(Repository, Repository)
+        seen = []
+
+        assert race(lambda: seen.extend(container.resolve(Repository) for _ in range(50))) == []
+
+        assert len(seen) == THREADS * 50
+        assert len({id(repository.config) for repository in seen}) == 1
+
+    def it_reports_a_cycle_split_across_threads_instead_of_deadlocking(self) -> None:
+        container = RustContainer()
+        building = threading.Barrier(2)
+        calls = []
+
+        class First:
+            pass
+
+        class Second:
+            pass
+
+        def first() -> First:
+            calls.append(First)
+            if len(calls) <= 2:
+                building.wait(timeout=5)
+            container.resolve(Second)
+            return First()
+
+        def second() -> Second:
+            calls.append(Second)
+            if len(calls) <= 2:
+                building.wait(timeout=5)
+            container.resolve(First)
+            return Second()
+
+        container.register_singleton_factory(First, first)
+        container.register_singleton_factory(Second, second)
+        started = [First, Second]
+
+        errors = race(lambda: container.resolve(started.pop()), count=2)
+
+        assert len(errors) == 2
+        assert all('Circular dependency detected' in str(error) for error in errors)
+
+    def it_resolves_while_other_threads_register(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        keys = [type(f'Registered{index}', (), {}) for index in range(THREADS * 20)]
+        config = container.resolve(Config)
+
+        def work() -> None:
+            for _ in range(20):
+                key = keys.pop()
+                container.register_singleton_factory(key, key)
+                assert container.resolve(key) is container.resolve(key)
+                assert container.resolve(Config) is config
+
+        assert race(work) == []
+
+        assert container.stats()['cache_size'] == THREADS * 20 + 1
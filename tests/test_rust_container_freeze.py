@@ -0,0 +1,133 @@
+"""Tests for sealing the registry with freeze()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import FrozenContainerError
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Request:
+    pass
+
+
+class Wrapped:
+    def __init__(self, inner: object) -> None:
+        self.inner = inner
+
+
+def frozen_container() -> RustContainer:
+    container = RustContainer()
+    container.register_class(Config, Config)
+    container.register_class(Database, Database)
+    container.register_transient_factory(Request, Request)
+    container.freeze()
+    return container
+
+
+class DescribeFreeze:
+    """freeze() seals the registry; resolves keep working, changes are rejected."""
+
+    def it_reports_whether_the_container_is_frozen(self) -> None:
+        container = RustContainer()
+        assert not container.is_frozen()
+
+        container.freeze()
+
+        assert container.is_frozen()
+
+    def it_resolves_from_the_sealed_registry(self) -> None:
+        container = frozen_container()
+
+        database = container.resolve(Database)
+
+        assert database is container.resolve(Database)
+        assert database.config is container.resolve(Config)
+        assert container.resolve(Request) is not container.resolve(Request)
+        assert container.contains(Database)
+
+    def it_still_creates_singletons_lazily(self) -> None:
+        container = frozen_container()
+
+        assert container.construction_log() == []
+
+        container.resolve(Config)
+
+        assert container.construction_log() == ['Config']
+
+    def it_keeps_decorators_added_before_freezing(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+        container.add_decorator(Request, Wrapped)
+        container.freeze()
+
+        assert isinstance(container.resolve(Request), Wrapped)
+
+    def it_rejects_registration(self) -> None:
+        container = frozen_container()
+
+        with pytest.raises(FrozenContainerError, match='Cannot register Wrapped: container is frozen'):
+            container.register_class(Wrapped, Wrapped)
+
+        assert not container.contains(Wrapped)
+
+    def it_rejects_registration_for_another_profile(self) -> None:
+        container = RustContainer(profile='production')
+        container.freeze()
+
+        with pytest.raises(FrozenContainerError, match='Cannot register Config'):
+            container.register_class(Config, Config, profile='test')
+
+    def it_rejects_every_other_registry_change(self) -> None:
+        container = frozen_container()
+
+        with pytest.raises(FrozenContainerError, match='Cannot unregister Config'):
+            container.unregister(Config)
+        with pytest.raises(FrozenContainerError, match='Cannot decorate Request'):
+            container.add_decorator(Request, Wrapped)
+        with pytest.raises(FrozenContainerError, match='Cannot rebind Request'):
+            container.rebind_factory(Request, Request)
+        with pytest.raises(FrozenContainerError, match='Cannot clear'):
+            container.clear()
+
+        assert len(container) == 3
+
+    def it_does_not_run_register_hooks_for_rejected_registrations(self) -> None:
+        container = RustContainer()
+        seen = []
+        container.on_register(lambda *event: seen.append(event))
+        container.freeze()
+
+        with pytest.raises(FrozenContainerError):
+            container.register_class(Config, Config)
+
+        assert seen == []
+
+    def it_still_resets_cached_instances(self) -> None:
+        container = frozen_container()
+        first = container.resolve(Config)
+
+        container.reset()
+
+        assert container.resolve(Config) is not first
+
+    def it_is_idempotent(self) -> None:
+        container = frozen_container()
+
+        container.freeze()
+
+        assert isinstance(container.resolve(Database), Database)
+
+    def it_reports_unregistered_keys_as_before(self) -> None:
+        container = frozen_container()
+
+        with pytest.raises(KeyError, match='Dependency not registered: Wrapped'):
+            container.resolve(Wrapped)
@@ -0,0 +1,165 @@
+"""Tests for generator factories and their teardown by close_generators()."""
+
+from __future__ import annotations
+
+from collections.abc import Generator
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Connection:
+    def __init__(self, config: Config | None = None) -> None:
+        self.config = config
+        self.open = True
+
+
+class Session:
+    pass
+
+
+class DescribeGeneratorFactories:
+    """The first yield is the instance; the rest runs on close_generators()."""
+
+    def it_resolves_the_yielded_instance(self) -> None:
+        def connect() -> Generator[Connection, None, None]:
+            connection = Connection()
+            yield connection
+            connection.open = False
+
+        container = RustContainer()
+        container.register_generator_factory(Connection, connect)
+
+        connection = container.resolve(Connection)
+
+        assert connection.open
+        assert container.resolve(Connection) is connection
+
+    def it_runs_the_teardown_on_close(self) -> None:
+        def connect() -> Generator[Connection, None, None]:
+            connection = Connection()
+            yield connection
+            connection.open = False
+
+        container = RustContainer()
+        container.register_generator_factory(Connection, connect)
+        connection = container.resolve(Connection)
+
+        assert container.close_generators() == []
+
+        assert not connection.open
+        assert container.resolve(Connection) is not connection
+
+    def it_tears_down_in_reverse_creation_order(self) -> None:
+        events = []
+
+        def make(name: str, cls: type) -> object:
+            def factory() -> Generator[object, None, None]:
+                yield cls()
+                events.append(name)
+
+            return factory
+
+        container = RustContainer()
+        container.register_generator_factory(Connection, make('connection', Connection))
+        container.register_generator_factory(Session, make('session', Session))
+        container.resolve(Connection)
+        container.resolve(Session)
+
+        container.close_generators()
+
+        assert events == ['session', 'connection']
+
+    def it_starts_a_generator_per_transient_resolve(self) -> None:
+        closed = []
+
+        def open_session() -> Generator[Session, None, None]:
+            session = Session()
+            yield session
+            closed.append(session)
+
+        container = RustContainer()
+        container.register_generator_factory(Session, open_session, lifetime='transient')
+        first = container.resolve(Session)
+        second = container.resolve(Session)
+
+        container.close_generators()
+
+        assert first is not second
+        assert closed == [second, first]
+
+    def it_auto_wires_generator_dependencies(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+
+        def connect() -> Generator[Connection, None, None]:
+            yield Connection(container.resolve(Config))
+
+        container.register_generator_factory(Connection, connect)
+
+        assert container.resolve(Connection).config is container.resolve(Config)
+
+    def it_reports_failing_teardowns_and_keeps_going(self) -> None:
+        events = []
+
+        def broken() -> Generator[Connection, None, None]:
+            yield Connection()
+            raise RuntimeError('socket already closed')
+
+        def fine() -> Generator[Session, None, None]:
+            yield Session()
+            events.append('session')
+
+        container = RustContainer()
+        container.register_generator_factory(Session, fine)
+        container.register_generator_factory(Connection, broken)
+        container.resolve(Session)
+        container.resolve(Connection)
+
+        reports = container.close_generators()
+
+        assert reports == ['Connection: teardown failed: RuntimeError: socket already closed']
+        assert events == ['session']
+
+    def it_reports_generators_that_yield_twice(self) -> None:
+        def greedy() -> Generator[Connection, None, None]:
+            yield Connection()
+            yield Connection()
+
+        container = RustContainer()
+        container.register_generator_factory(Connection, greedy)
+        container.resolve(Connection)
+
+        assert container.close_generators() == ['Connection: generator factory yielded more than once']
+
+    def it_rejects_generators_that_never_yield(self) -> None:
+        def empty() -> Generator[Connection, None, None]:
+            return
+            yield
+
+        container = RustContainer()
+        container.register_generator_factory(Connection, empty)
+
+        with pytest.raises(KeyError, match='did not yield'):
+            container.resolve(Connection)
+
+    def it_closes_only_once(self) -> None:
+        events = []
+
+        def connect() -> Generator[Connection, None, None]:
+            yield Connection()
+            events.append('closed')
+
+        container = RustContainer()
+        container.register_generator_factory(Connection, connect)
+        container.resolve(Connection)
+
+        container.close_generators()
+        container.close_generators()
+
+        assert events == ['closed']
@@ -0,0 +1,93 @@
+"""Tests for parameterized generic classes as container keys."""
+
+from typing import Generic, TypeVar
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+T = TypeVar('T')
+
+
+class User:
+    pass
+
+
+class Order:
+    pass
+
+
+class Repository(Generic[T]):
+    pass
+
+
+class UserRepository(Repository[User]):
+    pass
+
+
+class OrderRepository(Repository[Order]):
+    pass
+
+
+class SignupService:
+    def __init__(self, users: Repository[User]) -> None:
+        self.users = users
+
+
+class DescribeGenericKeys:
+    """Repository[User] and Repository[Order] are separate bindings."""
+
+    def it_resolves_a_parameterized_key(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository[User], UserRepository)
+
+        assert isinstance(container.resolve(Repository[User]), UserRepository)
+
+    def it_keeps_each_parameterization_separate(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository[User], UserRepository)
+        container.register_class(Repository[Order], OrderRepository)
+
+        assert isinstance(container.resolve(Repository[Order]), OrderRepository)
+        assert container.resolve(Repository[User]) is container.resolve(Repository[User])
+        assert not container.contains(Repository)
+
+    def it_matches_nested_type_arguments(self) -> None:
+        container = RustContainer()
+        users = Repository()
+        container.register_instance(Repository[list[User]], users)
+
+        assert container.resolve(Repository[list[User]]) is users
+        assert not container.contains(Repository[list[Order]])
+
+    def it_supports_builtin_generics(self) -> None:
+        container = RustContainer()
+        container.register_instance(dict[str, int], {'a': 1})
+
+        assert container.resolve(dict[str, int]) == {'a': 1}
+
+    def it_auto_wires_generic_annotations(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository[User], UserRepository)
+        container.register_class(SignupService, SignupService)
+
+        assert isinstance(container.resolve(SignupService).users, UserRepository)
+
+    def it_names_the_parameterization_in_errors(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match=r'Dependency not registered: Repository\[Order\]'):
+            container.resolve(Repository[Order])
+
+    def it_type_checks_against_the_generic_class(self) -> None:
+        container = RustContainer()
+
+        container.register_class(Repository[User], UserRepository, type_check=True)
+        with pytest.raises(KeyError, match='User is not a subclass of Repository'):
+            container.register_class(Repository[Order], User, type_check=True)
+
+    def it_still_rejects_unions(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(TypeError, match='Container key must be'):
+            container.register_instance(User | None, User())
@@ -0,0 +1,214 @@
+"""Tests for the Rust container's structured health check."""
+
+from abc import ABC, abstractmethod
+from typing import Annotated
+
+import pytest
+
+from dioxide._dioxide_core import Config
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DioxideError
+
+
+class Notifier(ABC):
+    @abstractmethod
+    def send(self, message: str) -> None: ...
+
+
+class EmailNotifier(Notifier):
+    def send(self, message: str) -> None:
+        pass
+
+
+class Database:
+    def __init__(self, url: str, pool_size: int = 5) -> None:
+        self.url = url
+
+
+class Clock:
+    pass
+
+
+class Session:
+    pass
+
+
+class Gateway:
+    def __init__(self, url: Annotated[str, Config('gateway.url')]) -> None:
+        self.url = url
+
+
+class Repository:
+    def __init__(self, session: Session) -> None:
+        self.session = session
+
+
+class Left:
+    def __init__(self, right: 'Right') -> None:
+        self.right = right
+
+
+class Right:
+    def __init__(self, left: Left) -> None:
+        self.left = left
+
+
+class DescribeHealthcheck:
+    """healthcheck() reports readiness without constructing anything."""
+
+    def it_reports_a_healthy_container(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, EmailNotifier)
+        container.register_singleton_factory(Database, lambda: Database('sqlite://'))
+        container.resolve(Database)
+
+        report = container.healthcheck()
+
+        assert report == {
+            'status': 'ok',
+            'registrations': 2,
+            'singletons': 1,
+            'configuration': {'healthy': True, 'problems': []},
+            'runtime': {'healthy': True, 'problems': []},
+        }
+
+    def it_reports_abstract_class_providers(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, Notifier)
+
+        report = container.healthcheck()
+
+        assert report['status'] == 'error'
+        assert report['configuration']['healthy'] is False
+        assert report['configuration']['problems'] == [
+            'Notifier: class Notifier is abstract and cannot be instantiated'
+        ]
+        assert report['runtime']['healthy'] is True
+
+    def it_reports_class_providers_with_required_constructor_arguments(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        problems = container.healthcheck()['configuration']['problems']
+
+        assert problems == [
+            'Database: class Database requires constructor arguments (url) '
+            'that cannot be auto-wired from registered types'
+        ]
+
+    def it_accepts_constructor_arguments_that_can_be_auto_wired(self) -> None:
+        class Repository:
+            def __init__(self, clock: Clock) -> None:
+                self.clock = clock
+
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Clock, Clock)
+
+        assert container.healthcheck()['configuration']['problems'] == []
+
+    def it_reports_a_missing_dependency_once_as_configuration(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+
+        report = container.healthcheck()
+
+        assert report['configuration']['problems'] == [
+            'Repository: class Repository requires constructor arguments (session) '
+            'that cannot be auto-wired from registered types'
+        ]
+        assert report['runtime'] == {'healthy': True, 'problems': []}
+
+    def it_checks_configuration_values_against_the_registered_config(self) -> None:
+        container = RustContainer()
+        container.register_class(Gateway, Gateway)
+
+        missing = container.healthcheck()
+        container.register_config({'gateway': {'url': 'https://pay.example'}})
+        configured = container.healthcheck()
+
+        assert missing['configuration']['problems'] == [
+            'Gateway: class Gateway requires constructor arguments (url) '
+            'that cannot be auto-wired from registered types'
+        ]
+        assert missing['runtime']['healthy'] is True
+        assert configured['status'] == 'ok'
+
+    def it_reports_validation_failures_as_configuration(self) -> None:
+        container = RustContainer()
+        container.register_class(Left, Left)
+        container.register_class(Right, Right)
+
+        report = container.healthcheck()
+
+        assert report['configuration']['problems'] == ['circular dependency: Left -> Right -> Left']
+        assert report['runtime'] == {'healthy': True, 'problems': []}
+
+    def it_reports_decorators_for_unregistered_types(self) -> None:
+        container = RustContainer()
+        container.add_decorator(Clock, lambda clock: clock)
+
+        problems = container.healthcheck()['configuration']['problems']
+
+        assert problems == ['Clock: decorators registered but the type is not registered']
+
+    def it_includes_shadowed_registrations(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, EmailNotifier)
+        container.register_singleton_factory(EmailNotifier, EmailNotifier)
+
+        problems = container.healthcheck()['configuration']['problems']
+
+        assert len(problems) == 1
+        assert 'bypasses the more specific registration for EmailNotifier' in problems[0]
+
+    def it_does_not_construct_anything(self) -> None:
+        container = RustContainer()
+        calls: list[str] = []
+
+        class Service:
+            def __init__(self) -> None:
+                calls.append('built')
+
+        container.register_class(Service, Service)
+
+        container.healthcheck()
+
+        assert calls == []
+
+
+class DescribeHealthcheckRuntime:
+    """healthcheck() reports what would fail once the container is in use."""
+
+    def it_reports_singletons_outliving_their_dependencies(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Session, Session, lifetime='thread')
+
+        problems = container.healthcheck()['runtime']['problems']
+
+        assert problems == ['Repository: singleton depends on Session (thread lifetime), which it would outlive']
+
+    def it_reports_failed_singleton_constructions_until_one_succeeds(self) -> None:
+        container = RustContainer()
+        attempts: list[int] = []
+
+        def connect() -> Session:
+            attempts.append(1)
+            if len(attempts) == 1:
+                raise ConnectionError('database unreachable')
+            return Session()
+
+        container.register_singleton_factory(Session, connect)
+        with pytest.raises(DioxideError):
+            container.resolve(Session)
+
+        report = container.healthcheck()
+        assert report['status'] == 'error'
+        assert report['runtime']['problems'] == [
+            'construction failed: Session: Python error: ConnectionError: database unreachable'
+        ]
+
+        container.resolve(Session)
+
+        assert container.healthcheck()['runtime'] == {'healthy': True, 'problems': []}
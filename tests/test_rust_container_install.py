@@ -0,0 +1,118 @@
+"""Tests for installing registration bundles with install()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class PostgresDatabase(Database):
+    pass
+
+
+class Clock:
+    pass
+
+
+class StorageBundle:
+    def configure(self, binder) -> None:
+        binder.bind_class(Database, PostgresDatabase)
+        binder.bind_factory(Clock, Clock, scope='factory')
+
+
+class AppBundle:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+    def configure(self, binder) -> None:
+        binder.install(StorageBundle())
+        binder.bind_instance(Config, self.config)
+
+
+class DescribeInstall:
+    """A bundle's bindings are applied together or not at all."""
+
+    def it_applies_every_binding_of_the_bundle(self) -> None:
+        container = RustContainer()
+
+        keys = container.install(StorageBundle())
+
+        assert keys == [Database, Clock]
+        assert isinstance(container.resolve(Database), PostgresDatabase)
+        assert container.resolve(Database) is container.resolve(Database)
+
+    def it_honours_the_declared_scope(self) -> None:
+        container = RustContainer()
+        container.install(StorageBundle())
+
+        assert container.resolve(Clock) is not container.resolve(Clock)
+
+    def it_composes_bundles(self) -> None:
+        container = RustContainer()
+        config = Config()
+
+        keys = container.install(AppBundle(config))
+
+        assert keys == [Database, Clock, Config]
+        assert container.resolve(Config) is config
+
+    def it_configures_a_bundle_installed_twice_once(self) -> None:
+        storage = StorageBundle()
+
+        class Twice:
+            def configure(self, binder) -> None:
+                binder.install(storage)
+                binder.install(storage)
+
+        container = RustContainer()
+
+        assert container.install(Twice()) == [Database, Clock]
+
+    def it_binds_qualified_keys(self) -> None:
+        primary, replica = Database(), Database()
+
+        class Databases:
+            def configure(self, binder) -> None:
+                binder.bind_instance(Database, primary, name='primary')
+                binder.bind_instance(Database, replica, name='replica')
+
+        container = RustContainer()
+        container.install(Databases())
+
+        assert container.resolve(Database, name='primary') is primary
+        assert container.resolve(Database, name='replica') is replica
+
+    def it_registers_nothing_when_a_binding_conflicts(self) -> None:
+        container = RustContainer()
+        container.register_class(Clock, Clock)
+
+        with pytest.raises(KeyError, match='Clock'):
+            container.install(StorageBundle())
+
+        assert container.contains(Database) is False
+
+    def it_registers_nothing_when_configure_raises(self) -> None:
+        class Broken:
+            def configure(self, binder) -> None:
+                binder.bind_class(Database, PostgresDatabase)
+                raise RuntimeError('boom')
+
+        container = RustContainer()
+
+        with pytest.raises(RuntimeError, match='boom'):
+            container.install(Broken())
+
+        assert container.contains(Database) is False
+
+    def it_rejects_objects_without_configure(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(TypeError, match='configure'):
+            container.install(object())
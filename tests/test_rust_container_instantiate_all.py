@@ -0,0 +1,101 @@
+"""Tests for eagerly constructing every singleton with instantiate_all()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Repository:
+    def __init__(self, database: Database) -> None:
+        self.database = database
+
+
+class Request:
+    pass
+
+
+class Connection:
+    pass
+
+
+class Broker:
+    def __init__(self) -> None:
+        raise ConnectionError('broker unreachable')
+
+
+class Left:
+    def __init__(self, right: 'Right') -> None:
+        self.right = right
+
+
+class Right:
+    def __init__(self, left: Left) -> None:
+        self.left = left
+
+
+class DescribeInstantiateAll:
+    """instantiate_all() constructs singleton registrations up front."""
+
+    def it_constructs_singletons_in_dependency_order(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Database, Database)
+        container.register_singleton_factory(Config, Config)
+
+        warmed = container.instantiate_all()
+
+        assert warmed == [Config, Database, Repository]
+        assert container.construction_log() == ['Config', 'Database', 'Repository']
+
+    def it_caches_what_it_constructs(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_class(Config, Config)
+        container.instantiate_all()
+
+        assert container.resolve_cached_only([Database, Config])[0].config is container.resolve(Config)
+
+    def it_skips_transient_tenant_and_async_providers(self) -> None:
+        async def connect() -> Connection:
+            return Connection()
+
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+        container.register_tenant_factory(Config, Config)
+        container.register_async_factory(Connection, connect)
+
+        assert container.instantiate_all() == []
+
+    def it_opens_generator_factories(self) -> None:
+        def connect():  # type: ignore[no-untyped-def]
+            yield Connection()
+
+        container = RustContainer()
+        container.register_generator_factory(Connection, connect)
+
+        assert container.instantiate_all() == [Connection]
+        assert isinstance(container.resolve_cached_only([Connection])[0], Connection)
+
+    def it_surfaces_construction_errors(self) -> None:
+        container = RustContainer()
+        container.register_class(Broker, Broker)
+
+        with pytest.raises(KeyError, match='broker unreachable'):
+            container.instantiate_all()
+
+    def it_reports_cycles(self) -> None:
+        container = RustContainer()
+        container.register_class(Left, Left)
+        container.register_class(Right, Right)
+
+        with pytest.raises(KeyError, match='Circular dependency'):
+            container.instantiate_all()
@@ -0,0 +1,97 @@
+"""Tests for interceptors wrapping resolved instances by type or tag."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Repository:
+    pass
+
+
+class Mailer:
+    pass
+
+
+class Traced:
+    def __init__(self, target: object, label: str = 'traced') -> None:
+        self.target = target
+        self.label = label
+
+
+class DescribeInterceptors:
+    """Interceptors wrap instances before they are cached or returned."""
+
+    def it_wraps_instances_of_one_type(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.add_interceptor(Traced, key=Repository)
+
+        repository = container.resolve(Repository)
+
+        assert isinstance(repository, Traced)
+        assert isinstance(repository.target, Repository)
+
+    def it_wraps_every_type_with_the_tag(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_transient_factory(Mailer, Mailer)
+        container.tag(Repository, 'traced')
+        container.tag(Mailer, 'traced')
+        container.add_interceptor(Traced, tag='traced')
+
+        assert isinstance(container.resolve(Repository), Traced)
+        assert isinstance(container.resolve(Mailer), Traced)
+
+    def it_leaves_untagged_types_alone(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Mailer, Mailer)
+        container.tag(Repository, 'traced')
+        container.add_interceptor(Traced, tag='traced')
+
+        assert type(container.resolve(Mailer)) is Mailer
+
+    def it_caches_the_wrapped_singleton(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.tag(Repository, 'traced')
+        container.add_interceptor(Traced, tag='traced')
+
+        assert container.resolve(Repository) is container.resolve(Repository)
+
+    def it_applies_the_chain_in_declared_order(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+        container.tag(Repository, 'retry', 'auth')
+        container.add_interceptor(lambda obj: Traced(obj, 'auth'), tag='auth')
+        container.add_interceptor(lambda obj: Traced(obj, 'retry'), tag='retry')
+
+        outer = container.resolve(Repository)
+
+        assert outer.label == 'retry'
+        assert outer.target.label == 'auth'
+
+    def it_runs_type_interceptors_before_tag_interceptors(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.tag(Repository, 'traced')
+        container.add_interceptor(lambda obj: Traced(obj, 'tag'), tag='traced')
+        container.add_interceptor(lambda obj: Traced(obj, 'type'), key=Repository)
+
+        assert container.resolve(Repository).target.label == 'type'
+
+    def it_lists_the_tags_of_a_registration(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.tag(Repository, 'traced', 'audited')
+
+        assert container.tags(Repository) == ['audited', 'traced']
+
+    def it_requires_exactly_one_selector(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(TypeError, match='exactly one of key and tag'):
+            container.add_interceptor(Traced)
+        with pytest.raises(TypeError, match='exactly one of key and tag'):
+            container.add_interceptor(Traced, key=Repository, tag='traced')
@@ -0,0 +1,80 @@
+"""Tests for feature-detecting bindings with is_registered() and `in`."""
+
+from enum import Enum
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Database:
+    pass
+
+
+class Cache:
+    pass
+
+
+class Setting(Enum):
+    TIMEOUT = 'timeout'
+    RETRIES = 'retries'
+
+
+class DescribeIsRegistered:
+    """is_registered() reports what resolve() can find, without resolving it."""
+
+    def it_reports_registered_types(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        assert container.is_registered(Database) is True
+        assert container.is_registered(Cache) is False
+
+    def it_looks_up_named_bindings(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database, name='replica')
+
+        assert container.is_registered(Database, name='replica') is True
+        assert container.is_registered(Database, name='primary') is False
+        assert container.is_registered(Database) is False
+
+    def it_sees_fallback_registrations(self) -> None:
+        parent = RustContainer()
+        parent.register_class(Database, Database)
+        child = parent.create_child()
+
+        assert child.is_registered(Database) is True
+        assert child.contains(Database) is False
+
+    def it_reports_enum_member_keys(self) -> None:
+        container = RustContainer()
+        container.register_instance(Setting.TIMEOUT, 30)
+
+        assert container.is_registered(Setting.TIMEOUT) is True
+        assert container.is_registered(Setting.RETRIES) is False
+
+    def it_does_not_construct_anything(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        container.is_registered(Database)
+
+        assert container.construction_log() == []
+
+
+class DescribeContainsOperator:
+    """`key in container` checks the unnamed binding."""
+
+    def it_supports_the_in_operator(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        assert Database in container
+        assert Cache not in container
+
+    def it_ignores_named_bindings(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database, name='replica')
+
+        assert Database not in container
+
+    def it_returns_false_for_objects_that_cannot_be_keys(self) -> None:
+        assert 'Database' not in RustContainer()
@@ -0,0 +1,116 @@
+"""Tests for Lazy[X] proxies injected in place of constructor dependencies."""
+
+from __future__ import annotations
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import Lazy
+
+
+class Publisher:
+    def __init__(self, subscriber: Subscriber) -> None:
+        self.subscriber = subscriber
+        self.name = 'publisher'
+
+    def publish(self) -> str:
+        return f'{self.name} -> {self.subscriber.name}'
+
+
+class Subscriber:
+    def __init__(self, publisher: Lazy[Publisher]) -> None:
+        self.publisher = publisher
+        self.name = 'subscriber'
+
+
+class Clock:
+    created = 0
+
+    def __init__(self) -> None:
+        Clock.created += 1
+        self.now = 42
+
+
+class Report:
+    def __init__(self, clock: Lazy[Clock]) -> None:
+        self.clock = clock
+
+
+class Missing:
+    pass
+
+
+class Orphan:
+    def __init__(self, missing: Lazy[Missing]) -> None:
+        self.missing = missing
+
+
+class DescribeLazyInjection:
+    """A Lazy[X] parameter receives a proxy that resolves X on first use."""
+
+    def it_breaks_a_constructor_cycle(self) -> None:
+        container = RustContainer()
+        container.register_class(Publisher, Publisher)
+        container.register_class(Subscriber, Subscriber)
+
+        publisher = container.resolve(Publisher)
+
+        assert publisher.subscriber.publisher.name == 'publisher'
+        assert publisher.subscriber.publisher.publish() == 'publisher -> subscriber'
+
+    def it_defers_construction_until_first_access(self) -> None:
+        container = RustContainer()
+        container.register_class(Clock, Clock)
+        container.register_transient_factory(Report, Report)
+        before = Clock.created
+
+        report = container.resolve(Report)
+
+        assert isinstance(report.clock, Lazy)
+        assert Clock.created == before
+        assert report.clock.now == 42
+        assert Clock.created == before + 1
+
+    def it_reuses_the_resolved_instance(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Clock, Clock)
+        container.register_transient_factory(Report, Report)
+        report = container.resolve(Report)
+
+        report.clock.now = 7
+
+        assert report.clock.now == 7
+
+    def it_shows_whether_it_has_resolved(self) -> None:
+        container = RustContainer()
+        container.register_class(Clock, Clock)
+        container.register_transient_factory(Report, Report)
+        report = container.resolve(Report)
+
+        assert repr(report.clock) == '<Lazy Clock (unresolved)>'
+        report.clock.now  # noqa: B018
+        assert repr(report.clock).startswith('<Lazy Clock: <')
+
+    def it_still_requires_the_dependency_to_be_registered(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Orphan, Orphan)
+
+        with pytest.raises(KeyError, match="parameter 'missing'"):
+            container.resolve(Orphan)
+
+    def it_reports_failures_on_first_access(self) -> None:
+        container = RustContainer()
+        container.register_class(Missing, Missing)
+        container.register_transient_factory(Orphan, Orphan)
+        orphan = container.resolve(Orphan)
+        container.unregister(Missing)
+
+        with pytest.raises(KeyError, match='Dependency not registered: Missing'):
+            orphan.missing.anything  # noqa: B018
+
+    def it_leaves_lazy_edges_out_of_cycle_validation(self) -> None:
+        container = RustContainer()
+        container.register_class(Publisher, Publisher)
+        container.register_class(Subscriber, Subscriber)
+
+        container.validate()
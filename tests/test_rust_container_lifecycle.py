@@ -0,0 +1,187 @@
+"""Tests for starting and stopping components with the container lifecycle."""
+
+from collections.abc import Generator
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Events(list):
+    pass
+
+
+class Database:
+    def __init__(self, events: Events) -> None:
+        self.events = events
+
+    def start(self) -> None:
+        self.events.append('database started')
+
+    def stop(self) -> None:
+        self.events.append('database stopped')
+
+
+class Server:
+    def __init__(self, database: Database, events: Events) -> None:
+        self.database = database
+        self.events = events
+
+    def start(self) -> None:
+        self.events.append('server started')
+
+    def stop(self) -> None:
+        self.events.append('server stopped')
+
+
+class Session:
+    def __init__(self, events: Events) -> None:
+        self.events = events
+
+    def __enter__(self) -> 'Session':
+        self.events.append('session entered')
+        return self
+
+    def __exit__(self, *exc_info: object) -> None:
+        self.events.append('session exited')
+
+
+class Config:
+    pass
+
+
+def make_container() -> tuple[RustContainer, Events]:
+    events = Events()
+    container = RustContainer()
+    container.register_instance(Events, events)
+    container.register_class(Database, Database)
+    container.register_class(Server, Server)
+    container.register_class(Session, Session)
+    return container, events
+
+
+class DescribeContainerLifecycle:
+    """Entering the container starts components; exiting stops them in reverse."""
+
+    def it_starts_cached_singletons_on_enter(self) -> None:
+        container, events = make_container()
+        container.resolve(Server)
+
+        with container:
+            assert events == ['database started', 'server started']
+
+        assert events[2:] == ['server stopped', 'database stopped']
+
+    def it_starts_singletons_created_inside_the_block(self) -> None:
+        container, events = make_container()
+
+        with container:
+            container.resolve(Server)
+            assert events == ['database started', 'server started']
+
+        assert events[2:] == ['server stopped', 'database stopped']
+
+    def it_uses_enter_and_exit_without_start_and_stop(self) -> None:
+        container, events = make_container()
+
+        with container:
+            container.resolve(Session)
+
+        assert events == ['session entered', 'session exited']
+
+    def it_ignores_transients_and_plain_objects(self) -> None:
+        container, events = make_container()
+        container.register_transient_factory(Config, Config)
+        container.unregister(Database)
+        container.register_transient_factory(Database, lambda: Database(events))
+
+        with container:
+            container.resolve(Config)
+            container.resolve(Database)
+
+        assert events == []
+
+    def it_stops_started_components_when_start_fails(self) -> None:
+        class Broken:
+            def start(self) -> None:
+                raise OSError('port in use')
+
+        container, events = make_container()
+        container.register_class(Broken, Broken)
+        container.resolve(Database)
+        container.resolve(Broken)
+
+        with pytest.raises(OSError, match='port in use'):
+            container.start()
+
+        assert events == ['database started', 'database stopped']
+
+    def it_evicts_a_singleton_that_fails_to_start_lazily(self) -> None:
+        attempts = []
+
+        class Flaky:
+            def start(self) -> None:
+                attempts.append(self)
+                if len(attempts) == 1:
+                    raise OSError('not ready')
+
+        container = RustContainer()
+        container.register_class(Flaky, Flaky)
+        container.start()
+
+        with pytest.raises(KeyError, match='not ready'):
+            container.resolve(Flaky)
+
+        assert container.resolve(Flaky) is attempts[1]
+        container.stop()
+
+    def it_reports_components_that_fail_to_stop(self) -> None:
+        class Stubborn:
+            def start(self) -> None:
+                pass
+
+            def stop(self) -> None:
+                raise RuntimeError('still busy')
+
+        container = RustContainer()
+        container.register_class(Stubborn, Stubborn)
+        container.start()
+        container.resolve(Stubborn)
+
+        assert container.stop() == ['Stubborn: stop failed: RuntimeError: still busy']
+
+    def it_warns_about_failures_on_exit(self) -> None:
+        class Stubborn:
+            def start(self) -> None:
+                pass
+
+            def stop(self) -> None:
+                raise RuntimeError('still busy')
+
+        container = RustContainer()
+        container.register_class(Stubborn, Stubborn)
+
+        with pytest.warns(RuntimeWarning, match='still busy'), container:
+            container.resolve(Stubborn)
+
+    def it_closes_generator_factories_on_exit(self) -> None:
+        events = Events()
+
+        def connect() -> Generator[Config, None, None]:
+            yield Config()
+            events.append('closed')
+
+        container = RustContainer()
+        container.register_generator_factory(Config, connect)
+
+        with container:
+            container.resolve(Config)
+
+        assert events == ['closed']
+
+    def it_rejects_starting_twice(self) -> None:
+        container = RustContainer()
+        container.start()
+
+        with pytest.raises(RuntimeError, match='already started'):
+            container.start()
@@ -0,0 +1,149 @@
+"""Tests for lifespan_context() and the async warm-up and disposal it runs."""
+
+import asyncio
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import ContainerClosedError, lifespan_context
+
+
+class Events(list):
+    pass
+
+
+class Settings:
+    def __init__(self, events: Events) -> None:
+        events.append('settings built')
+
+
+class Database:
+    def __init__(self, settings: Settings, events: Events) -> None:
+        self.events = events
+        events.append('database built')
+
+    async def dispose(self) -> None:
+        await asyncio.sleep(0)
+        self.events.append('database disposed')
+
+
+class Repository:
+    def __init__(self, database: Database, events: Events) -> None:
+        self.events = events
+
+    async def dispose(self) -> None:
+        await asyncio.sleep(0)
+        self.events.append('repository disposed')
+
+
+class Broker:
+    pass
+
+
+class Mailer:
+    def close(self) -> None:
+        raise ConnectionError('mail server gone')
+
+
+def container_with(events: Events) -> RustContainer:
+    container = RustContainer()
+    container.register_instance(Events, events)
+    container.register_class(Settings, Settings)
+    container.register_class(Database, Database)
+    container.register_class(Repository, Repository)
+    return container
+
+
+class DescribeLifespanContext:
+    """lifespan_context() warms singletons on entry and disposes them on exit."""
+
+    @pytest.mark.asyncio
+    async def it_warms_singletons_on_entry(self) -> None:
+        events = Events()
+        container = container_with(events)
+
+        async with lifespan_context(container) as entered:
+            assert entered is container
+            assert events == ['settings built', 'database built']
+
+    @pytest.mark.asyncio
+    async def it_awaits_async_singleton_factories_on_entry(self) -> None:
+        broker = Broker()
+
+        async def connect() -> Broker:
+            await asyncio.sleep(0)
+            return broker
+
+        container = RustContainer()
+        container.register_async_factory(Broker, connect)
+
+        async with lifespan_context(container):
+            assert container.resolve_cached_only([Broker]) == [broker]
+
+    @pytest.mark.asyncio
+    async def it_disposes_in_reverse_dependency_order_on_exit(self) -> None:
+        events = Events()
+        container = container_with(events)
+
+        async with lifespan_context(container):
+            events.clear()
+
+        assert events == ['repository disposed', 'database disposed']
+        assert container.is_closed()
+
+    @pytest.mark.asyncio
+    async def it_warns_about_failed_disposals_on_exit(self) -> None:
+        container = RustContainer()
+        container.register_class(Mailer, Mailer)
+
+        with pytest.warns(RuntimeWarning, match='mail server gone'):
+            async with lifespan_context(container):
+                pass
+
+
+class DescribeAsyncInstantiateAll:
+    """async_instantiate_all() warms sync and async singletons."""
+
+    @pytest.mark.asyncio
+    async def it_returns_the_warmed_keys(self) -> None:
+        async def connect() -> Broker:
+            return Broker()
+
+        container = container_with(Events())
+        container.register_async_factory(Broker, connect)
+
+        assert await container.async_instantiate_all() == [Settings, Database, Repository, Broker]
+
+    @pytest.mark.asyncio
+    async def it_raises_the_first_async_failure(self) -> None:
+        async def connect() -> Broker:
+            raise ConnectionError('broker down')
+
+        container = RustContainer()
+        container.register_async_factory(Broker, connect)
+
+        with pytest.raises(ConnectionError, match='broker down'):
+            await container.async_instantiate_all()
+
+
+class DescribeAsyncDispose:
+    """async_dispose() closes the container, awaiting each async disposer in turn."""
+
+    @pytest.mark.asyncio
+    async def it_reports_failed_disposals(self) -> None:
+        container = RustContainer()
+        container.register_class(Mailer, Mailer)
+        container.resolve(Mailer)
+
+        assert await container.async_dispose() == ['Mailer: disposal failed: ConnectionError: mail server gone']
+
+    @pytest.mark.asyncio
+    async def it_closes_the_container(self) -> None:
+        container = container_with(Events())
+        container.resolve(Repository)
+
+        assert await container.async_dispose() == []
+
+        with pytest.raises(ContainerClosedError):
+            container.resolve(Repository)
+        assert await container.async_dispose() == []
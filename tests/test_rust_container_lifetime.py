@@ -0,0 +1,86 @@
+"""Tests for per-registration lifetimes of class providers."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import Lifetime
+
+
+class Config:
+    pass
+
+
+class Request:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class DescribeLifetime:
+    """register_class(lifetime=...) chooses between caching and rebuilding."""
+
+    def it_defaults_to_singleton(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+
+        assert container.resolve(Config) is container.resolve(Config)
+        assert container.all_bindings(Config)[0]['provider'] == 'class'
+
+    def it_builds_transient_classes_on_every_resolve(self) -> None:
+        container = RustContainer()
+        container.register_class(Request, Request, lifetime=Lifetime.TRANSIENT)
+        container.register_class(Config, Config)
+
+        first = container.resolve(Request)
+        second = container.resolve(Request)
+
+        assert first is not second
+        assert first.config is second.config
+        assert container.all_bindings(Request)[0]['provider'] == 'transient_class'
+
+    def it_accepts_lifetime_strings(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config, lifetime='transient')
+
+        assert container.resolve(Config) is not container.resolve(Config)
+
+    def it_accepts_an_explicit_singleton_lifetime(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config, lifetime=Lifetime.SINGLETON)
+
+        assert container.resolve(Config) is container.resolve(Config)
+
+    def it_does_not_cache_transient_classes(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config, lifetime=Lifetime.TRANSIENT)
+
+        container.resolve(Config)
+
+        assert container.construction_log() == []
+        with pytest.raises(KeyError, match='warm-up missing'):
+            container.resolve_cached_only([Config])
+
+    def it_builds_transient_classes_while_singletons_are_frozen(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config, lifetime=Lifetime.TRANSIENT)
+
+        with container.freeze_singletons():
+            assert isinstance(container.resolve(Config), Config)
+
+    def it_distinguishes_lifetimes_when_comparing_containers(self) -> None:
+        singleton = RustContainer()
+        singleton.register_class(Config, Config)
+        transient = RustContainer()
+        transient.register_class(Config, Config, lifetime=Lifetime.TRANSIENT)
+
+        assert not singleton.is_equivalent_to(transient)
+
+    def it_rejects_unknown_lifetimes(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(TypeError, match='Unknown lifetime'):
+            container.register_class(Config, Config, lifetime='scoped')
+
+    def it_exposes_lifetime_values(self) -> None:
+        assert Lifetime.SINGLETON.value == 'singleton'
+        assert Lifetime.TRANSIENT.value == 'transient'
+        assert Lifetime.SINGLETON != Lifetime.TRANSIENT
@@ -0,0 +1,77 @@
+"""Tests for describing every binding with list_registrations()."""
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class Handler:
+    pass
+
+
+class DescribeListRegistrations:
+    """list_registrations() returns one record per binding, sorted by key name."""
+
+    def it_describes_each_binding(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        [record] = container.list_registrations()
+        record.pop('source')
+
+        assert record == {
+            'key': Database,
+            'name': None,
+            'provider': 'class',
+            'lifetime': 'singleton',
+            'tags': [],
+            'description': None,
+            'cached': False,
+        }
+
+    def it_sorts_bindings_by_key_name(self) -> None:
+        container = RustContainer()
+        container.register_class(Handler, Handler)
+        container.register_instance(Config, Config())
+        container.register_class(Database, Database)
+
+        assert [record['key'] for record in container.list_registrations()] == [Config, Database, Handler]
+
+    def it_reports_provider_kind_and_lifetime(self) -> None:
+        container = RustContainer()
+        container.register_instance(Config, Config())
+        container.register_transient_factory(Handler, Handler)
+
+        records = {record['key']: record for record in container.list_registrations()}
+
+        assert (records[Config]['provider'], records[Config]['lifetime']) == ('instance', 'singleton')
+        assert (records[Handler]['provider'], records[Handler]['lifetime']) == ('transient_factory', 'transient')
+
+    def it_reports_qualifiers(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database, name='replica')
+
+        assert container.list_registrations()[0]['name'] == 'replica'
+
+    def it_reports_tags(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.tag(Database, 'storage', 'critical')
+
+        assert container.list_registrations()[0]['tags'] == ['critical', 'storage']
+
+    def it_reports_cached_singletons_without_constructing(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_class(Database, Database)
+        container.resolve(Config)
+
+        records = container.list_registrations()
+
+        assert [record['cached'] for record in records] == [True, False]
+        assert container.construction_log() == ['Config']
@@ -0,0 +1,97 @@
+"""Tests for injecting dict[str, X] parameters from named bindings."""
+
+from __future__ import annotations
+
+from collections.abc import Mapping
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Exporter:
+    pass
+
+
+class CsvExporter(Exporter):
+    pass
+
+
+class JsonExporter(Exporter):
+    pass
+
+
+class ExportService:
+    def __init__(self, exporters: dict[str, Exporter]) -> None:
+        self.exporters = exporters
+
+
+class MappingExportService:
+    def __init__(self, exporters: Mapping[str, Exporter]) -> None:
+        self.exporters = exporters
+
+
+class DefaultedExportService:
+    def __init__(self, exporters: dict[str, Exporter] | None = None) -> None:
+        self.exporters = exporters
+
+
+class DescribeMappingInjection:
+    """A dict[str, X] parameter maps each binding name of X to its instance."""
+
+    def it_maps_qualifiers_to_instances(self) -> None:
+        container = RustContainer()
+        container.register_class(Exporter, CsvExporter, name='csv')
+        container.register_class(Exporter, JsonExporter, name='json')
+        container.register_transient_factory(ExportService, ExportService)
+
+        exporters = container.resolve(ExportService).exporters
+
+        assert exporters == {
+            'csv': container.resolve(Exporter, name='csv'),
+            'json': container.resolve(Exporter, name='json'),
+        }
+
+    def it_injects_mapping_parameters(self) -> None:
+        container = RustContainer()
+        csv = CsvExporter()
+        container.register_instance(Exporter, csv, name='csv')
+        container.register_transient_factory(MappingExportService, MappingExportService)
+
+        assert container.resolve(MappingExportService).exporters == {'csv': csv}
+
+    def it_leaves_out_the_unnamed_binding(self) -> None:
+        container = RustContainer()
+        container.register_class(Exporter, CsvExporter)
+        container.register_class(Exporter, JsonExporter, name='json')
+        container.register_transient_factory(ExportService, ExportService)
+
+        assert list(container.resolve(ExportService).exporters) == ['json']
+
+    def it_includes_named_bindings_of_the_parent(self) -> None:
+        parent = RustContainer()
+        parent.register_class(Exporter, CsvExporter, name='csv')
+        child = parent.create_child()
+        child.register_class(Exporter, JsonExporter, name='json')
+        child.register_transient_factory(ExportService, ExportService)
+
+        assert sorted(child.resolve(ExportService).exporters) == ['csv', 'json']
+
+    def it_injects_an_empty_mapping_without_named_bindings(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(ExportService, ExportService)
+
+        assert container.resolve(ExportService).exporters == {}
+
+    def it_keeps_the_default_without_named_bindings(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(DefaultedExportService, DefaultedExportService)
+
+        assert container.resolve(DefaultedExportService).exporters is None
+
+    def it_picks_up_bindings_registered_after_the_first_resolve(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(ExportService, ExportService)
+        assert container.resolve(ExportService).exporters == {}
+
+        container.register_class(Exporter, CsvExporter, name='csv')
+
+        assert list(container.resolve(ExportService).exporters) == ['csv']
@@ -0,0 +1,72 @@
+"""Tests for singleton memoization of class providers."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Repository:
+    instances = 0
+
+    def __init__(self, config: Config) -> None:
+        Repository.instances += 1
+        self.config = config
+
+
+class DescribeClassProviderMemoization:
+    """Class providers construct once and then serve the cached instance."""
+
+    def it_returns_the_same_instance_across_resolves(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+
+        assert container.resolve(Config) is container.resolve(Config)
+
+    def it_constructs_the_class_only_once(self) -> None:
+        Repository.instances = 0
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Config, Config)
+
+        first = container.resolve(Repository)
+        second = container.resolve(Repository)
+
+        assert first is second
+        assert Repository.instances == 1
+
+    def it_shares_the_cached_instance_with_dependents(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Config, Config)
+
+        assert container.resolve(Repository).config is container.resolve(Config)
+
+    def it_records_the_construction(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Config, Config)
+
+        container.resolve(Repository)
+
+        assert container.construction_log() == ['Config', 'Repository']
+        assert container.resolve_cached_only([Repository, Config])
+
+    def it_builds_a_new_instance_after_reset(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        first = container.resolve(Config)
+
+        container.reset()
+
+        assert container.resolve(Config) is not first
+
+    def it_refuses_to_construct_while_singletons_are_frozen(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+
+        with container.freeze_singletons(), pytest.raises(KeyError, match='singleton creation is frozen'):
+            container.resolve(Config)
@@ -0,0 +1,122 @@
+"""Tests for merging one container's registrations into another."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class ReplicaDatabase(Database):
+    pass
+
+
+class Clock:
+    pass
+
+
+class Tagged:
+    def __init__(self, inner: object) -> None:
+        self.inner = inner
+
+
+def library() -> RustContainer:
+    container = RustContainer()
+    container.register_class(Database, ReplicaDatabase)
+    container.register_class(Clock, Clock)
+    return container
+
+
+class DescribeMerge:
+    """merge(other, on_conflict) copies registrations in one transaction."""
+
+    def it_adds_registrations_from_the_other_container(self) -> None:
+        app = RustContainer()
+        app.register_class(Config, Config)
+
+        app.merge(library())
+
+        assert sorted(key.__name__ for key in app.get_registered_types()) == ['Clock', 'Config', 'Database']
+        assert isinstance(app.resolve(Database), ReplicaDatabase)
+
+    def it_rejects_conflicts_by_default_without_merging_anything(self) -> None:
+        app = RustContainer()
+        app.register_class(Database, Database)
+
+        with pytest.raises(KeyError, match='Duplicate provider registration: Database'):
+            app.merge(library())
+
+        assert not app.contains(Clock)
+
+    def it_keeps_its_own_binding_when_preferring_self(self) -> None:
+        app = RustContainer()
+        app.register_class(Database, Database)
+
+        app.merge(library(), on_conflict='prefer_self')
+
+        assert type(app.resolve(Database)) is Database
+        assert app.contains(Clock)
+
+    def it_takes_the_other_binding_and_evicts_its_own_singleton(self) -> None:
+        app = RustContainer()
+        app.register_class(Database, Database)
+        app.resolve(Database)
+
+        app.merge(library(), on_conflict='prefer_other')
+
+        assert isinstance(app.resolve(Database), ReplicaDatabase)
+
+    def it_shares_singletons_the_other_container_already_cached(self) -> None:
+        other = library()
+        clock = other.resolve(Clock)
+        app = RustContainer()
+
+        app.merge(other)
+
+        assert app.resolve(Clock) is clock
+
+    def it_brings_decorators_along_with_the_binding(self) -> None:
+        other = RustContainer()
+        other.register_transient_factory(Config, Config)
+        other.add_decorator(Config, Tagged)
+        app = RustContainer()
+
+        app.merge(other)
+
+        assert isinstance(app.resolve(Config), Tagged)
+
+    def it_runs_register_hooks_for_each_binding_taken(self) -> None:
+        app = RustContainer()
+        app.register_class(Database, Database)
+        seen = []
+        app.on_register(lambda key, kind, lifetime: seen.append(key))
+
+        app.merge(library(), on_conflict='prefer_self')
+
+        assert seen == [Clock]
+
+    def it_leaves_the_other_container_unchanged(self) -> None:
+        other = library()
+        app = RustContainer()
+        app.register_class(Config, Config)
+
+        app.merge(other)
+
+        assert not other.contains(Config)
+
+    def it_treats_merging_itself_as_a_no_op(self) -> None:
+        app = library()
+
+        app.merge(app)
+
+        assert len(app) == 2
+
+    def it_rejects_unknown_conflict_policies(self) -> None:
+        with pytest.raises(TypeError, match='Unknown conflict policy'):
+            RustContainer().merge(library(), on_conflict='newest')
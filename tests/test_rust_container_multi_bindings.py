@@ -0,0 +1,149 @@
+"""Tests for multi-bindings resolved with resolve_all()."""
+
+import gc
+import weakref
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import Lifetime
+
+
+class Config:
+    pass
+
+
+class EventHandler:
+    pass
+
+
+class EmailHandler(EventHandler):
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class AuditHandler(EventHandler):
+    pass
+
+
+class DescribeMultiBindings:
+    """Several providers contributed to one key resolve together as a list."""
+
+    def it_resolves_in_registration_order(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        audit = AuditHandler()
+        container.register_multi_class(EventHandler, EmailHandler)
+        container.register_multi_instance(EventHandler, audit)
+        container.register_multi_factory(EventHandler, AuditHandler)
+
+        handlers = container.resolve_all(EventHandler)
+
+        assert [type(handler) for handler in handlers] == [EmailHandler, AuditHandler, AuditHandler]
+        assert handlers[1] is audit
+        assert handlers[0].config is container.resolve(Config)
+
+    def it_caches_singleton_bindings(self) -> None:
+        container = RustContainer()
+        container.register_multi_class(EventHandler, AuditHandler)
+        container.register_multi_factory(EventHandler, AuditHandler)
+
+        first = container.resolve_all(EventHandler)
+        second = container.resolve_all(EventHandler)
+
+        assert first[0] is second[0]
+        assert first[1] is second[1]
+        assert first[0] is not first[1]
+
+    def it_constructs_transient_bindings_every_time(self) -> None:
+        container = RustContainer()
+        container.register_multi_class(EventHandler, AuditHandler, lifetime=Lifetime.TRANSIENT)
+        container.register_multi_factory(EventHandler, AuditHandler, lifetime='transient')
+
+        first = container.resolve_all(EventHandler)
+        second = container.resolve_all(EventHandler)
+
+        assert first[0] is not second[0]
+        assert first[1] is not second[1]
+
+    def it_resolves_to_an_empty_list_without_bindings(self) -> None:
+        container = RustContainer()
+
+        assert container.resolve_all(EventHandler) == []
+
+    def it_keeps_multi_bindings_apart_from_the_single_binding(self) -> None:
+        container = RustContainer()
+        container.register_class(EventHandler, AuditHandler)
+        container.register_multi_class(EventHandler, AuditHandler)
+
+        assert isinstance(container.resolve(EventHandler), AuditHandler)
+        assert container.resolve(EventHandler) is not container.resolve_all(EventHandler)[0]
+        assert len(container) == 1
+
+    def it_drops_cached_instances_on_reset(self) -> None:
+        container = RustContainer()
+        container.register_multi_class(EventHandler, AuditHandler)
+        [first] = container.resolve_all(EventHandler)
+
+        container.reset()
+
+        assert container.resolve_all(EventHandler)[0] is not first
+
+    def it_drops_multi_bindings_on_clear(self) -> None:
+        container = RustContainer()
+        container.register_multi_class(EventHandler, AuditHandler)
+
+        container.clear()
+
+        assert container.resolve_all(EventHandler) == []
+
+    def it_wraps_each_instance_with_decorators_and_interceptors(self) -> None:
+        container = RustContainer()
+        container.register_multi_instance(EventHandler, AuditHandler())
+        container.register_multi_factory(EventHandler, AuditHandler, lifetime='transient')
+        container.add_decorator(EventHandler, lambda handler: ('decorated', handler))
+        container.tag(EventHandler, 'audited')
+        container.add_interceptor(lambda wrapped: ('intercepted', wrapped), tag='audited')
+
+        handlers = container.resolve_all(EventHandler)
+
+        assert [(outer, inner) for outer, (inner, _) in handlers] == [('intercepted', 'decorated')] * 2
+
+    def it_caches_the_decorated_instance(self) -> None:
+        container = RustContainer()
+        calls: list[object] = []
+        container.register_multi_instance(EventHandler, AuditHandler())
+        container.register_multi_class(EventHandler, AuditHandler)
+        container.add_decorator(EventHandler, lambda handler: calls.append(handler) or handler)
+
+        first = container.resolve_all(EventHandler)
+        second = container.resolve_all(EventHandler)
+
+        assert first == second
+        assert len(calls) == 2
+
+    def it_caches_weak_singleton_bindings_while_referenced(self) -> None:
+        container = RustContainer()
+        container.register_multi_factory(EventHandler, AuditHandler, cache='weak')
+
+        [first] = container.resolve_all(EventHandler)
+        assert container.resolve_all(EventHandler)[0] is first
+
+        reference = weakref.ref(first)
+        del first
+        gc.collect()
+
+        assert reference() is None
+        assert isinstance(container.resolve_all(EventHandler)[0], AuditHandler)
+
+    def it_rejects_weak_caching_for_transient_factories(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='weak caching requires the singleton lifetime'):
+            container.register_multi_factory(EventHandler, AuditHandler, lifetime='transient', cache='weak')
+
+    def it_rejects_unknown_lifetimes(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(TypeError, match='Unknown lifetime'):
+            container.register_multi_class(EventHandler, AuditHandler, lifetime='scoped')
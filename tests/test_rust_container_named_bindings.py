@@ -0,0 +1,74 @@
+"""Tests for named (qualified) bindings of the same type."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DependencyNotRegisteredError, DuplicateRegistrationError
+
+
+class DatabaseConnection:
+    def __init__(self, dsn: str = 'default') -> None:
+        self.dsn = dsn
+
+
+class Repository:
+    def __init__(self, connection: DatabaseConnection) -> None:
+        self.connection = connection
+
+
+class DescribeNamedBindings:
+    """name= qualifies a key so bindings of one type coexist."""
+
+    def it_keeps_named_and_unnamed_bindings_apart(self) -> None:
+        container = RustContainer()
+        primary = DatabaseConnection('primary')
+        replica = DatabaseConnection('replica')
+        container.register_instance(DatabaseConnection, primary)
+        container.register_instance(DatabaseConnection, replica, name='replica')
+
+        assert container.resolve(DatabaseConnection) is primary
+        assert container.resolve(DatabaseConnection, name='replica') is replica
+        assert len(container) == 2
+
+    def it_caches_each_named_singleton_separately(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(DatabaseConnection, lambda: DatabaseConnection('a'), name='a')
+        container.register_singleton_factory(DatabaseConnection, lambda: DatabaseConnection('b'), name='b')
+
+        a = container.resolve(DatabaseConnection, name='a')
+
+        assert a is container.resolve(DatabaseConnection, name='a')
+        assert container.resolve(DatabaseConnection, name='b').dsn == 'b'
+
+    def it_does_not_fall_back_to_the_unnamed_binding(self) -> None:
+        container = RustContainer()
+        container.register_class(DatabaseConnection, DatabaseConnection)
+
+        with pytest.raises(DependencyNotRegisteredError, match=r'DatabaseConnection\[name=replica\]'):
+            container.resolve(DatabaseConnection, name='replica')
+
+    def it_rejects_duplicate_names(self) -> None:
+        container = RustContainer()
+        container.register_class(DatabaseConnection, DatabaseConnection, name='replica')
+
+        with pytest.raises(DuplicateRegistrationError, match=r'DatabaseConnection\[name=replica\]'):
+            container.register_class(DatabaseConnection, DatabaseConnection, name='replica')
+
+    def it_auto_wires_the_unnamed_binding(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_instance(DatabaseConnection, DatabaseConnection('primary'))
+        container.register_instance(DatabaseConnection, DatabaseConnection('replica'), name='replica')
+
+        assert container.resolve(Repository).connection.dsn == 'primary'
+
+    def it_checks_and_removes_named_bindings(self) -> None:
+        container = RustContainer()
+        container.register_class(DatabaseConnection, DatabaseConnection, name='replica')
+
+        assert container.contains(DatabaseConnection, name='replica')
+        assert not container.contains(DatabaseConnection)
+
+        container.unregister(DatabaseConnection, name='replica')
+
+        assert container.is_empty()
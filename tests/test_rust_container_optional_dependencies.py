@@ -0,0 +1,86 @@
+"""Tests for Optional[X] constructor parameters during auto-wiring."""
+
+from __future__ import annotations
+
+from typing import Optional, Union
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Logger:
+    pass
+
+
+class Metrics:
+    pass
+
+
+class Service:
+    def __init__(self, logger: Logger | None, metrics: Optional[Metrics]) -> None:  # noqa: UP045
+        self.logger = logger
+        self.metrics = metrics
+
+
+class Sink:
+    def __init__(self, target: Union[Logger, Metrics, None]) -> None:  # noqa: UP007
+        self.target = target
+
+
+class Fallback:
+    def __init__(self, logger: Logger | None = Logger) -> None:  # type: ignore[assignment]
+        self.logger = logger
+
+
+class DescribeOptionalDependencies:
+    """An Optional[X] parameter receives None when nothing provides X."""
+
+    def it_passes_none_when_the_type_is_unbound(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+
+        service = container.resolve(Service)
+
+        assert service.logger is None
+        assert service.metrics is None
+
+    def it_injects_the_instance_when_the_type_is_bound(self) -> None:
+        container = RustContainer()
+        container.register_class(Logger, Logger)
+        container.register_class(Metrics, Metrics)
+        container.register_class(Service, Service)
+
+        service = container.resolve(Service)
+
+        assert service.logger is container.resolve(Logger)
+        assert service.metrics is container.resolve(Metrics)
+
+    def it_picks_up_a_binding_added_later(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Service, Service)
+        assert container.resolve(Service).logger is None
+
+        container.register_class(Logger, Logger)
+
+        assert isinstance(container.resolve(Service).logger, Logger)
+
+    def it_prefers_an_explicit_default_over_none(self) -> None:
+        container = RustContainer()
+        container.register_class(Fallback, Fallback)
+
+        assert container.resolve(Fallback).logger is Logger
+
+    def it_does_not_unwrap_unions_of_several_classes(self) -> None:
+        container = RustContainer()
+        container.register_class(Logger, Logger)
+        container.register_class(Sink, Sink)
+
+        with pytest.raises(KeyError, match="parameter 'target'"):
+            container.resolve(Sink)
+
+    def it_passes_validation_without_the_optional_types(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+
+        container.validate()
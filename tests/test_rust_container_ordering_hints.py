@@ -0,0 +1,100 @@
+"""Tests for before=/after= ordering hints on register_class()."""
+
+from __future__ import annotations
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import ValidationError
+
+disposed: list[str] = []
+
+
+class Disposable:
+    def close(self) -> None:
+        disposed.append(type(self).__name__)
+
+
+class Logger(Disposable):
+    pass
+
+
+class Database(Disposable):
+    pass
+
+
+class Metrics(Disposable):
+    pass
+
+
+class Repository(Disposable):
+    def __init__(self, database: Database) -> None:
+        self.database = database
+
+
+class DescribeOrderingHints:
+    """before= and after= order registrations that share no constructor dependency."""
+
+    def setup_method(self) -> None:
+        disposed.clear()
+
+    def it_orders_by_type_name_without_hints(self) -> None:
+        container = RustContainer()
+        container.register_class(Logger, Logger)
+        container.register_class(Database, Database)
+
+        assert container.build_order() == [Database, Logger]
+
+    def it_builds_after_the_keys_in_after(self) -> None:
+        container = RustContainer()
+        container.register_class(Logger, Logger)
+        container.register_class(Database, Database, after=[Logger])
+
+        assert container.build_order() == [Logger, Database]
+        assert container.instantiate_all() == [Logger, Database]
+
+    def it_builds_before_the_keys_in_before(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_class(Metrics, Metrics, before=[Database])
+
+        assert container.build_order() == [Metrics, Database]
+
+    def it_keeps_constructor_dependencies_first(self) -> None:
+        container = RustContainer()
+        container.register_class(Logger, Logger, before=[Database])
+        container.register_class(Database, Database)
+        container.register_class(Repository, Repository)
+
+        assert container.build_order() == [Logger, Database, Repository]
+
+    def it_disposes_in_reverse_hint_order(self) -> None:
+        container = RustContainer()
+        container.register_class(Logger, Logger)
+        container.register_class(Database, Database, after=[Logger])
+        container.resolve(Database)
+        container.resolve(Logger)
+
+        assert container.close() == []
+
+        assert disposed == ['Database', 'Logger']
+
+    def it_reports_conflicting_hints_when_validating(self) -> None:
+        container = RustContainer()
+        container.register_class(Logger, Logger, before=[Database])
+        container.register_class(Database, Database, before=[Logger])
+
+        with pytest.raises(ValidationError) as excinfo:
+            container.validate()
+
+        assert excinfo.value.problems == ['conflicting ordering hints: Database -> Logger -> Database']
+
+    def it_reports_hints_contradicting_a_dependency(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_class(Repository, Repository, before=[Database])
+
+        with pytest.raises(ValidationError) as excinfo:
+            container.validate()
+
+        assert excinfo.value.problems == ['conflicting ordering hints: Database -> Repository -> Database']
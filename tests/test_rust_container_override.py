@@ -0,0 +1,115 @@
+"""Tests for temporary provider overrides with container.override()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import FrozenContainerError
+
+
+class EmailSender:
+    pass
+
+
+class FakeSender(EmailSender):
+    pass
+
+
+class Notifier:
+    def __init__(self, sender: EmailSender) -> None:
+        self.sender = sender
+
+
+class DescribeOverride:
+    """override() swaps a binding inside a with block and reverts it on exit."""
+
+    def it_swaps_the_provider_inside_the_block(self) -> None:
+        container = RustContainer()
+        container.register_class(EmailSender, EmailSender)
+        fake = FakeSender()
+
+        with container.override(EmailSender, fake) as sender:
+            assert sender is fake
+            assert container.resolve(EmailSender) is fake
+
+    def it_restores_the_original_provider_and_cached_singleton(self) -> None:
+        container = RustContainer()
+        container.register_class(EmailSender, EmailSender)
+        original = container.resolve(EmailSender)
+
+        with container.override(EmailSender, FakeSender()):
+            pass
+
+        assert container.resolve(EmailSender) is original
+        assert container.construction_log() == ['EmailSender']
+
+    def it_wires_dependents_with_the_override(self) -> None:
+        container = RustContainer()
+        container.register_class(EmailSender, EmailSender)
+        container.register_transient_factory(Notifier, Notifier)
+        fake = FakeSender()
+
+        with container.override(EmailSender, fake):
+            assert container.resolve(Notifier).sender is fake
+
+        assert type(container.resolve(Notifier).sender) is EmailSender
+
+    def it_reverts_when_the_block_raises(self) -> None:
+        container = RustContainer()
+        container.register_class(EmailSender, EmailSender)
+        original = container.resolve(EmailSender)
+
+        with pytest.raises(RuntimeError), container.override(EmailSender, FakeSender()):
+            raise RuntimeError('boom')
+
+        assert container.resolve(EmailSender) is original
+
+    def it_unwinds_nested_overrides_of_one_key(self) -> None:
+        container = RustContainer()
+        container.register_class(EmailSender, EmailSender)
+        original = container.resolve(EmailSender)
+        outer, inner = FakeSender(), FakeSender()
+
+        with container.override(EmailSender, outer):
+            with container.override(EmailSender, inner):
+                assert container.resolve(EmailSender) is inner
+            assert container.resolve(EmailSender) is outer
+
+        assert container.resolve(EmailSender) is original
+
+    def it_reverts_inner_overrides_still_active_when_an_outer_one_exits(self) -> None:
+        container = RustContainer()
+        container.register_class(EmailSender, EmailSender)
+        outer = container.override(EmailSender, FakeSender())
+        inner = container.override(EmailSender, FakeSender())
+
+        outer.__enter__()
+        inner.__enter__()
+        outer.__exit__(None, None, None)
+        inner.__exit__(None, None, None)
+
+        assert type(container.resolve(EmailSender)) is EmailSender
+
+    def it_removes_a_binding_that_did_not_exist_before(self) -> None:
+        container = RustContainer()
+
+        with container.override(EmailSender, FakeSender()):
+            assert container.contains(EmailSender)
+
+        assert not container.contains(EmailSender)
+
+    def it_does_nothing_until_entered(self) -> None:
+        container = RustContainer()
+        container.register_class(EmailSender, EmailSender)
+
+        container.override(EmailSender, FakeSender())
+
+        assert type(container.resolve(EmailSender)) is EmailSender
+
+    def it_rejects_overrides_of_a_frozen_container(self) -> None:
+        container = RustContainer()
+        container.register_class(EmailSender, EmailSender)
+        container.freeze()
+
+        with pytest.raises(FrozenContainerError, match='Cannot override EmailSender'):
+            with container.override(EmailSender, FakeSender()):
+                pass
@@ -0,0 +1,117 @@
+"""Tests for pooled providers with borrow/return semantics."""
+
+import threading
+import time
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import PoolExhaustedError
+
+
+class Connection:
+    pass
+
+
+def pooled_container(size: int, timeout_ms: int | None = 0) -> RustContainer:
+    container = RustContainer()
+    container.register_pooled_factory(Connection, Connection, size=size, timeout_ms=timeout_ms)
+    return container
+
+
+class DescribePooledProviders:
+    """resolve() borrows from a bounded pool; the handle returns the instance."""
+
+    def it_borrows_distinct_instances_up_to_the_pool_size(self) -> None:
+        container = pooled_container(size=2)
+
+        first = container.resolve(Connection)
+        second = container.resolve(Connection)
+
+        assert isinstance(first.value, Connection)
+        assert first.value is not second.value
+        with pytest.raises(PoolExhaustedError, match='all 2 instances are borrowed'):
+            container.resolve(Connection)
+
+    def it_reuses_a_returned_instance(self) -> None:
+        container = pooled_container(size=1)
+        handle = container.resolve(Connection)
+        connection = handle.value
+
+        handle.release()
+
+        assert handle.released
+        assert container.resolve(Connection).value is connection
+        assert container.stats()['instances_created']['pooled_factory'] == 1
+
+    def it_returns_the_instance_when_the_with_block_exits(self) -> None:
+        container = pooled_container(size=1)
+
+        with container.resolve(Connection) as connection:
+            assert isinstance(connection, Connection)
+
+        with container.resolve(Connection) as again:
+            assert again is connection
+
+    def it_returns_the_instance_when_the_handle_is_dropped(self) -> None:
+        container = pooled_container(size=1)
+        connection = container.resolve(Connection).value
+
+        assert container.resolve(Connection).value is connection
+
+    def it_rejects_use_of_a_returned_handle(self) -> None:
+        container = pooled_container(size=1)
+        handle = container.resolve(Connection)
+        handle.release()
+        handle.release()
+
+        with pytest.raises(RuntimeError, match='already returned'):
+            handle.value  # noqa: B018
+
+    def it_blocks_until_an_instance_is_returned(self) -> None:
+        container = pooled_container(size=1, timeout_ms=None)
+        handle = container.resolve(Connection)
+        connection = handle.value
+        seen = []
+        waiter = threading.Thread(target=lambda: seen.append(container.resolve(Connection).value))
+
+        waiter.start()
+        time.sleep(0.05)
+        assert seen == []
+        handle.release()
+        waiter.join(timeout=5)
+
+        assert seen == [connection]
+
+    def it_fails_once_the_timeout_passes(self) -> None:
+        container = pooled_container(size=1, timeout_ms=50)
+        _handle = container.resolve(Connection)
+
+        started = time.monotonic()
+        with pytest.raises(PoolExhaustedError):
+            container.resolve(Connection)
+
+        assert time.monotonic() - started >= 0.04
+
+    def it_frees_the_slot_of_an_instance_that_failed_to_build(self) -> None:
+        attempts = []
+
+        def connect() -> Connection:
+            attempts.append(1)
+            if len(attempts) == 1:
+                raise ConnectionError('refused')
+            return Connection()
+
+        container = RustContainer()
+        container.register_pooled_factory(Connection, connect, size=1, timeout_ms=0)
+
+        with pytest.raises(KeyError, match='refused'):
+            container.resolve(Connection)
+
+        assert isinstance(container.resolve(Connection).value, Connection)
+
+    def it_rejects_an_empty_pool(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='pool size must be at least 1'):
+            container.register_pooled_factory(Connection, Connection, size=0)
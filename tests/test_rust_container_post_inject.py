@@ -0,0 +1,107 @@
+"""Tests for post-construct hooks called once dependencies are injected."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import post_inject
+
+
+class Config:
+    url = 'sqlite://'
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+        self.calls: list[str] = []
+
+    def __post_inject__(self) -> None:
+        self.calls.append(f'connect {self.config.url}')
+
+
+class Cache:
+    def __init__(self, config: Config) -> None:
+        self.calls: list[str] = []
+
+    @post_inject
+    def warm(self) -> None:
+        self.calls.append('warm')
+
+    @post_inject
+    def verify(self) -> None:
+        self.calls.append('verify')
+
+
+class TieredCache(Cache):
+    def __post_inject__(self) -> None:
+        self.calls.append('post_inject')
+
+    @post_inject
+    def link(self) -> None:
+        self.calls.append('link')
+
+
+class UnmarkedCache(Cache):
+    def warm(self) -> None:
+        self.calls.append('unmarked warm')
+
+
+class Broken:
+    def __post_inject__(self) -> None:
+        raise RuntimeError('not ready')
+
+
+class DescribePostInject:
+    """Constructed instances finish initialization after injection."""
+
+    def it_calls_post_inject_after_injecting(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_class(Database, Database)
+
+        assert container.resolve(Database).calls == ['connect sqlite://']
+
+    def it_calls_marked_methods_in_definition_order(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_transient_factory(Cache, Cache)
+
+        assert container.resolve(Cache).calls == ['warm', 'verify']
+
+    def it_calls_inherited_hooks_before_the_subclass_ones(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_class(TieredCache, TieredCache)
+
+        assert container.resolve(TieredCache).calls == ['post_inject', 'warm', 'verify', 'link']
+
+    def it_skips_a_marked_method_overridden_without_the_marker(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_class(UnmarkedCache, UnmarkedCache)
+
+        assert container.resolve(UnmarkedCache).calls == ['verify']
+
+    def it_runs_hooks_once_per_constructed_instance(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_transient_factory(Cache, Cache)
+
+        first = container.resolve(Cache)
+        container.resolve(Cache)
+
+        assert first.calls == ['warm', 'verify']
+
+    def it_leaves_factory_results_alone(self) -> None:
+        container = RustContainer()
+        config = Config()
+        container.register_singleton_factory(Database, lambda: Database(config))
+
+        assert container.resolve(Database).calls == []
+
+    def it_fails_the_resolve_when_a_hook_raises(self) -> None:
+        container = RustContainer()
+        container.register_class(Broken, Broken)
+
+        with pytest.raises(KeyError, match='not ready'):
+            container.resolve(Broken)
@@ -0,0 +1,95 @@
+"""Tests for profile-tagged registrations filtered by the active profile."""
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide.profile_enum import Profile
+
+
+class EmailSender:
+    pass
+
+
+class SmtpSender(EmailSender):
+    pass
+
+
+class FakeSender(EmailSender):
+    pass
+
+
+class Clock:
+    pass
+
+
+class Notifier:
+    def __init__(self, sender: EmailSender) -> None:
+        self.sender = sender
+
+
+def register_senders(container: RustContainer) -> None:
+    container.register_class(EmailSender, SmtpSender, profile='production')
+    container.register_class(EmailSender, FakeSender, profile='test')
+
+
+class DescribeProfiles:
+    """Registrations tagged for another profile are invisible to the container."""
+
+    def it_keeps_only_registrations_for_the_active_profile(self) -> None:
+        production = RustContainer(profile='production')
+        test = RustContainer(profile='test')
+        register_senders(production)
+        register_senders(test)
+
+        assert isinstance(production.resolve(EmailSender), SmtpSender)
+        assert isinstance(test.resolve(EmailSender), FakeSender)
+        assert len(test) == 1
+
+    def it_wires_dependencies_from_the_active_profile(self) -> None:
+        container = RustContainer(profile='test')
+        register_senders(container)
+        container.register_transient_factory(Notifier, Notifier)
+
+        assert isinstance(container.resolve(Notifier).sender, FakeSender)
+
+    def it_applies_untagged_and_wildcard_registrations_everywhere(self) -> None:
+        container = RustContainer(profile='development')
+        container.register_class(Clock, Clock)
+        container.register_class(EmailSender, SmtpSender, profile='*')
+
+        assert container.contains(Clock)
+        assert container.contains(EmailSender)
+
+    def it_leaves_non_matching_keys_unregistered(self) -> None:
+        container = RustContainer(profile='development')
+        register_senders(container)
+
+        assert not container.contains(EmailSender)
+
+    def it_does_not_run_register_hooks_for_skipped_registrations(self) -> None:
+        container = RustContainer(profile='test')
+        seen = []
+        container.on_register(lambda *event: seen.append(event))
+
+        register_senders(container)
+
+        assert len(seen) == 1
+
+    def it_compares_profiles_case_insensitively(self) -> None:
+        container = RustContainer(profile=Profile.PRODUCTION)
+        container.register_class(EmailSender, SmtpSender, profile='PRODUCTION')
+
+        assert container.profile() == 'production'
+        assert isinstance(container.resolve(EmailSender), SmtpSender)
+
+    def it_applies_every_registration_without_an_active_profile(self) -> None:
+        container = RustContainer(on_duplicate='replace')
+        register_senders(container)
+
+        assert container.profile() is None
+        assert isinstance(container.resolve(EmailSender), FakeSender)
+
+    def it_passes_the_profile_to_child_containers(self) -> None:
+        child = RustContainer(profile='test').create_child()
+        register_senders(child)
+
+        assert child.profile() == 'test'
+        assert isinstance(child.resolve(EmailSender), FakeSender)
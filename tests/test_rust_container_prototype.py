@@ -0,0 +1,55 @@
+"""Tests for prototype (deep-copied) instance registrations."""
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class ReportTemplate:
+    def __init__(self) -> None:
+        self.title = 'Quarterly report'
+        self.sections: list[str] = ['summary']
+        self.options = {'format': 'pdf', 'tags': ['finance']}
+
+
+class DescribeRegisterInstanceWithCopy:
+    """register_instance(..., copy=True) returns a deep copy per resolve."""
+
+    def it_returns_a_new_copy_on_every_resolve(self) -> None:
+        container = RustContainer()
+        template = ReportTemplate()
+        container.register_instance(ReportTemplate, template, copy=True)
+
+        first = container.resolve(ReportTemplate)
+        second = container.resolve(ReportTemplate)
+
+        assert first is not template
+        assert first is not second
+        assert first.title == second.title == template.title
+
+    def it_does_not_leak_mutations_to_the_template(self) -> None:
+        container = RustContainer()
+        template = ReportTemplate()
+        container.register_instance(ReportTemplate, template, copy=True)
+
+        report = container.resolve(ReportTemplate)
+        report.sections.append('appendix')
+        report.options['tags'].append('draft')
+
+        assert template.sections == ['summary']
+        assert template.options == {'format': 'pdf', 'tags': ['finance']}
+
+    def it_does_not_leak_mutations_between_callers(self) -> None:
+        container = RustContainer()
+        container.register_instance(ReportTemplate, ReportTemplate(), copy=True)
+
+        first = container.resolve(ReportTemplate)
+        first.options['tags'].append('draft')
+        second = container.resolve(ReportTemplate)
+
+        assert second.options['tags'] == ['finance']
+
+    def it_shares_the_instance_without_copy(self) -> None:
+        container = RustContainer()
+        template = ReportTemplate()
+        container.register_instance(ReportTemplate, template)
+
+        assert container.resolve(ReportTemplate) is template
@@ -0,0 +1,67 @@
+"""Tests for swapping a registration's factory during module reloads."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Greeter:
+    def __init__(self, greeting: str) -> None:
+        self.greeting = greeting
+
+
+class DescribeRebindFactory:
+    """rebind_factory() swaps the callable and keeps the registration."""
+
+    def it_uses_the_new_factory_on_next_resolve(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Greeter, lambda: Greeter('hello'))
+        original = container.resolve(Greeter)
+
+        container.rebind_factory(Greeter, lambda: Greeter('hi'))
+        reloaded = container.resolve(Greeter)
+
+        assert original.greeting == 'hello'
+        assert reloaded.greeting == 'hi'
+        assert reloaded is not original
+
+    def it_keeps_singleton_registrations_cached(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Greeter, lambda: Greeter('hello'))
+
+        container.rebind_factory(Greeter, lambda: Greeter('hi'))
+
+        assert container.resolve(Greeter) is container.resolve(Greeter)
+
+    def it_keeps_transient_registrations_transient(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Greeter, lambda: Greeter('hello'))
+
+        container.rebind_factory(Greeter, lambda: Greeter('hi'))
+        first = container.resolve(Greeter)
+        second = container.resolve(Greeter)
+
+        assert first.greeting == second.greeting == 'hi'
+        assert first is not second
+
+    def it_does_not_add_a_registration(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Greeter, lambda: Greeter('hello'))
+
+        container.rebind_factory(Greeter, lambda: Greeter('hi'))
+
+        assert len(container) == 1
+        assert container.contains(Greeter)
+
+    def it_rejects_unregistered_types(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='not registered'):
+            container.rebind_factory(Greeter, lambda: Greeter('hi'))
+
+    def it_rejects_non_factory_registrations(self) -> None:
+        container = RustContainer()
+        container.register_instance(Greeter, Greeter('hello'))
+
+        with pytest.raises(KeyError, match='not a factory'):
+            container.rebind_factory(Greeter, lambda: Greeter('hi'))
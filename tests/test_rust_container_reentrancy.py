@@ -0,0 +1,196 @@
+"""Tests for Python code re-entering the container while it is working."""
+
+import threading
+import time
+from enum import Enum
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DuplicatePolicy, NotCachedError
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class Service:
+    pass
+
+
+class Region(Enum):
+    EU = 'eu'
+    US = 'us'
+
+
+class Endpoint(Enum):
+    """Registers into the container whenever a member's name is read."""
+
+    PRIMARY = 'primary'
+    REPLICA = 'replica'
+
+    @property
+    def name(self) -> str:
+        for container in NAME_LISTENERS:
+            container.register_instance(Config, Config(), on_duplicate=DuplicatePolicy.REPLACE)
+        return self.value.upper()
+
+
+NAME_LISTENERS: list[RustContainer] = []
+
+
+class Finalizer:
+    """Resolves Config from the container when garbage collected."""
+
+    def __init__(self, container: RustContainer, seen: list) -> None:
+        self.container = container
+        self.seen = seen
+
+    def __del__(self) -> None:
+        self.seen.append(self.container.resolve(Config))
+
+
+def register_finalizer(container: RustContainer, seen: list) -> None:
+    container.register_instance(Config, Config())
+    container.register_singleton_factory(Database, lambda: Finalizer(container, seen))
+    container.resolve(Database)
+
+
+class DescribeReentrantResolution:
+    """Providers, decorators and hooks may call back into the container."""
+
+    def it_lets_factories_resolve_their_dependencies(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        container.register_singleton_factory(Service, lambda: (container.resolve(Config), Service())[1])
+
+        container.resolve(Service)
+
+        assert container.construction_log() == ['Config', 'Service']
+
+    def it_lets_decorators_and_hooks_resolve(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        container.register_transient_factory(Service, Service)
+        container.add_decorator(Service, lambda service: (container.resolve(Config), service)[1])
+        configs = []
+        container.on_resolve(lambda key, *_: configs.append(container.resolve(Config)) if key is Service else None)
+
+        container.resolve(Service)
+
+        assert configs == [container.resolve(Config)]
+
+    def it_lets_finalizers_resolve_when_a_singleton_is_reset(self) -> None:
+        container = RustContainer()
+        seen = []
+        register_finalizer(container, seen)
+
+        container.reset()
+
+        assert seen == [container.resolve(Config)]
+
+    def it_lets_finalizers_resolve_when_a_singleton_is_replaced(self) -> None:
+        container = RustContainer()
+        seen = []
+        register_finalizer(container, seen)
+
+        container.register_instance(Database, Database(), on_duplicate=DuplicatePolicy.REPLACE)
+
+        assert len(seen) == 1
+
+    def it_lets_finalizers_resolve_when_a_binding_is_unregistered(self) -> None:
+        container = RustContainer()
+        seen = []
+        register_finalizer(container, seen)
+
+        container.unregister(Database)
+
+        assert len(seen) == 1
+
+    def it_lets_finalizers_touch_the_registry_during_clear(self) -> None:
+        container = RustContainer()
+        counts = []
+
+        class Counter:
+            def __del__(self) -> None:
+                counts.append(len(container))
+
+        container.register_singleton_factory(Database, Counter)
+        container.resolve(Database)
+
+        container.clear()
+
+        assert counts == [0]
+
+    def it_reports_unregistered_enum_members_with_their_siblings(self) -> None:
+        container = RustContainer()
+        container.register_instance(Region.EU, 'eu-west-1')
+
+        with pytest.raises(KeyError, match=r'registered members: Region\.EU'):
+            container.resolve(Region.US)
+
+
+class DescribeReentrantDisplayNames:
+    """Display names are formatted after registry locks are released."""
+
+    def setup_method(self) -> None:
+        NAME_LISTENERS.clear()
+
+    def registering_container(self) -> RustContainer:
+        container = RustContainer()
+        container.register_instance(Endpoint.PRIMARY, 'db-1')
+        container.register_singleton_factory(Endpoint.REPLICA, lambda: 'db-2')
+        NAME_LISTENERS.append(container)
+        return container
+
+    def it_renders_dot_while_names_register(self) -> None:
+        container = self.registering_container()
+
+        assert '"Endpoint.PRIMARY"' in container.to_dot()
+
+    def it_lists_registrations_while_names_register(self) -> None:
+        container = self.registering_container()
+
+        names = [registration['key'] for registration in container.list_registrations()]
+
+        assert Endpoint.PRIMARY in names
+
+    def it_renders_json_while_names_register(self) -> None:
+        container = self.registering_container()
+
+        assert '"Endpoint.PRIMARY"' in container.to_json()
+
+    def it_reports_cold_keys_while_names_register(self) -> None:
+        container = self.registering_container()
+
+        with pytest.raises(NotCachedError, match=r'Endpoint\.REPLICA'):
+            container.resolve_cached_only([Endpoint.PRIMARY, Endpoint.REPLICA])
+
+
+class DescribeConcurrentResolution:
+    """Threads resolving through slow factories share one singleton."""
+
+    def it_keeps_the_first_cached_singleton_when_threads_race(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+
+        def slow_database() -> Database:
+            time.sleep(0.01)
+            container.resolve(Config)
+            return Database()
+
+        container.register_singleton_factory(Database, slow_database)
+        results = []
+        threads = [threading.Thread(target=lambda: results.append(container.resolve(Database))) for _ in range(8)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join(timeout=10)
+
+        assert len(results) == 8
+        assert all(result is container.resolve(Database) for result in results)
+        assert container.construction_log().count('Database') == 1
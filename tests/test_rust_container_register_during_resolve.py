@@ -0,0 +1,103 @@
+"""Tests for registering providers from inside a resolution."""
+
+import threading
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DuplicatePolicy
+
+
+class Config:
+    pass
+
+
+class Exporter:
+    pass
+
+
+class Metrics:
+    def __init__(self, exporter: Exporter) -> None:
+        self.exporter = exporter
+
+
+class Plugin:
+    pass
+
+
+class DescribeRegistrationDuringResolution:
+    """Factories may register providers while they are being resolved."""
+
+    def it_lets_a_plugin_factory_register_and_resolve_its_services(self) -> None:
+        container = RustContainer()
+
+        def load_plugin() -> Plugin:
+            container.register_instance(Exporter, Exporter())
+            container.register_class(Metrics, Metrics)
+            assert isinstance(container.resolve(Metrics).exporter, Exporter)
+            return Plugin()
+
+        container.register_singleton_factory(Plugin, load_plugin)
+
+        container.resolve(Plugin)
+
+        assert container.resolve(Metrics).exporter is container.resolve(Exporter)
+
+    def it_lets_auto_wired_constructors_register(self) -> None:
+        container = RustContainer()
+
+        class SelfRegistering:
+            def __init__(self, config: Config) -> None:
+                container.register_instance(Exporter, Exporter())
+
+        container.register_class(SelfRegistering, SelfRegistering)
+        container.register_class(Config, Config)
+
+        container.resolve(SelfRegistering)
+
+        assert container.contains(Exporter)
+
+    def it_lets_resolve_hooks_and_register_hooks_register(self) -> None:
+        container = RustContainer()
+        container.register_instance(Config, Config())
+        container.on_resolve(
+            lambda key, *_: container.register_class(Exporter, Exporter) if not container.contains(Exporter) else None
+        )
+        container.on_register(
+            lambda key, *_: container.register_class(Plugin, Plugin) if key is Exporter else None
+        )
+
+        container.resolve(Config)
+
+        assert container.contains(Exporter)
+        assert container.contains(Plugin)
+
+    def it_does_not_cache_an_instance_whose_binding_was_replaced_while_building(self) -> None:
+        container = RustContainer()
+        replacement = Plugin()
+
+        def bootstrap() -> Plugin:
+            container.register_instance(Plugin, replacement, on_duplicate=DuplicatePolicy.REPLACE)
+            return Plugin()
+
+        container.register_singleton_factory(Plugin, bootstrap)
+
+        first = container.resolve(Plugin)
+
+        assert first is not replacement
+        assert container.resolve(Plugin) is replacement
+
+    def it_lets_factories_on_several_threads_register_concurrently(self) -> None:
+        container = RustContainer()
+        services = [type(f'Service{index}', (), {}) for index in range(8)]
+        loaders = [type(f'Loader{index}', (), {}) for index in range(8)]
+        for service, loader in zip(services, loaders):
+            container.register_singleton_factory(
+                loader, lambda service=service: container.register_transient_factory(service, service)
+            )
+
+        threads = [threading.Thread(target=container.resolve, args=(loader,)) for loader in loaders]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join(timeout=10)
+
+        assert all(container.contains(service) for service in services)
@@ -0,0 +1,115 @@
+"""Tests for registration hooks registered with on_register()."""
+
+from types import ModuleType
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DuplicatePolicy, provider
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class Plugin:
+    pass
+
+
+class DescribeRegisterHooks:
+    """on_register() hooks see every registration before it is applied."""
+
+    def it_reports_the_key_provider_kind_and_lifetime(self) -> None:
+        container = RustContainer()
+        events = []
+        container.on_register(lambda *event: events.append(event))
+
+        container.register_class(Database, Database)
+        container.register_transient_factory(Config, Config)
+        container.register_tenant_factory(Plugin, Plugin)
+
+        assert events == [
+            (Database, 'class', 'singleton'),
+            (Config, 'transient_factory', 'transient'),
+            (Plugin, 'tenant_factory', 'tenant'),
+        ]
+
+    def it_rejects_registrations_when_a_hook_raises(self) -> None:
+        container = RustContainer()
+
+        @container.on_register
+        def forbid_transients(key: type, kind: str, lifetime: str) -> None:
+            if lifetime == 'transient':
+                raise ValueError(f'{key.__name__} must not be transient')
+
+        container.register_class(Database, Database)
+        with pytest.raises(KeyError, match='Config must not be transient'):
+            container.register_transient_factory(Config, Config)
+
+        assert container.contains(Database)
+        assert not container.contains(Config)
+
+    def it_skips_duplicates_that_are_not_applied(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        events = []
+        container.on_register(lambda *event: events.append(event))
+
+        container.register_class(Database, Database, on_duplicate=DuplicatePolicy.IGNORE)
+        with pytest.raises(KeyError, match='Duplicate'):
+            container.register_class(Database, Database)
+        container.register_instance(Database, Database(), on_duplicate=DuplicatePolicy.REPLACE)
+
+        assert events == [(Database, 'instance', 'singleton')]
+
+    def it_reports_multi_bindings(self) -> None:
+        container = RustContainer()
+        events = []
+        container.on_register(lambda *event: events.append(event))
+
+        container.register_multi_class(Plugin, Plugin)
+
+        assert events == [(Plugin, 'class', 'singleton')]
+
+    def it_rejects_a_whole_module_batch(self) -> None:
+        @provider
+        class Cache:
+            pass
+
+        @provider
+        class Queue:
+            pass
+
+        module = ModuleType('app.batch')
+        for member in (Cache, Queue):
+            member.__module__ = module.__name__
+            setattr(module, member.__name__, member)
+        container = RustContainer()
+
+        def reject_queue(key: type, *_: str) -> None:
+            if key is Queue:
+                raise PermissionError('queues are not allowed')
+
+        container.on_register(reject_queue)
+
+        with pytest.raises(KeyError, match='queues are not allowed'):
+            container.register_module(module)
+
+        assert container.is_empty()
+
+    def it_stops_calling_removed_hooks(self) -> None:
+        container = RustContainer()
+        events = []
+
+        def hook(*event: object) -> None:
+            events.append(event)
+
+        container.on_register(hook)
+        assert container.remove_register_hook(hook) is True
+        container.register_class(Database, Database)
+
+        assert events == []
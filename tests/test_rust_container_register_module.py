@@ -0,0 +1,158 @@
+"""Tests for registering a module's @provider members atomically."""
+
+from abc import ABC, abstractmethod
+from types import ModuleType
+
+import pytest
+
+from dioxide import Scope
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import provider
+
+
+class Clock(ABC):
+    @abstractmethod
+    def now(self) -> float: ...
+
+
+class Settings:
+    pass
+
+
+def make_module(name: str, **members: object) -> ModuleType:
+    module = ModuleType(name)
+    for attr, member in members.items():
+        member.__module__ = name
+        setattr(module, attr, member)
+    return module
+
+
+class DescribeRegisterModule:
+    """register_module() registers every @provider defined in a module."""
+
+    def it_registers_marked_classes_and_factories(self) -> None:
+        @provider
+        class Database:
+            pass
+
+        @provider(key=Clock, scope=Scope.SINGLETON)
+        class FixedClock(Clock):
+            def now(self) -> float:
+                return 0.0
+
+        @provider(scope='factory')
+        def make_settings() -> Settings:
+            return Settings()
+
+        class Unmarked:
+            pass
+
+        module = make_module(
+            'app.providers', Database=Database, FixedClock=FixedClock, make_settings=make_settings, Unmarked=Unmarked
+        )
+        container = RustContainer()
+
+        keys = container.register_module(module)
+
+        assert keys == [Database, Clock, Settings]
+        assert container.all_bindings(Database)[0]['provider'] == 'class'
+        assert container.all_bindings(Clock)[0]['provider'] == 'class'
+        assert container.all_bindings(Settings)[0]['provider'] == 'transient_factory'
+        assert container.resolve(Clock) is container.resolve(Clock)
+        assert container.resolve(Settings) is not container.resolve(Settings)
+        assert not container.contains(Unmarked)
+
+    def it_registers_named_providers(self) -> None:
+        @provider(key=Clock, name='fixed')
+        class FixedClock(Clock):
+            def now(self) -> float:
+                return 0.0
+
+        @provider(name='defaults', scope='factory')
+        def make_settings() -> Settings:
+            return Settings()
+
+        module = make_module('app.named', FixedClock=FixedClock, make_settings=make_settings)
+        container = RustContainer()
+
+        assert container.register_module(module) == [Clock, Settings]
+        assert container.registration_info(Clock, name='fixed')['name'] == 'fixed'
+        assert container.registration_info(Clock) is None
+        assert isinstance(container.resolve(Clock, name='fixed'), FixedClock)
+        assert container.resolve(Settings, name='defaults') is not container.resolve(Settings, name='defaults')
+
+    def it_skips_providers_imported_from_other_modules(self) -> None:
+        @provider
+        class Database:
+            pass
+
+        module = ModuleType('app.reexports')
+        module.Database = Database
+        container = RustContainer()
+
+        assert container.register_module(module) == []
+        assert container.is_empty()
+
+    def it_skips_subclasses_that_only_inherit_the_marker(self) -> None:
+        @provider
+        class Database:
+            pass
+
+        class ReplicaDatabase(Database):
+            pass
+
+        module = make_module('app.db', Database=Database, ReplicaDatabase=ReplicaDatabase)
+        container = RustContainer()
+
+        assert container.register_module(module) == [Database]
+
+    def it_rolls_back_every_registration_on_a_duplicate(self) -> None:
+        @provider
+        class Database:
+            pass
+
+        @provider
+        def make_settings() -> Settings:
+            return Settings()
+
+        module = make_module('app.dupes', Database=Database, make_settings=make_settings)
+        container = RustContainer()
+        container.register_instance(Settings, Settings())
+
+        with pytest.raises(KeyError, match='Duplicate provider registration: Settings'):
+            container.register_module(module)
+
+        assert len(container) == 1
+        assert not container.contains(Database)
+
+    def it_rejects_abstract_classes(self) -> None:
+        @provider
+        class Database:
+            pass
+
+        @provider
+        class AbstractClock(Clock):
+            pass
+
+        module = make_module('app.abstract', Database=Database, AbstractClock=AbstractClock)
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='abstract classes cannot be instantiated'):
+            container.register_module(module)
+
+        assert container.is_empty()
+
+    def it_rejects_factories_without_a_key(self) -> None:
+        @provider
+        def make_settings():  # type: ignore[no-untyped-def]
+            return Settings()
+
+        module = make_module('app.untyped', make_settings=make_settings)
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='factory needs key= or a return annotation'):
+            container.register_module(module)
+
+    def it_rejects_unknown_scopes_when_decorating(self) -> None:
+        with pytest.raises(TypeError, match='Unknown scope'):
+            provider(scope='session')
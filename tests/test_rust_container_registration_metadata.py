@@ -0,0 +1,97 @@
+"""Tests for the tags, description and source location kept with registrations."""
+
+import inspect
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Database:
+    pass
+
+
+class Cache:
+    pass
+
+
+def next_line() -> tuple[str, int]:
+    """File and line just after the caller's."""
+    frame = inspect.currentframe().f_back  # type: ignore[union-attr]
+    return frame.f_code.co_filename, frame.f_lineno + 1  # type: ignore[union-attr]
+
+
+class DescribeRegistrationMetadata:
+    """register_* calls carry tags and a description and record where they ran."""
+
+    def it_records_the_file_and_line_of_the_registration(self) -> None:
+        container = RustContainer()
+
+        location = next_line()
+        container.register_class(Database, Database)
+
+        assert container.registration_info(Database)['source'] == location
+
+    def it_keeps_the_description(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database, description='Primary Postgres pool')
+
+        assert container.registration_info(Database)['description'] == 'Primary Postgres pool'
+
+    def it_attaches_tags(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Cache, Cache, tags=['infra', 'cache'])
+
+        assert container.registration_info(Cache)['tags'] == ['cache', 'infra']
+        assert container.tags(Cache) == ['cache', 'infra']
+
+    def it_accepts_metadata_on_every_register_method(self) -> None:
+        container = RustContainer()
+
+        container.register_instance(Database, Database(), tags=['a'], description='instance')
+        container.register_env(Cache, 'CACHE_URL', required=False, tags=['b'], description='env')
+
+        assert [record['description'] for record in container.list_registrations()] == ['env', 'instance']
+
+    def it_looks_up_named_registrations(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database, name='replica', description='Read replica')
+
+        assert container.registration_info(Database, name='replica')['description'] == 'Read replica'
+        assert container.registration_info(Database) is None
+
+    def it_replaces_the_metadata_with_the_registration(self) -> None:
+        container = RustContainer(on_duplicate='replace')
+        container.register_class(Database, Database, description='old')
+
+        location = next_line()
+        container.register_class(Database, Database)
+
+        info = container.registration_info(Database)
+        assert info['description'] is None
+        assert info['source'] == location
+
+    def it_keeps_the_metadata_of_a_rejected_duplicate(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database, description='first')
+
+        with pytest.raises(KeyError):
+            container.register_class(Database, Database, description='second')
+
+        assert container.registration_info(Database)['description'] == 'first'
+
+    def it_drops_the_metadata_when_unregistered(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database, description='gone soon')
+        container.unregister(Database)
+
+        container.register_class(Database, Database)
+
+        assert container.registration_info(Database)['description'] is None
+
+    def it_skips_metadata_of_registrations_for_other_profiles(self) -> None:
+        container = RustContainer(profile='test')
+
+        container.register_class(Database, Database, profile='prod', tags=['prod-only'])
+
+        assert container.tags(Database) == []
@@ -0,0 +1,105 @@
+"""Tests for compiled resolution plans and their invalidation."""
+
+from __future__ import annotations
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Cache:
+    pass
+
+
+class Repository:
+    def __init__(self, config: Config, cache: Cache = None) -> None:  # type: ignore[assignment]
+        self.config = config
+        self.cache = cache
+
+
+class Service:
+    def __init__(self, repository: Repository, /) -> None:
+        self.repository = repository
+
+
+class DescribeResolutionPlans:
+    """Auto-wiring replays a compiled plan until the registry changes."""
+
+    def it_replays_the_plan_on_later_resolves(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+        container.register_class(Config, Config)
+
+        first = container.resolve(Repository)
+        second = container.resolve(Repository)
+
+        assert first is not second
+        assert first.config is second.config
+
+    def it_fills_positional_only_parameters(self) -> None:
+        container = RustContainer()
+        container.register_class(Service, Service)
+        container.register_class(Repository, Repository)
+        container.register_class(Config, Config)
+
+        assert isinstance(container.resolve(Service).repository, Repository)
+
+    def it_picks_up_a_dependency_registered_after_compiling(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+        container.register_class(Config, Config)
+        assert container.resolve(Repository).cache is None
+
+        container.register_class(Cache, Cache)
+
+        assert isinstance(container.resolve(Repository).cache, Cache)
+
+    def it_falls_back_to_the_default_once_a_dependency_is_unregistered(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+        container.register_class(Config, Config)
+        container.register_class(Cache, Cache)
+        assert container.resolve(Repository).cache is not None
+
+        container.unregister(Cache)
+
+        assert container.resolve(Repository).cache is None
+
+    def it_recompiles_when_a_fallback_gains_a_registration(self) -> None:
+        base = RustContainer()
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+        container.register_class(Config, Config)
+        container.add_fallback_container(base)
+        assert container.resolve(Repository).cache is None
+
+        base.register_class(Cache, Cache)
+
+        assert container.resolve(Repository).cache is base.resolve(Cache)
+
+    def it_recompiles_when_a_fallback_is_added(self) -> None:
+        base = RustContainer()
+        base.register_class(Cache, Cache)
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+        container.register_class(Config, Config)
+        assert container.resolve(Repository).cache is None
+
+        container.add_fallback_container(base)
+
+        assert container.resolve(Repository).cache is base.resolve(Cache)
+
+    def it_keeps_failing_until_the_missing_dependency_is_registered(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+
+        with pytest.raises(KeyError, match="parameter 'config'"):
+            container.resolve(Repository)
+
+        container.register_class(Config, Config)
+
+        assert isinstance(container.resolve(Repository).config, Config)
@@ -0,0 +1,62 @@
+"""Tests for resolving with a caller-side type assertion."""
+
+from abc import ABC, abstractmethod
+from enum import Enum
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Notifier(ABC):
+    @abstractmethod
+    def send(self, message: str) -> None: ...
+
+
+class EmailNotifier(Notifier):
+    def send(self, message: str) -> None:
+        pass
+
+
+class SmsNotifier(Notifier):
+    def send(self, message: str) -> None:
+        pass
+
+
+class Channel(Enum):
+    EMAIL = 'email'
+
+
+class DescribeResolveAs:
+    """resolve_as() returns the instance only if it has the expected type."""
+
+    def it_returns_instances_of_the_expected_type(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, EmailNotifier)
+
+        notifier = container.resolve_as(Notifier, EmailNotifier)
+
+        assert isinstance(notifier, EmailNotifier)
+
+    def it_accepts_base_classes_as_the_expected_type(self) -> None:
+        container = RustContainer()
+        container.register_instance(Channel.EMAIL, EmailNotifier())
+
+        assert isinstance(container.resolve_as(Channel.EMAIL, Notifier), EmailNotifier)
+
+    def it_raises_a_descriptive_type_error_on_mismatch(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, SmsNotifier)
+
+        with pytest.raises(TypeError) as exc_info:
+            container.resolve_as(Notifier, EmailNotifier)
+
+        assert str(exc_info.value) == (
+            'Resolved Notifier to an instance of SmsNotifier, expected an instance of EmailNotifier'
+        )
+
+    def it_keeps_resolution_errors_as_key_errors(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='not registered'):
+            container.resolve_as(Notifier, EmailNotifier)
@@ -0,0 +1,100 @@
+"""Tests for warm-path resolution that never constructs."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class Cache:
+    pass
+
+
+class DescribeResolveCachedOnly:
+    """resolve_cached_only() returns warm instances or fails loudly."""
+
+    def it_returns_cached_instances_in_request_order(self) -> None:
+        container = RustContainer()
+        config = Config()
+        container.register_instance(Config, config)
+        container.register_singleton_factory(Database, Database)
+        database = container.resolve(Database)
+
+        assert container.resolve_cached_only([Database, Config]) == [database, config]
+
+    def it_names_every_cold_type_in_one_error(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+        container.register_singleton_factory(Cache, Cache)
+        container.register_instance(Config, Config())
+
+        with pytest.raises(KeyError) as exc_info:
+            container.resolve_cached_only([Config, Database, Cache])
+
+        message = str(exc_info.value)
+        assert 'warm-up missing' in message
+        assert 'Database, Cache' in message
+        assert 'Config' not in message
+
+    def it_does_not_construct_cold_types(self) -> None:
+        container = RustContainer()
+        calls: list[str] = []
+
+        def make_database() -> Database:
+            calls.append('database')
+            return Database()
+
+        container.register_singleton_factory(Database, make_database)
+
+        with pytest.raises(KeyError):
+            container.resolve_cached_only([Database])
+
+        assert calls == []
+
+    def it_treats_transient_registrations_as_cold(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Database, Database)
+        container.resolve(Database)
+
+        with pytest.raises(KeyError, match='Database'):
+            container.resolve_cached_only([Database])
+
+    def it_marks_unregistered_types(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match=r'Cache \(not registered\)'):
+            container.resolve_cached_only([Cache])
+
+    def it_returns_the_tenant_partition_instance(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Database, Database)
+        database = container.resolve(Database, tenant='acme')
+
+        assert container.resolve_cached_only([Database], tenant='acme') == [database]
+        with pytest.raises(KeyError, match='Database'):
+            container.resolve_cached_only([Database], tenant='globex')
+
+    def it_returns_this_threads_instance(self) -> None:
+        container = RustContainer()
+        container.register_thread_factory(Database, Database)
+        database = container.resolve(Database)
+
+        assert container.resolve_cached_only([Database]) == [database]
+
+    def it_reads_the_active_cache_namespace(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+
+        with container.cache_namespace('request'):
+            database = container.resolve(Database)
+            assert container.resolve_cached_only([Database]) == [database]
+
+        with pytest.raises(KeyError, match='Database'):
+            container.resolve_cached_only([Database])
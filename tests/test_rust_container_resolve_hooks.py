@@ -0,0 +1,106 @@
+"""Tests for resolution event hooks registered with on_resolve()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Request:
+    pass
+
+
+class DescribeResolveHooks:
+    """on_resolve() hooks observe every successful resolution."""
+
+    def it_reports_cold_and_warm_resolves(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        events = []
+        container.on_resolve(lambda key, provider, cache_hit, duration_ms: events.append((key, provider, cache_hit)))
+
+        container.resolve(Config)
+        container.resolve(Config)
+
+        assert events == [(Config, 'singleton_factory', False), (Config, 'singleton_factory', True)]
+
+    def it_reports_auto_wired_dependencies_before_their_dependents(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_class(Config, Config)
+        resolved = []
+        container.on_resolve(lambda key, *_: resolved.append(key))
+
+        container.resolve(Database)
+
+        assert resolved == [Config, Database]
+
+    def it_reports_the_duration_in_milliseconds(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+        durations = []
+        container.on_resolve(lambda *event: durations.append(event[3]))
+
+        container.resolve(Request)
+
+        assert isinstance(durations[0], float)
+        assert durations[0] >= 0
+
+    def it_can_be_used_as_a_decorator(self) -> None:
+        container = RustContainer()
+        container.register_instance(Config, Config())
+        providers = []
+
+        @container.on_resolve
+        def record(key: type, provider: str, cache_hit: bool, duration_ms: float) -> None:
+            providers.append(provider)
+
+        container.resolve(Config)
+
+        assert callable(record)
+        assert providers == ['instance']
+
+    def it_stops_calling_removed_hooks(self) -> None:
+        container = RustContainer()
+        container.register_instance(Config, Config())
+        calls = []
+
+        def hook(*event: object) -> None:
+            calls.append(event)
+
+        container.on_resolve(hook)
+        assert container.remove_resolve_hook(hook) is True
+        container.resolve(Config)
+
+        assert calls == []
+        assert container.remove_resolve_hook(hook) is False
+
+    def it_skips_failed_resolves(self) -> None:
+        container = RustContainer()
+        calls = []
+        container.on_resolve(lambda *event: calls.append(event))
+
+        with pytest.raises(KeyError):
+            container.resolve(Config)
+
+        assert calls == []
+
+    def it_fails_the_resolve_when_a_hook_raises(self) -> None:
+        container = RustContainer()
+        container.register_instance(Config, Config())
+
+        def reject(*_: object) -> None:
+            raise PermissionError('audit rejected Config')
+
+        container.on_resolve(reject)
+
+        with pytest.raises(KeyError, match='audit rejected Config'):
+            container.resolve(Config)
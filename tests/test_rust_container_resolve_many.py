@@ -0,0 +1,85 @@
+"""Tests for resolving several keys in one call with resolve_many()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DependencyNotRegisteredError
+
+
+class Config:
+    pass
+
+
+class Cache:
+    pass
+
+
+class Repository:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Missing:
+    pass
+
+
+class DescribeResolveMany:
+    """resolve_many() returns a tuple of instances in the order asked for."""
+
+    def it_returns_the_instances_in_order(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_class(Cache, Cache)
+
+        config, cache = container.resolve_many([Config, Cache])
+
+        assert config is container.resolve(Config)
+        assert cache is container.resolve(Cache)
+
+    def it_shares_singletons_with_later_keys(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_transient_factory(Repository, Repository)
+
+        repository, config = container.resolve_many((Repository, Config))
+
+        assert repository.config is config
+
+    def it_accepts_any_iterable_of_keys(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+
+        assert container.resolve_many(key for key in [Config]) == (container.resolve(Config),)
+
+    def it_returns_an_empty_tuple_for_no_keys(self) -> None:
+        assert RustContainer().resolve_many([]) == ()
+
+    def it_raises_for_the_key_that_fails(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+
+        with pytest.raises(DependencyNotRegisteredError) as caught:
+            container.resolve_many([Config, Missing])
+
+        assert caught.value.requested_type is Missing
+
+    def it_resolves_for_a_tenant(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Config, Config)
+
+        first = container.resolve_many([Config], tenant='acme')
+        second = container.resolve_many([Config], tenant='globex')
+
+        assert first == container.resolve_many([Config], tenant='acme')
+        assert first != second
+
+    def it_notifies_resolve_hooks_for_every_key(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        container.register_class(Cache, Cache)
+        seen = []
+        container.on_resolve(lambda key, provider, cache_hit, duration_ms: seen.append(key))
+
+        container.resolve_many([Config, Cache])
+
+        assert seen == [Config, Cache]
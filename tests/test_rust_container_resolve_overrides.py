@@ -0,0 +1,99 @@
+"""Tests for per-call parameter overrides passed to resolve()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DioxideError
+
+
+class Translator:
+    pass
+
+
+class ReportGenerator:
+    def __init__(self, translator: Translator, locale: str = 'en') -> None:
+        self.translator = translator
+        self.locale = locale
+
+
+class Cache:
+    def __init__(self, size: int, policy: str) -> None:
+        self.size = size
+        self.policy = policy
+
+
+def make_cache(size: int = 64, policy: str = 'lru') -> Cache:
+    return Cache(size, policy)
+
+
+class DescribeResolveOverrides:
+    """resolve(key, overrides=...) passes explicit values and injects the rest."""
+
+    def it_overrides_selected_constructor_parameters(self) -> None:
+        container = RustContainer()
+        container.register_class(Translator, Translator)
+        container.register_transient_factory(ReportGenerator, ReportGenerator)
+
+        report = container.resolve(ReportGenerator, overrides={'locale': 'de'})
+
+        assert report.locale == 'de'
+        assert report.translator is container.resolve(Translator)
+
+    def it_overrides_an_injected_dependency(self) -> None:
+        container = RustContainer()
+        container.register_class(Translator, Translator)
+        container.register_transient_factory(ReportGenerator, ReportGenerator)
+        translator = Translator()
+
+        assert container.resolve(ReportGenerator, overrides={'translator': translator}).translator is translator
+
+    def it_fills_parameters_nothing_provides(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Cache, Cache)
+
+        cache = container.resolve(Cache, overrides={'size': 8, 'policy': 'fifo'})
+
+        assert (cache.size, cache.policy) == (8, 'fifo')
+
+    def it_merges_over_bound_factory_kwargs(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Cache, make_cache, kwargs={'size': 1024, 'policy': 'lfu'})
+
+        cache = container.resolve(Cache, overrides={'policy': 'fifo'})
+
+        assert (cache.size, cache.policy) == (1024, 'fifo')
+
+    def it_leaves_the_singleton_cache_alone(self) -> None:
+        container = RustContainer()
+        container.register_class(Translator, Translator)
+        container.register_class(ReportGenerator, ReportGenerator)
+        shared = container.resolve(ReportGenerator)
+
+        custom = container.resolve(ReportGenerator, overrides={'locale': 'fr'})
+
+        assert custom is not shared
+        assert container.resolve(ReportGenerator) is shared
+        assert shared.locale == 'en'
+
+    def it_still_applies_decorators(self) -> None:
+        container = RustContainer()
+        container.register_class(Translator, Translator)
+        container.register_transient_factory(ReportGenerator, ReportGenerator)
+        container.add_decorator(ReportGenerator, lambda report: (report, 'decorated'))
+
+        report, marker = container.resolve(ReportGenerator, overrides={'locale': 'de'})
+
+        assert (report.locale, marker) == ('de', 'decorated')
+
+    def it_rejects_providers_that_are_not_called_per_resolve(self) -> None:
+        container = RustContainer()
+        container.register_instance(Translator, Translator())
+
+        with pytest.raises(DioxideError, match='instance providers are not called per resolve'):
+            container.resolve(Translator, overrides={'locale': 'de'})
+
+    def it_reports_unregistered_keys(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='Dependency not registered: ReportGenerator'):
+            container.resolve(ReportGenerator, overrides={'locale': 'de'})
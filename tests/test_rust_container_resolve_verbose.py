@@ -0,0 +1,95 @@
+"""Tests for resolving with per-call diagnostics."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Request:
+    pass
+
+
+class DescribeResolveVerbose:
+    """resolve_verbose() returns the instance with a diagnostics dict."""
+
+    def it_reports_a_cold_resolve(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        container.register_singleton_factory(Database, lambda: Database(container.resolve(Config)))
+
+        database, info = container.resolve_verbose(Database)
+
+        assert isinstance(database, Database)
+        assert info['cache_hit'] is False
+        assert info['constructions'] == 2
+        assert info['provider'] == 'singleton_factory'
+        assert info['elapsed_ms'] >= 0
+
+    def it_reports_a_warm_resolve(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        first = container.resolve(Config)
+
+        config, info = container.resolve_verbose(Config)
+
+        assert config is first
+        assert info['cache_hit'] is True
+        assert info['constructions'] == 0
+
+    def it_counts_each_transient_construction(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+        container.resolve(Request)
+
+        _, info = container.resolve_verbose(Request)
+
+        assert info['cache_hit'] is False
+        assert info['constructions'] == 1
+        assert info['provider'] == 'transient_factory'
+
+    def it_reports_registered_instances_as_constructing_nothing(self) -> None:
+        container = RustContainer()
+        container.register_instance(Config, Config())
+
+        _, info = container.resolve_verbose(Config)
+
+        assert info['constructions'] == 0
+        assert info['provider'] == 'instance'
+
+    def it_reports_tenant_cache_hits(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Config, Config)
+        container.resolve(Config, tenant='acme')
+
+        _, warm = container.resolve_verbose(Config, tenant='acme')
+        _, cold = container.resolve_verbose(Config, tenant='globex')
+
+        assert warm['cache_hit'] is True
+        assert cold['cache_hit'] is False
+
+    def it_reports_the_provider_of_a_fallback_container(self) -> None:
+        base = RustContainer()
+        base.register_singleton_factory(Config, Config)
+        container = RustContainer()
+        container.add_fallback_container(base)
+        base.resolve(Config)
+
+        _, info = container.resolve_verbose(Config)
+
+        assert info['cache_hit'] is True
+        assert info['provider'] == 'singleton_factory'
+
+    def it_raises_for_unregistered_keys(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError):
+            container.resolve_verbose(Config)
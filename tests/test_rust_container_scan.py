@@ -0,0 +1,96 @@
+"""Tests for registering decorated classes of a package with scan()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+PACKAGE = 'tests.fixtures.rust_scan_pkg'
+
+
+def names(classes: list[type]) -> list[str]:
+    return [cls.__name__ for cls in classes]
+
+
+class DescribeScan:
+    """scan() imports a package and registers the classes its decorators marked."""
+
+    def it_registers_services_under_their_own_type(self) -> None:
+        from tests.fixtures.rust_scan_pkg.services import Clock
+
+        container = RustContainer()
+        container.scan(PACKAGE, profile='production')
+
+        assert container.resolve(Clock) is container.resolve(Clock)
+
+    def it_honours_the_declared_scope(self) -> None:
+        from tests.fixtures.rust_scan_pkg.services import RequestId
+
+        container = RustContainer()
+        container.scan(PACKAGE, profile='production')
+
+        assert container.resolve(RequestId) is not container.resolve(RequestId)
+
+    def it_registers_the_adapter_of_the_active_profile(self) -> None:
+        from tests.fixtures.rust_scan_pkg.services import Mailer, Notifier
+
+        production = RustContainer()
+        production.scan(PACKAGE, profile='production')
+        testing = RustContainer(profile='test')
+        testing.scan(PACKAGE)
+
+        assert production.resolve(Notifier).mailer.send('a') == 'smtp:a'
+        assert testing.resolve(Mailer).send('a') == 'fake:a'
+
+    def it_contributes_multi_adapters_from_sub_packages(self) -> None:
+        from tests.fixtures.rust_scan_pkg.plugins.exporters import Exporter
+
+        container = RustContainer()
+        container.scan(PACKAGE, profile='production')
+
+        assert [exporter.export() for exporter in container.resolve_all(Exporter)] == ['json', 'csv']
+
+    def it_returns_the_registered_classes_once_each(self) -> None:
+        container = RustContainer()
+
+        classes = container.scan(PACKAGE, profile='production')
+
+        assert sorted(names(classes)) == [
+            'Clock',
+            'CsvExporter',
+            'JsonExporter',
+            'Notifier',
+            'RequestId',
+            'SmtpMailer',
+        ]
+
+    def it_ignores_markers_inherited_from_a_decorated_base(self) -> None:
+        from tests.fixtures.rust_scan_pkg.services import SubClock
+
+        container = RustContainer()
+        container.scan(PACKAGE, profile='production')
+
+        assert container.contains(SubClock) is False
+
+    def it_keeps_existing_registrations(self) -> None:
+        from tests.fixtures.rust_scan_pkg.services import Clock
+
+        clock = Clock()
+        container = RustContainer()
+        container.register_instance(Clock, clock)
+
+        classes = container.scan(PACKAGE, profile='production')
+
+        assert container.resolve(Clock) is clock
+        assert 'Clock' not in names(classes)
+
+    def it_rejects_two_adapters_for_one_port(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='Mailer'):
+            container.scan(PACKAGE)
+
+    def it_raises_for_a_missing_package(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(ModuleNotFoundError):
+            container.scan('tests.fixtures.no_such_package')
@@ -0,0 +1,77 @@
+"""Tests for detecting registrations that shadow more specific ones."""
+
+from abc import ABC, abstractmethod
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Notifier(ABC):
+    @abstractmethod
+    def send(self, message: str) -> None: ...
+
+
+class EmailNotifier(Notifier):
+    def send(self, message: str) -> None:
+        pass
+
+
+class SmtpEmailNotifier(EmailNotifier):
+    pass
+
+
+class DescribeCheckShadowing:
+    """check_shadowing() reports bypassed specific registrations."""
+
+    def it_reports_nothing_for_independent_registrations(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, EmailNotifier)
+        container.register_instance(str, 'config')
+
+        assert container.check_shadowing() == []
+
+    def it_reports_interface_bound_to_separately_registered_concrete_class(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, EmailNotifier)
+        container.register_singleton_factory(EmailNotifier, EmailNotifier)
+
+        reports = container.check_shadowing()
+
+        assert reports == [
+            'Notifier is bound to EmailNotifier, which bypasses the more specific registration for EmailNotifier'
+        ]
+
+    def it_reports_intermediate_subtype_registrations(self) -> None:
+        container = RustContainer()
+        container.register_class(Notifier, SmtpEmailNotifier)
+        container.register_singleton_factory(EmailNotifier, SmtpEmailNotifier)
+
+        reports = container.check_shadowing()
+
+        assert len(reports) == 1
+        assert 'Notifier is bound to SmtpEmailNotifier' in reports[0]
+        assert 'registration for EmailNotifier' in reports[0]
+
+    def it_reports_instance_providers_by_their_runtime_type(self) -> None:
+        container = RustContainer()
+        container.register_instance(Notifier, EmailNotifier())
+        container.register_class(EmailNotifier, EmailNotifier)
+
+        reports = container.check_shadowing()
+
+        assert len(reports) == 1
+        assert reports[0].startswith('Notifier is bound to EmailNotifier')
+
+    def it_ignores_registrations_the_implementation_does_not_satisfy(self) -> None:
+        container = RustContainer()
+        container.register_class(SmtpEmailNotifier, SmtpEmailNotifier)
+        container.register_class(EmailNotifier, EmailNotifier)
+
+        # EmailNotifier's implementation is not a SmtpEmailNotifier, so nothing is shadowed
+        assert container.check_shadowing() == []
+
+    def it_ignores_factory_providers(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Notifier, EmailNotifier)
+        container.register_class(EmailNotifier, EmailNotifier)
+
+        assert container.check_shadowing() == []
@@ -0,0 +1,57 @@
+"""Tests for the sharded singleton cache under concurrent resolution."""
+
+import threading
+
+from dioxide._dioxide_core import Container as RustContainer
+
+KEYS = [type(f'Singleton{index}', (), {}) for index in range(64)]
+
+
+class DescribeShardedSingletonCache:
+    """Singletons spread across cache shards behave like one cache."""
+
+    def it_shares_one_instance_per_key_across_threads(self) -> None:
+        container = RustContainer()
+        for key in KEYS:
+            container.register_singleton_factory(key, key)
+        seen: list[dict[type, object]] = []
+
+        def worker() -> None:
+            seen.append({key: container.resolve(key) for key in KEYS})
+
+        threads = [threading.Thread(target=worker) for _ in range(8)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        assert len(seen) == 8
+        for key in KEYS:
+            assert len({id(instances[key]) for instances in seen}) == 1
+        assert container.stats()['cache_size'] == len(KEYS)
+
+    def it_evicts_and_resets_every_shard(self) -> None:
+        container = RustContainer()
+        for key in KEYS:
+            container.register_singleton_factory(key, key)
+        first = {key: container.resolve(key) for key in KEYS}
+
+        container.unregister(KEYS[0])
+        assert container.stats()['cache_size'] == len(KEYS) - 1
+
+        container.reset()
+
+        assert container.stats()['cache_size'] == 0
+        assert all(container.resolve(key) is not first[key] for key in KEYS[1:])
+
+    def it_restores_a_snapshot_spanning_every_shard(self) -> None:
+        container = RustContainer()
+        restored = RustContainer()
+        for key in KEYS:
+            container.register_singleton_factory(key, key)
+            restored.register_singleton_factory(key, key)
+        first = {key: container.resolve(key) for key in KEYS}
+
+        restored.restore_fork_snapshot(container.snapshot_for_fork())
+
+        assert all(restored.resolve(key) is first[key] for key in KEYS)
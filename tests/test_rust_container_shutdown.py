@@ -0,0 +1,162 @@
+"""Tests for graceful shutdown of the Rust container's singletons."""
+
+import asyncio
+import time
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class DescribeShutdown:
+    """shutdown() disposes singletons in reverse order within a budget."""
+
+    def it_disposes_singletons_in_reverse_creation_order(self) -> None:
+        container = RustContainer()
+        disposed: list[str] = []
+
+        class Database:
+            def close(self) -> None:
+                disposed.append('database')
+
+        class Repository:
+            def __init__(self, database: Database) -> None:
+                self.database = database
+
+            def close(self) -> None:
+                disposed.append('repository')
+
+        container.register_singleton_factory(Database, Database)
+        container.register_singleton_factory(Repository, lambda: Repository(container.resolve(Database)))
+        container.resolve(Repository)
+
+        reports = container.shutdown(1000)
+
+        assert reports == []
+        assert disposed == ['repository', 'database']
+
+    def it_awaits_async_disposers(self) -> None:
+        container = RustContainer()
+        disposed: list[str] = []
+
+        class Pool:
+            async def dispose(self) -> None:
+                await asyncio.sleep(0)
+                disposed.append('pool')
+
+        container.register_singleton_factory(Pool, Pool)
+        container.resolve(Pool)
+
+        assert container.shutdown(1000) == []
+        assert disposed == ['pool']
+
+    def it_supports_context_manager_disposal(self) -> None:
+        container = RustContainer()
+        exited: list[object] = []
+
+        class Session:
+            def __enter__(self) -> 'Session':
+                return self
+
+            def __exit__(self, *args: object) -> None:
+                exited.append(args)
+
+        container.register_singleton_factory(Session, Session)
+        container.resolve(Session)
+
+        container.shutdown(1000)
+
+        assert exited == [(None, None, None)]
+
+    def it_reports_a_slow_sync_disposer_and_still_completes(self) -> None:
+        container = RustContainer()
+        disposed: list[str] = []
+
+        class SlowCache:
+            def close(self) -> None:
+                time.sleep(0.1)
+                disposed.append('cache')
+
+        class Logger:
+            def close(self) -> None:
+                disposed.append('logger')
+
+        container.register_singleton_factory(Logger, Logger)
+        container.register_singleton_factory(SlowCache, SlowCache)
+        container.resolve(Logger)
+        container.resolve(SlowCache)
+
+        reports = container.shutdown(50)
+
+        assert len(reports) == 2
+        assert reports[0].startswith('SlowCache: disposal took')
+        assert 'exceeding its' in reports[0]
+        assert reports[1] == 'Logger: not disposed, shutdown timeout exhausted'
+        assert disposed == ['cache']
+
+    def it_cancels_a_slow_async_disposer(self) -> None:
+        container = RustContainer()
+        disposed: list[str] = []
+
+        class SlowClient:
+            async def aclose(self) -> None:
+                await asyncio.sleep(10)
+                disposed.append('client')
+
+        container.register_singleton_factory(SlowClient, SlowClient)
+        container.resolve(SlowClient)
+
+        reports = container.shutdown(50)
+
+        assert len(reports) == 1
+        assert reports[0].startswith('SlowClient: disposal cancelled')
+        assert disposed == []
+
+    def it_reports_failures_without_stopping_other_disposals(self) -> None:
+        container = RustContainer()
+        disposed: list[str] = []
+
+        class Healthy:
+            def close(self) -> None:
+                disposed.append('healthy')
+
+        class Broken:
+            def close(self) -> None:
+                raise RuntimeError('socket already closed')
+
+        container.register_singleton_factory(Healthy, Healthy)
+        container.register_singleton_factory(Broken, Broken)
+        container.resolve(Healthy)
+        container.resolve(Broken)
+
+        reports = container.shutdown(1000)
+
+        assert reports == ['Broken: disposal failed: RuntimeError: socket already closed']
+        assert disposed == ['healthy']
+
+    def it_clears_the_singleton_cache(self) -> None:
+        container = RustContainer()
+
+        class Service:
+            pass
+
+        container.register_singleton_factory(Service, Service)
+        before = container.resolve(Service)
+
+        container.shutdown(1000)
+
+        assert container.resolve(Service) is not before
+        assert container.contains(Service)
+
+    def it_does_not_dispose_registered_instances(self) -> None:
+        container = RustContainer()
+        disposed: list[str] = []
+
+        class External:
+            def close(self) -> None:
+                disposed.append('external')
+
+        container.register_instance(External, External())
+        container.resolve(External)
+
+        container.shutdown(1000)
+
+        assert disposed == []
@@ -0,0 +1,101 @@
+"""Tests for capturing and restoring container state with snapshot()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import FrozenContainerError
+
+
+class Config:
+    pass
+
+
+class Database:
+    pass
+
+
+class FakeDatabase(Database):
+    pass
+
+
+class Plugin:
+    pass
+
+
+class Tagged:
+    def __init__(self, inner: object) -> None:
+        self.inner = inner
+
+
+class DescribeSnapshotRestore:
+    """restore(snapshot) undoes registration changes and new singletons."""
+
+    def it_restores_replaced_and_removed_bindings(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_class(Config, Config)
+        snapshot = container.snapshot()
+
+        container.register_class(Database, FakeDatabase, on_duplicate='replace')
+        container.unregister(Config)
+        container.register_class(Plugin, Plugin)
+        container.restore(snapshot)
+
+        assert type(container.resolve(Database)) is Database
+        assert container.contains(Config)
+        assert not container.contains(Plugin)
+        assert len(snapshot) == 2
+
+    def it_keeps_singletons_cached_before_the_snapshot(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+        config = container.resolve(Config)
+        snapshot = container.snapshot()
+
+        container.reset()
+        container.resolve(Config)
+        container.restore(snapshot)
+
+        assert container.resolve(Config) is config
+
+    def it_discards_singletons_created_after_the_snapshot(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        snapshot = container.snapshot()
+
+        container.register_class(Database, FakeDatabase, on_duplicate='replace')
+        container.resolve(Database)
+        container.restore(snapshot)
+
+        assert type(container.resolve(Database)) is Database
+
+    def it_restores_decorators_and_multi_bindings(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Config, Config)
+        snapshot = container.snapshot()
+
+        container.add_decorator(Config, Tagged)
+        container.register_multi_instance(Plugin, Plugin())
+        container.restore(snapshot)
+
+        assert type(container.resolve(Config)) is Config
+        assert container.resolve_all(Plugin) == []
+
+    def it_can_restore_the_same_snapshot_repeatedly(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        snapshot = container.snapshot()
+
+        for _ in range(2):
+            container.register_class(Database, FakeDatabase, on_duplicate='replace')
+            container.restore(snapshot)
+
+            assert type(container.resolve(Database)) is Database
+
+    def it_rejects_restoring_a_frozen_container(self) -> None:
+        container = RustContainer()
+        snapshot = container.snapshot()
+        container.freeze()
+
+        with pytest.raises(FrozenContainerError, match='Cannot restore: container is frozen'):
+            container.restore(snapshot)
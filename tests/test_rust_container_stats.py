@@ -0,0 +1,170 @@
+"""Tests for the container statistics counters."""
+
+import threading
+import time
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Request:
+    pass
+
+
+class Connection:
+    pass
+
+
+class DescribeStats:
+    """stats() reports counters maintained by the Rust core."""
+
+    def it_starts_at_zero(self) -> None:
+        assert RustContainer().stats() == {
+            'resolutions': 0,
+            'cache_hits': 0,
+            'cache_misses': 0,
+            'instances_created': {},
+            'cache_size': 0,
+            'registrations': 0,
+            'max_concurrent_constructions': 0,
+            'single_flight_waits': 0,
+        }
+
+    def it_counts_resolutions_hits_and_misses(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_singleton_factory(Config, Config)
+        container.register_transient_factory(Request, Request)
+
+        container.resolve(Database)
+        container.resolve(Database)
+        container.resolve(Request)
+        container.resolve(Request)
+
+        stats = container.stats()
+        assert stats['resolutions'] == 5
+        assert stats['cache_hits'] == 1
+        assert stats['cache_misses'] == 2
+        assert stats['instances_created'] == {'class': 1, 'singleton_factory': 1, 'transient_factory': 2}
+        assert stats['cache_size'] == 2
+        assert stats['registrations'] == 3
+
+    def it_counts_tenant_partitions_in_the_cache_size(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Config, Config)
+
+        container.resolve(Config, tenant='acme')
+        container.resolve(Config, tenant='globex')
+        container.resolve(Config, tenant='acme')
+
+        stats = container.stats()
+        assert stats['cache_size'] == 2
+        assert stats['cache_hits'] == 1
+
+    def it_does_not_count_registered_instances_as_created(self) -> None:
+        container = RustContainer()
+        container.register_instance(Config, Config())
+
+        container.resolve(Config)
+
+        assert container.stats()['instances_created'] == {}
+
+    @pytest.mark.asyncio
+    async def it_counts_async_resolves(self) -> None:
+        async def connect() -> Connection:
+            return Connection()
+
+        container = RustContainer()
+        container.register_async_factory(Connection, connect)
+
+        await container.resolve_async(Connection)
+        await container.resolve_async(Connection)
+
+        stats = container.stats()
+        assert stats['resolutions'] == 2
+        assert stats['cache_hits'] == 1
+        assert stats['instances_created'] == {'async_factory': 1}
+
+    def it_resets_counters_but_not_caches(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        container.resolve(Config)
+
+        container.reset_stats()
+
+        stats = container.stats()
+        assert stats['resolutions'] == 0
+        assert stats['instances_created'] == {}
+        assert stats['cache_size'] == 1
+
+
+class DescribeConstructionStats:
+    """stats() reports how concurrent singleton constructions overlapped and coalesced."""
+
+    def it_counts_threads_waiting_for_one_singleton(self) -> None:
+        container = RustContainer()
+
+        def build() -> Config:
+            time.sleep(0.05)
+            return Config()
+
+        container.register_singleton_factory(Config, build)
+        barrier = threading.Barrier(8)
+
+        def worker() -> None:
+            barrier.wait()
+            container.resolve(Config)
+
+        threads = [threading.Thread(target=worker) for _ in range(8)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        stats = container.stats()
+        assert stats['instances_created'] == {'singleton_factory': 1}
+        assert stats['single_flight_waits'] >= 1
+        assert stats['max_concurrent_constructions'] == 1
+
+    def it_records_the_most_constructions_in_flight(self) -> None:
+        container = RustContainer()
+        both_building = threading.Barrier(2, timeout=5)
+
+        def build_config() -> Config:
+            both_building.wait()
+            return Config()
+
+        def build_connection() -> Connection:
+            both_building.wait()
+            return Connection()
+
+        container.register_singleton_factory(Config, build_config)
+        container.register_singleton_factory(Connection, build_connection)
+        threads = [threading.Thread(target=container.resolve, args=(key,)) for key in (Config, Connection)]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        stats = container.stats()
+        assert stats['max_concurrent_constructions'] == 2
+        assert stats['single_flight_waits'] == 0
+
+    def it_resets_the_construction_counters(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Config, Config)
+        container.resolve(Config)
+
+        container.reset_stats()
+
+        assert container.stats()['max_concurrent_constructions'] == 0
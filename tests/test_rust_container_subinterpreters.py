@@ -0,0 +1,72 @@
+"""Tests for using the Rust core from subinterpreters."""
+
+import sys
+from pathlib import Path
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DependencyNotRegisteredError
+
+RESOLVE_IN_SUBINTERPRETER = """
+import sys
+
+sys.path[:] = {path!r}
+try:
+    from dioxide._dioxide_core import Container, DependencyNotRegisteredError
+
+    class Database:
+        pass
+
+    container = Container()
+    container.register_singleton_factory(Database, Database)
+    outcome = type(container.resolve(Database)).__name__
+    if container.resolve(Database) is not container.resolve(Database):
+        outcome = 'singleton not cached'
+    try:
+        container.resolve(int)
+    except DependencyNotRegisteredError:
+        pass
+    else:
+        outcome = 'int resolved'
+except BaseException as exc:
+    outcome = f'{{type(exc).__name__}}: {{exc}}'
+with open({result!r}, 'w') as result:
+    result.write(outcome)
+"""
+
+
+def run_in_subinterpreter(code: str) -> None:
+    """Run code in a fresh subinterpreter that shares the main interpreter's GIL."""
+    try:
+        import _interpreters as low_level  # type: ignore[import-not-found]
+    except ImportError:
+        low_level = pytest.importorskip('_xxsubinterpreters')
+        interpreter_id = low_level.create(isolated=False) if sys.version_info >= (3, 12) else low_level.create()
+    else:
+        interpreter_id = low_level.create('legacy')
+    try:
+        low_level.run_string(interpreter_id, code)
+    finally:
+        low_level.destroy(interpreter_id)
+
+
+class DescribeSubinterpreters:
+    """Each interpreter imports its own module and builds its own containers."""
+
+    def it_resolves_a_container_inside_a_subinterpreter(self, tmp_path: Path) -> None:
+        result = tmp_path / 'outcome.txt'
+
+        run_in_subinterpreter(RESOLVE_IN_SUBINTERPRETER.format(path=sys.path, result=str(result)))
+
+        assert result.read_text() == 'Database'
+
+    def it_keeps_working_in_the_main_interpreter(self, tmp_path: Path) -> None:
+        container = RustContainer()
+        container.register_instance(str, 'still here')
+
+        run_in_subinterpreter(RESOLVE_IN_SUBINTERPRETER.format(path=sys.path, result=str(tmp_path / 'outcome.txt')))
+
+        assert container.resolve(str) == 'still here'
+        with pytest.raises(DependencyNotRegisteredError):
+            container.resolve(int)
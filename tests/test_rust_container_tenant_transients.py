@@ -0,0 +1,108 @@
+"""Tests for disposing transients created for a tenant."""
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Session:
+    def __init__(self) -> None:
+        self.closed = False
+
+    def close(self) -> None:
+        self.closed = True
+
+
+class Cursor:
+    def __init__(self) -> None:
+        self.exited = False
+
+    def __exit__(self, *args: object) -> None:
+        self.exited = True
+
+
+class Plain:
+    pass
+
+
+class Report:
+    def __init__(self, session: Session) -> None:
+        self.session = session
+
+
+class DescribeTenantTransients:
+    """Disposable transients created for a tenant end with its partition."""
+
+    def it_disposes_transients_with_their_tenant(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Session, Session)
+        first = container.resolve(Session, tenant='acme')
+        second = container.resolve(Session, tenant='acme')
+
+        assert container.dispose_tenant('acme', 1000) == []
+
+        assert first.closed is True
+        assert second.closed is True
+
+    def it_leaves_other_tenants_transients_alone(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Session, Session)
+        acme = container.resolve(Session, tenant='acme')
+        globex = container.resolve(Session, tenant='globex')
+
+        container.dispose_tenant('acme', 1000)
+
+        assert acme.closed is True
+        assert globex.closed is False
+
+    def it_tracks_transient_dependencies(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Session, Session)
+        container.register_tenant_factory(Report, Report)
+        report = container.resolve(Report, tenant='acme')
+
+        container.dispose_tenant('acme', 1000)
+
+        assert report.session.closed is True
+
+    def it_exits_context_manager_transients(self) -> None:
+        container = RustContainer()
+        container.register_class(Cursor, Cursor, lifetime='transient')
+        cursor = container.resolve(Cursor, tenant='acme')
+
+        container.dispose_tenant('acme', 1000)
+
+        assert cursor.exited is True
+
+    def it_does_not_track_transients_resolved_without_a_tenant(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Session, Session)
+        session = container.resolve(Session)
+
+        container.shutdown(1000)
+
+        assert session.closed is False
+
+    def it_disposes_every_tenants_transients_on_shutdown(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Session, Session)
+        session = container.resolve(Session, tenant='acme')
+
+        assert container.shutdown(1000) == []
+
+        assert session.closed is True
+
+    def it_skips_unmanaged_keys(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Session, Session)
+        container.mark_unmanaged(Session)
+        session = container.resolve(Session, tenant='acme')
+
+        container.dispose_tenant('acme', 1000)
+
+        assert session.closed is False
+
+    def it_ignores_transients_without_a_disposer(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Plain, Plain)
+        container.resolve(Plain, tenant='acme')
+
+        assert container.dispose_tenant('acme', 1000) == []
@@ -0,0 +1,85 @@
+"""Tests for per-tenant partitioned singletons."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class TenantDatabase:
+    created = 0
+
+    def __init__(self) -> None:
+        TenantDatabase.created += 1
+        self.closed = False
+
+    def close(self) -> None:
+        self.closed = True
+
+
+class Clock:
+    pass
+
+
+class DescribeTenantFactory:
+    """register_tenant_factory() caches one instance per tenant id."""
+
+    def it_isolates_instances_per_tenant(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(TenantDatabase, TenantDatabase)
+
+        acme = container.resolve(TenantDatabase, tenant='acme')
+        globex = container.resolve(TenantDatabase, tenant='globex')
+
+        assert acme is not globex
+        assert container.resolve(TenantDatabase, tenant='acme') is acme
+        assert container.resolve(TenantDatabase, tenant='globex') is globex
+
+    def it_requires_a_tenant_id(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(TenantDatabase, TenantDatabase)
+
+        with pytest.raises(KeyError, match='requires a tenant id'):
+            container.resolve(TenantDatabase)
+
+    def it_ignores_the_tenant_for_other_providers(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Clock, Clock)
+
+        assert container.resolve(Clock, tenant='acme') is container.resolve(Clock)
+
+    def it_disposes_a_single_tenant(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(TenantDatabase, TenantDatabase)
+        acme = container.resolve(TenantDatabase, tenant='acme')
+        globex = container.resolve(TenantDatabase, tenant='globex')
+
+        reports = container.dispose_tenant('acme', 1000)
+
+        assert reports == []
+        assert acme.closed is True
+        assert globex.closed is False
+        assert container.resolve(TenantDatabase, tenant='acme') is not acme
+        assert container.resolve(TenantDatabase, tenant='globex') is globex
+
+    def it_disposes_every_tenant_partition_on_shutdown(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(TenantDatabase, TenantDatabase)
+        acme = container.resolve(TenantDatabase, tenant='acme')
+        globex = container.resolve(TenantDatabase, tenant='globex')
+
+        assert container.shutdown(1000) == []
+
+        assert acme.closed is True
+        assert globex.closed is True
+
+    def it_names_the_tenant_in_disposal_reports(self) -> None:
+        container = RustContainer()
+
+        class Broken:
+            def close(self) -> None:
+                raise RuntimeError('boom')
+
+        container.register_tenant_factory(Broken, Broken)
+        container.resolve(Broken, tenant='acme')
+
+        assert container.shutdown(1000) == ['Broken[tenant=acme]: disposal failed: RuntimeError: boom']
@@ -0,0 +1,99 @@
+"""Tests for the THREAD lifetime: one cached instance per OS thread."""
+
+import json
+import threading
+import weakref
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import Lifetime
+
+
+class Session:
+    pass
+
+
+class Repository:
+    def __init__(self, session: Session) -> None:
+        self.session = session
+
+
+def resolve_in_thread(container: RustContainer, key: type) -> object:
+    seen = []
+    worker = threading.Thread(target=lambda: seen.append(container.resolve(key)))
+    worker.start()
+    worker.join()
+    return seen[0]
+
+
+class DescribeThreadLifetime:
+    """Thread-lifetime providers cache one instance per thread."""
+
+    def it_caches_a_class_once_per_thread(self) -> None:
+        container = RustContainer()
+        container.register_class(Session, Session, lifetime=Lifetime.THREAD)
+
+        session = container.resolve(Session)
+
+        assert container.resolve(Session) is session
+        assert resolve_in_thread(container, Session) is not session
+
+    def it_caches_a_factory_once_per_thread(self) -> None:
+        container = RustContainer()
+        container.register_thread_factory(Session, Session)
+
+        session = container.resolve(Session)
+
+        assert container.resolve(Session) is session
+        assert resolve_in_thread(container, Session) is not session
+
+    def it_wires_the_calling_threads_instance(self) -> None:
+        container = RustContainer()
+        container.register_class(Session, Session, lifetime='thread')
+        container.register_transient_factory(Repository, Repository)
+
+        assert container.resolve(Repository).session is container.resolve(Session)
+
+    def it_drops_a_threads_instances_when_the_thread_ends(self) -> None:
+        container = RustContainer()
+        container.register_thread_factory(Session, Session)
+
+        released = weakref.ref(resolve_in_thread(container, Session))
+
+        assert released() is None
+
+    def it_releases_the_calling_threads_instances_on_request(self) -> None:
+        container = RustContainer()
+        container.register_thread_factory(Session, Session)
+        session = container.resolve(Session)
+
+        assert container.release_thread_instances() == 1
+        assert container.release_thread_instances() == 0
+        assert container.resolve(Session) is not session
+
+    def it_evicts_every_threads_instance_when_rebound(self) -> None:
+        container = RustContainer()
+        container.register_thread_factory(Session, Session)
+        session = container.resolve(Session)
+
+        container.rebind_factory(Session, Session)
+
+        assert container.resolve(Session) is not session
+
+    def it_supports_the_thread_scope_in_the_builder(self) -> None:
+        container = RustContainer()
+        container.register(Session).as_factory(Session).scoped('thread').done()
+
+        assert container.resolve(Session) is container.resolve(Session)
+        registration = json.loads(container.to_json())['registrations'][0]
+        assert (registration['provider'], registration['lifetime']) == ('thread_factory', 'thread')
+
+    def it_rejects_the_thread_lifetime_for_async_factories(self) -> None:
+        container = RustContainer()
+
+        async def make_session() -> Session:
+            return Session()
+
+        with pytest.raises(KeyError, match='thread lifetime'):
+            container.register_async_factory(Session, make_session, lifetime='thread')
@@ -0,0 +1,61 @@
+"""Tests for exporting the dependency graph as Graphviz DOT."""
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Request:
+    def __init__(self, database: Database, config: Config, retries: int = 3) -> None:
+        self.database = database
+        self.config = config
+        self.retries = retries
+
+
+class DescribeToDot:
+    """to_dot() renders registrations and constructor dependencies."""
+
+    def it_renders_nodes_with_lifetimes_and_dependency_edges(self) -> None:
+        container = RustContainer()
+        container.register_class(Request, Request, lifetime='transient')
+        container.register_class(Database, Database)
+        container.register_tenant_factory(Config, Config)
+
+        assert container.to_dot() == (
+            'digraph dioxide {\n'
+            '    "Config" [label="Config\\n(tenant)"];\n'
+            '    "Database" [label="Database\\n(singleton)"];\n'
+            '    "Request" [label="Request\\n(transient)"];\n'
+            '    "Database" -> "Config";\n'
+            '    "Request" -> "Config";\n'
+            '    "Request" -> "Database";\n'
+            '}\n'
+        )
+
+    def it_omits_edges_to_unregistered_parameters(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+
+        assert '->' not in container.to_dot()
+
+    def it_renders_fallback_dependencies_as_dashed_nodes(self) -> None:
+        base = RustContainer()
+        base.register_instance(Config, Config())
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.add_fallback_container(base)
+
+        dot = container.to_dot()
+
+        assert '"Config" [label="Config\\n(fallback)", style=dashed];' in dot
+        assert '"Database" -> "Config";' in dot
+
+    def it_renders_an_empty_container(self) -> None:
+        assert RustContainer().to_dot() == 'digraph dioxide {\n}\n'
@@ -0,0 +1,83 @@
+"""Tests for exporting registrations and dependency edges as JSON."""
+
+import json
+from enum import Enum
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class Region(Enum):
+    EU = 'eu'
+
+
+class DescribeToJson:
+    """to_json() describes every binding and auto-wiring edge."""
+
+    def it_describes_registrations_and_edges(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_class(Database, Database, name='replica', lifetime='transient')
+        container.register_tenant_factory(Config, Config)
+
+        document = json.loads(container.to_json())
+
+        assert document == {
+            'registrations': [
+                {
+                    'key': 'Config',
+                    'type': 'Config',
+                    'module': __name__,
+                    'name': None,
+                    'provider': 'tenant_factory',
+                    'lifetime': 'tenant',
+                },
+                {
+                    'key': 'Database',
+                    'type': 'Database',
+                    'module': __name__,
+                    'name': None,
+                    'provider': 'class',
+                    'lifetime': 'singleton',
+                },
+                {
+                    'key': 'Database[name=replica]',
+                    'type': 'Database',
+                    'module': __name__,
+                    'name': 'replica',
+                    'provider': 'transient_class',
+                    'lifetime': 'transient',
+                },
+            ],
+            'edges': [
+                {'from': 'Database', 'to': 'Config'},
+                {'from': 'Database[name=replica]', 'to': 'Config'},
+            ],
+        }
+
+    def it_describes_enum_member_keys(self) -> None:
+        container = RustContainer()
+        container.register_instance(Region.EU, 'eu-west-1')
+
+        [registration] = json.loads(container.to_json())['registrations']
+
+        assert registration['key'] == 'Region.EU'
+        assert registration['module'] == __name__
+        assert registration['provider'] == 'instance'
+
+    def it_indents_when_asked(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+
+        assert container.to_json(indent=2).startswith('{\n  "registrations": [\n')
+
+    def it_describes_an_empty_container(self) -> None:
+        assert json.loads(RustContainer().to_json()) == {'registrations': [], 'edges': []}
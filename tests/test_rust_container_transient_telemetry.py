@@ -0,0 +1,100 @@
+"""Tests for transient allocation rate telemetry."""
+
+import time
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Request:
+    pass
+
+
+class Handler:
+    pass
+
+
+class Database:
+    pass
+
+
+class DescribeTransientAllocationRates:
+    """transient_allocation_rates() and stats() report constructions per second per key."""
+
+    def it_is_empty_while_telemetry_is_disabled(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+
+        container.resolve(Request)
+
+        assert container.transient_allocation_rates() == {}
+
+    def it_reflects_a_burst_of_transient_resolves(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+        container.register_transient_factory(Handler, Handler)
+        container.enable_transient_telemetry(window_ms=10_000)
+
+        for _ in range(50):
+            container.resolve(Request)
+        for _ in range(20):
+            container.resolve(Handler)
+
+        rates = container.transient_allocation_rates()
+        assert rates[Request] == pytest.approx(5.0)
+        assert rates[Handler] == pytest.approx(2.0)
+
+    def it_ignores_singletons(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+        container.register_instance(Request, Request())
+        container.enable_transient_telemetry()
+
+        container.resolve(Database)
+        container.resolve(Request)
+
+        assert container.transient_allocation_rates() == {}
+
+    def it_drops_constructions_that_leave_the_window(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+        container.enable_transient_telemetry(window_ms=50)
+
+        for _ in range(10):
+            container.resolve(Request)
+        time.sleep(0.1)
+
+        assert container.transient_allocation_rates() == {}
+
+    def it_discards_counts_when_disabled(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+        container.enable_transient_telemetry()
+        container.resolve(Request)
+
+        container.disable_transient_telemetry()
+
+        assert container.transient_allocation_rates() == {}
+
+    def it_rejects_an_empty_window(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(ValueError, match='window_ms must be positive'):
+            container.enable_transient_telemetry(window_ms=0)
+
+    def it_reports_rates_through_stats_while_enabled(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Request, Request)
+
+        assert 'transient_allocation_rates' not in container.stats()
+
+        container.enable_transient_telemetry(window_ms=10_000)
+        for _ in range(30):
+            container.resolve(Request)
+
+        assert container.stats()['transient_allocation_rates'][Request] == pytest.approx(3.0)
+
+        container.disable_transient_telemetry()
+
+        assert 'transient_allocation_rates' not in container.stats()
@@ -0,0 +1,58 @@
+"""Tests for probing optional keys with try_resolve()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import UnresolvableParameterError
+
+
+class Config:
+    pass
+
+
+class Plugin:
+    pass
+
+
+class Repository:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class DescribeTryResolve:
+    """try_resolve() returns None for keys nothing provides."""
+
+    def it_returns_the_instance_of_a_registered_key(self) -> None:
+        container = RustContainer()
+        container.register_class(Config, Config)
+
+        assert container.try_resolve(Config) is container.resolve(Config)
+
+    def it_returns_none_for_an_unregistered_key(self) -> None:
+        assert RustContainer().try_resolve(Plugin) is None
+
+    def it_returns_none_for_an_unregistered_name(self) -> None:
+        container = RustContainer()
+        container.register_class(Plugin, Plugin)
+
+        assert container.try_resolve(Plugin, name='beta') is None
+
+    def it_resolves_from_a_fallback(self) -> None:
+        base = RustContainer()
+        base.register_class(Plugin, Plugin)
+        container = RustContainer()
+        container.add_fallback_container(base)
+
+        assert container.try_resolve(Plugin) is base.resolve(Plugin)
+
+    def it_builds_keys_auto_wiring_provides(self) -> None:
+        container = RustContainer(autowire=True)
+
+        assert isinstance(container.try_resolve(Plugin), Plugin)
+
+    def it_raises_when_a_provided_key_fails(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Repository, Repository)
+
+        with pytest.raises(UnresolvableParameterError, match="parameter 'config'"):
+            container.try_resolve(Repository)
@@ -0,0 +1,184 @@
+"""Tests for the opt-in registration type check in the Rust container."""
+
+from abc import ABC, abstractmethod
+from enum import Enum
+from typing import Protocol
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class UserService:
+    pass
+
+
+class OrderService:
+    pass
+
+
+class PaymentGateway(ABC):
+    @abstractmethod
+    def charge(self, amount: int) -> None: ...
+
+
+class StripeGateway(PaymentGateway):
+    def charge(self, amount: int) -> None:
+        pass
+
+
+class LegacyGateway:
+    def charge(self, amount: int) -> None:
+        pass
+
+
+class EmailSender(Protocol):
+    def send(self, to: str, body: str) -> None: ...
+
+
+class SmtpSender:
+    def send(self, to: str, body: str) -> None:
+        pass
+
+
+class PartialGateway(PaymentGateway):
+    def refund(self) -> None:
+        pass
+
+
+class MisspelledSender:
+    def send(self, recipient: str, body: str) -> None:
+        pass
+
+
+class VerboseSender:
+    def send(self, to: str, body: str, *, retries: int = 3, **headers: str) -> None:
+        pass
+
+
+class NeedyGateway(PaymentGateway):
+    def charge(self, amount: int, currency: str) -> None:
+        pass
+
+
+class Region(Enum):
+    EU = 'eu'
+
+
+class DescribeRegisterClassTypeCheck:
+    """register_class(..., type_check=True) rejects unrelated classes."""
+
+    def it_accepts_the_same_class(self) -> None:
+        container = RustContainer()
+
+        container.register_class(UserService, UserService, type_check=True)
+
+        assert container.contains(UserService)
+
+    def it_accepts_interface_to_implementation_bindings(self) -> None:
+        container = RustContainer()
+
+        container.register_class(PaymentGateway, StripeGateway, type_check=True)
+
+        assert isinstance(container.resolve(PaymentGateway), StripeGateway)
+
+    def it_accepts_abc_virtual_subclasses(self) -> None:
+        container = RustContainer()
+        PaymentGateway.register(LegacyGateway)
+
+        container.register_class(PaymentGateway, LegacyGateway, type_check=True)
+
+        assert container.contains(PaymentGateway)
+
+    def it_accepts_structural_protocol_implementations(self) -> None:
+        container = RustContainer()
+
+        container.register_class(EmailSender, SmtpSender, type_check=True)
+
+        assert container.contains(EmailSender)
+
+    def it_rejects_unrelated_classes(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='OrderService is not a subclass of UserService'):
+            container.register_class(UserService, OrderService, type_check=True)
+
+        assert not container.contains(UserService)
+
+    def it_rejects_classes_missing_protocol_methods(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='Provider registration failed: EmailSender'):
+            container.register_class(EmailSender, UserService, type_check=True)
+
+    def it_skips_the_check_by_default(self) -> None:
+        container = RustContainer()
+
+        container.register_class(UserService, OrderService)
+
+        assert container.contains(UserService)
+
+
+class DescribeRegisterClassStrictInterfaceCheck:
+    """register_class(..., strict=True) requires the interface to be implemented in full."""
+
+    def it_accepts_complete_implementations(self) -> None:
+        container = RustContainer()
+
+        container.register_class(PaymentGateway, StripeGateway, strict=True)
+        container.register_class(EmailSender, SmtpSender, strict=True)
+
+        assert isinstance(container.resolve(EmailSender), SmtpSender)
+
+    def it_accepts_signatures_that_take_extra_optional_arguments(self) -> None:
+        container = RustContainer()
+
+        container.register_class(EmailSender, VerboseSender, strict=True)
+
+        assert container.contains(EmailSender)
+
+    def it_rejects_abstract_implementations(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match=r'PartialGateway is abstract \(unimplemented: charge\)'):
+            container.register_class(PaymentGateway, PartialGateway, strict=True)
+
+        assert not container.contains(PaymentGateway)
+
+    def it_rejects_methods_with_incompatible_signatures(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='NeedyGateway.charge.*does not match PaymentGateway.charge'):
+            container.register_class(PaymentGateway, NeedyGateway, strict=True)
+        with pytest.raises(KeyError, match='MisspelledSender.send.*does not match EmailSender.send'):
+            container.register_class(EmailSender, MisspelledSender, strict=True)
+
+    def it_still_rejects_unrelated_classes(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='OrderService is not a subclass of UserService'):
+            container.register_class(UserService, OrderService, strict=True)
+
+
+class DescribeRegisterInstanceTypeCheck:
+    """register_instance(..., type_check=True) rejects unrelated instances."""
+
+    def it_accepts_instances_of_the_key_type(self) -> None:
+        container = RustContainer()
+
+        container.register_instance(PaymentGateway, StripeGateway(), type_check=True)
+
+        assert container.contains(PaymentGateway)
+
+    def it_rejects_instances_of_unrelated_types(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='instance of OrderService is not an instance of UserService'):
+            container.register_instance(UserService, OrderService(), type_check=True)
+
+    def it_does_not_check_enum_member_keys(self) -> None:
+        container = RustContainer()
+
+        container.register_instance(Region.EU, OrderService(), type_check=True)
+
+        assert container.contains(Region.EU)
@@ -0,0 +1,106 @@
+"""Tests for removing bindings with unregister(), clear() and clear_singletons()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DependencyNotRegisteredError
+
+
+class Database:
+    pass
+
+
+class FakeDatabase(Database):
+    pass
+
+
+class Cache:
+    pass
+
+
+class DescribeUnregister:
+    """unregister() removes a binding and what it cached."""
+
+    def it_removes_the_binding(self) -> None:
+        container = RustContainer()
+        container.register_class(Database, Database)
+        container.register_class(Cache, Cache)
+
+        container.unregister(Database)
+
+        assert not container.contains(Database)
+        assert container.contains(Cache)
+        with pytest.raises(DependencyNotRegisteredError):
+            container.resolve(Database)
+
+    def it_evicts_the_cached_singleton(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+        container.resolve(Database)
+
+        container.unregister(Database)
+        container.register_singleton_factory(Database, FakeDatabase)
+
+        assert isinstance(container.resolve(Database), FakeDatabase)
+
+    def it_evicts_tenant_instances(self) -> None:
+        container = RustContainer()
+        container.register_tenant_factory(Database, Database)
+        original = container.resolve(Database, tenant='acme')
+
+        container.unregister(Database)
+        container.register_tenant_factory(Database, Database)
+
+        assert container.resolve(Database, tenant='acme') is not original
+
+    def it_drops_decorators_of_the_key(self) -> None:
+        container = RustContainer()
+        container.register_transient_factory(Database, Database)
+        container.add_decorator(Database, lambda database: FakeDatabase())
+
+        container.unregister(Database)
+        container.register_transient_factory(Database, Database)
+
+        assert type(container.resolve(Database)) is Database
+
+    def it_rejects_unregistered_keys(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(DependencyNotRegisteredError):
+            container.unregister(Database)
+
+
+class DescribeClear:
+    """clear() wipes every binding; clear_singletons() only the caches."""
+
+    def it_removes_every_binding(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+        container.register_instance(Cache, Cache())
+        container.resolve(Database)
+
+        container.clear()
+
+        assert container.is_empty()
+        container.register_singleton_factory(Database, Database)
+        assert container.resolve_verbose(Database)[1]['cache_hit'] is False
+
+    def it_keeps_fallback_containers(self) -> None:
+        base = RustContainer()
+        base.register_class(Cache, Cache)
+        container = RustContainer()
+        container.add_fallback_container(base)
+
+        container.clear()
+
+        assert isinstance(container.resolve(Cache), Cache)
+
+    def it_clears_singletons_but_keeps_bindings(self) -> None:
+        container = RustContainer()
+        container.register_singleton_factory(Database, Database)
+        original = container.resolve(Database)
+
+        container.clear_singletons()
+
+        assert container.contains(Database)
+        assert container.resolve(Database) is not original
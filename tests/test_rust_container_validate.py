@@ -0,0 +1,130 @@
+"""Tests for statically validating the registry with validate()."""
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import DioxideError, ValidationError
+
+
+class Config:
+    pass
+
+
+class Database:
+    def __init__(self, config: Config) -> None:
+        self.config = config
+
+
+class PostgresDatabase(Database):
+    pass
+
+
+class SqliteDatabase(Database):
+    pass
+
+
+class Repository:
+    def __init__(self, database: Database) -> None:
+        self.database = database
+
+
+class Mailer:
+    def __init__(self, host) -> None:  # type: ignore[no-untyped-def]
+        self.host = host
+
+
+class Cache:
+    def __init__(self, config: Config, ttl: int = 30) -> None:
+        self.config = config
+        self.ttl = ttl
+
+
+class Left:
+    def __init__(self, right: 'Right') -> None:
+        self.right = right
+
+
+class Right:
+    def __init__(self, left: Left) -> None:
+        self.left = left
+
+
+class DescribeValidate:
+    """validate() checks every registration is resolvable without constructing."""
+
+    def it_passes_for_a_resolvable_graph(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Database, PostgresDatabase)
+        container.register_class(Config, Config)
+        container.register_class(Cache, Cache)
+
+        container.validate()
+
+        assert container.construction_log() == []
+
+    def it_reports_every_missing_dependency_at_once(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Cache, Cache)
+        container.register_class(Mailer, Mailer)
+
+        with pytest.raises(ValidationError) as excinfo:
+            container.validate()
+
+        assert excinfo.value.problems == [
+            "Cache: parameter 'config' needs Config, which is not registered",
+            "Mailer: parameter 'host' has no type annotation",
+            "Repository: parameter 'database' needs Database, which is not registered",
+        ]
+
+    def it_reports_ambiguous_bindings(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(PostgresDatabase, PostgresDatabase)
+        container.register_class(SqliteDatabase, SqliteDatabase)
+        container.register_class(Config, Config)
+
+        with pytest.raises(ValidationError) as excinfo:
+            container.validate()
+
+        assert excinfo.value.problems == [
+            "Repository: parameter 'database' is ambiguous: Database is not registered "
+            'but PostgresDatabase, SqliteDatabase could provide it'
+        ]
+
+    def it_reports_named_bindings_as_ambiguous(self) -> None:
+        container = RustContainer()
+        container.register_class(Repository, Repository)
+        container.register_class(Database, PostgresDatabase, name='primary')
+        container.register_class(Database, SqliteDatabase, name='replica')
+        container.register_class(Config, Config)
+
+        with pytest.raises(ValidationError, match=r'Database\[name=primary\], Database\[name=replica\]'):
+            container.validate()
+
+    def it_reports_cycles_once(self) -> None:
+        container = RustContainer()
+        container.register_class(Right, Right)
+        container.register_class(Left, Left)
+
+        with pytest.raises(ValidationError) as excinfo:
+            container.validate()
+
+        assert excinfo.value.problems == ['circular dependency: Left -> Right -> Left']
+
+    def it_accepts_dependencies_from_fallback_containers(self) -> None:
+        base = RustContainer()
+        base.register_class(Config, Config)
+        container = RustContainer()
+        container.register_class(Cache, Cache)
+        container.add_fallback_container(base)
+
+        container.validate()
+
+    def it_raises_a_dioxide_error(self) -> None:
+        container = RustContainer()
+        container.register_class(Cache, Cache)
+
+        with pytest.raises(DioxideError, match='Container validation failed'):
+            container.validate()
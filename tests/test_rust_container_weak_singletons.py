@@ -0,0 +1,99 @@
+"""Tests for singletons cached through weak references with cache='weak'."""
+
+import gc
+import weakref
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+
+
+class Model:
+    pass
+
+
+class Service:
+    def __init__(self, model: Model) -> None:
+        self.model = model
+
+
+class DescribeWeakSingletons:
+    """A weakly cached singleton is shared while referenced and rebuilt once collected."""
+
+    def it_shares_the_instance_while_it_is_referenced(self) -> None:
+        container = RustContainer()
+        container.register_class(Model, Model, cache='weak')
+
+        model = container.resolve(Model)
+
+        assert container.resolve(Model) is model
+
+    def it_does_not_keep_the_instance_alive(self) -> None:
+        container = RustContainer()
+        container.register_class(Model, Model, cache='weak')
+        reference = weakref.ref(container.resolve(Model))
+
+        gc.collect()
+
+        assert reference() is None
+
+    def it_rebuilds_the_instance_after_collection(self) -> None:
+        container = RustContainer()
+        container.register_class(Model, Model, cache='weak')
+        first = container.resolve(Model)
+        first.marker = 'first'
+        del first
+        gc.collect()
+
+        second = container.resolve(Model)
+
+        assert not hasattr(second, 'marker')
+        assert container.resolve(Model) is second
+
+    def it_caches_factory_results_weakly(self) -> None:
+        container = RustContainer()
+        calls = []
+
+        def build() -> Model:
+            calls.append(1)
+            return Model()
+
+        container.register_singleton_factory(Model, build, cache='weak')
+        model = container.resolve(Model)
+        assert container.resolve(Model) is model
+        del model
+        gc.collect()
+
+        container.resolve(Model)
+
+        assert len(calls) == 2
+
+    def it_injects_the_weak_singleton_into_dependents(self) -> None:
+        container = RustContainer()
+        container.register_class(Model, Model, cache='weak')
+        container.register_transient_factory(Service, Service)
+
+        service = container.resolve(Service)
+
+        assert service.model is container.resolve(Model)
+
+    def it_keeps_strong_caching_by_default(self) -> None:
+        container = RustContainer()
+        container.register_class(Model, Model, cache='strong')
+        reference = weakref.ref(container.resolve(Model))
+
+        gc.collect()
+
+        assert reference() is container.resolve(Model)
+
+    def it_rejects_weak_caching_for_other_lifetimes(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(KeyError, match='weak caching requires the singleton lifetime'):
+            container.register_class(Model, Model, lifetime='transient', cache='weak')
+
+    def it_rejects_unknown_cache_modes(self) -> None:
+        container = RustContainer()
+
+        with pytest.raises(TypeError, match='Unknown cache mode'):
+            container.register_class(Model, Model, cache='soft')
@@ -0,0 +1,185 @@
+"""Tests for wiring containers from TOML and YAML files with from_config()."""
+
+import importlib
+import textwrap
+from pathlib import Path
+from types import ModuleType
+
+import pytest
+
+from dioxide._dioxide_core import Container as RustContainer
+from dioxide._dioxide_core import WiringError
+
+TARGETS = 'tests.fixtures.wiring_targets'
+
+
+def targets() -> ModuleType:
+    # Fixture modules are reloaded for every test, so look them up afresh
+    return importlib.import_module(TARGETS)
+
+
+def write(tmp_path: Path, name: str, content: str) -> Path:
+    path = tmp_path / name
+    path.write_text(textwrap.dedent(content))
+    return path
+
+
+class DescribeFromConfig:
+    """from_config() applies every binding of a wiring file."""
+
+    def it_binds_interfaces_to_implementations(self, tmp_path: Path) -> None:
+        path = write(
+            tmp_path,
+            'wiring.toml',
+            f"""
+            [[bindings]]
+            interface = "{TARGETS}:Mailer"
+            implementation = "{TARGETS}:SmtpMailer"
+
+            [[bindings]]
+            interface = "{TARGETS}.Clock"
+            implementation = "{TARGETS}.make_clock"
+            lifetime = "transient"
+            """,
+        )
+
+        container = RustContainer.from_config(path)
+
+        wired = targets()
+        assert isinstance(container.resolve(wired.Mailer), wired.SmtpMailer)
+        assert container.resolve(wired.Mailer) is container.resolve(wired.Mailer)
+        assert container.resolve(wired.Clock) is not container.resolve(wired.Clock)
+
+    def it_applies_qualifiers_and_profiles(self, tmp_path: Path) -> None:
+        path = write(
+            tmp_path,
+            'wiring.toml',
+            f"""
+            [[bindings]]
+            interface = "{TARGETS}:Mailer"
+            implementation = "{TARGETS}:SmtpMailer"
+            profile = "production"
+
+            [[bindings]]
+            interface = "{TARGETS}:Mailer"
+            implementation = "{TARGETS}:FakeMailer"
+            profile = "test"
+
+            [[bindings]]
+            interface = "{TARGETS}:Mailer"
+            implementation = "{TARGETS}:FakeMailer"
+            name = "audit"
+            """,
+        )
+
+        container = RustContainer.from_config(path, profile='test')
+
+        wired = targets()
+        assert isinstance(container.resolve(wired.Mailer), wired.FakeMailer)
+        assert isinstance(container.resolve(wired.Mailer, name='audit'), wired.FakeMailer)
+
+    def it_reads_yaml_files(self, tmp_path: Path) -> None:
+        pytest.importorskip('yaml')
+        path = write(
+            tmp_path,
+            'wiring.yaml',
+            f"""
+            bindings:
+              - interface: {TARGETS}:Mailer
+                implementation: {TARGETS}:SmtpMailer
+                lifetime: singleton
+            """,
+        )
+
+        container = RustContainer.from_config(str(path))
+
+        wired = targets()
+        assert isinstance(container.resolve(wired.Mailer), wired.SmtpMailer)
+
+    def it_loads_into_an_existing_container(self, tmp_path: Path) -> None:
+        path = write(
+            tmp_path,
+            'wiring.toml',
+            f"""
+            [[bindings]]
+            interface = "{TARGETS}:Mailer"
+            implementation = "{TARGETS}:SmtpMailer"
+            """,
+        )
+        container = RustContainer()
+
+        assert container.load_config(path) == [targets().Mailer]
+
+
+class DescribeWiringErrors:
+    """Invalid wiring files are rejected whole, naming the offending entry."""
+
+    def it_names_an_unimportable_implementation(self, tmp_path: Path) -> None:
+        path = write(
+            tmp_path,
+            'wiring.toml',
+            f"""
+            [[bindings]]
+            interface = "{TARGETS}:Clock"
+            implementation = "{TARGETS}:make_clock"
+
+            [[bindings]]
+            interface = "{TARGETS}:Mailer"
+            implementation = "{TARGETS}:MissingMailer"
+            """,
+        )
+        container = RustContainer()
+
+        with pytest.raises(WiringError, match=r'at bindings\[1\]\.implementation: cannot import'):
+            container.load_config(path)
+
+        assert container.contains(targets().Clock) is False
+
+    def it_rejects_unknown_fields_and_lifetimes(self, tmp_path: Path) -> None:
+        unknown_field = write(
+            tmp_path,
+            'field.toml',
+            f"""
+            [[bindings]]
+            interface = "{TARGETS}:Clock"
+            implementation = "{TARGETS}:Clock"
+            scope = "singleton"
+            """,
+        )
+        unknown_lifetime = write(
+            tmp_path,
+            'lifetime.toml',
+            f"""
+            [[bindings]]
+            interface = "{TARGETS}:Clock"
+            implementation = "{TARGETS}:Clock"
+            lifetime = "forever"
+            """,
+        )
+
+        with pytest.raises(WiringError, match=r'at bindings\[0\]\.scope: unknown field'):
+            RustContainer.from_config(unknown_field)
+        with pytest.raises(WiringError, match=r'at bindings\[0\]\.lifetime: Unknown scope'):
+            RustContainer.from_config(unknown_lifetime)
+
+    def it_rejects_missing_fields_and_non_callables(self, tmp_path: Path) -> None:
+        missing = write(tmp_path, 'missing.toml', f'[[bindings]]\ninterface = "{TARGETS}:Clock"\n')
+        constant = write(
+            tmp_path,
+            'constant.toml',
+            f'[[bindings]]\ninterface = "{TARGETS}:Clock"\nimplementation = "{TARGETS}:NOT_CALLABLE"\n',
+        )
+
+        with pytest.raises(WiringError, match=r'at bindings\[0\]: missing implementation'):
+            RustContainer.from_config(missing)
+        with pytest.raises(WiringError, match='expected a class or a factory'):
+            RustContainer.from_config(constant)
+
+    def it_reports_syntax_errors_and_unknown_formats(self, tmp_path: Path) -> None:
+        broken = write(tmp_path, 'broken.toml', '[[bindings]\n')
+        ini = write(tmp_path, 'wiring.ini', '')
+
+        with pytest.raises(WiringError, match='broken.toml'):
+            RustContainer.from_config(broken)
+        with pytest.raises(WiringError, match='expected a .toml, .yaml or .yml file'):
+            RustContainer.from_config(ini)
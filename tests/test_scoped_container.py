@@ -233,6 +233,94 @@ class DescribeRequestScopeOutsideScope:
         assert 'scope' in error_message.lower()
 
 
+class DescribeResolveWithExplicitScope:
+    """Tests for container.resolve(component_type, scope=handle)."""
+
+    @pytest.mark.asyncio
+    async def it_resolves_request_scoped_component_into_the_given_scope(self) -> None:
+        """REQUEST-scoped components are cached in the scope handle passed to resolve()."""
+
+        @service(scope=Scope.REQUEST)
+        class RequestContext:
+            pass
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as scope:
+            ctx = container.resolve(RequestContext, scope=scope)
+
+            assert ctx is scope.resolve(RequestContext)
+
+    @pytest.mark.asyncio
+    async def it_isolates_the_given_scope_from_the_current_scope(self) -> None:
+        """Resolving on behalf of another scope leaves the caller's scope untouched."""
+
+        @service(scope=Scope.REQUEST)
+        class RequestContext:
+            pass
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as other:
+            async with container.create_scope() as current:
+                on_behalf = container.resolve(RequestContext, scope=other)
+                own = current.resolve(RequestContext)
+
+                assert on_behalf is not own
+                assert on_behalf is other.resolve(RequestContext)
+
+    @pytest.mark.asyncio
+    async def it_delegates_singletons_to_the_container(self) -> None:
+        """SINGLETON components resolve to the shared instance regardless of the scope handle."""
+
+        @service
+        class Config:
+            pass
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as scope:
+            assert container.resolve(Config, scope=scope) is container.resolve(Config)
+
+    @pytest.mark.asyncio
+    async def it_raises_scope_error_for_a_closed_scope(self) -> None:
+        """A scope handle cannot be used after its context has exited."""
+
+        @service(scope=Scope.REQUEST)
+        class RequestContext:
+            pass
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as scope:
+            pass
+
+        assert scope.closed
+        with pytest.raises(ScopeError, match=f'scope {scope.scope_id} is closed'):
+            container.resolve(RequestContext, scope=scope)
+
+    @pytest.mark.asyncio
+    async def it_raises_scope_error_for_a_scope_of_another_container(self) -> None:
+        """A scope handle only works with the container that created it."""
+
+        @service(scope=Scope.REQUEST)
+        class RequestContext:
+            pass
+
+        container = Container()
+        container.scan()
+        other_container = Container()
+        other_container.scan()
+
+        async with other_container.create_scope() as scope:
+            with pytest.raises(ScopeError, match='belongs to another container'):
+                container.resolve(RequestContext, scope=scope)
+
+
 class DescribeCaptiveDependency:
     """Tests for captive dependency detection (SINGLETON depends on REQUEST)."""
 
@@ -425,6 +513,160 @@ class DescribeScopedLifecycle:
         assert 'GlobalDatabase' in disposed
 
 
+class DescribeScopedContextManagers:
+    """Tests for entering enter_context=True components on resolve and exiting them at scope close."""
+
+    @pytest.mark.asyncio
+    async def it_enters_on_resolve_and_exits_at_scope_close(self) -> None:
+        """A REQUEST-scoped context manager is entered once and exited when the scope exits."""
+        events: list[str] = []
+
+        @service(scope=Scope.REQUEST, enter_context=True)
+        class Transaction:
+            def __enter__(self) -> 'Transaction':
+                events.append('enter')
+                return self
+
+            def __exit__(self, *exc_info: object) -> None:
+                events.append('exit')
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as scope:
+            tx = scope.resolve(Transaction)
+            assert scope.resolve(Transaction) is tx
+            assert events == ['enter']
+
+        assert events == ['enter', 'exit']
+
+    @pytest.mark.asyncio
+    async def it_leaves_context_managers_alone_without_the_flag(self) -> None:
+        """Entering is opt-in per registration."""
+        events: list[str] = []
+
+        @service(scope=Scope.REQUEST)
+        class Transaction:
+            def __enter__(self) -> 'Transaction':
+                events.append('enter')
+                return self
+
+            def __exit__(self, *exc_info: object) -> None:
+                events.append('exit')
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as scope:
+            scope.resolve(Transaction)
+
+        assert events == []
+
+    @pytest.mark.asyncio
+    async def it_enters_every_factory_instance(self) -> None:
+        """Each FACTORY instance created in the scope is entered and exited in reverse order."""
+        events: list[str] = []
+
+        @service(scope=Scope.FACTORY, enter_context=True)
+        class Cursor:
+            def __init__(self) -> None:
+                self.name = f'cursor{len(events)}'
+
+            def __enter__(self) -> 'Cursor':
+                events.append(f'enter {self.name}')
+                return self
+
+            def __exit__(self, *exc_info: object) -> None:
+                events.append(f'exit {self.name}')
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as scope:
+            scope.resolve(Cursor)
+            scope.resolve(Cursor)
+
+        assert events == ['enter cursor0', 'enter cursor1', 'exit cursor1', 'exit cursor0']
+
+    @pytest.mark.asyncio
+    async def it_passes_the_scope_exception_to_exit(self) -> None:
+        """The exception that ended the scope reaches __exit__ so transactions can roll back."""
+        received: list[type[BaseException] | None] = []
+
+        @service(scope=Scope.REQUEST, enter_context=True)
+        class Transaction:
+            def __enter__(self) -> 'Transaction':
+                return self
+
+            def __exit__(self, exc_type: type[BaseException] | None, *rest: object) -> None:
+                received.append(exc_type)
+
+        container = Container()
+        container.scan()
+
+        with pytest.raises(RuntimeError):
+            async with container.create_scope() as scope:
+                scope.resolve(Transaction)
+                raise RuntimeError('request failed')
+
+        assert received == [RuntimeError]
+
+    @pytest.mark.asyncio
+    async def it_enters_async_context_managers_with_aresolve(self) -> None:
+        """Async context managers are awaited in dependency order by aresolve() and exited at scope close."""
+        events: list[str] = []
+
+        @service(scope=Scope.REQUEST, enter_context=True)
+        class Connection:
+            async def __aenter__(self) -> 'Connection':
+                events.append('enter connection')
+                return self
+
+            async def __aexit__(self, *exc_info: object) -> None:
+                events.append('exit connection')
+
+        @service(scope=Scope.REQUEST, enter_context=True)
+        class Transaction:
+            def __init__(self, connection: Connection) -> None:
+                self.connection = connection
+
+            async def __aenter__(self) -> 'Transaction':
+                events.append('enter transaction')
+                return self
+
+            async def __aexit__(self, *exc_info: object) -> None:
+                events.append('exit transaction')
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as scope:
+            tx = await scope.aresolve(Transaction)
+            assert tx.connection is scope.resolve(Connection)
+            assert events == ['enter connection', 'enter transaction']
+
+        assert events == ['enter connection', 'enter transaction', 'exit transaction', 'exit connection']
+
+    @pytest.mark.asyncio
+    async def it_raises_scope_error_for_async_context_managers_resolved_synchronously(self) -> None:
+        """An async context manager cannot be entered by the synchronous resolve()."""
+
+        @service(scope=Scope.REQUEST, enter_context=True)
+        class Transaction:
+            async def __aenter__(self) -> 'Transaction':
+                return self
+
+            async def __aexit__(self, *exc_info: object) -> None:
+                pass
+
+        container = Container()
+        container.scan()
+
+        async with container.create_scope() as scope:
+            with pytest.raises(ScopeError, match=r'use await scope\.aresolve\(\)'):
+                scope.resolve(Transaction)
+
+
 class DescribeAdapterWithRequestScope:
     """Tests for adapters with REQUEST scope."""
 